@@ -0,0 +1,103 @@
+use crate::model::alert::{AlertChannelConfig, AlertConfig, AlertRecord};
+use crate::model::api_response::ApiResponse;
+use crate::service::alert_service::{build_channel, sample_event, send_with_retries, AlertService};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// 조회 개수를 지정하지 않았을 때 반환하는 전송 기록 수
+const DEFAULT_HISTORY_LIMIT: usize = 200;
+
+#[tauri::command]
+pub fn get_alert_config(
+    alert_service: tauri::State<'_, Arc<AlertService>>,
+) -> Result<ApiResponse<AlertConfig>, String> {
+    let config = alert_service.get_config();
+    Ok(ApiResponse {
+        message: format!(
+            "알림 규칙 {}개, 채널 {}개 조회 완료",
+            config.rules.len(),
+            config.channels.len()
+        ),
+        success: true,
+        data: config,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAlertConfigPayload {
+    pub config: AlertConfig,
+}
+
+/// 알림 규칙·채널 전체 저장. id가 0인 항목은 새로 추가 (저장된 설정에 id가 채워져 반환됨).
+#[tauri::command]
+pub fn set_alert_config(
+    payload: SetAlertConfigPayload,
+    alert_service: tauri::State<'_, Arc<AlertService>>,
+) -> Result<ApiResponse<AlertConfig>, String> {
+    let channel_ids: Vec<u32> = payload.config.channels.iter().map(|c| c.id).collect();
+    if let Some(rule) = payload.config.rules.iter().find(|r| {
+        r.channel_ids
+            .iter()
+            .any(|id| *id == 0 || !channel_ids.contains(id))
+    }) {
+        return Ok(ApiResponse {
+            message: format!("규칙 '{}'에 없는 채널이 지정되어 있습니다.", rule.name),
+            success: false,
+            data: alert_service.get_config(),
+        });
+    }
+    let config = alert_service.set_config(payload.config);
+    Ok(ApiResponse {
+        message: "알림 설정 저장 완료".to_string(),
+        success: true,
+        data: config,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAlertHistoryPayload {
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// 최신 전송 기록부터 (채널별 성공·실패 포함)
+#[tauri::command]
+pub fn get_alert_history(
+    payload: GetAlertHistoryPayload,
+    alert_service: tauri::State<'_, Arc<AlertService>>,
+) -> Result<ApiResponse<Vec<AlertRecord>>, String> {
+    let list = alert_service.history(payload.limit.unwrap_or(DEFAULT_HISTORY_LIMIT));
+    Ok(ApiResponse {
+        message: format!("{}건의 알림 기록 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTestAlertPayload {
+    /// 저장 전 설정도 시험할 수 있도록 채널 설정을 그대로 받음
+    pub channel: AlertChannelConfig,
+}
+
+/// 채널로 예시 알림을 보냄 (재시도 포함, 전송 기록에는 남기지 않음).
+#[tauri::command]
+pub async fn send_test_alert(payload: SendTestAlertPayload) -> Result<ApiResponse<bool>, String> {
+    let channel = build_channel(&payload.channel.kind);
+    let (result, attempts) = send_with_retries(channel.as_ref(), &sample_event(Utc::now())).await;
+    Ok(match result {
+        Ok(()) => ApiResponse {
+            message: format!("'{}' 채널로 테스트 메시지 전송 완료", payload.channel.name),
+            success: true,
+            data: true,
+        },
+        Err(e) => ApiResponse {
+            message: format!("테스트 메시지 전송 실패 ({attempts}회 시도): {e}"),
+            success: false,
+            data: false,
+        },
+    })
+}
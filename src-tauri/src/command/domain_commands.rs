@@ -1,3 +1,4 @@
+use crate::command::domain_monitor_command::monitor_services;
 use crate::model::api_response::ApiResponse;
 use crate::model::domain::Domain;
 use crate::service::api_logging_settings_service::ApiLoggingSettingsService;
@@ -120,11 +121,12 @@ pub fn remove_domains(
     link_service: tauri::State<'_, DomainGroupLinkService>,
     monitor_service: tauri::State<'_, DomainMonitorService>,
     api_logging_service: tauri::State<'_, ApiLoggingSettingsService>,
+    app: tauri::AppHandle,
 ) -> Result<ApiResponse<Option<Domain>>, String> {
     link_service.remove_links_for_domain(payload.id);
     let domain = domain_service.delete_domain(payload.id);
     let all_domains = domain_service.get_all();
-    monitor_service.sync_with_domains(&all_domains);
+    monitor_service.sync_with_domains(&all_domains, &monitor_services(&app));
     api_logging_service.remove_link(payload.id, &all_domains);
     if domain.is_empty() {
         Ok(ApiResponse {
@@ -152,9 +154,10 @@ pub fn import_domains(
     payload: ImportDomainsPayload,
     domain_service: tauri::State<'_, DomainService>,
     monitor_service: tauri::State<'_, DomainMonitorService>,
+    app: tauri::AppHandle,
 ) -> Result<ApiResponse<Vec<Domain>>, String> {
     let list = domain_service.import_from_json(payload.domains);
-    monitor_service.sync_with_domains(&domain_service.get_all());
+    monitor_service.sync_with_domains(&domain_service.get_all(), &monitor_services(&app));
     Ok(ApiResponse {
         message: format!("{}개 도메인 임포트 완료!", list.len()),
         success: true,
@@ -166,9 +169,10 @@ pub fn import_domains(
 pub fn clear_all_domains(
     domain_service: tauri::State<'_, DomainService>,
     monitor_service: tauri::State<'_, DomainMonitorService>,
+    app: tauri::AppHandle,
 ) -> Result<ApiResponse<Vec<Domain>>, String> {
     let list = domain_service.import_from_json(vec![]);
-    monitor_service.sync_with_domains(&domain_service.get_all());
+    monitor_service.sync_with_domains(&domain_service.get_all(), &monitor_services(&app));
     Ok(ApiResponse {
         message: "모든 도메인이 삭제되었습니다.".to_string(),
         success: true,
@@ -1,11 +1,58 @@
 use crate::model::api_response::ApiResponse;
+use crate::model::cert_check::{CertCheckConfig, CertCheckResult};
+use crate::model::check_concurrency::{CheckConcurrency, CheckCycleReport};
+use crate::model::check_policy::{CheckPolicy, ConfirmedState, StateChange};
+use crate::model::content_watch::{
+    ContentChange, ContentDiff, ContentWatchConfig, ContentWatchStatus,
+};
+use crate::model::dns_check::{DnsCheckConfig, DnsEvent, DnsSnapshot};
 use crate::model::domain_monitor_link::DomainMonitorWithUrl;
 use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::heartbeat::{HeartbeatCheck, HeartbeatStatus};
+use crate::model::http_check::HttpCheck;
+use crate::model::incident::{Incident, IncidentStatsReport};
+use crate::model::latency_baseline::LatencyBaselineReport;
+use crate::model::openapi_check::{OpenApiCheckConfig, OpenApiCheckStatus, OperationCheckResult};
+use crate::model::probe_options::ProbeOptions;
+use crate::model::security_audit::{SecurityAuditConfig, SecurityAuditResult};
+use crate::model::sub_page::{CrawlOptions, CrawlReport, SubPageNode};
+use crate::model::tcp_check::TcpCheck;
+use crate::model::uptime::{BucketSize, UptimeReport};
+use crate::service::alert_service::AlertService;
+use crate::service::content_watch::ContentWatchService;
 use crate::service::domain_group_link_service::DomainGroupLinkService;
 use crate::service::domain_group_service::DomainGroupService;
+use crate::service::domain_monitor_service::{DomainMonitorService, MonitorServices};
 use crate::service::domain_service::DomainService;
-use crate::service::domain_monitor_service::DomainMonitorService;
+use crate::service::heartbeat_service::HeartbeatService;
+use crate::service::incident_service::IncidentService;
+use crate::service::latency_baseline::LatencyBaselineService;
+use crate::service::maintenance_service::MaintenanceService;
+use crate::service::metrics::MetricsService;
+use crate::service::openapi_check::OpenApiCheckService;
 use crate::service::proxy_settings_service::ProxySettingsService;
+use crate::service::security_audit::SecurityAuditService;
+use crate::service::status_page::StatusPageService;
+use crate::service::sub_page_service::SubPageService;
+use std::sync::Arc;
+use tauri::Manager;
+
+/// `app.manage`로 따로 등록된 서비스 중 체크 주기가 함께 쓰는 것들을 모음
+pub fn monitor_services(app: &tauri::AppHandle) -> MonitorServices<'_> {
+    MonitorServices {
+        incidents: app.state::<IncidentService>().inner(),
+        alerts: app.state::<Arc<AlertService>>().inner(),
+        maintenance: app.state::<MaintenanceService>().inner(),
+        heartbeats: app.state::<Arc<HeartbeatService>>().inner(),
+        status_page: app.state::<Arc<StatusPageService>>().inner(),
+        metrics: app.state::<Arc<MetricsService>>().inner(),
+        latency_baselines: app.state::<LatencyBaselineService>().inner(),
+        sub_pages: app.state::<SubPageService>().inner(),
+        content_watch: app.state::<ContentWatchService>().inner(),
+        security_audits: app.state::<SecurityAuditService>().inner(),
+        openapi_checks: app.state::<OpenApiCheckService>().inner(),
+    }
+}
 
 #[tauri::command]
 pub fn get_latest_status(
@@ -26,6 +73,7 @@ pub async fn check_domain_status(
     link_service: tauri::State<'_, DomainGroupLinkService>,
     monitor_service: tauri::State<'_, DomainMonitorService>,
     proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    app: tauri::AppHandle,
 ) -> Result<ApiResponse<Vec<DomainStatusLog>>, String> {
     let results = monitor_service
         .check_domains(
@@ -33,6 +81,7 @@ pub async fn check_domain_status(
             &group_service,
             &link_service,
             &proxy_settings_service,
+            &monitor_services(&app),
         )
         .await;
    Ok(ApiResponse {
@@ -79,6 +128,655 @@ pub fn set_domain_monitor_check_enabled(
     })
 }
 
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainMonitorIntervalPayload {
+    pub domain_ids: Vec<u32>,
+    pub interval_secs: u32,
+}
+
+/// 도메인별 체크 간격(초) 변경. 재시작 없이 다음 스케줄부터 적용. 적용된 간격(최소값 보정)을 반환.
+#[tauri::command]
+pub fn set_domain_monitor_interval(
+    payload: SetDomainMonitorIntervalPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<u32>, String> {
+    let applied =
+        monitor_service.set_domain_monitor_interval(&payload.domain_ids, payload.interval_secs);
+    Ok(ApiResponse {
+        message: format!(
+            "{}개 도메인 체크 간격 {}초로 변경",
+            payload.domain_ids.len(),
+            applied
+        ),
+        success: true,
+        data: applied,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainMonitorHttpCheckPayload {
+    pub domain_id: u32,
+    /// `None`이면 기본 체크(HEAD + 2xx/3xx)로 초기화
+    pub http_check: Option<HttpCheck>,
+}
+
+/// 도메인별 HTTP 체크 정의(메서드·헤더·바디·검증 규칙) 설정.
+#[tauri::command]
+pub fn set_domain_monitor_http_check(
+    payload: SetDomainMonitorHttpCheckPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    let found =
+        monitor_service.set_domain_monitor_http_check(payload.domain_id, payload.http_check);
+    Ok(ApiResponse {
+        message: if found {
+            "체크 정의 저장 완료".to_string()
+        } else {
+            format!(
+                "도메인 ID {} 의 monitor 설정을 찾을 수 없습니다.",
+                payload.domain_id
+            )
+        },
+        success: found,
+        data: found,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainProbeOptionsPayload {
+    pub domain_id: u32,
+    /// `None`이면 기본 요청 방식 (HEAD→GET 재시도, 10초, TLS 검증)
+    pub probe: Option<ProbeOptions>,
+}
+
+/// 도메인별 HTTP 요청 방식(인증·제한 시간·User-Agent·TLS 검증·클라이언트 인증서) 설정.
+#[tauri::command]
+pub fn set_domain_probe_options(
+    payload: SetDomainProbeOptionsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    Ok(
+        match monitor_service.set_domain_probe_options(payload.domain_id, payload.probe) {
+            Ok(()) => ApiResponse {
+                message: "요청 설정 저장 완료".to_string(),
+                success: true,
+                data: true,
+            },
+            Err(e) => ApiResponse {
+                message: e,
+                success: false,
+                data: false,
+            },
+        },
+    )
+}
+
+/// 최근 체크 주기의 소요 시간·대기·건너뜀 통계와 현재 동시 실행 설정.
+#[tauri::command]
+pub fn get_check_cycle_stats(
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<CheckCycleReport>, String> {
+    let report = monitor_service.get_check_cycle_report();
+    Ok(ApiResponse {
+        message: format!("{}개 체크 주기 통계 조회 완료", report.cycles.len()),
+        success: true,
+        data: report,
+    })
+}
+
+/// 전체·호스트별 동시 요청 수와 체크 분산(지터·spread) 설정. 범위를 벗어난 값은 맞춰서 저장
+#[tauri::command]
+pub fn set_check_concurrency(
+    payload: CheckConcurrency,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<CheckConcurrency>, String> {
+    let saved = monitor_service.set_check_concurrency(&payload);
+    Ok(ApiResponse {
+        message: "동시 실행 설정 저장 완료".to_string(),
+        success: true,
+        data: saved,
+    })
+}
+
+/// 체크 대상 도메인의 최신 인증서 체크 결과 (만료일·발급자·SAN·체인·호스트 일치).
+#[tauri::command]
+pub fn get_latest_cert_checks(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Vec<CertCheckResult>>, String> {
+    let list = monitor_service.get_last_cert_checks(&domain_service, &sub_page_service);
+    Ok(ApiResponse {
+        message: format!("{}개의 인증서 상태 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+/// 체크 활성화된 https 도메인의 인증서를 즉시 점검.
+#[tauri::command]
+pub async fn check_domain_certificates(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    metrics_service: tauri::State<'_, Arc<MetricsService>>,
+) -> Result<ApiResponse<Vec<CertCheckResult>>, String> {
+    let results = monitor_service
+        .check_certificates(&domain_service, &proxy_settings_service, &metrics_service)
+        .await;
+    Ok(ApiResponse {
+        message: format!("{}개 도메인 인증서 체크 완료", results.len()),
+        success: true,
+        data: results,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainCertCheckPayload {
+    pub domain_id: u32,
+    /// `None`이면 기본값 (warning 30일, error 7일)
+    pub cert_check: Option<CertCheckConfig>,
+}
+
+/// 도메인별 인증서 체크 활성화·만료 임계값(일) 설정.
+#[tauri::command]
+pub fn set_domain_cert_check(
+    payload: SetDomainCertCheckPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    let found = monitor_service.set_domain_cert_check(
+        payload.domain_id,
+        payload.cert_check,
+        &domain_service,
+    );
+    Ok(ApiResponse {
+        message: if found {
+            "인증서 체크 설정 저장 완료".to_string()
+        } else {
+            format!(
+                "도메인 ID {} 의 monitor 설정을 찾을 수 없습니다.",
+                payload.domain_id
+            )
+        },
+        success: found,
+        data: found,
+    })
+}
+
+/// 체크 대상 도메인의 마지막 보안 헤더·TLS 점검 (점수·규칙별 판정·회귀).
+#[tauri::command]
+pub fn get_security_audits(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+    security_audit_service: tauri::State<'_, SecurityAuditService>,
+) -> Result<ApiResponse<Vec<SecurityAuditResult>>, String> {
+    let list = monitor_service.get_security_audits(
+        &domain_service,
+        &sub_page_service,
+        &security_audit_service,
+    );
+    Ok(ApiResponse {
+        message: format!("{}개의 보안 점검 결과 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditDomainSecurityPayload {
+    /// 없으면 점검이 켜진 모든 http(s) 도메인
+    #[serde(default)]
+    pub domain_id: Option<u32>,
+}
+
+/// 보안 점검을 간격과 무관하게 즉시 실행.
+#[tauri::command]
+pub async fn audit_domain_security(
+    payload: AuditDomainSecurityPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+    security_audit_service: tauri::State<'_, SecurityAuditService>,
+) -> Result<ApiResponse<Vec<SecurityAuditResult>>, String> {
+    let results = monitor_service
+        .audit_security(
+            payload.domain_id,
+            &domain_service,
+            &proxy_settings_service,
+            &sub_page_service,
+            &security_audit_service,
+        )
+        .await;
+    Ok(ApiResponse {
+        message: format!("{}개 도메인 보안 점검 완료", results.len()),
+        success: true,
+        data: results,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainSecurityAuditPayload {
+    pub domain_id: u32,
+    /// `None`이면 기본값 (하루에 한 번)
+    pub security_audit: Option<SecurityAuditConfig>,
+}
+
+/// 도메인별 보안 점검 활성화·간격(시간) 설정.
+#[tauri::command]
+pub fn set_domain_security_audit(
+    payload: SetDomainSecurityAuditPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    let found =
+        monitor_service.set_domain_security_audit(payload.domain_id, payload.security_audit);
+    Ok(ApiResponse {
+        message: if found {
+            "보안 점검 설정 저장 완료".to_string()
+        } else {
+            format!(
+                "도메인 ID {} 의 monitor 설정을 찾을 수 없습니다.",
+                payload.domain_id
+            )
+        },
+        success: found,
+        data: found,
+    })
+}
+
+/// `OpenAPI` 체크가 설정된 도메인의 마지막 실행 (오퍼레이션별 결과·건너뛴 오퍼레이션).
+#[tauri::command]
+pub fn get_openapi_checks(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    openapi_check_service: tauri::State<'_, OpenApiCheckService>,
+) -> Result<ApiResponse<Vec<OpenApiCheckStatus>>, String> {
+    let list = monitor_service.get_openapi_check_status(&domain_service, &openapi_check_service);
+    Ok(ApiResponse {
+        message: format!("{}개 도메인의 OpenAPI 체크 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunOpenApiChecksPayload {
+    /// 없으면 체크가 켜진 모든 도메인
+    #[serde(default)]
+    pub domain_id: Option<u32>,
+}
+
+/// 저장된 스키마로 `OpenAPI` 체크를 간격과 무관하게 즉시 실행.
+#[tauri::command]
+pub async fn run_openapi_checks(
+    payload: RunOpenApiChecksPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+    openapi_check_service: tauri::State<'_, OpenApiCheckService>,
+) -> Result<ApiResponse<Vec<OperationCheckResult>>, String> {
+    let results = monitor_service
+        .check_openapi(
+            payload.domain_id,
+            &domain_service,
+            &proxy_settings_service,
+            &sub_page_service,
+            &openapi_check_service,
+        )
+        .await;
+    Ok(ApiResponse {
+        message: format!("{}개 오퍼레이션 체크 완료", results.len()),
+        success: true,
+        data: results,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainOpenApiCheckPayload {
+    pub domain_id: u32,
+    /// `None`이면 체크하지 않음
+    pub openapi_check: Option<OpenApiCheckConfig>,
+}
+
+/// 도메인별 `OpenAPI` 체크 활성화·간격(분)·기준 url 설정.
+#[tauri::command]
+pub fn set_domain_openapi_check(
+    payload: SetDomainOpenApiCheckPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    let found = monitor_service.set_domain_openapi_check(payload.domain_id, payload.openapi_check);
+    Ok(ApiResponse {
+        message: if found {
+            "OpenAPI 체크 설정 저장 완료".to_string()
+        } else {
+            format!(
+                "도메인 ID {} 의 monitor 설정을 찾을 수 없습니다.",
+                payload.domain_id
+            )
+        },
+        success: found,
+        data: found,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainTcpCheckPayload {
+    pub domain_id: u32,
+    /// `None`이면 연결 성공만 확인
+    pub tcp_check: Option<TcpCheck>,
+}
+
+/// `tcp://` 대상의 보낼 데이터·기대 배너 설정.
+#[tauri::command]
+pub fn set_domain_tcp_check(
+    payload: SetDomainTcpCheckPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    let found = monitor_service.set_domain_tcp_check(payload.domain_id, payload.tcp_check);
+    Ok(ApiResponse {
+        message: if found {
+            "TCP 체크 설정 저장 완료".to_string()
+        } else {
+            format!(
+                "도메인 ID {} 의 monitor 설정을 찾을 수 없습니다.",
+                payload.domain_id
+            )
+        },
+        success: found,
+        data: found,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainHeartbeatPayload {
+    pub domain_id: u32,
+    /// `token`이 비어 있으면 새로 발급. `None`이면 설정 해제
+    pub heartbeat: Option<HeartbeatCheck>,
+}
+
+/// `heartbeat://` 대상의 주기·여유 설정. 발급·유지된 토큰이 담긴 설정을 반환.
+#[tauri::command]
+pub fn set_domain_heartbeat(
+    payload: SetDomainHeartbeatPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    heartbeat_service: tauri::State<'_, Arc<HeartbeatService>>,
+) -> Result<ApiResponse<Option<HeartbeatCheck>>, String> {
+    Ok(
+        match monitor_service.set_domain_heartbeat(
+            payload.domain_id,
+            payload.heartbeat,
+            &heartbeat_service,
+        ) {
+            Ok(check) => ApiResponse {
+                message: "하트비트 설정 저장 완료".to_string(),
+                success: true,
+                data: check,
+            },
+            Err(e) => ApiResponse {
+                message: e,
+                success: false,
+                data: None,
+            },
+        },
+    )
+}
+
+/// 하트비트 도메인별 핑 경로와 최근 수신 상태
+#[tauri::command]
+pub fn get_heartbeat_status(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    heartbeat_service: tauri::State<'_, Arc<HeartbeatService>>,
+) -> Result<ApiResponse<Vec<HeartbeatStatus>>, String> {
+    let list = monitor_service.get_heartbeat_status(&domain_service, &heartbeat_service);
+    Ok(ApiResponse {
+        message: format!("하트비트 {}개 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[tauri::command]
+pub fn get_latency_baselines(
+    domain_service: tauri::State<'_, DomainService>,
+    latency_baseline_service: tauri::State<'_, LatencyBaselineService>,
+) -> Result<ApiResponse<Vec<LatencyBaselineReport>>, String> {
+    let list =
+        DomainMonitorService::get_latency_baselines(&domain_service, &latency_baseline_service);
+    Ok(ApiResponse {
+        message: format!("지연 시간 기준선 {}개 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainCheckPolicyPayload {
+    pub domain_id: u32,
+    /// `None`이면 재시도 없이 1회 결과로 확정
+    pub policy: Option<CheckPolicy>,
+}
+
+/// 도메인별 재시도·down/up 확정 횟수·플랩 판정 설정. 허용 범위로 맞춰 저장한 정책을 반환.
+#[tauri::command]
+pub fn set_domain_check_policy(
+    payload: SetDomainCheckPolicyPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Option<CheckPolicy>>, String> {
+    Ok(
+        match monitor_service.set_domain_check_policy(payload.domain_id, payload.policy) {
+            Ok(policy) => ApiResponse {
+                message: "체크 정책 저장 완료".to_string(),
+                success: true,
+                data: policy,
+            },
+            Err(e) => ApiResponse {
+                message: e,
+                success: false,
+                data: None,
+            },
+        },
+    )
+}
+
+/// 확정 상태 (재시도·연속 횟수를 거친 up/down, flapping 여부).
+#[tauri::command]
+pub fn get_confirmed_states(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Vec<ConfirmedState>>, String> {
+    let list = monitor_service.get_confirmed_states(&domain_service, &sub_page_service);
+    Ok(ApiResponse {
+        message: format!("{}개의 확정 상태 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+/// DNS 감시 중인 도메인의 최신 레코드 스냅샷 (리졸버별).
+#[tauri::command]
+pub fn get_dns_snapshots(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Vec<DnsSnapshot>>, String> {
+    let list = monitor_service.get_dns_snapshots(&domain_service, &sub_page_service);
+    Ok(ApiResponse {
+        message: format!("{}개의 DNS 스냅샷 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+/// DNS 감시 중인 도메인을 즉시 조회. 변경·NXDOMAIN·SERVFAIL·리졸버 불일치는 이벤트로 기록.
+#[tauri::command]
+pub async fn check_domain_dns(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Vec<DnsSnapshot>>, String> {
+    let snapshots = monitor_service
+        .check_dns(&domain_service, &proxy_settings_service, &sub_page_service)
+        .await;
+    Ok(ApiResponse {
+        message: format!("{}개 DNS 스냅샷 갱신 완료", snapshots.len()),
+        success: true,
+        data: snapshots,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainDnsCheckPayload {
+    pub domain_id: u32,
+    /// `None`이면 DNS 감시 중지
+    pub dns_check: Option<DnsCheckConfig>,
+}
+
+/// 도메인별 DNS 감시 설정 (레코드 타입, 추가 비교 리졸버).
+#[tauri::command]
+pub fn set_domain_dns_check(
+    payload: SetDomainDnsCheckPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    let found = monitor_service.set_domain_dns_check(payload.domain_id, payload.dns_check);
+    Ok(ApiResponse {
+        message: if found {
+            "DNS 감시 설정 저장 완료".to_string()
+        } else {
+            format!(
+                "도메인 ID {} 의 monitor 설정을 찾을 수 없습니다.",
+                payload.domain_id
+            )
+        },
+        success: found,
+        data: found,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDomainContentWatchPayload {
+    pub domain_id: u32,
+    /// `None`이면 내용 감시 중지
+    pub content_watch: Option<ContentWatchConfig>,
+}
+
+/// 도메인별 내용 감시 설정. 선택자·JSON 경로·무시 패턴이 잘못되면 `success: false`
+#[tauri::command]
+pub fn set_domain_content_watch(
+    payload: SetDomainContentWatchPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<bool>, String> {
+    Ok(
+        match monitor_service.set_domain_content_watch(payload.domain_id, payload.content_watch) {
+            Ok(()) => ApiResponse {
+                message: "내용 감시 설정 저장 완료".to_string(),
+                success: true,
+                data: true,
+            },
+            Err(e) => ApiResponse {
+                message: e,
+                success: false,
+                data: false,
+            },
+        },
+    )
+}
+
+/// 내용 감시가 켜진 모든 도메인을 즉시 조회. 바뀐 도메인의 변경 기록을 반환
+#[tauri::command]
+pub async fn check_domain_content(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+    content_watch_service: tauri::State<'_, ContentWatchService>,
+) -> Result<ApiResponse<Vec<ContentChange>>, String> {
+    let changes = monitor_service
+        .check_content(
+            &domain_service,
+            &proxy_settings_service,
+            &sub_page_service,
+            &content_watch_service,
+        )
+        .await;
+    Ok(ApiResponse {
+        message: format!("{}개 도메인의 내용이 변경되었습니다.", changes.len()),
+        success: true,
+        data: changes,
+    })
+}
+
+#[tauri::command]
+pub fn get_content_watch_status(
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    content_watch_service: tauri::State<'_, ContentWatchService>,
+) -> Result<ApiResponse<Vec<ContentWatchStatus>>, String> {
+    let list = monitor_service.get_content_watch_status(&domain_service, &content_watch_service);
+    Ok(ApiResponse {
+        message: format!("내용 감시 {}개 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetContentDiffPayload {
+    pub domain_id: u32,
+    /// 비교 기준 스냅샷 해시. 없으면 `to` 직전 스냅샷
+    pub from: Option<String>,
+    /// 없으면 최신 스냅샷
+    pub to: Option<String>,
+}
+
+/// 두 스냅샷의 unified diff. 스냅샷이 없거나 해시를 찾지 못하면 `success: false`
+#[tauri::command]
+pub fn get_content_diff(
+    payload: GetContentDiffPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    content_watch_service: tauri::State<'_, ContentWatchService>,
+) -> Result<ApiResponse<Option<ContentDiff>>, String> {
+    Ok(
+        match DomainMonitorService::get_content_diff(
+            payload.domain_id,
+            payload.from.as_deref(),
+            payload.to.as_deref(),
+            &domain_service,
+            &content_watch_service,
+        ) {
+            Ok(diff) => ApiResponse {
+                message: "내용 diff 조회 완료".to_string(),
+                success: true,
+                data: Some(diff),
+            },
+            Err(e) => ApiResponse {
+                message: e,
+                success: false,
+                data: None,
+            },
+        },
+    )
+}
+
 #[derive(serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDomainStatusLogsPayload {
@@ -97,3 +795,315 @@ pub fn get_domain_status_logs(
         data: logs,
     })
 }
+
+#[tauri::command]
+pub fn get_cert_check_logs(
+    payload: GetDomainStatusLogsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Vec<CertCheckResult>>, String> {
+    let logs = monitor_service.get_cert_logs_by_date(&payload.date);
+    Ok(ApiResponse {
+        message: format!("{} 건의 인증서 로그가 조회되었습니다.", logs.len()),
+        success: true,
+        data: logs,
+    })
+}
+
+#[tauri::command]
+pub fn get_security_audit_logs(
+    payload: GetDomainStatusLogsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Vec<SecurityAuditResult>>, String> {
+    let logs = monitor_service.get_security_audit_logs_by_date(&payload.date);
+    Ok(ApiResponse {
+        message: format!("{} 건의 보안 점검 로그가 조회되었습니다.", logs.len()),
+        success: true,
+        data: logs,
+    })
+}
+
+#[tauri::command]
+pub fn get_openapi_check_logs(
+    payload: GetDomainStatusLogsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Vec<OperationCheckResult>>, String> {
+    let logs = monitor_service.get_openapi_check_logs_by_date(&payload.date);
+    Ok(ApiResponse {
+        message: format!("{} 건의 OpenAPI 체크 로그가 조회되었습니다.", logs.len()),
+        success: true,
+        data: logs,
+    })
+}
+
+#[tauri::command]
+pub fn get_dns_events(
+    payload: GetDomainStatusLogsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Vec<DnsEvent>>, String> {
+    let events = monitor_service.get_dns_events_by_date(&payload.date);
+    Ok(ApiResponse {
+        message: format!("{} 건의 DNS 이벤트가 조회되었습니다.", events.len()),
+        success: true,
+        data: events,
+    })
+}
+
+#[tauri::command]
+pub fn get_content_changes(
+    payload: GetDomainStatusLogsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Vec<ContentChange>>, String> {
+    let changes = monitor_service.get_content_changes_by_date(&payload.date);
+    Ok(ApiResponse {
+        message: format!("{} 건의 내용 변경이 조회되었습니다.", changes.len()),
+        success: true,
+        data: changes,
+    })
+}
+
+#[tauri::command]
+pub fn get_state_changes(
+    payload: GetDomainStatusLogsPayload,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Vec<StateChange>>, String> {
+    let changes = monitor_service.get_state_changes_by_date(&payload.date);
+    Ok(ApiResponse {
+        message: format!("{} 건의 상태 변경이 조회되었습니다.", changes.len()),
+        success: true,
+        data: changes,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIncidentsPayload {
+    /// 없으면 전체 도메인
+    #[serde(default)]
+    pub domain_id: Option<u32>,
+    /// true면 해결되지 않은 장애만
+    #[serde(default)]
+    pub open_only: bool,
+}
+
+/// 장애 목록 (최신순).
+#[tauri::command]
+pub fn get_incidents(
+    payload: GetIncidentsPayload,
+    incident_service: tauri::State<'_, IncidentService>,
+) -> Result<ApiResponse<Vec<Incident>>, String> {
+    let list = incident_service.list(payload.domain_id, payload.open_only);
+    Ok(ApiResponse {
+        message: format!("{} 건의 장애가 조회되었습니다.", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotateIncidentPayload {
+    pub id: u32,
+    pub text: String,
+}
+
+/// 장애에 메모 추가.
+#[tauri::command]
+pub fn annotate_incident(
+    payload: AnnotateIncidentPayload,
+    incident_service: tauri::State<'_, IncidentService>,
+) -> Result<ApiResponse<Option<Incident>>, String> {
+    let updated = incident_service.annotate(payload.id, payload.text);
+    Ok(ApiResponse {
+        message: if updated.is_some() {
+            "장애 메모 저장 완료".to_string()
+        } else {
+            format!("장애 ID {} 를 찾을 수 없습니다.", payload.id)
+        },
+        success: updated.is_some(),
+        data: updated,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcknowledgeIncidentPayload {
+    pub id: u32,
+}
+
+/// 장애 확인 처리.
+#[tauri::command]
+pub fn acknowledge_incident(
+    payload: AcknowledgeIncidentPayload,
+    incident_service: tauri::State<'_, IncidentService>,
+) -> Result<ApiResponse<Option<Incident>>, String> {
+    let updated = incident_service.acknowledge(payload.id);
+    Ok(ApiResponse {
+        message: if updated.is_some() {
+            "장애 확인 처리 완료".to_string()
+        } else {
+            format!("장애 ID {} 를 찾을 수 없습니다.", payload.id)
+        },
+        success: updated.is_some(),
+        data: updated,
+    })
+}
+
+/// 도메인별·그룹별 장애 횟수, MTTR, MTBF.
+#[tauri::command]
+pub fn get_incident_stats(
+    incident_service: tauri::State<'_, IncidentService>,
+) -> Result<ApiResponse<IncidentStatsReport>, String> {
+    let report = incident_service.stats();
+    Ok(ApiResponse {
+        message: format!("{}개 도메인의 장애 통계 조회 완료", report.by_domain.len()),
+        success: true,
+        data: report,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUptimeReportPayload {
+    /// 둘 다 없으면 전체 도메인
+    #[serde(default)]
+    pub domain_id: Option<u32>,
+    #[serde(default)]
+    pub group_id: Option<u32>,
+    /// 로컬 날짜 YYYY-MM-DD (양끝 포함)
+    pub from: String,
+    pub to: String,
+    pub bucket: BucketSize,
+}
+
+/// 도메인·그룹의 기간별 가용성(%)·체크 수·지연 시간 p50/p95/p99/max와 차트용 시계열.
+#[tauri::command]
+pub fn get_uptime_report(
+    payload: GetUptimeReportPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    link_service: tauri::State<'_, DomainGroupLinkService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+) -> Result<ApiResponse<Option<UptimeReport>>, String> {
+    let urls = DomainMonitorService::uptime_urls(
+        payload.domain_id,
+        payload.group_id,
+        &domain_service,
+        &link_service,
+    );
+    match monitor_service.get_uptime_report(urls, &payload.from, &payload.to, payload.bucket) {
+        Ok(report) => Ok(ApiResponse {
+            message: format!("{} ~ {} 가용성 조회 완료", payload.from, payload.to),
+            success: true,
+            data: Some(report),
+        }),
+        Err(e) => Ok(ApiResponse {
+            message: e,
+            success: false,
+            data: None,
+        }),
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlDomainPayload {
+    pub domain_id: u32,
+    /// 없으면 기본값 (깊이 3, 200페이지, robots.txt 준수)
+    pub options: Option<CrawlOptions>,
+}
+
+/// robots.txt·sitemap.xml·같은 출처 링크로 하위 페이지를 찾아 트리에 반영. 깨진 링크는 결과에 포함
+#[tauri::command]
+pub async fn crawl_domain(
+    payload: CrawlDomainPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Option<CrawlReport>>, String> {
+    let result = monitor_service
+        .crawl_domain(
+            payload.domain_id,
+            payload.options.unwrap_or_default(),
+            &domain_service,
+            &proxy_settings_service,
+            &sub_page_service,
+        )
+        .await;
+    Ok(match result {
+        Ok(report) => ApiResponse {
+            message: format!(
+                "{}개 페이지 크롤링 완료 (새 하위 페이지 {}개, 깨진 링크 {}개)",
+                report.pages_crawled,
+                report.pages_added,
+                report.broken_links.len()
+            ),
+            success: true,
+            data: Some(report),
+        },
+        Err(e) => ApiResponse {
+            message: e,
+            success: false,
+            data: None,
+        },
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainIdPayload {
+    pub domain_id: u32,
+}
+
+/// 도메인의 하위 페이지 트리 (경로 기준)
+#[tauri::command]
+pub fn get_sub_pages(
+    payload: DomainIdPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Vec<SubPageNode>>, String> {
+    let tree = DomainMonitorService::get_sub_page_tree(
+        payload.domain_id,
+        &domain_service,
+        &sub_page_service,
+    );
+    Ok(ApiResponse {
+        message: "하위 페이지 조회 완료".to_string(),
+        success: true,
+        data: tree,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSubPageMonitorCheckEnabledPayload {
+    pub sub_page_ids: Vec<u32>,
+    pub enabled: bool,
+}
+
+/// 하위 페이지별 체크 활성화/비활성화. 켜진 페이지는 소속 도메인의 체크 주기에 함께 체크
+#[tauri::command]
+pub fn set_sub_page_monitor_check_enabled(
+    payload: SetSubPageMonitorCheckEnabledPayload,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<usize>, String> {
+    let count = sub_page_service.set_check_enabled(&payload.sub_page_ids, payload.enabled);
+    Ok(ApiResponse {
+        message: format!("{count}개 하위 페이지 체크 설정 변경 완료"),
+        success: true,
+        data: count,
+    })
+}
+
+/// 도메인의 마지막 크롤링 결과 (깨진 링크 포함). 크롤링한 적 없으면 `None`
+#[tauri::command]
+pub fn get_crawl_report(
+    payload: DomainIdPayload,
+    sub_page_service: tauri::State<'_, SubPageService>,
+) -> Result<ApiResponse<Option<CrawlReport>>, String> {
+    let report = sub_page_service.report(payload.domain_id);
+    Ok(ApiResponse {
+        message: "크롤링 결과 조회 완료".to_string(),
+        success: true,
+        data: report,
+    })
+}
@@ -1,12 +1,17 @@
 use crate::model::api_response::ApiResponse;
 use crate::model::local_route::LocalRoute;
 use crate::model::proxy_settings::ProxySettings;
+use crate::model::route_health::RouteHealth;
 use crate::service::api_logging_settings_service::ApiLoggingSettingsService;
 use crate::service::api_log_service::ApiLogService;
 use crate::service::ca_service::CaService;
-use crate::service::local_proxy;
+use crate::service::heartbeat_service::HeartbeatService;
+use crate::service::local_proxy::{self, ReservedPathServices};
 use crate::service::local_route_service::LocalRouteService;
+use crate::service::metrics::MetricsService;
 use crate::service::proxy_settings_service::ProxySettingsService;
+use crate::service::route_health::RouteHealthService;
+use crate::service::status_page::StatusPageService;
 use crate::service::system_proxy_service::SystemProxyService;
 use std::fmt::Write;
 use std::io;
@@ -173,6 +178,65 @@ pub fn set_local_route_enabled(
     })
 }
 
+/// Latest backend health check per route (enabled routes that have been checked).
+#[tauri::command]
+pub fn get_route_health(
+    route_service: tauri::State<'_, std::sync::Arc<LocalRouteService>>,
+    route_health: tauri::State<'_, RouteHealthService>,
+) -> Result<ApiResponse<Vec<RouteHealth>>, String> {
+    let list = route_health.latest(&route_service.get_all());
+    Ok(ApiResponse {
+        message: format!("{} route health results", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+/// Check every enabled route's backend now (port, HTTP with the route's Host header, latency).
+#[tauri::command]
+pub async fn check_local_routes(
+    route_service: tauri::State<'_, std::sync::Arc<LocalRouteService>>,
+    route_health: tauri::State<'_, RouteHealthService>,
+) -> Result<ApiResponse<Vec<RouteHealth>>, String> {
+    let list = route_health
+        .check_all(
+            &route_service.get_all(),
+            local_proxy::is_local_routing_enabled(),
+        )
+        .await;
+    let down = list.iter().filter(|h| h.level == "error").count();
+    Ok(ApiResponse {
+        message: format!("Checked {} routes ({down} down)", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetRouteHealthIntervalPayload {
+    /// None = background checks off
+    pub interval_secs: Option<u32>,
+}
+
+#[tauri::command]
+pub fn set_route_health_interval(
+    payload: SetRouteHealthIntervalPayload,
+    proxy_settings_service: tauri::State<'_, ProxySettingsService>,
+    route_health: tauri::State<'_, RouteHealthService>,
+) -> Result<ApiResponse<ProxySettings>, String> {
+    let settings = proxy_settings_service.set_route_health_interval(payload.interval_secs);
+    route_health.notify_settings_changed();
+    Ok(ApiResponse {
+        message: match settings.route_health_interval_secs {
+            Some(secs) => format!("Route health checks every {secs}s"),
+            None => "Route health checks off".to_string(),
+        },
+        success: true,
+        data: settings,
+    })
+}
+
 /// Last auto-start error (persisted until proxy starts successfully or cleared).
 static PROXY_AUTO_START_ERR: std::sync::Mutex<Option<String>> = std::sync::Mutex::new(None);
 
@@ -298,6 +362,7 @@ pub struct StartLocalProxyPayload {
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn start_local_proxy(
     app: AppHandle,
     payload: Option<StartLocalProxyPayload>,
@@ -306,6 +371,9 @@ pub async fn start_local_proxy(
     api_logging_service: tauri::State<'_, ApiLoggingSettingsService>,
     api_log_service: tauri::State<'_, ApiLogService>,
     ca_service: tauri::State<'_, std::sync::Arc<CaService>>,
+    heartbeat_service: tauri::State<'_, std::sync::Arc<HeartbeatService>>,
+    status_page_service: tauri::State<'_, std::sync::Arc<StatusPageService>>,
+    metrics_service: tauri::State<'_, std::sync::Arc<MetricsService>>,
 ) -> Result<ApiResponse<ProxyStatusPayload>, String> {
     let port = payload
         .and_then(|p| p.port)
@@ -345,6 +413,11 @@ pub async fn start_local_proxy(
     let api_logging_map = api_logging_service.settings_map_arc();
     let api_log_service_arc = std::sync::Arc::new((*api_log_service).clone());
     let ca_service_arc = (*ca_service).clone();
+    let reserved = ReservedPathServices {
+        heartbeats: std::sync::Arc::clone(&heartbeat_service),
+        status_page: std::sync::Arc::clone(&status_page_service),
+        metrics: std::sync::Arc::clone(&metrics_service),
+    };
 
     match local_proxy::run_proxy(
         port,
@@ -353,6 +426,7 @@ pub async fn start_local_proxy(
         api_logging_map.clone(),
         api_log_service_arc.clone(),
         ca_service_arc.clone(),
+        reserved.clone(),
     )
     .await
     {
@@ -369,6 +443,7 @@ pub async fn start_local_proxy(
             api_logging_map.clone(),
             api_log_service_arc.clone(),
             ca_service_arc.clone(),
+            reserved.clone(),
         )
         .await
         {
@@ -391,6 +466,7 @@ pub async fn start_local_proxy(
             api_logging_map,
             api_log_service_arc.clone(),
             ca_service_arc,
+            reserved,
         )
         .await
         {
@@ -554,6 +630,7 @@ pub async fn auto_start_proxy(
     api_logging_map: std::sync::Arc<std::sync::RwLock<std::collections::HashMap<String, (bool, bool)>>>,
     api_log_service: std::sync::Arc<ApiLogService>,
     ca_service: std::sync::Arc<CaService>,
+    reserved: ReservedPathServices,
 ) -> Result<(), String> {
     // Restore persisted local_routing_enabled flag
     local_proxy::set_local_routing_enabled(settings.local_routing_enabled);
@@ -587,6 +664,7 @@ pub async fn auto_start_proxy(
         api_logging_map.clone(),
         api_log_service.clone(),
         ca_service.clone(),
+        reserved.clone(),
     )
     .await
     {
@@ -603,6 +681,7 @@ pub async fn auto_start_proxy(
             api_logging_map.clone(),
             api_log_service.clone(),
             ca_service.clone(),
+            reserved.clone(),
         )
         .await
         {
@@ -625,6 +704,7 @@ pub async fn auto_start_proxy(
             api_logging_map,
             api_log_service.clone(),
             ca_service,
+            reserved,
         )
         .await
         {
@@ -0,0 +1,63 @@
+use crate::model::api_response::ApiResponse;
+use crate::model::maintenance::{ActiveMaintenance, MaintenanceWindow};
+use crate::service::domain_group_link_service::DomainGroupLinkService;
+use crate::service::domain_monitor_service::DomainMonitorService;
+use crate::service::domain_service::DomainService;
+use crate::service::maintenance_service::MaintenanceService;
+
+#[tauri::command]
+pub fn get_maintenance_windows(
+    maintenance_service: tauri::State<'_, MaintenanceService>,
+) -> Result<ApiResponse<Vec<MaintenanceWindow>>, String> {
+    let list = maintenance_service.get_all();
+    Ok(ApiResponse {
+        message: format!("점검 시간 {}개 조회 완료", list.len()),
+        success: true,
+        data: list,
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMaintenanceWindowsPayload {
+    pub windows: Vec<MaintenanceWindow>,
+}
+
+/// 점검 시간 전체 저장. id가 0인 항목은 새로 추가. 일정이 잘못되면 저장하지 않음 (`success: false`).
+#[tauri::command]
+pub fn set_maintenance_windows(
+    payload: SetMaintenanceWindowsPayload,
+    maintenance_service: tauri::State<'_, MaintenanceService>,
+) -> Result<ApiResponse<Vec<MaintenanceWindow>>, String> {
+    Ok(match maintenance_service.set_all(payload.windows) {
+        Ok(list) => ApiResponse {
+            message: "점검 시간 저장 완료".to_string(),
+            success: true,
+            data: list,
+        },
+        Err(e) => ApiResponse {
+            message: e,
+            success: false,
+            data: maintenance_service.get_all(),
+        },
+    })
+}
+
+/// 지금 점검 중인 도메인과 종료 시각
+#[tauri::command]
+pub fn get_active_maintenance(
+    domain_service: tauri::State<'_, DomainService>,
+    link_service: tauri::State<'_, DomainGroupLinkService>,
+    maintenance_service: tauri::State<'_, MaintenanceService>,
+) -> Result<ApiResponse<Vec<ActiveMaintenance>>, String> {
+    let list = DomainMonitorService::get_active_maintenance(
+        &domain_service,
+        &link_service,
+        &maintenance_service,
+    );
+    Ok(ApiResponse {
+        message: format!("점검 중인 도메인 {}개", list.len()),
+        success: true,
+        data: list,
+    })
+}
@@ -1,3 +1,4 @@
+use crate::command::domain_monitor_command::monitor_services;
 use crate::model::api_response::ApiResponse;
 use crate::model::settings_export::{SettingsExport, SETTINGS_EXPORT_VERSION};
 use crate::service::ca_service::CaService;
@@ -6,7 +7,9 @@ use crate::service::domain_group_service::DomainGroupService;
 use crate::service::domain_service::DomainService;
 use crate::service::domain_monitor_service::DomainMonitorService;
 use crate::service::local_route_service::LocalRouteService;
+use crate::service::maintenance_service::MaintenanceService;
 use crate::service::proxy_settings_service::ProxySettingsService;
+use crate::service::status_page::StatusPageService;
 use std::sync::Arc;
 use tauri_plugin_dialog::DialogExt;
 
@@ -42,6 +45,7 @@ pub async fn save_root_ca(
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub fn export_all_settings(
     domain_service: tauri::State<'_, DomainService>,
     group_service: tauri::State<'_, DomainGroupService>,
@@ -49,6 +53,8 @@ pub fn export_all_settings(
     route_service: tauri::State<'_, Arc<LocalRouteService>>,
     proxy_settings_service: tauri::State<'_, ProxySettingsService>,
     monitor_service: tauri::State<'_, DomainMonitorService>,
+    maintenance_service: tauri::State<'_, MaintenanceService>,
+    status_page_service: tauri::State<'_, Arc<StatusPageService>>,
 ) -> Result<ApiResponse<SettingsExport>, String> {
     let exported_at = chrono::Utc::now().to_rfc3339();
     let payload = SettingsExport {
@@ -60,6 +66,8 @@ pub fn export_all_settings(
         local_routes: route_service.get_all(),
         proxy_settings: proxy_settings_service.get(),
         domain_monitor: monitor_service.get_domain_monitor_for_export(&domain_service),
+        maintenance_windows: maintenance_service.get_all(),
+        status_page: Some(status_page_service.get_config()),
     };
     Ok(ApiResponse {
         message: "Export ready".to_string(),
@@ -69,6 +77,7 @@ pub fn export_all_settings(
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub fn import_all_settings(
     payload: SettingsExport,
     domain_service: tauri::State<'_, DomainService>,
@@ -77,6 +86,7 @@ pub fn import_all_settings(
     route_service: tauri::State<'_, Arc<LocalRouteService>>,
     proxy_settings_service: tauri::State<'_, ProxySettingsService>,
     monitor_service: tauri::State<'_, DomainMonitorService>,
+    app: tauri::AppHandle,
 ) -> Result<ApiResponse<bool>, String> {
     if payload.version > SETTINGS_EXPORT_VERSION {
         return Err(format!(
@@ -85,8 +95,21 @@ pub fn import_all_settings(
         ));
     }
     domain_service.import_from_json(payload.domains);
-    monitor_service.sync_with_domains(&domain_service.get_all());
-    monitor_service.import_domain_monitor(&payload.domain_monitor, &domain_service);
+    let services = monitor_services(&app);
+    monitor_service.sync_with_domains(&domain_service.get_all(), &services);
+    monitor_service.import_domain_monitor(
+        &payload.domain_monitor,
+        &domain_service,
+        services.heartbeats,
+    );
+    services
+        .maintenance
+        .replace_all(payload.maintenance_windows);
+    if let Some(config) = payload.status_page {
+        if let Err(e) = services.status_page.set_config(config) {
+            tracing::warn!("[import] status page settings skipped: {e}");
+        }
+    }
     group_service.replace_all(payload.groups);
     link_service.replace_all(payload.domain_group_links);
     route_service.replace_all(payload.local_routes);
@@ -0,0 +1,81 @@
+use crate::command::domain_monitor_command::monitor_services;
+use crate::model::api_response::ApiResponse;
+use crate::model::status_page::{StatusPage, StatusPageConfig};
+use crate::service::domain_group_link_service::DomainGroupLinkService;
+use crate::service::domain_group_service::DomainGroupService;
+use crate::service::domain_monitor_service::DomainMonitorService;
+use crate::service::domain_service::DomainService;
+use crate::service::status_page::StatusPageService;
+use std::sync::Arc;
+
+#[tauri::command]
+pub fn get_status_page_config(
+    status_page_service: tauri::State<'_, Arc<StatusPageService>>,
+) -> Result<ApiResponse<StatusPageConfig>, String> {
+    Ok(ApiResponse {
+        message: "상태 페이지 설정 조회 완료".to_string(),
+        success: true,
+        data: status_page_service.get_config(),
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetStatusPageConfigPayload {
+    pub config: StatusPageConfig,
+}
+
+/// 상태 페이지 설정 저장. 켜져 있으면 바로 다시 만들어 폴더에 씀 (다음 체크 주기를 기다리지 않음).
+#[tauri::command]
+pub fn set_status_page_config(
+    payload: SetStatusPageConfigPayload,
+    domain_service: tauri::State<'_, DomainService>,
+    group_service: tauri::State<'_, DomainGroupService>,
+    link_service: tauri::State<'_, DomainGroupLinkService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    status_page_service: tauri::State<'_, Arc<StatusPageService>>,
+    app: tauri::AppHandle,
+) -> Result<ApiResponse<StatusPageConfig>, String> {
+    Ok(match status_page_service.set_config(payload.config) {
+        Ok(config) => {
+            monitor_service.publish_status_page(
+                &domain_service,
+                &group_service,
+                &link_service,
+                &monitor_services(&app),
+            );
+            ApiResponse {
+                message: "상태 페이지 설정 저장 완료".to_string(),
+                success: true,
+                data: config,
+            }
+        }
+        Err(e) => ApiResponse {
+            message: e,
+            success: false,
+            data: status_page_service.get_config(),
+        },
+    })
+}
+
+/// 지금 설정으로 만든 상태 페이지 (미리보기, 꺼져 있어도 만듦)
+#[tauri::command]
+pub fn preview_status_page(
+    domain_service: tauri::State<'_, DomainService>,
+    group_service: tauri::State<'_, DomainGroupService>,
+    link_service: tauri::State<'_, DomainGroupLinkService>,
+    monitor_service: tauri::State<'_, DomainMonitorService>,
+    app: tauri::AppHandle,
+) -> Result<ApiResponse<StatusPage>, String> {
+    let page = monitor_service.build_status_page(
+        &domain_service,
+        &group_service,
+        &link_service,
+        &monitor_services(&app),
+    );
+    Ok(ApiResponse {
+        message: "상태 페이지 생성 완료".to_string(),
+        success: true,
+        data: page,
+    })
+}
@@ -9,54 +9,114 @@ mod storage {
     pub mod versioned;
 }
 mod model {
+    pub mod alert;
     pub mod api_response;
+    pub mod cert_check;
+    pub mod check_concurrency;
+    pub mod check_policy;
+    pub mod content_watch;
+    pub mod dns_check;
     pub mod domain;
     pub mod domain_api_logging_link;
     pub mod domain_group;
     pub mod domain_group_link;
     pub mod domain_monitor_link;
     pub mod domain_status_log;
+    pub mod heartbeat;
+    pub mod http_check;
+    pub mod incident;
+    pub mod latency_baseline;
     pub mod local_route;
+    pub mod maintenance;
     pub mod api_log;
+    pub mod openapi_check;
+    pub mod probe_options;
     pub mod proxy_settings;
+    pub mod route_health;
+    pub mod security_audit;
     pub mod settings_export;
+    pub mod status_page;
+    pub mod sub_page;
+    pub mod tcp_check;
+    pub mod uptime;
 }
 mod service {
+    pub mod alert_service;
     pub mod api_logging_settings_service;
     pub mod api_log_service;
     pub mod ca_service;
+    pub mod cert_check;
+    pub mod chat_notify;
+    pub mod check_limiter;
+    pub mod confirmation;
+    pub mod content_watch;
+    pub mod crawler;
+    pub mod dns_check;
     pub mod domain_group_link_service;
     pub mod domain_group_service;
     pub mod domain_monitor_service;
     pub mod domain_service;
+    pub mod email_notify;
+    pub mod heartbeat_service;
+    pub mod http_probe;
+    pub mod http_transport;
+    pub mod incident_service;
+    pub mod latency_baseline;
     pub mod local_proxy;
     pub mod local_route_service;
+    pub mod maintenance_service;
+    pub mod metrics;
+    pub mod net_probe;
+    pub mod openapi_check;
     pub mod proxy_settings_service;
+    pub mod route_health;
+    pub mod security_audit;
+    pub mod status_page;
+    pub mod sub_page_service;
     pub mod system_proxy_service;
+    pub mod uptime_rollup;
 }
 
+use crate::service::alert_service::AlertService;
 use crate::service::api_logging_settings_service::ApiLoggingSettingsService;
 use crate::service::api_log_service::ApiLogService;
 use crate::service::ca_service::CaService;
+use crate::service::content_watch::ContentWatchService;
 use crate::service::domain_group_link_service::DomainGroupLinkService;
 use crate::service::domain_group_service::DomainGroupService;
-use crate::service::domain_monitor_service::DomainMonitorService;
+use crate::service::domain_monitor_service::{DomainMonitorService, MonitorServices};
 use crate::service::domain_service::DomainService;
+use crate::service::heartbeat_service::HeartbeatService;
+use crate::service::incident_service::IncidentService;
+use crate::service::latency_baseline::LatencyBaselineService;
 use crate::service::local_route_service::LocalRouteService;
+use crate::service::maintenance_service::MaintenanceService;
+use crate::service::metrics::MetricsService;
+use crate::service::openapi_check::OpenApiCheckService;
 use crate::service::proxy_settings_service::ProxySettingsService;
+use crate::service::route_health::RouteHealthService;
+use crate::service::security_audit::SecurityAuditService;
+use crate::service::status_page::StatusPageService;
+use crate::service::sub_page_service::SubPageService;
 use std::sync::Arc;
 
 mod logging;
 mod command {
+    pub mod alert_commands;
     pub mod api_log_commands;
     pub mod domain_commands;
     pub mod domain_group_commands;
     pub mod domain_monitor_command;
     pub mod local_route_commands;
+    pub mod maintenance_commands;
     pub mod settings_commands;
+    pub mod status_page_commands;
     pub mod window_commands;
 }
 
+use command::alert_commands::{
+    get_alert_config, get_alert_history, send_test_alert, set_alert_config,
+};
 use command::domain_commands::{
     clear_all_domains, get_domain_by_id, get_domains, import_domains, regist_domains,
     remove_domains, update_domain_by_id,
@@ -65,22 +125,40 @@ use command::domain_group_commands::{
     create_group, delete_group, get_domain_group_links, get_domains_by_group, get_groups,
     get_groups_for_domain, set_domain_groups, set_group_domains, update_group,
 };
+use command::domain_monitor_command::monitor_services;
 use command::domain_monitor_command::{
-    check_domain_status, get_domain_monitor_list, get_domain_status_logs, get_latest_status,
-    set_domain_monitor_check_enabled,
+    acknowledge_incident, annotate_incident, audit_domain_security, check_domain_certificates,
+    check_domain_content, check_domain_dns, check_domain_status, crawl_domain, get_cert_check_logs,
+    get_check_cycle_stats, get_confirmed_states, get_content_changes, get_content_diff,
+    get_content_watch_status, get_crawl_report, get_dns_events, get_dns_snapshots,
+    get_domain_monitor_list, get_domain_status_logs, get_heartbeat_status, get_incident_stats,
+    get_incidents, get_latency_baselines, get_latest_cert_checks, get_latest_status,
+    get_openapi_check_logs, get_openapi_checks, get_security_audit_logs, get_security_audits,
+    get_state_changes, get_sub_pages, get_uptime_report, run_openapi_checks, set_check_concurrency,
+    set_domain_cert_check, set_domain_check_policy, set_domain_content_watch, set_domain_dns_check,
+    set_domain_heartbeat, set_domain_monitor_check_enabled, set_domain_monitor_http_check,
+    set_domain_monitor_interval, set_domain_openapi_check, set_domain_probe_options,
+    set_domain_security_audit, set_domain_tcp_check, set_sub_page_monitor_check_enabled,
 };
 use command::local_route_commands::{
-    add_local_route, get_local_routes, get_proxy_auto_start_error, get_proxy_settings,
-    get_proxy_setup_url, get_proxy_status, remove_local_route, set_local_route_enabled,
-    set_local_routing_enabled, set_proxy_dns_server, set_proxy_port, set_proxy_reverse_ports,
-    start_local_proxy, stop_local_proxy, update_local_route,
+    add_local_route, check_local_routes, get_local_routes, get_proxy_auto_start_error,
+    get_proxy_settings, get_proxy_setup_url, get_proxy_status, get_route_health,
+    remove_local_route, set_local_route_enabled, set_local_routing_enabled, set_proxy_dns_server,
+    set_proxy_port, set_proxy_reverse_ports, set_route_health_interval, start_local_proxy,
+    stop_local_proxy, update_local_route,
 };
 use command::api_log_commands::{
     download_api_schema, get_api_schema_content, get_domain_api_logging_links,
     remove_domain_api_logging, send_api_request, set_domain_api_logging,
     list_api_log_dates, get_api_logs, clear_api_logs,
 };
+use command::maintenance_commands::{
+    get_active_maintenance, get_maintenance_windows, set_maintenance_windows,
+};
 use command::settings_commands::{export_all_settings, import_all_settings, save_root_ca};
+use command::status_page_commands::{
+    get_status_page_config, preview_status_page, set_status_page_config,
+};
 use command::window_commands::open_window;
 
 #[tauri::command]
@@ -134,12 +212,52 @@ pub fn run() {
             let domain_service = DomainService::new(storage_path);
             let group_service = DomainGroupService::new(groups_storage_path);
             let link_service = DomainGroupLinkService::new(links_storage_path);
-            let monitor_service = DomainMonitorService::new(logs_dir, monitor_links_path);
+            let monitor_service = DomainMonitorService::new(logs_dir.clone(), monitor_links_path);
+            // Services the check cycle reports into; registered separately from `DomainMonitorService`.
+            let incident_service = IncidentService::new(logs_dir.join("incidents.json"));
+            let alert_service = Arc::new(AlertService::new(
+                app_data_dir.join("alert_settings.json"),
+                logs_dir.join("alert_history.json"),
+            ));
+            let maintenance_service =
+                MaintenanceService::new(app_data_dir.join("maintenance_windows.json"));
+            let heartbeat_service =
+                Arc::new(HeartbeatService::new(logs_dir.join("heartbeats.json")));
+            let status_page_service = Arc::new(StatusPageService::new(
+                app_data_dir.join("status_page.json"),
+                app_data_dir.join("status_page"),
+            ));
+            let metrics_service = Arc::new(MetricsService::new());
+            let latency_baseline_service =
+                LatencyBaselineService::new(logs_dir.join("latency_baselines.json"));
+            let sub_page_service = SubPageService::new(
+                app_data_dir.join("sub_pages.json"),
+                app_data_dir.join("sub_page_monitor_links.json"),
+                logs_dir.join("crawl"),
+            );
+            let content_watch_service = ContentWatchService::new(logs_dir.join("content"));
+            let security_audit_service = SecurityAuditService::new(logs_dir.join("security"));
+            let openapi_check_service = OpenApiCheckService::new(logs_dir.join("openapi"));
             let local_route_service = Arc::new(LocalRouteService::new(local_routes_path));
             let proxy_settings_service = ProxySettingsService::new(proxy_settings_path);
             let api_logging_service = ApiLoggingSettingsService::new(api_logging_path);
             let api_log_service = ApiLogService::new(app_data_dir.clone());
-            monitor_service.sync_with_domains(&domain_service.get_all());
+            monitor_service.sync_with_domains(
+                &domain_service.get_all(),
+                &MonitorServices {
+                    incidents: &incident_service,
+                    alerts: &alert_service,
+                    maintenance: &maintenance_service,
+                    heartbeats: &heartbeat_service,
+                    status_page: &status_page_service,
+                    metrics: &metrics_service,
+                    latency_baselines: &latency_baseline_service,
+                    sub_pages: &sub_page_service,
+                    content_watch: &content_watch_service,
+                    security_audits: &security_audit_service,
+                    openapi_checks: &openapi_check_service,
+                },
+            );
             api_logging_service.refresh_map(&domain_service.get_all());
 
             // Clone/read values needed for auto-start before `app.manage()` moves them.
@@ -147,13 +265,30 @@ pub fn run() {
             let proxy_settings_snapshot = proxy_settings_service.get();
             let api_logging_map_for_proxy = api_logging_service.settings_map_arc();
             let ca_service_for_proxy = Arc::clone(&ca_service);
+            let reserved_for_proxy = crate::service::local_proxy::ReservedPathServices {
+                heartbeats: Arc::clone(&heartbeat_service),
+                status_page: Arc::clone(&status_page_service),
+                metrics: Arc::clone(&metrics_service),
+            };
 
             app.manage(ca_service);
             app.manage(domain_service);
             app.manage(group_service);
             app.manage(link_service);
             app.manage(monitor_service);
+            app.manage(incident_service);
+            app.manage(alert_service);
+            app.manage(maintenance_service);
+            app.manage(heartbeat_service);
+            app.manage(status_page_service);
+            app.manage(metrics_service);
+            app.manage(latency_baseline_service);
+            app.manage(sub_page_service);
+            app.manage(content_watch_service);
+            app.manage(security_audit_service);
+            app.manage(openapi_check_service);
             app.manage(local_route_service);
+            app.manage(RouteHealthService::new());
             app.manage(proxy_settings_service);
             app.manage(api_logging_service);
             app.manage(api_log_service.clone());
@@ -169,6 +304,7 @@ pub fn run() {
                         api_logging_map_for_proxy,
                         std::sync::Arc::new(api_log_service.clone()),
                         ca_service_for_proxy,
+                        reserved_for_proxy,
                     )
                     .await
                     {
@@ -191,31 +327,93 @@ pub fn run() {
                 });
             }
 
-            // Background status check probe (runs every 2 min for domains with check_enabled)
+            // Background status check scheduler: each domain is checked on its own `interval_secs`.
+            // Due domains are checked in a spawned task so a slow batch does not delay the others;
+            // interval/enable changes wake the loop immediately.
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    {
+                        use tauri::Manager;
+                        let monitor_service = handle.state::<DomainMonitorService>();
+                        let domain_service = handle.state::<DomainService>();
+                        let due = monitor_service
+                            .take_due_domain_ids(&domain_service, std::time::Instant::now());
+                        if !due.is_empty() {
+                            let handle = handle.clone();
+                            tauri::async_runtime::spawn(async move {
+                                let domain_service = handle.state::<DomainService>();
+                                let group_service = handle.state::<DomainGroupService>();
+                                let link_service = handle.state::<DomainGroupLinkService>();
+                                let monitor_service = handle.state::<DomainMonitorService>();
+                                let proxy_settings_service = handle.state::<ProxySettingsService>();
+
+                                // Perform checks (uses global DNS from Settings when set)
+                                let results = monitor_service
+                                    .check_domain_ids(
+                                        &due,
+                                        &domain_service,
+                                        &group_service,
+                                        &link_service,
+                                        &proxy_settings_service,
+                                        &monitor_services(&handle),
+                                    )
+                                    .await;
+                                tracing::info!(
+                                    "Background status check completed ({} domains)",
+                                    results.len()
+                                );
+                            });
+                        }
+                        let wait = monitor_service.time_until_next_due(std::time::Instant::now());
+                        monitor_service.wait_for_schedule(wait).await;
+                    }
+                }
+            });
+
+            // Local route backend health: every `route_health_interval_secs` when set.
+            // Interval changes wake the loop immediately.
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    use tauri::Manager;
+                    let route_health = handle.state::<RouteHealthService>();
+                    let interval = handle
+                        .state::<ProxySettingsService>()
+                        .get()
+                        .route_health_interval_secs;
+                    let wait = if let Some(secs) = interval {
+                        let routes = handle.state::<Arc<LocalRouteService>>().get_all();
+                        let routing = crate::service::local_proxy::is_local_routing_enabled();
+                        route_health.check_all(&routes, routing).await;
+                        std::time::Duration::from_secs(u64::from(secs))
+                    } else {
+                        std::time::Duration::from_secs(3600)
+                    };
+                    route_health.wait(wait).await;
+                }
+            });
+
+            // Daily email digests: checked once a minute, each mail channel sends after its `digestTime`.
             let handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
                 loop {
                     {
                         use tauri::Manager;
+                        let monitor_service = handle.state::<DomainMonitorService>();
                         let domain_service = handle.state::<DomainService>();
                         let group_service = handle.state::<DomainGroupService>();
                         let link_service = handle.state::<DomainGroupLinkService>();
-                        let monitor_service = handle.state::<DomainMonitorService>();
-                        let proxy_settings_service = handle.state::<ProxySettingsService>();
-
-                        // Perform checks (uses global DNS from Settings when set)
-                        let _ = monitor_service
-                            .check_domains(
+                        monitor_service
+                            .send_due_digests(
                                 &domain_service,
                                 &group_service,
                                 &link_service,
-                                &proxy_settings_service,
+                                &monitor_services(&handle),
                             )
                             .await;
-                        tracing::info!("Background status check completed");
                     }
-                    // Wait for 2 minutes before next check
-                    tokio::time::sleep(std::time::Duration::from_secs(120)).await;
+                    tokio::time::sleep(std::time::Duration::from_secs(60)).await;
                 }
             });
 
@@ -253,6 +451,9 @@ pub fn run() {
             update_local_route,
             remove_local_route,
             set_local_route_enabled,
+            get_route_health,
+            check_local_routes,
+            set_route_health_interval,
             get_proxy_status,
             start_local_proxy,
             stop_local_proxy,
@@ -266,6 +467,58 @@ pub fn run() {
             save_root_ca,
             get_domain_monitor_list,
             set_domain_monitor_check_enabled,
+            set_domain_monitor_interval,
+            set_domain_monitor_http_check,
+            set_domain_probe_options,
+            set_check_concurrency,
+            get_check_cycle_stats,
+            set_domain_tcp_check,
+            set_domain_heartbeat,
+            get_heartbeat_status,
+            get_latency_baselines,
+            set_domain_check_policy,
+            get_confirmed_states,
+            get_state_changes,
+            get_incidents,
+            annotate_incident,
+            acknowledge_incident,
+            get_incident_stats,
+            get_uptime_report,
+            get_alert_config,
+            set_alert_config,
+            get_alert_history,
+            send_test_alert,
+            get_maintenance_windows,
+            set_maintenance_windows,
+            get_active_maintenance,
+            get_status_page_config,
+            set_status_page_config,
+            preview_status_page,
+            set_domain_cert_check,
+            get_latest_cert_checks,
+            check_domain_certificates,
+            get_cert_check_logs,
+            set_domain_dns_check,
+            get_dns_snapshots,
+            check_domain_dns,
+            get_dns_events,
+            set_domain_content_watch,
+            get_content_watch_status,
+            set_domain_security_audit,
+            get_security_audits,
+            audit_domain_security,
+            get_security_audit_logs,
+            set_domain_openapi_check,
+            get_openapi_checks,
+            run_openapi_checks,
+            get_openapi_check_logs,
+            check_domain_content,
+            get_content_changes,
+            get_content_diff,
+            crawl_domain,
+            get_sub_pages,
+            set_sub_page_monitor_check_enabled,
+            get_crawl_report,
             get_domain_api_logging_links,
             set_domain_api_logging,
             remove_domain_api_logging,
@@ -0,0 +1,257 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 규칙이 적용되는 대상
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AlertScope {
+    /// 체크 대상 전체
+    #[default]
+    All,
+    #[serde(rename_all = "camelCase")]
+    Domain { domain_id: u32 },
+    #[serde(rename_all = "camelCase")]
+    Group { group_id: u32 },
+}
+
+/// 알림 조건
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AlertTrigger {
+    /// down 확정 (`StateChange.to == Down`)
+    Down,
+    /// down → up 확정
+    Recovered,
+    /// error가 아닌 체크의 응답 시간이 `ms` 초과
+    LatencyOver { ms: u32 },
+    /// 인증서 남은 일수가 `days` 이하 (만료 포함)
+    CertExpiring { days: i64 },
+    /// 보안 점검 규칙 점수가 직전 정상 점검보다 떨어짐
+    SecurityRegression,
+}
+
+impl AlertTrigger {
+    /// `AlertEvent.trigger`에 쓰는 이름 (serde tag와 동일)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Down => "down",
+            Self::Recovered => "recovered",
+            Self::LatencyOver { .. } => "latencyOver",
+            Self::CertExpiring { .. } => "certExpiring",
+            Self::SecurityRegression => "securityRegression",
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_cooldown_secs() -> u64 {
+    900
+}
+
+fn default_attempts() -> u32 {
+    1
+}
+
+/// 알림 규칙. 조건이 맞으면 `channel_ids`의 채널로 보냄.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRule {
+    pub id: u32,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub scope: AlertScope,
+    pub trigger: AlertTrigger,
+    pub channel_ids: Vec<u32>,
+    /// 같은 규칙·url로 다시 보내기까지 최소 간격 (초). 기본 15분
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+}
+
+/// 채널 종류별 설정
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ChannelKind {
+    /// `AlertEvent`를 JSON 그대로 POST
+    Webhook {
+        url: String,
+        #[serde(default)]
+        headers: HashMap<String, String>,
+    },
+    /// Slack Incoming Webhook (blocks)
+    #[serde(rename_all = "camelCase")]
+    Slack {
+        webhook_url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        template: Option<String>,
+    },
+    /// Discord 웹훅 (embed)
+    #[serde(rename_all = "camelCase")]
+    Discord {
+        webhook_url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        template: Option<String>,
+    },
+    /// Microsoft Teams Incoming Webhook (`MessageCard`)
+    #[serde(rename_all = "camelCase")]
+    Teams {
+        webhook_url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        template: Option<String>,
+    },
+    /// SMTP 메일. 상태 변경 즉시 알림과 일일 요약(digest)
+    Email(EmailChannelConfig),
+}
+
+impl ChannelKind {
+    /// 규칙 알림을 즉시 보내는 채널인지 (digest 전용 메일 채널은 제외)
+    pub fn sends_alerts(&self) -> bool {
+        !matches!(self, Self::Email(email) if !email.immediate)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SmtpSecurity {
+    /// 평문 연결 후 STARTTLS (보통 587)
+    #[default]
+    StartTls,
+    /// 처음부터 TLS (보통 465)
+    Tls,
+    /// 암호화 없음 (로컬 릴레이·테스트용)
+    None,
+}
+
+/// 그룹 소속 도메인의 알림·요약을 받을 추가 수신자
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRecipients {
+    pub group_id: u32,
+    pub recipients: Vec<String>,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailChannelConfig {
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// 보내는 주소 (`Watchtower <alerts@example.com>` 형식 가능)
+    pub from: String,
+    /// 모든 알림·전체 요약을 받는 수신자
+    #[serde(default)]
+    pub recipients: Vec<String>,
+    #[serde(default)]
+    pub group_recipients: Vec<GroupRecipients>,
+    /// 규칙 알림을 바로 메일로 보냄. 끄면 digest만
+    #[serde(default = "default_enabled")]
+    pub immediate: bool,
+    /// 일일 요약 발송 시각 (로컬 `HH:MM`). 없으면 보내지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest_time: Option<String>,
+    /// 즉시 알림 본문 템플릿 (채팅 채널과 같은 자리표시자)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertChannelConfig {
+    pub id: u32,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub kind: ChannelKind,
+}
+
+/// 알림 규칙·채널. `alert_settings.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertConfig {
+    #[serde(default)]
+    pub rules: Vec<AlertRule>,
+    #[serde(default)]
+    pub channels: Vec<AlertChannelConfig>,
+}
+
+/// 규칙 하나가 발생시킨 알림 내용. 채널에 보내는 본문.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertEvent {
+    pub rule_id: u32,
+    pub rule_name: String,
+    /// `AlertTrigger::kind`
+    pub trigger: String,
+    pub domain_id: u32,
+    pub url: String,
+    /// 소속 그룹 이름. 그룹이 없으면 `["Default"]`
+    pub groups: Vec<String>,
+    /// 소속 그룹 id (그룹별 수신자 선택용)
+    #[serde(default)]
+    pub group_ids: Vec<u32>,
+    /// 체크 상태 (예: `503 Service Unavailable`) 또는 인증서 상태
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency: Option<u32>,
+    /// 사람이 읽는 요약
+    pub message: String,
+    /// down·recovered 알림이면 관련 장애 id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incident_id: Option<u32>,
+    /// recovered 알림이면 장애 지속 시간 (초)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downtime_secs: Option<i64>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 채널 하나로의 전송 기록. `logs/alert_history.json` (versioned, 최근 `MAX_ALERT_HISTORY`건)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRecord {
+    pub id: u64,
+    pub channel_id: u32,
+    pub event: AlertEvent,
+    pub delivered: bool,
+    /// 보낸 횟수 (실패 시 재시도 포함)
+    #[serde(default = "default_attempts")]
+    pub attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_json_shape() {
+        let json = r#"{
+            "id": 1,
+            "name": "prod down",
+            "scope": {"type": "group", "groupId": 3},
+            "trigger": {"type": "latencyOver", "ms": 800},
+            "channelIds": [1]
+        }"#;
+        let rule: AlertRule = serde_json::from_str(json).unwrap();
+        assert!(rule.enabled);
+        assert_eq!(rule.scope, AlertScope::Group { group_id: 3 });
+        assert_eq!(rule.trigger.kind(), "latencyOver");
+        assert_eq!(rule.cooldown_secs, 900);
+    }
+}
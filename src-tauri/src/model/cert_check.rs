@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 도메인별 인증서 체크 설정 (`DomainMonitorLink.cert_check`). 없으면 기본값으로 활성화.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CertCheckConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 만료까지 남은 일수가 이 값 이하이면 warning
+    #[serde(default = "default_warning_days")]
+    pub warning_days: u32,
+    /// 만료까지 남은 일수가 이 값 이하이면 error
+    #[serde(default = "default_error_days")]
+    pub error_days: u32,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_warning_days() -> u32 {
+    30
+}
+
+fn default_error_days() -> u32 {
+    7
+}
+
+impl Default for CertCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            warning_days: default_warning_days(),
+            error_days: default_error_days(),
+        }
+    }
+}
+
+/// https 도메인의 리프 인증서 체크 결과. 최신은 메모리, 과거는 logs/certs/{date}.json
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CertCheckResult {
+    pub url: String,
+    pub host: String,
+    pub port: u16,
+    pub timestamp: DateTime<Utc>,
+    /// `info` / `warning` / `error` (`DomainStatusLog.level`과 동일 규칙)
+    pub level: String,
+    pub ok: bool,
+    pub not_after: Option<DateTime<Utc>>,
+    /// 만료까지 남은 일수. 이미 만료면 음수
+    pub days_remaining: Option<i64>,
+    pub issuer: Option<String>,
+    pub subject: Option<String>,
+    /// Subject Alternative Names (DNS, IP)
+    #[serde(default)]
+    pub sans: Vec<String>,
+    /// 서버가 보낸 인증서 수 (리프 포함)
+    pub chain_length: usize,
+    /// 신뢰 루트까지 체인 검증 성공 여부
+    pub chain_valid: bool,
+    /// SAN(또는 CN)이 호스트와 일치하는지
+    pub hostname_match: bool,
+    pub error_message: Option<String>,
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_max_concurrent() -> u32 {
+    32
+}
+
+fn default_max_per_host() -> u32 {
+    4
+}
+
+/// 체크 동시 실행·분산 설정. `check_concurrency.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConcurrency {
+    /// 동시에 보내는 체크 요청 수 (모든 주기 합산, 1~256)
+    #[serde(default = "default_max_concurrent")]
+    pub max_concurrent: u32,
+    /// 같은 호스트로 동시에 보내는 체크 요청 수 (1~64)
+    #[serde(default = "default_max_per_host")]
+    pub max_per_host: u32,
+    /// 다음 체크 예정 시각을 간격의 ±N% 안에서 무작위로 흔듦 (0~50)
+    #[serde(default)]
+    pub jitter_percent: u32,
+    /// 새로 추가되거나 앱 시작 직후인 도메인을 한꺼번에 체크하지 않고 간격 안에 고르게 나눔
+    #[serde(default)]
+    pub spread: bool,
+}
+
+impl Default for CheckConcurrency {
+    fn default() -> Self {
+        Self {
+            max_concurrent: default_max_concurrent(),
+            max_per_host: default_max_per_host(),
+            jitter_percent: 0,
+            spread: false,
+        }
+    }
+}
+
+impl CheckConcurrency {
+    /// 허용 범위로 맞춘 값
+    pub fn normalized(&self) -> Self {
+        Self {
+            max_concurrent: self.max_concurrent.clamp(1, 256),
+            max_per_host: self.max_per_host.clamp(1, 64),
+            jitter_percent: self.jitter_percent.min(50),
+            spread: self.spread,
+        }
+    }
+}
+
+/// 체크 주기 한 번 (`check_domain_ids` 호출 1회)의 시간·대기 통계. 메모리 전용 (최근 100개)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckCycleStats {
+    pub started_at: DateTime<Utc>,
+    /// 체크·기록·알림·인증서·DNS·내용 감시까지 전체
+    pub duration_ms: u32,
+    /// 그중 프로브(재시도 포함) 단계
+    pub probe_ms: u32,
+    pub domains: u32,
+    /// 도메인 + 체크가 켜진 하위 페이지
+    pub targets: u32,
+    /// 재시도를 포함한 요청 수 (인증서·보안 점검·DNS·내용 감시·`OpenAPI` 요청 포함)
+    pub attempts: u32,
+    /// 동시 실행 제한 때문에 기다린 요청 수
+    pub queued: u32,
+    /// 가장 오래 기다린 시간 (ms)
+    pub max_wait_ms: u32,
+    /// 이전 주기 이후, 예정 시각이 됐지만 이전 체크가 끝나지 않아 건너뛴 도메인 수
+    pub skipped: u32,
+}
+
+/// `get_check_cycle_stats` 응답
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckCycleReport {
+    pub settings: CheckConcurrency,
+    /// 지금 체크 중인 도메인 수
+    pub in_flight: u32,
+    /// 최근 주기 (최신이 앞)
+    pub cycles: Vec<CheckCycleStats>,
+    pub avg_duration_ms: u32,
+    pub max_duration_ms: u32,
+    pub total_queued: u32,
+    pub total_skipped: u32,
+}
@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 한 체크 안에서 재시도하는 최대 횟수
+pub const MAX_RETRIES: u32 = 5;
+/// 재시도 대기 상한 (ms). 첫 대기도, 2배씩 늘어난 대기도 이보다 길지 않음
+pub const MAX_RETRY_BACKOFF_MS: u32 = 30_000;
+
+/// 재시도·확정·플랩 판정 규칙 (`DomainMonitorLink.policy`). 없으면 기본값 = 재시도 없이 1회 결과로 확정.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckPolicy {
+    /// 한 번의 체크 안에서 error일 때 다시 시도하는 횟수
+    #[serde(default)]
+    pub retries: u32,
+    /// 첫 재시도 전 대기 (ms). 이후 재시도마다 2배
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u32,
+    /// 연속 N회 실패해야 down으로 확정
+    #[serde(default = "default_threshold")]
+    pub fail_threshold: u32,
+    /// 연속 M회 성공해야 up으로 확정
+    #[serde(default = "default_threshold")]
+    pub success_threshold: u32,
+    /// 플랩 판정에 쓰는 최근 체크 수
+    #[serde(default = "default_flap_window")]
+    pub flap_window: u32,
+    /// `flap_window` 안에서 성공/실패가 이 횟수 이상 뒤바뀌면 flapping
+    #[serde(default = "default_flap_threshold")]
+    pub flap_threshold: u32,
+}
+
+fn default_retry_backoff_ms() -> u32 {
+    500
+}
+
+fn default_threshold() -> u32 {
+    1
+}
+
+fn default_flap_window() -> u32 {
+    10
+}
+
+fn default_flap_threshold() -> u32 {
+    4
+}
+
+impl Default for CheckPolicy {
+    fn default() -> Self {
+        Self {
+            retries: 0,
+            retry_backoff_ms: default_retry_backoff_ms(),
+            fail_threshold: default_threshold(),
+            success_threshold: default_threshold(),
+            flap_window: default_flap_window(),
+            flap_threshold: default_flap_threshold(),
+        }
+    }
+}
+
+impl CheckPolicy {
+    /// 재시도 횟수·대기를 허용 범위로 맞춘 값 (가져온 설정 파일의 큰 값이 체크를 붙잡지 않도록)
+    pub fn normalized(&self) -> Self {
+        Self {
+            retries: self.retries.min(MAX_RETRIES),
+            retry_backoff_ms: self.retry_backoff_ms.min(MAX_RETRY_BACKOFF_MS),
+            ..self.clone()
+        }
+    }
+}
+
+/// 확정 상태
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConfirmedStatus {
+    /// 아직 확정 전 (체크 이력 없음 또는 실패 횟수가 임계값 미만)
+    #[default]
+    Unknown,
+    Up,
+    Down,
+}
+
+/// url별 확정 상태. 시도별 원본 결과(`DomainStatusLog`)와 별도로 logs/state/confirmed.json에 저장.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedState {
+    pub url: String,
+    pub status: ConfirmedStatus,
+    /// 현재 상태로 바뀐 시각
+    pub since: Option<DateTime<Utc>>,
+    pub consecutive_failures: u32,
+    pub consecutive_successes: u32,
+    /// 최근 체크 성공 여부 (오래된 것부터, 최대 `flap_window`개)
+    #[serde(default)]
+    pub recent: Vec<bool>,
+    /// 성공/실패가 자주 뒤바뀌는 중. 알림은 이 동안 억제
+    pub flapping: bool,
+    pub last_checked: Option<DateTime<Utc>>,
+    /// 플래핑으로 억제되지 않은 마지막 변경의 상태. 플래핑이 끝났을 때 이와 다르면 다시 변경으로 냄
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alerted_status: Option<ConfirmedStatus>,
+}
+
+impl ConfirmedState {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            status: ConfirmedStatus::Unknown,
+            since: None,
+            consecutive_failures: 0,
+            consecutive_successes: 0,
+            recent: Vec::new(),
+            flapping: false,
+            last_checked: None,
+            alerted_status: None,
+        }
+    }
+}
+
+/// 확정 상태 변경 기록. logs/state/{date}.json
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StateChange {
+    pub url: String,
+    pub from: ConfirmedStatus,
+    pub to: ConfirmedStatus,
+    /// 변경 시점에 flapping이었는지 (true면 알림 억제 대상)
+    pub flapping: bool,
+    /// down 확정 시 마지막 실패 사유
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
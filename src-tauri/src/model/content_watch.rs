@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_strip_whitespace() -> bool {
+    true
+}
+
+/// 도메인별 내용 변경 감시 설정 (`DomainMonitorLink.content_watch`). 없으면 감시하지 않음.
+/// GET 응답 바디를 정규화해 해시가 바뀌면 변경으로 기록.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentWatchConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// HTML에서 비교할 부분 (CSS 선택자). 여러 요소면 텍스트를 줄 단위로 이음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    /// JSON에서 비교할 값 (`$.a.b[0]`). `selector`보다 우선
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_path: Option<String>,
+    /// 줄마다 앞뒤 공백 제거·연속 공백 합치기·빈 줄 제거
+    #[serde(default = "default_strip_whitespace")]
+    pub strip_whitespace: bool,
+    /// 비교 전에 지울 정규식 (타임스탬프·CSRF 토큰 등 매번 바뀌는 부분)
+    #[serde(default)]
+    pub ignore_patterns: Vec<String>,
+    /// 추가 요청 헤더
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+}
+
+/// 정규화한 내용 한 벌. `logs/content/snapshots/{url 해시}.json`에 url별 최근 `MAX_CONTENT_SNAPSHOTS`개
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSnapshot {
+    /// 정규화한 내용의 SHA-256 (hex)
+    pub hash: String,
+    pub content: String,
+    /// 이 내용을 처음 받은 시각
+    pub captured_at: DateTime<Utc>,
+}
+
+/// url별 감시 상태. `logs/content/state.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentWatchState {
+    pub url: String,
+    /// 마지막으로 받은 내용의 해시. 아직 성공한 적 없으면 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    pub last_checked: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_changed: Option<DateTime<Utc>>,
+    /// 마지막 조회·추출 실패 사유. 성공하면 지움
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 내용 변경 기록. `logs/content/{date}.json` (NDJSON)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentChange {
+    pub url: String,
+    pub timestamp: DateTime<Utc>,
+    /// 처음 받은 내용이면 `None` (기준 저장만 하고 변경으로 기록하지 않음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_hash: Option<String>,
+    pub hash: String,
+    pub added_lines: u32,
+    pub removed_lines: u32,
+}
+
+/// FE 표시용: 감시 중인 도메인 + 설정 + 상태
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentWatchStatus {
+    pub domain_id: u32,
+    pub url: String,
+    pub config: ContentWatchConfig,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ContentWatchState>,
+    /// 보관 중인 스냅샷 (최신순, 내용 제외)
+    pub snapshots: Vec<ContentSnapshotInfo>,
+}
+
+/// 스냅샷 목록용 (내용 제외)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSnapshotInfo {
+    pub hash: String,
+    pub captured_at: DateTime<Utc>,
+}
+
+/// 두 스냅샷의 unified diff
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentDiff {
+    pub url: String,
+    pub from: ContentSnapshotInfo,
+    pub to: ContentSnapshotInfo,
+    /// `---`·`+++` 헤더가 붙은 unified diff. 같으면 빈 문자열
+    pub diff: String,
+}
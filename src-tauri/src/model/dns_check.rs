@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 감시할 DNS 레코드 종류
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DnsRecordType {
+    A,
+    Aaaa,
+    Cname,
+    Mx,
+    Txt,
+    Ns,
+}
+
+impl DnsRecordType {
+    pub const ALL: [Self; 6] = [
+        Self::A,
+        Self::Aaaa,
+        Self::Cname,
+        Self::Mx,
+        Self::Txt,
+        Self::Ns,
+    ];
+}
+
+impl std::fmt::Display for DnsRecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::A => "A",
+            Self::Aaaa => "AAAA",
+            Self::Cname => "CNAME",
+            Self::Mx => "MX",
+            Self::Txt => "TXT",
+            Self::Ns => "NS",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 도메인별 DNS 감시 설정 (`DomainMonitorLink.dns_check`). 없으면 감시하지 않음.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsCheckConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_record_types")]
+    pub record_types: Vec<DnsRecordType>,
+    /// 시스템 리졸버·`ProxySettings.dns_server` 외에 비교할 리졸버 (`"8.8.8.8"`, `"10.0.0.2:53"`)
+    #[serde(default)]
+    pub resolvers: Vec<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_record_types() -> Vec<DnsRecordType> {
+    DnsRecordType::ALL.to_vec()
+}
+
+impl Default for DnsCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            record_types: default_record_types(),
+            resolvers: Vec::new(),
+        }
+    }
+}
+
+/// 레코드 타입 하나에 대한 질의 결과
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DnsAnswerStatus {
+    /// 레코드 있음
+    Ok,
+    /// 이름은 있지만 해당 타입 레코드 없음
+    NoRecords,
+    Nxdomain,
+    Servfail,
+    /// 타임아웃·연결 실패 등. 변경 비교에서 제외
+    Error,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsAnswer {
+    pub record_type: DnsRecordType,
+    pub status: DnsAnswerStatus,
+    /// 정렬된 값 (A: IP, MX: `"10 mail.example.com"`, TXT: 문자열, 이름 끝 `.` 제거)
+    #[serde(default)]
+    pub values: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+/// 리졸버 하나로 조회한 도메인의 레코드 스냅샷. 최신은 logs/dns/snapshots.json
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsSnapshot {
+    pub url: String,
+    pub host: String,
+    /// `"system"` 또는 `"ip:port"`
+    pub resolver: String,
+    pub timestamp: DateTime<Utc>,
+    pub answers: Vec<DnsAnswer>,
+}
+
+impl DnsSnapshot {
+    pub fn answer(&self, record_type: DnsRecordType) -> Option<&DnsAnswer> {
+        self.answers.iter().find(|a| a.record_type == record_type)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DnsEventKind {
+    /// 이전 스냅샷과 값이 달라짐
+    Changed,
+    /// NXDOMAIN으로 바뀜
+    Nxdomain,
+    /// SERVFAIL로 바뀜
+    Servfail,
+    /// 리졸버끼리 답이 다름 (split-horizon 등)
+    Mismatch,
+}
+
+/// DNS 변경·이상 이벤트. logs/dns/{date}.json
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsEvent {
+    pub url: String,
+    pub host: String,
+    pub kind: DnsEventKind,
+    pub record_type: DnsRecordType,
+    /// 이벤트가 난 리졸버. `Mismatch`는 비교한 리졸버 목록 (`", "`로 연결)
+    pub resolver: String,
+    /// `warning` / `error`
+    pub level: String,
+    #[serde(default)]
+    pub previous: Vec<String>,
+    #[serde(default)]
+    pub current: Vec<String>,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
@@ -1,5 +1,18 @@
+use crate::model::cert_check::CertCheckConfig;
+use crate::model::check_policy::CheckPolicy;
+use crate::model::content_watch::ContentWatchConfig;
+use crate::model::dns_check::DnsCheckConfig;
+use crate::model::heartbeat::HeartbeatCheck;
+use crate::model::http_check::HttpCheck;
+use crate::model::openapi_check::OpenApiCheckConfig;
+use crate::model::probe_options::ProbeOptions;
+use crate::model::security_audit::SecurityAuditConfig;
+use crate::model::tcp_check::TcpCheck;
 use serde::{Deserialize, Serialize};
 
+/// 새 도메인의 기본 체크 간격 (초)
+pub const DEFAULT_INTERVAL_SECS: u32 = 120;
+
 /// Domain–Monitor 링크. 체크 대상 도메인 + 옵션 (체크 결과 아님)
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -9,6 +22,36 @@ pub struct DomainMonitorLink {
     pub check_enabled: bool,
     #[serde(default = "default_interval")]
     pub interval_secs: u32,
+    /// 요청 방식·검증 규칙. 없으면 HEAD + 2xx/3xx
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_check: Option<HttpCheck>,
+    /// HTTP 요청 방식 (HEAD→GET 대체·인증·제한 시간·User-Agent·TLS). 없으면 기본값
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probe: Option<ProbeOptions>,
+    /// 인증서 만료 임계값. 없으면 기본값 (warning 30일, error 7일)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert_check: Option<CertCheckConfig>,
+    /// DNS 레코드 감시. 없으면 감시하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_check: Option<DnsCheckConfig>,
+    /// `tcp://` 대상의 보낼 데이터·기대 배너. 없으면 연결만 확인
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_check: Option<TcpCheck>,
+    /// `heartbeat://` 대상의 핑 토큰·주기·여유. 없으면 `Not Configured` (error)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heartbeat: Option<HeartbeatCheck>,
+    /// 페이지 내용 변경 감시 (GET + 정규화 + 해시). 없으면 감시하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_watch: Option<ContentWatchConfig>,
+    /// 보안 헤더·TLS 점검 간격. 없으면 기본값 (http(s) 도메인을 하루에 한 번)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_audit: Option<SecurityAuditConfig>,
+    /// 저장된 `OpenAPI` 스키마로 GET 엔드포인트 체크. 없으면 체크하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub openapi_check: Option<OpenApiCheckConfig>,
+    /// 재시도·down/up 확정·플랩 판정. 없으면 재시도 없이 1회 결과로 확정
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<CheckPolicy>,
 }
 
 fn default_check_enabled() -> bool {
@@ -16,7 +59,7 @@ fn default_check_enabled() -> bool {
 }
 
 fn default_interval() -> u32 {
-    120
+    DEFAULT_INTERVAL_SECS
 }
 
 /// `DomainMonitorLink` + url (FE 표시용)
@@ -27,4 +70,14 @@ pub struct DomainMonitorWithUrl {
     pub url: String,
     pub check_enabled: bool,
     pub interval_secs: u32,
+    pub http_check: Option<HttpCheck>,
+    pub probe: Option<ProbeOptions>,
+    pub cert_check: Option<CertCheckConfig>,
+    pub dns_check: Option<DnsCheckConfig>,
+    pub tcp_check: Option<TcpCheck>,
+    pub heartbeat: Option<HeartbeatCheck>,
+    pub content_watch: Option<ContentWatchConfig>,
+    pub security_audit: Option<SecurityAuditConfig>,
+    pub openapi_check: Option<OpenApiCheckConfig>,
+    pub policy: Option<CheckPolicy>,
 }
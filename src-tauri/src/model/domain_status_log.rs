@@ -1,3 +1,5 @@
+use crate::model::http_check::AssertionFailure;
+use crate::model::maintenance::MaintenanceMark;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -13,4 +15,63 @@ pub struct DomainStatusLog {
     pub group: String,
     pub timestamp: DateTime<Utc>,
     pub error_message: Option<String>,
+    /// 실패한 검증 규칙 목록 (`HttpCheck.assertions`). 성공 또는 요청 실패 시 비어 있음.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<AssertionFailure>,
+    /// 한 체크 안에서 몇 번째 시도인지 (1부터, `CheckPolicy.retries`). 재시도한 시도도 모두 로그에 남음.
+    #[serde(default = "first_attempt")]
+    pub attempt: u32,
+    /// HTTP 체크의 단계별 시간·연결 정보. HTTP 외 프로브와 이전 로그에는 없음.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing: Option<ProbeTiming>,
+    /// 리다이렉트 체인 (`HttpCheck.record_redirects` 또는 리다이렉트 루프). 마지막 항목이 최종 응답.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirects: Vec<RedirectHop>,
+    /// 점검 시간 중의 체크. 확정 상태·장애·알림에서 제외
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintenance: Option<MaintenanceMark>,
+}
+
+/// 리다이렉트를 따라가며 보낸 요청 한 건
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RedirectHop {
+    pub url: String,
+    pub status: u16,
+    /// 다음 요청 url (상대 경로는 절대 url로 변환). 리다이렉트가 아니면 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// HTTP 체크 단계별 시간 (ms)과 연결 정보.
+/// 리다이렉트를 따라간 경우 시간은 모든 요청의 합, 연결 정보는 마지막 요청 기준.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeTiming {
+    /// 호스트 이름 조회. IP로 지정한 대상은 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ms: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_ms: Option<u32>,
+    /// TLS 핸드셰이크. http 대상은 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_ms: Option<u32>,
+    /// 요청 전송 ~ 응답 헤더 수신
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttfb_ms: Option<u32>,
+    /// 응답 헤더 ~ 바디 수신 완료
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download_ms: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_ip: Option<String>,
+    /// `HTTP/1.1`, `HTTP/2`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_version: Option<String>,
+    /// `TLS 1.2`, `TLS 1.3`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_version: Option<String>,
+}
+
+fn first_attempt() -> u32 {
+    1
 }
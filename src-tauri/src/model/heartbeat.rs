@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_period_secs() -> u32 {
+    3600
+}
+
+fn default_grace_secs() -> u32 {
+    300
+}
+
+/// `heartbeat://` 대상의 설정 (`DomainMonitorLink.heartbeat`). 작업이 프록시의 핑 url을 호출.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatCheck {
+    /// 핑 url (`/.watchtower/heartbeat/{token}`)에 쓰는 식별자. 비어 있으면 저장 시 생성
+    #[serde(default)]
+    pub token: String,
+    /// 핑 예상 주기 (초). 기본 1시간
+    #[serde(default = "default_period_secs")]
+    pub period_secs: u32,
+    /// 주기를 넘겨도 down으로 보지 않는 여유 (초). 기본 5분
+    #[serde(default = "default_grace_secs")]
+    pub grace_secs: u32,
+}
+
+/// 핑 종류. url 끝의 `/start`, `/success`, `/fail` (없으면 success)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeartbeatPing {
+    Start,
+    Success,
+    Fail,
+}
+
+/// 작업 실행 한 번 (success·fail 핑 기준)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatRun {
+    /// 앞선 start 핑. 없으면 소요 시간도 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: DateTime<Utc>,
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+/// 핑 토큰별 수신 상태. `logs/heartbeats.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatState {
+    pub token: String,
+    /// 감시를 시작한 시각. 첫 핑 전에는 이 시각부터 주기를 셈
+    pub since: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_ping: Option<DateTime<Utc>>,
+    /// 마지막 success·fail 핑. down 판정 기준
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_finished: Option<DateTime<Utc>>,
+    /// start 핑 후 아직 끝나지 않은 실행의 시작 시각
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub running_since: Option<DateTime<Utc>>,
+    /// 최근 실행 (오래된 것부터, 최대 `MAX_HEARTBEAT_RUNS`개)
+    #[serde(default)]
+    pub runs: Vec<HeartbeatRun>,
+}
+
+/// FE 표시용: 도메인 + 핑 경로 + 수신 상태
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatStatus {
+    pub domain_id: u32,
+    pub url: String,
+    /// 프록시 기준 경로 (`/.watchtower/heartbeat/{token}`)
+    pub ping_path: String,
+    pub check: HeartbeatCheck,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<HeartbeatState>,
+}
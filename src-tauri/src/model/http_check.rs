@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 체크 요청 메서드. 기본은 기존 동작과 같은 HEAD.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpCheckMethod {
+    Get,
+    #[default]
+    Head,
+    Post,
+}
+
+/// 도메인별 HTTP 체크 정의 (`DomainMonitorLink.http_check`).
+/// 없으면 기존 동작: HEAD 요청, 2xx/3xx = ok.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpCheck {
+    #[serde(default)]
+    pub method: HttpCheckMethod,
+    /// 추가 요청 헤더
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// 요청 바디 (POST용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// 모두 통과해야 ok. `StatusIn`이 없으면 2xx/3xx를 기대 상태로 간주.
+    #[serde(default)]
+    pub assertions: Vec<CheckAssertion>,
+    /// 리다이렉트 체인(요청마다 url·상태·Location)을 `DomainStatusLog.redirects`에 기록
+    #[serde(default)]
+    pub record_redirects: bool,
+}
+
+/// 응답 검증 규칙. FE에서는 `{ "type": "bodyContains", "value": "..." }` 형태.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CheckAssertion {
+    /// 상태 코드가 목록 중 하나
+    StatusIn { codes: Vec<u16> },
+    /// 바디에 부분 문자열 포함
+    BodyContains { value: String },
+    /// 바디가 정규식과 매치
+    BodyMatches { pattern: String },
+    /// JSON 바디의 경로 값이 `equals`와 같음. 경로 예: `$.status`, `$.items[0].state`
+    JsonPath {
+        path: String,
+        equals: serde_json::Value,
+    },
+    /// 응답 헤더 존재. `equals`/`contains`가 있으면 값도 검사 (대소문자 무시)
+    Header {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        equals: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        contains: Option<String>,
+    },
+    /// 응답 시간 상한 (ms)
+    MaxLatency { ms: u32 },
+    /// 리다이렉트를 따라간 최종 url의 스킴 (예: `https`)
+    FinalScheme { scheme: String },
+    /// 최종 url이 정확히 일치 (정규화 후 비교, 예: 끝의 `/`)
+    FinalUrl { url: String },
+    /// 따라간 리다이렉트 횟수 상한
+    MaxRedirects { count: u32 },
+}
+
+impl CheckAssertion {
+    /// `AssertionFailure.kind`에 쓰는 이름 (serde tag와 동일)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::StatusIn { .. } => "statusIn",
+            Self::BodyContains { .. } => "bodyContains",
+            Self::BodyMatches { .. } => "bodyMatches",
+            Self::JsonPath { .. } => "jsonPath",
+            Self::Header { .. } => "header",
+            Self::MaxLatency { .. } => "maxLatency",
+            Self::FinalScheme { .. } => "finalScheme",
+            Self::FinalUrl { .. } => "finalUrl",
+            Self::MaxRedirects { .. } => "maxRedirects",
+        }
+    }
+
+    /// 바디를 읽어야 검사할 수 있는 규칙인지
+    pub fn needs_body(&self) -> bool {
+        matches!(
+            self,
+            Self::BodyContains { .. } | Self::BodyMatches { .. } | Self::JsonPath { .. }
+        )
+    }
+}
+
+/// 실패한 검증 한 건. `DomainStatusLog.failures`에 저장.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssertionFailure {
+    pub kind: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl std::fmt::Display for AssertionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.kind, self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assertion_json_shape() {
+        let json = r#"[
+            {"type": "statusIn", "codes": [200, 204]},
+            {"type": "jsonPath", "path": "$.status", "equals": "ok"},
+            {"type": "header", "name": "content-type", "contains": "json"},
+            {"type": "maxLatency", "ms": 500},
+            {"type": "maxRedirects", "count": 2}
+        ]"#;
+        let list: Vec<CheckAssertion> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            list[0],
+            CheckAssertion::StatusIn {
+                codes: vec![200, 204]
+            }
+        );
+        assert_eq!(list[3].kind(), "maxLatency");
+        assert!(list[1].needs_body());
+        assert!(!list[2].needs_body());
+        assert_eq!(list[4], CheckAssertion::MaxRedirects { count: 2 });
+    }
+
+    #[test]
+    fn test_http_check_defaults() {
+        let check: HttpCheck = serde_json::from_str("{}").unwrap();
+        assert_eq!(check.method, HttpCheckMethod::Head);
+        assert!(check.assertions.is_empty());
+        assert!(!check.record_redirects);
+        let check: HttpCheck = serde_json::from_str(r#"{"method": "POST", "body": "{}"}"#).unwrap();
+        assert_eq!(check.method, HttpCheckMethod::Post);
+    }
+}
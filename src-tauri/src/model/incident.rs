@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 장애 메모
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IncidentNote {
+    pub text: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 도메인 장애. down 확정 시 열리고 up 확정 시 해결됨. logs/incidents.json (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Incident {
+    pub id: u32,
+    pub domain_id: u32,
+    pub url: String,
+    /// 장애 시점의 소속 그룹 이름. 그룹이 없으면 `["Default"]`
+    pub groups: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    /// down 확정 시 마지막 실패 사유
+    pub first_error: String,
+    #[serde(default)]
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// 해결까지 걸린 시간 (초). 열린 장애는 `None`
+    #[serde(default)]
+    pub duration_secs: Option<i64>,
+    #[serde(default)]
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub notes: Vec<IncidentNote>,
+}
+
+impl Incident {
+    pub fn is_open(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+}
+
+/// 도메인 또는 그룹 하나의 장애 통계
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IncidentStats {
+    /// 도메인이면 url, 그룹이면 그룹 이름
+    pub key: String,
+    pub incident_count: u32,
+    pub open_count: u32,
+    /// 해결된 장애의 총 지속 시간 (초)
+    pub total_downtime_secs: i64,
+    /// 평균 복구 시간 (해결된 장애의 평균 지속 시간). 해결된 장애가 없으면 `None`
+    pub mttr_secs: Option<i64>,
+    /// 평균 장애 간격 (해결 → 다음 장애 시작까지 평균). 장애가 2건 미만이면 `None`
+    pub mtbf_secs: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IncidentStatsReport {
+    pub by_domain: Vec<IncidentStats>,
+    pub by_group: Vec<IncidentStats>,
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 하루 중 한 시간대(로컬 시각)의 지연 시간 기준선. 지수 이동 평균(EWMA)과 분산
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HourBaseline {
+    pub mean_ms: f64,
+    pub variance: f64,
+    /// 반영한 체크 수. `MIN_BASELINE_SAMPLES` 미만이면 점수를 매기지 않음
+    pub samples: u32,
+}
+
+/// url별 기준선. `logs/latency_baselines.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyBaseline {
+    pub url: String,
+    /// 0~23시 (로컬), 항상 24개
+    pub hours: Vec<HourBaseline>,
+    /// 마지막으로 반영한 체크의 지연 시간
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_latency: Option<u32>,
+    /// 마지막 체크의 이상 점수 (기준선 평균에서 표준편차 몇 배만큼 떨어졌는지)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_score: Option<f64>,
+    /// 마지막 체크를 `degraded`로 표시했는지
+    #[serde(default)]
+    pub last_degraded: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// FE 표시용: 도메인의 현재 시간대 기준선과 마지막 이상 점수
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyBaselineReport {
+    pub domain_id: u32,
+    pub url: String,
+    /// 현재 로컬 시각의 시간 (0~23)
+    pub hour: u32,
+    /// 현재 시간대 기준선. 체크가 부족하면 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mean_ms: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stddev_ms: Option<f64>,
+    pub samples: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_latency: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// 마지막 체크가 `degraded`로 표시되었는지
+    pub degraded: bool,
+    /// 시간대별 기준선 (차트용, 0~23시)
+    pub hours: Vec<HourBaseline>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 점검 시간 일정. weekly·cron은 로컬 시각 기준
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MaintenanceSchedule {
+    /// 한 번만 (`start` 이상 `end` 미만)
+    Once {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+    /// 매주 `days` 요일(0=일요일 … 6=토요일)의 `start_time`(`HH:MM`)부터 `duration_mins`분
+    #[serde(rename_all = "camelCase")]
+    Weekly {
+        days: Vec<u8>,
+        start_time: String,
+        duration_mins: u32,
+    },
+    /// cron 식(분 시 일 월 요일)에 맞는 시각부터 `duration_mins`분
+    #[serde(rename_all = "camelCase")]
+    Cron {
+        expression: String,
+        duration_mins: u32,
+    },
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 점검 시간. 이 시간 안의 체크는 계속 실행되지만 `maintenance`로 표시되고 알림·장애에서 빠짐.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceWindow {
+    pub id: u32,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 대상 도메인. `group_ids`와 합집합
+    #[serde(default)]
+    pub domain_ids: Vec<u32>,
+    /// 대상 그룹 (소속 도메인 전체)
+    #[serde(default)]
+    pub group_ids: Vec<u32>,
+    pub schedule: MaintenanceSchedule,
+    /// 가용성 롤업에서 이 시간의 체크를 뺌
+    #[serde(default)]
+    pub exclude_from_uptime: bool,
+}
+
+/// 점검 시간 중 체크에 붙는 표시 (`DomainStatusLog.maintenance`)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceMark {
+    pub window_id: u32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub exclude_from_uptime: bool,
+}
+
+/// 지금 점검 중인 도메인
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveMaintenance {
+    pub domain_id: u32,
+    pub window_id: u32,
+    pub window_name: String,
+    pub ends_at: DateTime<Utc>,
+}
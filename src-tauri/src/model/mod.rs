@@ -1,10 +1,29 @@
+pub mod alert;
 pub mod api_response;
+pub mod cert_check;
+pub mod check_concurrency;
+pub mod check_policy;
+pub mod content_watch;
+pub mod dns_check;
 pub mod domain;
 pub mod domain_api_logging_link;
 pub mod domain_group;
 pub mod domain_group_link;
 pub mod domain_monitor_link;
 pub mod domain_status_log;
+pub mod heartbeat;
+pub mod http_check;
+pub mod incident;
+pub mod latency_baseline;
 pub mod local_route;
+pub mod maintenance;
+pub mod openapi_check;
+pub mod probe_options;
 pub mod proxy_settings;
+pub mod route_health;
+pub mod security_audit;
 pub mod settings_export;
+pub mod status_page;
+pub mod sub_page;
+pub mod tcp_check;
+pub mod uptime;
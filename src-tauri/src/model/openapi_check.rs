@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_interval_mins() -> u32 {
+    15
+}
+
+/// 도메인별 `OpenAPI` 엔드포인트 체크 설정 (`DomainMonitorLink.openapi_check`).
+/// 없으면 체크하지 않음. 스키마는 `download_api_schema`로 받은 `schemas/{domain_id}.json`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenApiCheckConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 체크 간격 (분, 1~1440)
+    #[serde(default = "default_interval_mins")]
+    pub interval_mins: u32,
+    /// 요청 기준 url. 없으면 스키마의 `servers`(`host`·`basePath`), 그것도 없으면 도메인 url
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// 도메인과 출처(scheme·host·port)가 다른 url에도 도메인 자격 증명을 보낼지.
+    /// 기본은 같은 출처에만 (스키마의 `servers`·`host`가 다른 호스트를 가리킬 수 있으므로)
+    #[serde(default)]
+    pub allow_cross_origin_credentials: bool,
+}
+
+impl Default for OpenApiCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_mins: default_interval_mins(),
+            base_url: None,
+            allow_cross_origin_credentials: false,
+        }
+    }
+}
+
+/// 오퍼레이션 하나의 체크 결과. 매번 `logs/openapi/{date}.json` (NDJSON)에 남김
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationCheckResult {
+    pub domain_id: u32,
+    /// `GET /users/{id}` (스키마의 경로 그대로)
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+    /// 예시 값을 넣어 실제로 요청한 url
+    pub url: String,
+    /// 도메인 자격 증명(추가 헤더·인증·클라이언트 인증서)을 붙여 요청했는지
+    #[serde(default)]
+    pub with_credentials: bool,
+    pub timestamp: DateTime<Utc>,
+    /// 응답을 받지 못하면 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// 스키마에 선언된 응답 코드 (`200`, `2XX`, `default` …)
+    pub expected_statuses: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u32>,
+    /// 응답 코드에 맞는 바디 스키마가 있어 바디를 검증했는지
+    pub schema_checked: bool,
+    /// 요청 실패·선언되지 않은 응답 코드·바디 스키마 위반 (JSON 경로 포함)
+    #[serde(default)]
+    pub errors: Vec<String>,
+    /// `info` / `warning`(바디 스키마 위반·선언된 4xx) / `error`(요청 실패·선언되지 않은 코드·5xx)
+    pub level: String,
+}
+
+/// 체크하지 않은 오퍼레이션과 이유 (필수 파라미터에 예시 값 없음 등)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedOperation {
+    pub operation: String,
+    pub reason: String,
+}
+
+/// 도메인별 마지막 실행. `logs/openapi/state.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenApiCheckState {
+    pub domain_id: u32,
+    pub last_run: DateTime<Utc>,
+    /// 스키마가 없거나 읽지 못한 경우. 있으면 `results`는 비어 있음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// 오퍼레이션별 최신 결과 (스키마 순서)
+    #[serde(default)]
+    pub results: Vec<OperationCheckResult>,
+    #[serde(default)]
+    pub skipped: Vec<SkippedOperation>,
+}
+
+/// FE 표시용: 체크가 설정된 도메인 + 설정 + 마지막 실행
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenApiCheckStatus {
+    pub domain_id: u32,
+    pub url: String,
+    pub config: OpenApiCheckConfig,
+    /// `schemas/{domain_id}.json`이 있는지
+    pub schema_saved: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OpenApiCheckState>,
+}
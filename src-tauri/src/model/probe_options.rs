@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+fn default_true() -> bool {
+    true
+}
+
+/// 도메인별 HTTP 요청 방식 (`DomainMonitorLink.probe`). 없으면 기본값.
+/// 메서드·헤더·바디·검증 규칙은 `HttpCheck`, 여기는 서버에 닿는 방법 (인증·제한 시간·TLS).
+/// 체크가 켜진 하위 페이지에도 같이 적용
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeOptions {
+    /// HEAD가 405·501이면 같은 url을 GET으로 다시 요청
+    #[serde(default = "default_true")]
+    pub head_fallback: bool,
+    /// `Authorization` 헤더로 보냄. `HttpCheck.headers`에 직접 넣은 값이 있으면 그쪽이 우선
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<ProbeAuth>,
+    /// 요청 제한 시간 (초, 리다이렉트 포함, 1~120). 없으면 10초
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u32>,
+    /// 없으면 `Watchtower/0.1.0`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// 끄면 서버 인증서를 검증하지 않음 (자체 서명 내부 서버용)
+    #[serde(default = "default_true")]
+    pub verify_tls: bool,
+    /// mTLS 클라이언트 인증서
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<ClientCertConfig>,
+}
+
+impl Default for ProbeOptions {
+    fn default() -> Self {
+        Self {
+            head_fallback: true,
+            auth: None,
+            timeout_secs: None,
+            user_agent: None,
+            verify_tls: true,
+            client_cert: None,
+        }
+    }
+}
+
+/// 요청 인증. FE에서는 `{ "type": "bearer", "token": "..." }` 형태.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ProbeAuth {
+    Basic { username: String, password: String },
+    Bearer { token: String },
+}
+
+/// 클라이언트 인증서·개인 키 PEM 파일 경로. 체크할 때마다 읽음
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientCertConfig {
+    /// 인증서 체인 (첫 번째가 클라이언트 인증서)
+    pub cert_path: String,
+    pub key_path: String,
+}
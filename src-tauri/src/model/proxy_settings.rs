@@ -26,6 +26,9 @@ pub struct ProxySettings {
     /// When true, matching local routes are applied; when false, all traffic passes through.
     #[serde(default = "default_local_routing_enabled")]
     pub local_routing_enabled: bool,
+    /// Seconds between local route backend health checks (5–3600). None = only on demand.
+    #[serde(default)]
+    pub route_health_interval_secs: Option<u32>,
 }
 
 impl Default for ProxySettings {
@@ -36,6 +39,7 @@ impl Default for ProxySettings {
             reverse_http_port: None,
             reverse_https_port: None,
             local_routing_enabled: true,
+            route_health_interval_secs: None,
         }
     }
 }
@@ -82,11 +86,13 @@ mod tests {
             reverse_http_port: None,
             reverse_https_port: None,
             local_routing_enabled: false,
+            route_health_interval_secs: Some(30),
         };
         let json = serde_json::to_string(&settings).unwrap();
         let deserialized: ProxySettings = serde_json::from_str(&json).unwrap();
         assert!(!deserialized.local_routing_enabled);
         assert_eq!(deserialized.proxy_port, 8888);
+        assert_eq!(deserialized.route_health_interval_secs, Some(30));
     }
 
     /// Default should have local_routing_enabled = true.
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 로컬 라우트 백엔드 체크 결과. 라우트별 최신 결과만 메모리에 둠 (`RouteHealthService`).
+/// 대상은 프록시의 `resolve_target`으로 정하므로 프록시가 실제로 보낼 곳과 같음
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteHealth {
+    pub route_id: u32,
+    pub domain: String,
+    /// 요청에 보낸 Host 헤더 (라우트 도메인의 호스트)
+    pub host: String,
+    /// 체크한 `host:port`. 프록시가 이 도메인을 보낼 로컬 대상
+    pub target: String,
+    /// 로컬 라우팅이 꺼져 있어 프록시가 이 라우트를 쓰지 않음 (pass-through). 대상은 라우트 설정값
+    #[serde(default)]
+    pub pass_through: bool,
+    /// 같은 호스트의 다른 라우트가 대신 매칭됨 (예: `https://` 라우트가 우선). 그 라우트 id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadowed_by: Option<u32>,
+    pub port_open: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_ms: Option<u32>,
+    /// HTTP 응답 상태 (리다이렉트는 따라가지 않음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// 요청 전송 ~ 응답 헤더 수신 (ms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u32>,
+    /// `info`(2xx/3xx) / `warning`(4xx, pass-through, shadowed) / `error`(포트 닫힘, 5xx, 요청 실패)
+    pub level: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_interval_hours() -> u32 {
+    24
+}
+
+/// 도메인별 보안 헤더·TLS 점검 설정 (`DomainMonitorLink.security_audit`).
+/// 없으면 기본값으로 http(s) 도메인을 하루에 한 번 점검.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityAuditConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 점검 간격 (시간, 1~168)
+    #[serde(default = "default_interval_hours")]
+    pub interval_hours: u32,
+}
+
+impl Default for SecurityAuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: default_interval_hours(),
+        }
+    }
+}
+
+/// 규칙 하나의 판정. 통과하지 못해도 일부 점수를 받을 수 있음 (예: 짧은 HSTS max-age)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityFinding {
+    /// `httpsRedirect` / `hsts` / `csp` / `contentTypeOptions` / `referrerPolicy` / `cookies` /
+    /// `legacyTls` / `tls13`
+    pub rule: String,
+    pub passed: bool,
+    /// `info`(통과) / `warning` / `error`
+    pub level: String,
+    pub points: u32,
+    pub max_points: u32,
+    /// 본 값과 판정 이유 (예: `max-age=300 (< 180 days)`)
+    pub detail: String,
+}
+
+/// 직전 정상 점검보다 점수가 떨어진 규칙
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityRegression {
+    pub rule: String,
+    pub previous_points: u32,
+    pub points: u32,
+    pub previous_detail: String,
+    pub detail: String,
+}
+
+/// 보안 점검 한 번의 결과. 매번 `logs/security/{date}.json` (NDJSON)에 남김
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityAuditResult {
+    pub domain_id: u32,
+    pub url: String,
+    pub timestamp: DateTime<Utc>,
+    /// 0~100 (규칙 점수 합)
+    pub score: u32,
+    /// A(90+) / B(80+) / C(70+) / D(60+) / F
+    pub grade: String,
+    /// 규칙 중 가장 나쁜 level. 점검 자체가 실패하면 `error`
+    pub level: String,
+    pub findings: Vec<SecurityFinding>,
+    /// 핸드셰이크에 성공한 TLS 버전 (`TLS 1.0` ~ `TLS 1.3`)
+    #[serde(default)]
+    pub tls_versions: Vec<String>,
+    /// 비교한 직전 정상 점검의 점수. 처음이거나 이번 점검이 실패하면 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_score: Option<u32>,
+    #[serde(default)]
+    pub regressions: Vec<SecurityRegression>,
+    /// HTTPS 응답을 받지 못하는 등 점검 실패 사유. 있으면 회귀 비교에서 제외
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+/// url별 점검 상태. `logs/security/state.json` (versioned)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityAuditState {
+    pub url: String,
+    /// 마지막 점검 (실패 포함). 다음 점검 시각의 기준
+    pub last: SecurityAuditResult,
+    /// 회귀 비교 기준: 마지막으로 성공한 점검
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_ok: Option<SecurityAuditResult>,
+}
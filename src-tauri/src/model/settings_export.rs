@@ -1,22 +1,54 @@
 //! Full app settings export/import payload (JSON).
 //! Status logs (DomainStatusLog) are excluded - they are runtime data, not settings.
 
+use crate::model::cert_check::CertCheckConfig;
+use crate::model::check_policy::CheckPolicy;
+use crate::model::content_watch::ContentWatchConfig;
+use crate::model::dns_check::DnsCheckConfig;
 use crate::model::domain::Domain;
 use crate::model::domain_group::DomainGroup;
 use crate::model::domain_group_link::DomainGroupLink;
+use crate::model::heartbeat::HeartbeatCheck;
+use crate::model::http_check::HttpCheck;
 use crate::model::local_route::LocalRoute;
+use crate::model::maintenance::MaintenanceWindow;
+use crate::model::openapi_check::OpenApiCheckConfig;
+use crate::model::probe_options::ProbeOptions;
 use crate::model::proxy_settings::ProxySettings;
+use crate::model::security_audit::SecurityAuditConfig;
+use crate::model::status_page::StatusPageConfig;
+use crate::model::tcp_check::TcpCheck;
 use serde::{Deserialize, Serialize};
 
 pub const SETTINGS_EXPORT_VERSION: u32 = 2;
 
-/// Domain monitor settings (check_enabled, interval). Keyed by URL for import matching.
+/// Domain monitor settings (check_enabled, interval, http check). Keyed by URL for import matching.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct DomainMonitorExport {
     pub url: String,
     pub check_enabled: bool,
     pub interval_secs: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_check: Option<HttpCheck>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probe: Option<ProbeOptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert_check: Option<CertCheckConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_check: Option<DnsCheckConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_check: Option<TcpCheck>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heartbeat: Option<HeartbeatCheck>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_watch: Option<ContentWatchConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_audit: Option<SecurityAuditConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub openapi_check: Option<OpenApiCheckConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<CheckPolicy>,
 }
 
 fn default_domain_monitor() -> Vec<DomainMonitorExport> {
@@ -36,4 +68,10 @@ pub struct SettingsExport {
     /// Monitor settings per domain (check_enabled, interval). Status logs are excluded.
     #[serde(alias = "domain_status", default = "default_domain_monitor")]
     pub domain_monitor: Vec<DomainMonitorExport>,
+    /// Maintenance windows (domain/group ids refer to `domains`/`groups` above).
+    #[serde(default)]
+    pub maintenance_windows: Vec<MaintenanceWindow>,
+    /// Public status page (domain/group ids refer to `domains`/`groups` above).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_page: Option<StatusPageConfig>,
 }
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_title() -> String {
+    "Service Status".to_string()
+}
+
+/// 공개 상태 페이지에 보일 도메인과 표시 이름. url은 페이지에 나가지 않음.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageComponent {
+    pub domain_id: u32,
+    pub display_name: String,
+}
+
+/// 상태 페이지 설정 (`status_page.json`, versioned)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_title")]
+    pub title: String,
+    /// 페이지의 구역 (순서대로). 각 그룹에서 `components`에 있는 도메인만 보임
+    #[serde(default)]
+    pub group_ids: Vec<u32>,
+    #[serde(default)]
+    pub components: Vec<StatusPageComponent>,
+    /// `index.html`·`status.json`을 쓸 폴더. 없으면 데이터 폴더의 `status_page/`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_dir: Option<String>,
+}
+
+impl Default for StatusPageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            title: default_title(),
+            group_ids: Vec::new(),
+            components: Vec::new(),
+            output_dir: None,
+        }
+    }
+}
+
+/// 공개 상태. 순서는 심각도 (전체 상태 = 가장 심각한 구성 요소)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentStatus {
+    Operational,
+    /// 아직 확정 전
+    Unknown,
+    Maintenance,
+    /// warning·degraded 또는 flapping
+    Degraded,
+    Down,
+}
+
+/// 하루 가용성 막대
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageDay {
+    /// 로컬 날짜 YYYY-MM-DD
+    pub date: String,
+    /// 체크가 없으면 `None`
+    pub uptime_percent: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageComponentState {
+    pub name: String,
+    pub status: ComponentStatus,
+    /// 최근 90일 가용성
+    pub uptime_percent: Option<f64>,
+    /// 오래된 날부터 90일
+    pub days: Vec<StatusPageDay>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageGroup {
+    pub name: String,
+    pub components: Vec<StatusPageComponentState>,
+}
+
+/// 공개 장애 기록. 실패 사유·메모는 내보내지 않음
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageIncident {
+    pub component: String,
+    pub started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<i64>,
+}
+
+/// 상태 페이지 내용 (`status.json`과 같은 구조)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPage {
+    pub title: String,
+    pub generated_at: DateTime<Utc>,
+    pub status: ComponentStatus,
+    pub groups: Vec<StatusPageGroup>,
+    /// 최근 장애 (최신순)
+    pub incidents: Vec<StatusPageIncident>,
+}
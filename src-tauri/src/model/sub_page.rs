@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_max_depth() -> u32 {
+    3
+}
+
+fn default_max_pages() -> u32 {
+    200
+}
+
+fn default_respect_robots() -> bool {
+    true
+}
+
+/// 하위 페이지를 처음 찾은 경로
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SubPageSource {
+    /// sitemap.xml (또는 robots.txt의 `Sitemap:`)
+    Sitemap,
+    /// 크롤링 중 같은 출처의 `<a href>`
+    Link,
+}
+
+/// 도메인 하위 페이지. `sub_pages.json` (versioned). url은 도메인 출처 + `path`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubPage {
+    pub id: u32,
+    pub domain_id: u32,
+    /// `/`로 시작하는 경로 (쿼리 포함 가능, fragment 제외)
+    pub path: String,
+    /// 경로상 가장 가까운 상위 페이지. 없으면 도메인 바로 아래
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub source: SubPageSource,
+    /// 마지막 크롤링에서 받은 HTTP 상태
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_status: Option<u16>,
+    pub discovered_at: DateTime<Utc>,
+    /// 마지막으로 크롤링에서 발견한 시각. 사이트에서 사라진 페이지도 지우지 않고 남김
+    pub last_seen: DateTime<Utc>,
+}
+
+/// 하위 페이지 모니터 체크 대상. `sub_page_monitor_links.json` (versioned).
+/// 켜진 페이지는 소속 도메인의 체크 주기·재시도 규칙으로 HEAD 체크
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubPageMonitorLink {
+    pub sub_page_id: u32,
+    pub check_enabled: bool,
+}
+
+/// 크롤링 범위
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlOptions {
+    /// 루트에서 링크를 따라갈 최대 깊이 (sitemap 페이지는 깊이 1)
+    #[serde(default = "default_max_depth")]
+    pub max_depth: u32,
+    /// 요청할 최대 페이지 수 (루트 포함)
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
+    /// robots.txt의 `User-agent: *` 규칙을 지킬지
+    #[serde(default = "default_respect_robots")]
+    pub respect_robots: bool,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: default_max_depth(),
+            max_pages: default_max_pages(),
+            respect_robots: default_respect_robots(),
+        }
+    }
+}
+
+/// 크롤링 중 4xx/5xx를 받았거나 요청에 실패한 같은 출처 링크
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokenLink {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// 요청 실패 사유 (연결·타임아웃 등)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 이 링크가 있던 페이지 url (sitemap에만 있으면 비어 있음)
+    pub found_on: Vec<String>,
+}
+
+/// 도메인의 마지막 크롤링 결과. `logs/crawl/{domain_id}.json`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlReport {
+    pub domain_id: u32,
+    pub root_url: String,
+    pub options: CrawlOptions,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    /// 요청한 페이지 수 (루트 포함)
+    pub pages_crawled: u32,
+    /// 이번에 새로 추가한 하위 페이지 수
+    pub pages_added: u32,
+    /// sitemap에서 찾은 같은 출처 url 수
+    pub sitemap_urls: u32,
+    /// robots.txt 때문에 건너뛴 url 수
+    pub disallowed: u32,
+    /// `max_pages`에 걸려 요청하지 못한 url이 있는지
+    pub truncated: bool,
+    pub broken_links: Vec<BrokenLink>,
+}
+
+/// FE 트리 표시용: 하위 페이지 + 모니터 여부 + 자식
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SubPageNode {
+    #[serde(flatten)]
+    pub page: SubPage,
+    /// 조회용 url (도메인 출처 + 경로)
+    pub url: String,
+    pub check_enabled: bool,
+    pub children: Vec<SubPageNode>,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// `tcp://host:port` 대상의 추가 검사 (`DomainMonitorLink.tcp_check`). 없으면 연결 성공만 확인.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TcpCheck {
+    /// 연결 후 보낼 데이터 (예: `"PING\r\n"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send: Option<String>,
+    /// 응답(배너)에 포함돼야 하는 문자열 (예: SMTP `"220"`, Redis `"+PONG"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect: Option<String>,
+}
+
+impl TcpCheck {
+    /// 빈 `send`·`expect`는 없는 것으로
+    pub fn normalized(self) -> Self {
+        Self {
+            send: self.send.filter(|s| !s.is_empty()),
+            expect: self.expect.filter(|s| !s.is_empty()),
+        }
+    }
+}
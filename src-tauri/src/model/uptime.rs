@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 지연 시간 히스토그램 구간 상한 (ms). 마지막 칸은 초과분. 롤업끼리 더해서 백분위수를 근사.
+pub const LATENCY_BOUNDS_MS: [u32; 20] = [
+    10, 20, 30, 50, 75, 100, 150, 200, 300, 500, 750, 1000, 1500, 2000, 3000, 5000, 7500, 10000,
+    15000, 30000,
+];
+
+/// url 하나의 시간 구간 집계. 시간 단위는 logs/rollups/hourly/{date}.json, 일 단위는 logs/rollups/daily/{YYYY-MM}.json
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RollupBucket {
+    pub url: String,
+    /// 구간 시작 (시간: UTC 정시, 일: 로컬 자정)
+    pub start: DateTime<Utc>,
+    /// 체크 수 (재시도는 최종 시도만)
+    pub total: u32,
+    /// error가 아닌 체크 수
+    pub up: u32,
+    /// 아래 지연 시간 값은 up 체크만
+    pub latency_sum: u64,
+    pub latency_max: u32,
+    /// `LATENCY_BOUNDS_MS` 구간별 개수 (길이 = 구간 수 + 1)
+    pub histogram: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BucketSize {
+    /// 원본 로그에서 계산 (범위 최대 `MAX_MINUTE_RANGE_DAYS`일)
+    Minute,
+    Hour,
+    Day,
+}
+
+/// 가용성·지연 시간 요약
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimeStats {
+    pub total_checks: u32,
+    pub up_checks: u32,
+    /// 체크가 없으면 `None`
+    pub uptime_percent: Option<f64>,
+    pub avg_latency: Option<u32>,
+    /// 백분위수는 히스토그램 구간 상한 (최대값을 넘지 않음)
+    pub p50_latency: Option<u32>,
+    pub p95_latency: Option<u32>,
+    pub p99_latency: Option<u32>,
+    pub max_latency: Option<u32>,
+}
+
+/// 차트용 시계열 한 점
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimePoint {
+    pub start: DateTime<Utc>,
+    #[serde(flatten)]
+    pub stats: UptimeStats,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimeReport {
+    /// 조회 범위 (로컬 날짜 YYYY-MM-DD, 양끝 포함)
+    pub from: String,
+    pub to: String,
+    pub bucket: BucketSize,
+    /// 집계에 포함된 url
+    pub urls: Vec<String>,
+    pub summary: UptimeStats,
+    pub points: Vec<UptimePoint>,
+}
@@ -0,0 +1,765 @@
+//! 알림 규칙 평가와 채널 전송.
+//! `DomainMonitorService`가 체크 결과·확정 상태 변경·인증서 점검 결과를 넘기면 규칙에 맞는 알림을 만들고,
+//! 규칙에 연결된 채널(`NotificationChannel`)로 보낸 뒤 전송 기록을 남긴다.
+
+use crate::model::alert::{
+    AlertChannelConfig, AlertConfig, AlertEvent, AlertRecord, AlertRule, AlertScope, AlertTrigger,
+    ChannelKind, EmailChannelConfig,
+};
+use crate::model::cert_check::CertCheckResult;
+use crate::model::check_policy::{ConfirmedStatus, StateChange};
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::incident::Incident;
+use crate::model::security_audit::SecurityAuditResult;
+use crate::service::chat_notify::{ChatChannel, ChatStyle};
+use crate::service::email_notify::{digest_due, EmailChannel};
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 보관하는 전송 기록 수. 넘으면 오래된 것부터 버림
+pub const MAX_ALERT_HISTORY: usize = 1000;
+
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 전송 실패 시 재시도 전 대기 (길이 = 재시도 횟수)
+const RETRY_DELAYS: [Duration; 2] = [Duration::from_millis(500), Duration::from_secs(2)];
+
+/// 알림 전송 수단. 채널 종류마다 구현하고 `build_channel`에서 설정으로 생성.
+pub trait NotificationChannel: Send + Sync {
+    fn send<'a>(&'a self, event: &'a AlertEvent) -> BoxFuture<'a, Result<(), String>>;
+}
+
+/// 웹훅 계열 채널이 함께 쓰는 HTTP 클라이언트
+pub fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(SEND_TIMEOUT)
+        .user_agent("Watchtower/0.1.0")
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+/// JSON 본문 POST. 2xx가 아니면 응답 상태·본문 일부를 담아 실패.
+pub async fn post_json<T: serde::Serialize + ?Sized>(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HashMap<String, String>,
+    body: &T,
+) -> Result<(), String> {
+    let mut request = client.post(url).json(body);
+    for (name, value) in headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let text = response.text().await.unwrap_or_default();
+    let snippet: String = text.chars().take(200).collect();
+    Err(format!("{url} responded {status}: {snippet}"))
+}
+
+/// `AlertEvent`를 JSON 그대로 POST. 2xx가 아니면 실패.
+pub struct WebhookChannel {
+    client: reqwest::Client,
+    url: String,
+    headers: HashMap<String, String>,
+}
+
+impl WebhookChannel {
+    pub fn new(url: String, headers: HashMap<String, String>) -> Self {
+        Self {
+            client: http_client(),
+            url,
+            headers,
+        }
+    }
+}
+
+impl NotificationChannel for WebhookChannel {
+    fn send<'a>(&'a self, event: &'a AlertEvent) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(post_json(&self.client, &self.url, &self.headers, event))
+    }
+}
+
+pub fn build_channel(kind: &ChannelKind) -> Box<dyn NotificationChannel> {
+    match kind {
+        ChannelKind::Webhook { url, headers } => {
+            Box::new(WebhookChannel::new(url.clone(), headers.clone()))
+        }
+        ChannelKind::Slack {
+            webhook_url,
+            template,
+        } => Box::new(ChatChannel::new(
+            ChatStyle::Slack,
+            webhook_url,
+            template.as_deref(),
+        )),
+        ChannelKind::Discord {
+            webhook_url,
+            template,
+        } => Box::new(ChatChannel::new(
+            ChatStyle::Discord,
+            webhook_url,
+            template.as_deref(),
+        )),
+        ChannelKind::Teams {
+            webhook_url,
+            template,
+        } => Box::new(ChatChannel::new(
+            ChatStyle::Teams,
+            webhook_url,
+            template.as_deref(),
+        )),
+        ChannelKind::Email(config) => Box::new(EmailChannel::new(config.clone())),
+    }
+}
+
+/// 실패하면 `RETRY_DELAYS`만큼 기다렸다 다시 보냄. (결과, 보낸 횟수)
+pub async fn send_with_retries(
+    channel: &dyn NotificationChannel,
+    event: &AlertEvent,
+) -> (Result<(), String>, u32) {
+    let mut attempts = 1;
+    let mut result = channel.send(event).await;
+    for delay in RETRY_DELAYS {
+        if result.is_ok() {
+            break;
+        }
+        tokio::time::sleep(delay).await;
+        attempts += 1;
+        result = channel.send(event).await;
+    }
+    (result, attempts)
+}
+
+/// 채널 설정 확인용 예시 알림 (`send_test_alert`)
+pub fn sample_event(now: DateTime<Utc>) -> AlertEvent {
+    AlertEvent {
+        rule_id: 0,
+        rule_name: "Test".to_string(),
+        trigger: "down".to_string(),
+        domain_id: 0,
+        url: "https://example.com".to_string(),
+        groups: vec!["Default".to_string()],
+        group_ids: Vec::new(),
+        status: "503 Service Unavailable".to_string(),
+        latency: Some(1234),
+        message: "Test message from Watchtower".to_string(),
+        incident_id: None,
+        downtime_secs: None,
+        timestamp: now,
+    }
+}
+
+/// 알림 대상 도메인 정보 (url별)
+pub struct AlertTarget {
+    pub domain_id: u32,
+    pub group_ids: Vec<u32>,
+    /// 그룹 이름. 그룹이 없으면 `["Default"]`
+    pub groups: Vec<String>,
+}
+
+fn scope_matches(scope: &AlertScope, target: &AlertTarget) -> bool {
+    match scope {
+        AlertScope::All => true,
+        AlertScope::Domain { domain_id } => *domain_id == target.domain_id,
+        AlertScope::Group { group_id } => target.group_ids.contains(group_id),
+    }
+}
+
+fn next_id<T>(items: &[T], id: impl Fn(&T) -> u32) -> u32 {
+    items.iter().map(id).max().unwrap_or(0) + 1
+}
+
+pub struct AlertService {
+    config: Mutex<AlertConfig>,
+    config_path: PathBuf,
+    history: Mutex<Vec<AlertRecord>>,
+    history_path: PathBuf,
+    /// (규칙 id, url) → 마지막으로 알림을 만든 시각. 재시작 시 전송 기록으로 복원
+    last_fired: Mutex<HashMap<(u32, String), DateTime<Utc>>>,
+    /// 메일 채널 id → 마지막으로 요약을 보낸 로컬 날짜. `logs/alert_digest.json` (versioned)
+    digest_sent: Mutex<HashMap<u32, NaiveDate>>,
+    digest_path: PathBuf,
+}
+
+impl AlertService {
+    pub fn new(config_path: PathBuf, history_path: PathBuf) -> Self {
+        let config = load_versioned(&config_path);
+        let history: Vec<AlertRecord> = load_versioned(&history_path);
+        let mut last_fired = HashMap::new();
+        for record in &history {
+            let key = (record.event.rule_id, record.event.url.clone());
+            let fired: &mut DateTime<Utc> = last_fired.entry(key).or_insert(record.sent_at);
+            *fired = (*fired).max(record.sent_at);
+        }
+        let digest_path = history_path.with_file_name("alert_digest.json");
+        Self {
+            config: Mutex::new(config),
+            config_path,
+            history: Mutex::new(history),
+            digest_sent: Mutex::new(load_versioned(&digest_path)),
+            digest_path,
+            history_path,
+            last_fired: Mutex::new(last_fired),
+        }
+    }
+
+    pub fn get_config(&self) -> AlertConfig {
+        self.config.lock().unwrap().clone()
+    }
+
+    /// 규칙·채널 전체를 교체. id가 0인 항목은 새 id를 받음. 저장된 설정을 반환.
+    pub fn set_config(&self, mut config: AlertConfig) -> AlertConfig {
+        for i in 0..config.channels.len() {
+            if config.channels[i].id == 0 {
+                config.channels[i].id = next_id(&config.channels, |c| c.id);
+            }
+        }
+        for i in 0..config.rules.len() {
+            if config.rules[i].id == 0 {
+                config.rules[i].id = next_id(&config.rules, |r| r.id);
+            }
+        }
+        save_versioned(&self.config_path, &config);
+        *self.config.lock().unwrap() = config.clone();
+        config
+    }
+
+    /// 최신 기록부터 최대 `limit`건
+    pub fn history(&self, limit: usize) -> Vec<AlertRecord> {
+        self.history
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// 지금 일일 요약을 보내야 하는 메일 채널 (채널 id, 설정)
+    pub fn due_digests(&self, now: DateTime<Local>) -> Vec<(u32, EmailChannelConfig)> {
+        let config = self.config.lock().unwrap();
+        let sent = self.digest_sent.lock().unwrap();
+        config
+            .channels
+            .iter()
+            .filter(|c| c.enabled)
+            .filter_map(|c| match &c.kind {
+                ChannelKind::Email(email) => email
+                    .digest_time
+                    .as_deref()
+                    .filter(|time| digest_due(time, sent.get(&c.id).copied(), now))
+                    .map(|_| (c.id, email.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 요약 발송 완료 기록 (실패해도 기록해서 같은 날 반복 발송하지 않음)
+    pub fn mark_digest_sent(&self, channel_id: u32, date: NaiveDate) {
+        let mut sent = self.digest_sent.lock().unwrap();
+        sent.insert(channel_id, date);
+        save_versioned(&self.digest_path, &*sent);
+    }
+
+    /// 조건에 맞는 규칙마다 알림 생성. 쿨다운 중인 (규칙, url)은 건너뛰고, 만든 알림은 쿨다운을 시작.
+    fn fire(
+        &self,
+        trigger_matches: impl Fn(&AlertTrigger) -> bool,
+        url: &str,
+        target: &AlertTarget,
+        now: DateTime<Utc>,
+        build: impl Fn(&AlertRule) -> AlertEvent,
+    ) -> Vec<AlertEvent> {
+        let config = self.config.lock().unwrap();
+        let mut last_fired = self.last_fired.lock().unwrap();
+        let mut events = Vec::new();
+        for rule in &config.rules {
+            if !rule.enabled
+                || !trigger_matches(&rule.trigger)
+                || !scope_matches(&rule.scope, target)
+            {
+                continue;
+            }
+            let key = (rule.id, url.to_string());
+            let cooling = last_fired.get(&key).is_some_and(|at| {
+                (now - *at)
+                    .to_std()
+                    .is_ok_and(|age| age < Duration::from_secs(rule.cooldown_secs))
+            });
+            if cooling {
+                continue;
+            }
+            last_fired.insert(key, now);
+            events.push(build(rule));
+        }
+        events
+    }
+
+    /// 체크 한 번의 결과로 down·recovered·latency 알림 생성.
+    /// 플래핑 중인 상태 변경(`StateChange.flapping`)은 알리지 않음.
+    /// `incidents`: 이번 체크로 열리거나 해결된 장애 (알림에 id를 붙임).
+    pub fn evaluate_checks(
+        &self,
+        changes: &[StateChange],
+        results: &[DomainStatusLog],
+        incidents: &[Incident],
+        targets: &HashMap<&str, AlertTarget>,
+        now: DateTime<Utc>,
+    ) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+        for change in changes.iter().filter(|c| !c.flapping) {
+            let Some(target) = targets.get(change.url.as_str()) else {
+                continue;
+            };
+            let kind = match (change.from, change.to) {
+                (_, ConfirmedStatus::Down) => "down",
+                (ConfirmedStatus::Down, ConfirmedStatus::Up) => "recovered",
+                _ => continue,
+            };
+            let log = results.iter().find(|r| r.url == change.url);
+            let incident = incidents.iter().find(|i| i.url == change.url);
+            let status = log.map_or_else(String::new, |l| l.status.clone());
+            let message = if kind == "down" {
+                format!(
+                    "{} is DOWN: {}",
+                    change.url,
+                    change.reason.as_deref().unwrap_or(&status)
+                )
+            } else {
+                format!("{} has RECOVERED ({status})", change.url)
+            };
+            events.extend(self.fire(
+                |t| t.kind() == kind,
+                &change.url,
+                target,
+                now,
+                |rule| AlertEvent {
+                    rule_id: rule.id,
+                    rule_name: rule.name.clone(),
+                    trigger: kind.to_string(),
+                    domain_id: target.domain_id,
+                    url: change.url.clone(),
+                    groups: target.groups.clone(),
+                    group_ids: target.group_ids.clone(),
+                    status: status.clone(),
+                    latency: log.map(|l| l.latency),
+                    message: message.clone(),
+                    incident_id: incident.map(|i| i.id),
+                    downtime_secs: incident.and_then(|i| i.duration_secs),
+                    timestamp: change.timestamp,
+                },
+            ));
+        }
+        for log in results.iter().filter(|r| r.level != "error") {
+            let Some(target) = targets.get(log.url.as_str()) else {
+                continue;
+            };
+            events.extend(self.fire(
+                |t| matches!(t, AlertTrigger::LatencyOver { ms } if log.latency > *ms),
+                &log.url,
+                target,
+                now,
+                |rule| {
+                    let limit = match rule.trigger {
+                        AlertTrigger::LatencyOver { ms } => ms,
+                        _ => 0,
+                    };
+                    AlertEvent {
+                        rule_id: rule.id,
+                        rule_name: rule.name.clone(),
+                        trigger: rule.trigger.kind().to_string(),
+                        domain_id: target.domain_id,
+                        url: log.url.clone(),
+                        groups: target.groups.clone(),
+                        group_ids: target.group_ids.clone(),
+                        status: log.status.clone(),
+                        latency: Some(log.latency),
+                        message: format!(
+                            "{} responded in {}ms (limit {limit}ms)",
+                            log.url, log.latency
+                        ),
+                        incident_id: None,
+                        downtime_secs: None,
+                        timestamp: log.timestamp,
+                    }
+                },
+            ));
+        }
+        events
+    }
+
+    /// 인증서 남은 일수로 만료 임박 알림 생성. 접속 실패 등으로 만료일을 모르면 제외.
+    pub fn evaluate_certs(
+        &self,
+        results: &[CertCheckResult],
+        targets: &HashMap<&str, AlertTarget>,
+        now: DateTime<Utc>,
+    ) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+        for result in results {
+            let (Some(target), Some(remaining)) =
+                (targets.get(result.url.as_str()), result.days_remaining)
+            else {
+                continue;
+            };
+            events.extend(self.fire(
+                |t| matches!(t, AlertTrigger::CertExpiring { days } if remaining <= *days),
+                &result.url,
+                target,
+                now,
+                |rule| AlertEvent {
+                    rule_id: rule.id,
+                    rule_name: rule.name.clone(),
+                    trigger: rule.trigger.kind().to_string(),
+                    domain_id: target.domain_id,
+                    url: result.url.clone(),
+                    groups: target.groups.clone(),
+                    group_ids: target.group_ids.clone(),
+                    status: result.level.clone(),
+                    latency: None,
+                    message: if remaining < 0 {
+                        format!("Certificate for {} has expired", result.host)
+                    } else {
+                        format!(
+                            "Certificate for {} expires in {remaining} days",
+                            result.host
+                        )
+                    },
+                    incident_id: None,
+                    downtime_secs: None,
+                    timestamp: result.timestamp,
+                },
+            ));
+        }
+        events
+    }
+
+    /// 보안 점검에서 점수가 떨어진 규칙이 있으면 회귀 알림 생성.
+    pub fn evaluate_security(
+        &self,
+        results: &[SecurityAuditResult],
+        targets: &HashMap<&str, AlertTarget>,
+        now: DateTime<Utc>,
+    ) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+        for result in results.iter().filter(|r| !r.regressions.is_empty()) {
+            let Some(target) = targets.get(result.url.as_str()) else {
+                continue;
+            };
+            let rules: Vec<&str> = result.regressions.iter().map(|r| r.rule.as_str()).collect();
+            events.extend(self.fire(
+                |t| matches!(t, AlertTrigger::SecurityRegression),
+                &result.url,
+                target,
+                now,
+                |rule| AlertEvent {
+                    rule_id: rule.id,
+                    rule_name: rule.name.clone(),
+                    trigger: rule.trigger.kind().to_string(),
+                    domain_id: target.domain_id,
+                    url: result.url.clone(),
+                    groups: target.groups.clone(),
+                    group_ids: target.group_ids.clone(),
+                    status: result.level.clone(),
+                    latency: None,
+                    message: format!(
+                        "Security audit for {} regressed: {} (score {} -> {})",
+                        result.url,
+                        rules.join(", "),
+                        result.previous_score.unwrap_or_default(),
+                        result.score
+                    ),
+                    incident_id: None,
+                    downtime_secs: None,
+                    timestamp: result.timestamp,
+                },
+            ));
+        }
+        events
+    }
+
+    /// 알림을 규칙에 연결된 (활성) 채널로 보내고 채널별 전송 기록을 저장. 새 기록을 반환.
+    pub async fn dispatch(&self, events: Vec<AlertEvent>) -> Vec<AlertRecord> {
+        if events.is_empty() {
+            return Vec::new();
+        }
+        let deliveries: Vec<(AlertChannelConfig, AlertEvent)> = {
+            let config = self.config.lock().unwrap();
+            events
+                .into_iter()
+                .flat_map(|event| {
+                    let channel_ids = config
+                        .rules
+                        .iter()
+                        .find(|r| r.id == event.rule_id)
+                        .map(|r| r.channel_ids.clone())
+                        .unwrap_or_default();
+                    config
+                        .channels
+                        .iter()
+                        .filter(|c| {
+                            c.enabled && channel_ids.contains(&c.id) && c.kind.sends_alerts()
+                        })
+                        .map(|c| (c.clone(), event.clone()))
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        };
+        let sends = deliveries.iter().map(|(channel, event)| async move {
+            let sent = send_with_retries(build_channel(&channel.kind).as_ref(), event).await;
+            if let Err(e) = &sent.0 {
+                tracing::warn!(
+                    "[alert] channel {} failed after {} attempts: {e}",
+                    channel.name,
+                    sent.1
+                );
+            }
+            sent
+        });
+        let results = futures::future::join_all(sends).await;
+
+        let mut history = self.history.lock().unwrap();
+        let mut id = history.last().map_or(0, |r| r.id);
+        let records: Vec<AlertRecord> = deliveries
+            .into_iter()
+            .zip(results)
+            .map(|((channel, event), (result, attempts))| {
+                id += 1;
+                AlertRecord {
+                    id,
+                    channel_id: channel.id,
+                    event,
+                    delivered: result.is_ok(),
+                    attempts,
+                    error: result.err(),
+                    sent_at: Utc::now(),
+                }
+            })
+            .collect();
+        if records.is_empty() {
+            return records;
+        }
+        history.extend(records.iter().cloned());
+        let overflow = history.len().saturating_sub(MAX_ALERT_HISTORY);
+        history.drain(..overflow);
+        save_versioned(&self.history_path, &*history);
+        records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::State, routing::post, Json, Router};
+    use std::sync::Arc;
+
+    fn rule(id: u32, scope: AlertScope, trigger: AlertTrigger) -> AlertRule {
+        AlertRule {
+            id,
+            name: format!("rule {id}"),
+            enabled: true,
+            scope,
+            trigger,
+            channel_ids: vec![1],
+            cooldown_secs: 600,
+        }
+    }
+
+    fn change(
+        url: &str,
+        from: ConfirmedStatus,
+        to: ConfirmedStatus,
+        flapping: bool,
+    ) -> StateChange {
+        StateChange {
+            url: url.to_string(),
+            from,
+            to,
+            flapping,
+            reason: Some("statusIn: expected 2xx or 3xx, got 503".to_string()),
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn latency_log(latency: u32) -> DomainStatusLog {
+        DomainStatusLog {
+            url: "https://a.example.com".to_string(),
+            status: "200 OK".to_string(),
+            level: "info".to_string(),
+            latency,
+            ok: true,
+            group: "Prod".to_string(),
+            timestamp: Utc::now(),
+            error_message: None,
+            failures: Vec::new(),
+            attempt: 1,
+            timing: None,
+            redirects: Vec::new(),
+            maintenance: None,
+        }
+    }
+
+    fn targets() -> HashMap<&'static str, AlertTarget> {
+        HashMap::from([
+            (
+                "https://a.example.com",
+                AlertTarget {
+                    domain_id: 1,
+                    group_ids: vec![7],
+                    groups: vec!["Prod".to_string()],
+                },
+            ),
+            (
+                "https://b.example.com",
+                AlertTarget {
+                    domain_id: 2,
+                    group_ids: Vec::new(),
+                    groups: vec!["Default".to_string()],
+                },
+            ),
+        ])
+    }
+
+    fn service(dir: &tempfile::TempDir, rules: Vec<AlertRule>, webhook: &str) -> AlertService {
+        let svc = AlertService::new(
+            dir.path().join("alert_settings.json"),
+            dir.path().join("alert_history.json"),
+        );
+        svc.set_config(AlertConfig {
+            rules,
+            channels: vec![AlertChannelConfig {
+                id: 0,
+                name: "hook".to_string(),
+                enabled: true,
+                kind: ChannelKind::Webhook {
+                    url: webhook.to_string(),
+                    headers: HashMap::from([("x-token".to_string(), "secret".to_string())]),
+                },
+            }],
+        });
+        svc
+    }
+
+    #[test]
+    fn test_scope_cooldown_and_flapping() {
+        let dir = tempfile::tempdir().unwrap();
+        let svc = service(
+            &dir,
+            vec![
+                rule(1, AlertScope::Group { group_id: 7 }, AlertTrigger::Down),
+                rule(2, AlertScope::All, AlertTrigger::Recovered),
+            ],
+            "http://127.0.0.1:9/",
+        );
+        let (a, b) = ("https://a.example.com", "https://b.example.com");
+        let now = Utc::now();
+        let changes = [
+            change(a, ConfirmedStatus::Up, ConfirmedStatus::Down, false),
+            change(b, ConfirmedStatus::Up, ConfirmedStatus::Down, false),
+        ];
+        let events = svc.evaluate_checks(&changes, &[], &[], &targets(), now);
+        // b는 그룹 7 소속이 아님
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].url, a);
+        assert!(events[0].message.contains("503"));
+
+        // 쿨다운 안에서는 다시 만들지 않음
+        let later = now + chrono::Duration::seconds(60);
+        assert!(svc
+            .evaluate_checks(&changes[..1], &[], &[], &targets(), later)
+            .is_empty());
+        let after = now + chrono::Duration::seconds(601);
+        assert_eq!(
+            svc.evaluate_checks(&changes[..1], &[], &[], &targets(), after)
+                .len(),
+            1
+        );
+
+        // 플래핑 중 변경은 억제, Unknown → Up은 recovered가 아님
+        let flapping = [change(b, ConfirmedStatus::Down, ConfirmedStatus::Up, true)];
+        assert!(svc
+            .evaluate_checks(&flapping, &[], &[], &targets(), now)
+            .is_empty());
+        let first_up = [change(
+            b,
+            ConfirmedStatus::Unknown,
+            ConfirmedStatus::Up,
+            false,
+        )];
+        assert!(svc
+            .evaluate_checks(&first_up, &[], &[], &targets(), now)
+            .is_empty());
+        let recovered = [change(b, ConfirmedStatus::Down, ConfirmedStatus::Up, false)];
+        let events = svc.evaluate_checks(&recovered, &[], &[], &targets(), now);
+        assert_eq!(events[0].trigger, "recovered");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery_is_recorded() {
+        let received: Arc<Mutex<Vec<(Option<String>, serde_json::Value)>>> = Arc::default();
+        let app = Router::new()
+            .route(
+                "/hook",
+                post(
+                    |State(received): State<
+                        Arc<Mutex<Vec<(Option<String>, serde_json::Value)>>>,
+                    >,
+                     headers: axum::http::HeaderMap,
+                     Json(body): Json<serde_json::Value>| async move {
+                        let token = headers
+                            .get("x-token")
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string);
+                        received.lock().unwrap().push((token, body));
+                    },
+                ),
+            )
+            .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let svc = service(
+            &dir,
+            vec![rule(
+                1,
+                AlertScope::Domain { domain_id: 1 },
+                AlertTrigger::LatencyOver { ms: 500 },
+            )],
+            &format!("http://{addr}/hook"),
+        );
+        let events = svc.evaluate_checks(&[], &[latency_log(900)], &[], &targets(), Utc::now());
+        let records = svc.dispatch(events).await;
+        assert_eq!(records.len(), 1);
+        assert!(records[0].delivered, "{:?}", records[0].error);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received[0].0.as_deref(), Some("secret"));
+        assert_eq!(received[0].1["trigger"], "latencyOver");
+        assert_eq!(received[0].1["latency"], 900);
+        assert_eq!(received[0].1["groups"][0], "Prod");
+
+        // 기록과 쿨다운은 재시작 후에도 유지
+        let reloaded = AlertService::new(
+            dir.path().join("alert_settings.json"),
+            dir.path().join("alert_history.json"),
+        );
+        assert_eq!(reloaded.history(10).len(), 1);
+        assert_eq!(reloaded.get_config().channels[0].id, 1);
+        assert!(reloaded
+            .evaluate_checks(&[], &[latency_log(950)], &[], &targets(), Utc::now())
+            .is_empty());
+    }
+}
@@ -0,0 +1,468 @@
+use crate::model::cert_check::{CertCheckConfig, CertCheckResult};
+use chrono::{DateTime, Utc};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::CryptoProvider;
+use rustls::{CertificateError, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 체인 검증 결과를 기록만 하고 핸드셰이크는 통과시키는 verifier.
+/// 만료·신뢰 불가 인증서도 내용을 읽어 리포트하기 위함 (이 연결로 데이터는 주고받지 않음).
+#[derive(Debug)]
+struct RecordingVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+    outcome: Mutex<Option<Result<(), rustls::Error>>>,
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let result = self
+            .inner
+            .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+            .map(|_| ());
+        *self.outcome.lock().unwrap() = Some(result);
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// 기본 신뢰 루트 (webpki-roots 번들)
+pub fn default_root_store() -> RootCertStore {
+    let mut roots = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    roots
+}
+
+/// 체크 대상 url에서 (host, port) 추출. TLS를 쓰는 https·wss(또는 스킴 없음 = https)만 대상.
+pub fn https_target(url: &str) -> Option<(String, u16)> {
+    let full = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    };
+    let parsed = reqwest::Url::parse(&full).ok()?;
+    if !matches!(parsed.scheme(), "https" | "wss") {
+        return None;
+    }
+    let host = parsed.host_str()?.trim_matches(['[', ']']).to_string();
+    Some((host, parsed.port_or_known_default().unwrap_or(443)))
+}
+
+/// `*.example.com` 같은 와일드카드는 한 단계 라벨만 매치
+fn dns_name_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim_end_matches('.').to_ascii_lowercase();
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => host
+            .split_once('.')
+            .is_some_and(|(label, rest)| !label.is_empty() && rest == suffix),
+        None => pattern == host,
+    }
+}
+
+fn hostname_matches(sans: &[String], common_name: Option<&str>, host: &str) -> bool {
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return sans.iter().any(|s| s.parse::<IpAddr>().ok() == Some(ip));
+    }
+    let dns_sans: Vec<&String> = sans
+        .iter()
+        .filter(|s| s.parse::<IpAddr>().is_err())
+        .collect();
+    if dns_sans.is_empty() {
+        // SAN이 없는 오래된 인증서만 CN으로 비교
+        return common_name.is_some_and(|cn| dns_name_matches(cn, host));
+    }
+    dns_sans.iter().any(|s| dns_name_matches(s, host))
+}
+
+/// 남은 일수·검증 결과로 level 결정. 만료/체인 실패/호스트 불일치는 error.
+fn level_for(result: &CertCheckResult, config: &CertCheckConfig) -> &'static str {
+    if result.error_message.is_some() || !result.chain_valid || !result.hostname_match {
+        return "error";
+    }
+    match result.days_remaining {
+        Some(days) if days <= i64::from(config.error_days) => "error",
+        Some(days) if days <= i64::from(config.warning_days) => "warning",
+        Some(_) => "info",
+        None => "error",
+    }
+}
+
+struct LeafInfo {
+    not_after: DateTime<Utc>,
+    issuer: String,
+    subject: String,
+    common_name: Option<String>,
+    sans: Vec<String>,
+}
+
+fn parse_leaf(der: &[u8]) -> Result<LeafInfo, String> {
+    let (_, cert) = X509Certificate::from_der(der).map_err(|e| e.to_string())?;
+    let not_after = DateTime::<Utc>::from_timestamp(cert.validity().not_after.timestamp(), 0)
+        .ok_or("invalid notAfter")?;
+    let common_name = cert
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .map(str::to_string);
+    let mut sans = Vec::new();
+    if let Ok(Some(ext)) = cert.subject_alternative_name() {
+        for name in &ext.value.general_names {
+            match name {
+                GeneralName::DNSName(dns) => sans.push((*dns).to_string()),
+                GeneralName::IPAddress(bytes) => {
+                    let ip = match bytes.len() {
+                        4 => <[u8; 4]>::try_from(*bytes).ok().map(IpAddr::from),
+                        16 => <[u8; 16]>::try_from(*bytes).ok().map(IpAddr::from),
+                        _ => None,
+                    };
+                    if let Some(ip) = ip {
+                        sans.push(ip.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(LeafInfo {
+        not_after,
+        issuer: cert.issuer().to_string(),
+        subject: cert.subject().to_string(),
+        common_name,
+        sans,
+    })
+}
+
+/// `host:port`에 TLS로 접속해 리프 인증서를 점검. 실패해도 `error_message`가 채워진 결과를 반환.
+/// `connect_host`: 실제 접속할 주소 (DNS 서버 설정으로 해석한 IP 등). 없으면 `host`.
+pub async fn inspect_certificate(
+    url: &str,
+    host: &str,
+    port: u16,
+    connect_host: Option<IpAddr>,
+    roots: Arc<RootCertStore>,
+    config: &CertCheckConfig,
+) -> CertCheckResult {
+    let mut result = CertCheckResult {
+        url: url.to_string(),
+        host: host.to_string(),
+        port,
+        timestamp: Utc::now(),
+        level: "error".to_string(),
+        ok: false,
+        not_after: None,
+        days_remaining: None,
+        issuer: None,
+        subject: None,
+        sans: Vec::new(),
+        chain_length: 0,
+        chain_valid: false,
+        hostname_match: false,
+        error_message: None,
+    };
+
+    match handshake(host, port, connect_host, roots).await {
+        Ok((chain, outcome)) => {
+            result.chain_length = chain.len();
+            // 이름 불일치만 난 경우 체인 자체는 신뢰 가능 (hostname_match로 따로 표시)
+            result.chain_valid = matches!(
+                outcome,
+                Some(
+                    Ok(())
+                        | Err(rustls::Error::InvalidCertificate(
+                            CertificateError::NotValidForName
+                                | CertificateError::NotValidForNameContext { .. }
+                        ))
+                )
+            );
+            match chain.first().map(|der| parse_leaf(der)) {
+                Some(Ok(leaf)) => {
+                    result.days_remaining = Some((leaf.not_after - result.timestamp).num_days());
+                    result.not_after = Some(leaf.not_after);
+                    result.hostname_match =
+                        hostname_matches(&leaf.sans, leaf.common_name.as_deref(), host);
+                    result.issuer = Some(leaf.issuer);
+                    result.subject = Some(leaf.subject);
+                    result.sans = leaf.sans;
+                }
+                Some(Err(e)) => result.error_message = Some(format!("invalid certificate: {e}")),
+                None => result.error_message = Some("no certificate presented".to_string()),
+            }
+            if result.error_message.is_none() && !result.chain_valid {
+                if let Some(Err(e)) = outcome {
+                    result.error_message = Some(e.to_string());
+                }
+            }
+        }
+        Err(e) => result.error_message = Some(e),
+    }
+
+    let level = level_for(&result, config);
+    result.ok = level == "info";
+    result.level = level.to_string();
+    result
+}
+
+async fn handshake(
+    host: &str,
+    port: u16,
+    connect_host: Option<IpAddr>,
+    roots: Arc<RootCertStore>,
+) -> Result<
+    (
+        Vec<CertificateDer<'static>>,
+        Option<Result<(), rustls::Error>>,
+    ),
+    String,
+> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let inner = WebPkiServerVerifier::builder_with_provider(roots, provider.clone())
+        .build()
+        .map_err(|e| e.to_string())?;
+    let verifier = Arc::new(RecordingVerifier {
+        inner,
+        outcome: Mutex::new(None),
+    });
+    let client_config =
+        rustls::ClientConfig::builder_with_provider(provider as Arc<CryptoProvider>)
+            .with_safe_default_protocol_versions()
+            .map_err(|e| e.to_string())?
+            .dangerous()
+            .with_custom_certificate_verifier(verifier.clone())
+            .with_no_client_auth();
+
+    let server_name = ServerName::try_from(host.to_string()).map_err(|e| e.to_string())?;
+    let tcp = match connect_host {
+        Some(ip) => tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((ip, port))).await,
+        None => tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((host, port))).await,
+    }
+    .map_err(|_| "connect timed out".to_string())?
+    .map_err(|e| e.to_string())?;
+
+    let connector = TlsConnector::from(Arc::new(client_config));
+    let tls = tokio::time::timeout(CONNECT_TIMEOUT, connector.connect(server_name, tcp))
+        .await
+        .map_err(|_| "TLS handshake timed out".to_string())?
+        .map_err(|e| e.to_string())?;
+    let chain = tls
+        .get_ref()
+        .1
+        .peer_certificates()
+        .map(|certs| certs.iter().map(|c| c.clone().into_owned()).collect())
+        .unwrap_or_default();
+    let outcome = verifier.outcome.lock().unwrap().take();
+    Ok((chain, outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::ca_service::CaService;
+    use rustls_pki_types::pem::PemObject;
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+
+    /// `CaService`로 서명한 인증서를 쓰는 로컬 TLS 서버. 핸드셰이크만 처리.
+    async fn spawn_tls_server(ca: &CaService, host: &str) -> u16 {
+        let (cert, key) = ca.sign_host_certificate(host).unwrap();
+        let ca_der = CertificateDer::from_pem_slice(ca.ca_cert_pem().as_bytes()).unwrap();
+        let key_der = rustls_pki_types::PrivateKeyDer::Pkcs8(key.serialize_der().into());
+        let server_config = rustls::ServerConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert.der().clone(), ca_der], key_der)
+        .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(server_config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    let _ = acceptor.accept(stream).await;
+                });
+            }
+        });
+        port
+    }
+
+    fn ca_roots(ca: &CaService) -> Arc<RootCertStore> {
+        let mut roots = RootCertStore::empty();
+        roots
+            .add(CertificateDer::from_pem_slice(ca.ca_cert_pem().as_bytes()).unwrap())
+            .unwrap();
+        Arc::new(roots)
+    }
+
+    #[tokio::test]
+    async fn test_inspect_certificate_from_local_server() {
+        let dir = tempfile::tempdir().unwrap();
+        let ca = CaService::new(dir.path()).unwrap();
+        let port = spawn_tls_server(&ca, "localhost").await;
+        let localhost = Some(IpAddr::from([127, 0, 0, 1]));
+
+        let config = CertCheckConfig::default();
+        let result = inspect_certificate(
+            "https://localhost",
+            "localhost",
+            port,
+            localhost,
+            ca_roots(&ca),
+            &config,
+        )
+        .await;
+        assert_eq!(result.error_message, None);
+        assert!(result.chain_valid);
+        assert!(result.hostname_match);
+        assert_eq!(result.chain_length, 2);
+        assert_eq!(result.sans, vec!["localhost".to_string()]);
+        assert!(result.issuer.unwrap().contains("Watchtower Root CA"));
+        // sign_host_certificate는 2년 유효
+        let days = result.days_remaining.unwrap();
+        assert!((728..=731).contains(&days), "{days}");
+        assert_eq!(result.level, "info");
+
+        // 임계값이 남은 일수보다 크면 warning / error
+        let warn = CertCheckConfig {
+            warning_days: 800,
+            ..CertCheckConfig::default()
+        };
+        let result = inspect_certificate(
+            "https://localhost",
+            "localhost",
+            port,
+            localhost,
+            ca_roots(&ca),
+            &warn,
+        )
+        .await;
+        assert_eq!(result.level, "warning");
+        let err = CertCheckConfig {
+            error_days: 800,
+            warning_days: 900,
+            ..CertCheckConfig::default()
+        };
+        let result = inspect_certificate(
+            "https://localhost",
+            "localhost",
+            port,
+            localhost,
+            ca_roots(&ca),
+            &err,
+        )
+        .await;
+        assert_eq!(result.level, "error");
+        assert!(!result.ok);
+    }
+
+    #[tokio::test]
+    async fn test_untrusted_chain_and_hostname_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let ca = CaService::new(dir.path()).unwrap();
+        let port = spawn_tls_server(&ca, "other.test").await;
+        let localhost = Some(IpAddr::from([127, 0, 0, 1]));
+        let config = CertCheckConfig::default();
+
+        // 같은 CA를 신뢰하지만 이름이 다름 → 체인은 유효, 호스트 불일치
+        let result = inspect_certificate(
+            "https://localhost",
+            "localhost",
+            port,
+            localhost,
+            ca_roots(&ca),
+            &config,
+        )
+        .await;
+        assert!(result.chain_valid);
+        assert!(!result.hostname_match);
+        assert_eq!(result.level, "error");
+
+        // 기본 루트에는 로컬 CA가 없음 → 체인 실패지만 인증서 정보는 기록
+        let result = inspect_certificate(
+            "https://other.test",
+            "other.test",
+            port,
+            localhost,
+            Arc::new(default_root_store()),
+            &config,
+        )
+        .await;
+        assert!(!result.chain_valid);
+        assert!(result.hostname_match);
+        assert!(result.not_after.is_some());
+        assert!(result.error_message.is_some());
+    }
+
+    #[test]
+    fn test_hostname_matching() {
+        let sans = vec!["*.example.com".to_string(), "example.com".to_string()];
+        assert!(hostname_matches(&sans, None, "www.example.com"));
+        assert!(hostname_matches(&sans, None, "EXAMPLE.com"));
+        assert!(!hostname_matches(&sans, None, "a.b.example.com"));
+        assert!(hostname_matches(&[], Some("legacy.test"), "legacy.test"));
+        assert!(hostname_matches(
+            &["127.0.0.1".to_string()],
+            None,
+            "127.0.0.1"
+        ));
+    }
+
+    #[test]
+    fn test_https_target() {
+        assert_eq!(
+            https_target("example.com"),
+            Some(("example.com".to_string(), 443))
+        );
+        assert_eq!(
+            https_target("https://example.com:8443/path"),
+            Some(("example.com".to_string(), 8443))
+        );
+        assert_eq!(https_target("http://example.com"), None);
+        assert_eq!(https_target("tcp://db.internal:5432"), None);
+        assert_eq!(
+            https_target("wss://example.com/socket"),
+            Some(("example.com".to_string(), 443))
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Slack·Discord·Teams 웹훅용 알림 채널.
+//! 채널의 템플릿(`{url}` 등 자리표시자)으로 본문을 만들고, 상태·지연 시간·그룹·장애 정보는 각 서비스의
+//! 구조화된 필드(Slack context, Discord embed field, Teams fact)로 붙인다.
+
+use crate::model::alert::AlertEvent;
+use crate::service::alert_service::{http_client, post_json, NotificationChannel};
+use chrono::Local;
+use futures::future::BoxFuture;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// 템플릿을 지정하지 않은 채널의 본문
+pub const DEFAULT_TEMPLATE: &str = "{message}";
+
+const DISCORD_TITLE_MAX: usize = 256;
+const DISCORD_DESCRIPTION_MAX: usize = 4096;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChatStyle {
+    Slack,
+    Discord,
+    Teams,
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+pub fn format_duration(secs: i64) -> String {
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    match (h, m) {
+        (0, 0) => format!("{s}s"),
+        (0, _) => format!("{m}m {s}s"),
+        _ => format!("{h}h {m}m"),
+    }
+}
+
+pub fn label(trigger: &str) -> &'static str {
+    match trigger {
+        "down" => "DOWN",
+        "recovered" => "RECOVERED",
+        "latencyOver" => "SLOW",
+        "certExpiring" => "CERT EXPIRING",
+        "securityRegression" => "SECURITY REGRESSION",
+        _ => "ALERT",
+    }
+}
+
+/// 조건별 색 (RGB). down 빨강, recovered 초록, 나머지 주황
+fn color(trigger: &str) -> u32 {
+    match trigger {
+        "down" => 0x00D9_3025,
+        "recovered" => 0x001E_8E3E,
+        _ => 0x00F2_9900,
+    }
+}
+
+/// 자리표시자 값. 값이 없으면 빈 문자열 (`{latency}`는 `-`)
+fn placeholders(event: &AlertEvent) -> Vec<(&'static str, String)> {
+    vec![
+        ("{url}", event.url.clone()),
+        ("{status}", event.status.clone()),
+        (
+            "{latency}",
+            event
+                .latency
+                .map_or_else(|| "-".to_string(), |ms| format!("{ms}ms")),
+        ),
+        ("{group}", event.groups.join(", ")),
+        ("{message}", event.message.clone()),
+        ("{rule}", event.rule_name.clone()),
+        ("{trigger}", label(&event.trigger).to_string()),
+        (
+            "{incident}",
+            event
+                .incident_id
+                .map(|id| format!("#{id}"))
+                .unwrap_or_default(),
+        ),
+        (
+            "{downtime}",
+            event.downtime_secs.map(format_duration).unwrap_or_default(),
+        ),
+        (
+            "{time}",
+            event
+                .timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+        ),
+    ]
+}
+
+/// 템플릿의 자리표시자를 알림 값으로 치환 (한 번만 훑으므로 값 안의 `{...}`는 다시 치환하지 않음).
+/// 모르는 자리표시자는 그대로 둠.
+pub fn render_template(template: &str, event: &AlertEvent) -> String {
+    let values = placeholders(event);
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let known = tail.find('}').and_then(|end| {
+            let key = &tail[..=end];
+            values
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| (v, end))
+        });
+        if let Some((value, end)) = known {
+            out.push_str(value);
+            rest = &tail[end + 1..];
+        } else {
+            out.push('{');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 본문 아래에 붙는 요약 항목 (이름, 값)
+pub fn facts(event: &AlertEvent) -> Vec<(&'static str, String)> {
+    let mut facts = vec![("Status", event.status.clone())];
+    if let Some(ms) = event.latency {
+        facts.push(("Latency", format!("{ms}ms")));
+    }
+    facts.push(("Group", event.groups.join(", ")));
+    if let Some(id) = event.incident_id {
+        facts.push(("Incident", format!("#{id}")));
+    }
+    if let Some(secs) = event.downtime_secs {
+        facts.push(("Downtime", format_duration(secs)));
+    }
+    facts
+}
+
+pub fn headline(event: &AlertEvent) -> String {
+    format!("[{}] {}", label(&event.trigger), event.url)
+}
+
+/// Slack Incoming Webhook 본문 (`text`는 알림 미리보기용)
+pub fn slack_payload(event: &AlertEvent, body: &str) -> Value {
+    let context: Vec<Value> = facts(event)
+        .into_iter()
+        .map(|(name, value)| json!({ "type": "mrkdwn", "text": format!("*{name}:* {value}") }))
+        .collect();
+    json!({
+        "text": format!("{} {body}", headline(event)),
+        "blocks": [
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": format!("*[{}]* <{}|{}>\n{body}", label(&event.trigger), event.url, event.url),
+                },
+            },
+            { "type": "context", "elements": context },
+        ],
+    })
+}
+
+/// Discord 웹훅 본문 (embed 하나). embed `url`은 http(s)만 받으므로 그 밖의 대상
+/// (`tcp://`·`dns://`·`ws://`·`heartbeat://` …)은 `Target` 필드로
+pub fn discord_payload(event: &AlertEvent, body: &str) -> Value {
+    let mut fields: Vec<Value> = facts(event)
+        .into_iter()
+        .map(|(name, value)| json!({ "name": name, "value": value, "inline": true }))
+        .collect();
+    let linkable = event.url.starts_with("http://") || event.url.starts_with("https://");
+    if !linkable {
+        fields.push(json!({ "name": "Target", "value": event.url, "inline": false }));
+    }
+    let mut embed = json!({
+        "title": truncate(&headline(event), DISCORD_TITLE_MAX),
+        "description": truncate(body, DISCORD_DESCRIPTION_MAX),
+        "color": color(&event.trigger),
+        "fields": fields,
+        "timestamp": event.timestamp.to_rfc3339(),
+    });
+    if linkable {
+        embed["url"] = json!(event.url);
+    }
+    json!({ "embeds": [embed] })
+}
+
+/// Teams Incoming Webhook 본문 (`MessageCard`)
+pub fn teams_payload(event: &AlertEvent, body: &str) -> Value {
+    let facts: Vec<Value> = facts(event)
+        .into_iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect();
+    json!({
+        "@type": "MessageCard",
+        "@context": "https://schema.org/extensions",
+        "themeColor": format!("{:06X}", color(&event.trigger)),
+        "summary": headline(event),
+        "title": headline(event),
+        "text": body,
+        "sections": [{ "facts": facts }],
+    })
+}
+
+/// 채팅 서비스 웹훅 채널
+pub struct ChatChannel {
+    client: reqwest::Client,
+    style: ChatStyle,
+    webhook_url: String,
+    template: String,
+}
+
+impl ChatChannel {
+    pub fn new(style: ChatStyle, webhook_url: &str, template: Option<&str>) -> Self {
+        Self {
+            client: http_client(),
+            style,
+            webhook_url: webhook_url.to_string(),
+            template: template
+                .filter(|t| !t.trim().is_empty())
+                .unwrap_or(DEFAULT_TEMPLATE)
+                .to_string(),
+        }
+    }
+
+    pub fn payload(&self, event: &AlertEvent) -> Value {
+        let body = render_template(&self.template, event);
+        match self.style {
+            ChatStyle::Slack => slack_payload(event, &body),
+            ChatStyle::Discord => discord_payload(event, &body),
+            ChatStyle::Teams => teams_payload(event, &body),
+        }
+    }
+}
+
+impl NotificationChannel for ChatChannel {
+    fn send<'a>(&'a self, event: &'a AlertEvent) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            let payload = self.payload(event);
+            post_json(&self.client, &self.webhook_url, &HashMap::new(), &payload).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::alert_service::{sample_event, send_with_retries};
+    use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+    use chrono::Utc;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_render_template() {
+        let mut event = sample_event(Utc::now());
+        event.groups = vec!["Prod".to_string(), "Edge".to_string()];
+        event.incident_id = Some(12);
+        let text = render_template(
+            "{trigger} {url} {status} {latency} [{group}] {incident} {unknown}",
+            &event,
+        );
+        assert_eq!(
+            text,
+            "DOWN https://example.com 503 Service Unavailable 1234ms [Prod, Edge] #12 {unknown}"
+        );
+        event.latency = None;
+        event.downtime_secs = Some(3725);
+        assert_eq!(render_template("{latency} {downtime}", &event), "- 1h 2m");
+        event.message = "literal {url}".to_string();
+        assert_eq!(render_template("{{message}}", &event), "{literal {url}}");
+    }
+
+    #[test]
+    fn test_payload_shapes() {
+        let mut event = sample_event(Utc::now());
+        event.trigger = "recovered".to_string();
+        event.downtime_secs = Some(90);
+        let channel = |style| ChatChannel::new(style, "http://unused", Some("{url} is back"));
+
+        let slack = channel(ChatStyle::Slack).payload(&event);
+        assert!(slack["blocks"][0]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .ends_with("https://example.com is back"));
+        assert_eq!(
+            slack["blocks"][1]["elements"][3]["text"],
+            "*Downtime:* 1m 30s"
+        );
+
+        let discord = channel(ChatStyle::Discord).payload(&event);
+        let embed = &discord["embeds"][0];
+        assert_eq!(embed["title"], "[RECOVERED] https://example.com");
+        assert_eq!(embed["color"], 0x001E_8E3E);
+        assert_eq!(embed["fields"][0]["name"], "Status");
+        assert_eq!(embed["url"], "https://example.com");
+
+        // http(s)가 아닌 대상은 embed url 없이 `Target` 필드로
+        let mut tcp = event.clone();
+        tcp.url = "tcp://db.local:5432".to_string();
+        let discord = channel(ChatStyle::Discord).payload(&tcp);
+        let embed = &discord["embeds"][0];
+        assert!(embed.get("url").is_none());
+        assert_eq!(embed["title"], "[RECOVERED] tcp://db.local:5432");
+        let target = embed["fields"].as_array().unwrap().last().unwrap();
+        assert_eq!(
+            (&target["name"], &target["value"]),
+            (&json!("Target"), &json!("tcp://db.local:5432"))
+        );
+
+        let teams = channel(ChatStyle::Teams).payload(&event);
+        assert_eq!(teams["@type"], "MessageCard");
+        assert_eq!(teams["themeColor"], "1E8E3E");
+        assert_eq!(teams["text"], "https://example.com is back");
+
+        // 빈 템플릿은 기본값 ({message})
+        let default = ChatChannel::new(ChatStyle::Teams, "http://unused", Some(" "));
+        assert_eq!(default.payload(&event)["text"], event.message);
+    }
+
+    #[tokio::test]
+    async fn test_delivery_retries_until_accepted() {
+        let received: Arc<Mutex<Vec<Value>>> = Arc::default();
+        let app =
+            Router::new()
+                .route(
+                    "/slack",
+                    post(
+                        |State(received): State<Arc<Mutex<Vec<Value>>>>,
+                         Json(body): Json<Value>| async move {
+                            let mut received = received.lock().unwrap();
+                            received.push(body);
+                            // 첫 요청은 실패시켜 재시도를 확인
+                            if received.len() == 1 {
+                                StatusCode::INTERNAL_SERVER_ERROR
+                            } else {
+                                StatusCode::OK
+                            }
+                        },
+                    ),
+                )
+                .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let channel = ChatChannel::new(ChatStyle::Slack, &format!("http://{addr}/slack"), None);
+        let (result, attempts) = send_with_retries(&channel, &sample_event(Utc::now())).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 2);
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert!(received[1]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Test message from Watchtower"));
+    }
+}
@@ -0,0 +1,178 @@
+//! 체크 요청 동시 실행 제한. 전체 상한과 호스트별 상한을 모든 체크 주기가 함께 씀
+//! (백그라운드 루프는 due 묶음마다 따로 실행하므로 주기별 제한으로는 부족).
+//! 재시도 대기 중에는 자리를 잡지 않도록 시도 한 번마다 받고 돌려줌.
+
+use crate::model::check_concurrency::CheckConcurrency;
+use crate::service::net_probe::ProbeKind;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// 요청이 끝날 때까지 들고 있는 자리
+pub struct CheckPermit {
+    _host: Option<OwnedSemaphorePermit>,
+    _global: OwnedSemaphorePermit,
+}
+
+/// 한 주기 동안의 대기 집계
+#[derive(Default)]
+pub struct QueueStats {
+    pub attempts: AtomicU32,
+    pub queued: AtomicU32,
+    pub max_wait_ms: AtomicU32,
+}
+
+impl QueueStats {
+    fn record(&self, waited: Option<Duration>) {
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+        if let Some(waited) = waited {
+            self.queued.fetch_add(1, Ordering::Relaxed);
+            let ms = u32::try_from(waited.as_millis()).unwrap_or(u32::MAX);
+            self.max_wait_ms.fetch_max(ms, Ordering::Relaxed);
+        }
+    }
+}
+
+/// 바로 받을 수 있으면 받고, 아니면 `waited`를 켜고 기다림
+async fn take(semaphore: Arc<Semaphore>, waited: &mut bool) -> OwnedSemaphorePermit {
+    if let Ok(permit) = semaphore.clone().try_acquire_owned() {
+        return permit;
+    }
+    *waited = true;
+    semaphore
+        .acquire_owned()
+        .await
+        .expect("semaphore is never closed")
+}
+
+/// 동시 실행 제한 대상 호스트 (소문자). 요청을 보내지 않는 하트비트는 없음
+pub fn host_key(url: &str) -> Option<String> {
+    let url = url.trim();
+    let parsed = match ProbeKind::of(url) {
+        ProbeKind::Heartbeat => return None,
+        ProbeKind::Http if !url.starts_with("http") => {
+            reqwest::Url::parse(&format!("https://{url}")).ok()
+        }
+        _ => reqwest::Url::parse(url).ok(),
+    }?;
+    Some(parsed.host_str()?.to_ascii_lowercase())
+}
+
+pub struct CheckLimiter {
+    global: Arc<Semaphore>,
+    per_host: Mutex<HashMap<String, Arc<Semaphore>>>,
+    max_per_host: usize,
+}
+
+impl CheckLimiter {
+    pub fn new(settings: &CheckConcurrency) -> Self {
+        let settings = settings.normalized();
+        Self {
+            global: Arc::new(Semaphore::new(settings.max_concurrent as usize)),
+            per_host: Mutex::new(HashMap::new()),
+            max_per_host: settings.max_per_host as usize,
+        }
+    }
+
+    fn host_semaphore(&self, host: &str) -> Arc<Semaphore> {
+        let mut per_host = self.per_host.lock().unwrap();
+        // 자리를 들고 있는 요청이 없는 호스트는 정리 (permit이 `Arc`를 하나씩 가짐)
+        per_host.retain(|_, s| Arc::strong_count(s) > 1);
+        per_host
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(self.max_per_host)))
+            .clone()
+    }
+
+    /// 호스트 자리 → 전체 자리 순으로 받음 (호스트 대기 중에 전체 자리를 막지 않도록).
+    /// 바로 받지 못하고 기다렸으면 `stats`에 대기로 기록
+    pub async fn acquire(&self, host: Option<&str>, stats: &QueueStats) -> CheckPermit {
+        let start = Instant::now();
+        let mut waited = false;
+        let host_permit = match host {
+            Some(host) => Some(take(self.host_semaphore(host), &mut waited).await),
+            None => None,
+        };
+        let global = take(self.global.clone(), &mut waited).await;
+        stats.record(waited.then(|| start.elapsed()));
+        CheckPermit {
+            _host: host_permit,
+            _global: global,
+        }
+    }
+
+    /// 자리를 받아 요청 하나를 실행하고 끝나면 돌려줌
+    pub async fn run<T>(
+        &self,
+        host: Option<&str>,
+        stats: &QueueStats,
+        request: impl Future<Output = T>,
+    ) -> T {
+        let _permit = self.acquire(host, stats).await;
+        request.await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_host_key() {
+        assert_eq!(host_key("Example.com/path").as_deref(), Some("example.com"));
+        assert_eq!(
+            host_key("http://a.example:8080/").as_deref(),
+            Some("a.example")
+        );
+        assert_eq!(host_key("tcp://db.local:5432").as_deref(), Some("db.local"));
+        assert_eq!(host_key("heartbeat://nightly"), None);
+    }
+
+    #[tokio::test]
+    async fn test_limits_global_and_per_host_concurrency() {
+        let limiter = Arc::new(CheckLimiter::new(&CheckConcurrency {
+            max_concurrent: 3,
+            max_per_host: 2,
+            ..CheckConcurrency::default()
+        }));
+        let stats = Arc::new(QueueStats::default());
+        let running = Arc::new(Mutex::new(HashMap::<String, usize>::new()));
+        let peak_total = Arc::new(AtomicUsize::new(0));
+        let peak_host = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..12)
+            .map(|i| {
+                let host = if i % 3 == 0 { "b.example" } else { "a.example" };
+                let (limiter, stats, running) = (limiter.clone(), stats.clone(), running.clone());
+                let (peak_total, peak_host) = (peak_total.clone(), peak_host.clone());
+                tokio::spawn(async move {
+                    let _permit = limiter.acquire(Some(host), &stats).await;
+                    {
+                        let mut running = running.lock().unwrap();
+                        *running.entry(host.to_string()).or_default() += 1;
+                        peak_total.fetch_max(running.values().sum(), Ordering::SeqCst);
+                        peak_host.fetch_max(running[host], Ordering::SeqCst);
+                    }
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    *running.lock().unwrap().get_mut(host).unwrap() -= 1;
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(peak_host.load(Ordering::SeqCst), 2);
+        assert!(peak_total.load(Ordering::SeqCst) <= 3);
+        assert_eq!(stats.attempts.load(Ordering::Relaxed), 12);
+        assert!(stats.queued.load(Ordering::Relaxed) >= 8);
+        assert!(stats.max_wait_ms.load(Ordering::Relaxed) >= 20);
+        // 다 끝난 호스트 자리는 정리됨
+        limiter.host_semaphore("c.example");
+        assert_eq!(limiter.per_host.lock().unwrap().len(), 1);
+    }
+}
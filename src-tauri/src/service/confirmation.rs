@@ -0,0 +1,250 @@
+//! 시도별 결과로 확정 상태(up/down)를 갱신하는 상태 기계.
+//! 연속 실패 N회 → down, 연속 성공 M회 → up, 최근 체크에서 성공/실패가 자주 바뀌면 flapping.
+
+use crate::model::check_policy::{
+    CheckPolicy, ConfirmedState, ConfirmedStatus, StateChange, MAX_RETRY_BACKOFF_MS,
+};
+use crate::model::domain_status_log::DomainStatusLog;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// 재시도·확정 판정에서 실패로 보는 결과 (warning은 down 사유가 아님)
+pub fn is_failure(log: &DomainStatusLog) -> bool {
+    log.level == "error"
+}
+
+/// `attempt`(1부터)번째 시도가 실패한 뒤 다음 시도까지 대기 시간. 최대 30초.
+pub fn retry_delay(policy: &CheckPolicy, attempt: u32) -> Duration {
+    let factor = 1u64 << attempt.saturating_sub(1).min(16);
+    Duration::from_millis(u64::from(policy.retry_backoff_ms).saturating_mul(factor))
+        .min(Duration::from_millis(u64::from(MAX_RETRY_BACKOFF_MS)))
+}
+
+fn count_flips(recent: &[bool]) -> u32 {
+    recent.windows(2).filter(|w| w[0] != w[1]).count() as u32
+}
+
+/// 체크 한 번(재시도 후 최종 결과)을 반영. 확정 상태가 바뀌면 `StateChange` 반환.
+/// 플래핑이 끝났는데 상태가 마지막으로 알린 상태(`alerted_status`)와 다르면, 억제됐던 변경을
+/// 그 상태에서 바뀐 것으로 다시 반환 (플래핑 뒤 down으로 남으면 down 알림이 나가도록).
+pub fn apply_result(
+    state: &mut ConfirmedState,
+    log: &DomainStatusLog,
+    policy: &CheckPolicy,
+    now: DateTime<Utc>,
+) -> Option<StateChange> {
+    let failed = is_failure(log);
+    if failed {
+        state.consecutive_failures += 1;
+        state.consecutive_successes = 0;
+    } else {
+        state.consecutive_successes += 1;
+        state.consecutive_failures = 0;
+    }
+    state.last_checked = Some(now);
+
+    let was_flapping = state.flapping;
+    let window = policy.flap_window.max(2) as usize;
+    state.recent.push(!failed);
+    if state.recent.len() > window {
+        let excess = state.recent.len() - window;
+        state.recent.drain(..excess);
+    }
+    state.flapping =
+        policy.flap_threshold > 0 && count_flips(&state.recent) >= policy.flap_threshold;
+
+    let next = match state.status {
+        ConfirmedStatus::Up | ConfirmedStatus::Unknown
+            if failed && state.consecutive_failures >= policy.fail_threshold.max(1) =>
+        {
+            ConfirmedStatus::Down
+        }
+        // 처음 성공은 바로 up, down에서 돌아올 때만 M회 연속 성공 필요
+        ConfirmedStatus::Unknown if !failed => ConfirmedStatus::Up,
+        ConfirmedStatus::Down
+            if !failed && state.consecutive_successes >= policy.success_threshold.max(1) =>
+        {
+            ConfirmedStatus::Up
+        }
+        current => current,
+    };
+    let from = if next == state.status {
+        let alerted = state.alerted_status.unwrap_or(ConfirmedStatus::Unknown);
+        if !was_flapping || state.flapping || alerted == next {
+            return None;
+        }
+        alerted
+    } else {
+        state.status
+    };
+    let change = StateChange {
+        url: state.url.clone(),
+        from,
+        to: next,
+        flapping: state.flapping,
+        reason: (next == ConfirmedStatus::Down).then(|| {
+            log.error_message
+                .clone()
+                .unwrap_or_else(|| log.status.clone())
+        }),
+        timestamp: now,
+    };
+    if !state.flapping {
+        state.alerted_status = Some(next);
+    }
+    if next != state.status {
+        state.status = next;
+        state.since = Some(now);
+    }
+    Some(change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(level: &str) -> DomainStatusLog {
+        DomainStatusLog {
+            url: "https://example.com".to_string(),
+            status: if level == "error" {
+                "503 Service Unavailable"
+            } else {
+                "200 OK"
+            }
+            .to_string(),
+            level: level.to_string(),
+            latency: 10,
+            ok: level == "info",
+            group: "Default".to_string(),
+            timestamp: Utc::now(),
+            error_message: None,
+            failures: Vec::new(),
+            attempt: 1,
+            timing: None,
+            redirects: Vec::new(),
+            maintenance: None,
+        }
+    }
+
+    fn run(
+        state: &mut ConfirmedState,
+        policy: &CheckPolicy,
+        levels: &[&str],
+    ) -> Vec<ConfirmedStatus> {
+        levels
+            .iter()
+            .filter_map(|level| apply_result(state, &result(level), policy, Utc::now()))
+            .map(|c| c.to)
+            .collect()
+    }
+
+    #[test]
+    fn test_default_policy_confirms_immediately() {
+        let mut state = ConfirmedState::new("https://example.com");
+        let policy = CheckPolicy::default();
+        assert_eq!(
+            run(&mut state, &policy, &["info", "error", "info"]),
+            vec![
+                ConfirmedStatus::Up,
+                ConfirmedStatus::Down,
+                ConfirmedStatus::Up
+            ]
+        );
+    }
+
+    #[test]
+    fn test_thresholds_absorb_blips() {
+        let mut state = ConfirmedState::new("https://example.com");
+        let policy = CheckPolicy {
+            fail_threshold: 3,
+            success_threshold: 2,
+            ..CheckPolicy::default()
+        };
+        assert_eq!(
+            run(&mut state, &policy, &["info"]),
+            vec![ConfirmedStatus::Up]
+        );
+        // 2회 실패 후 회복 → 상태 유지
+        assert!(run(&mut state, &policy, &["error", "error", "info"]).is_empty());
+        // warning은 실패로 보지 않음
+        assert!(run(&mut state, &policy, &["warning", "warning", "warning"]).is_empty());
+        assert_eq!(
+            run(&mut state, &policy, &["error", "error", "error"]),
+            vec![ConfirmedStatus::Down]
+        );
+        assert!(run(&mut state, &policy, &["info"]).is_empty());
+        assert_eq!(
+            run(&mut state, &policy, &["info"]),
+            vec![ConfirmedStatus::Up]
+        );
+    }
+
+    #[test]
+    fn test_flapping_detected_and_cleared() {
+        let mut state = ConfirmedState::new("https://example.com");
+        let policy = CheckPolicy {
+            flap_window: 6,
+            flap_threshold: 4,
+            ..CheckPolicy::default()
+        };
+        run(&mut state, &policy, &["info", "error", "info", "error"]);
+        assert!(!state.flapping);
+        let change = apply_result(&mut state, &result("info"), &policy, Utc::now()).unwrap();
+        assert!(state.flapping);
+        assert!(change.flapping);
+        run(
+            &mut state,
+            &policy,
+            &["info", "info", "info", "info", "info"],
+        );
+        assert!(!state.flapping);
+        assert_eq!(state.recent.len(), 6);
+    }
+
+    #[test]
+    fn test_flapping_then_staying_down_reports_down_once_flapping_ends() {
+        let mut state = ConfirmedState::new("https://example.com");
+        let policy = CheckPolicy {
+            flap_window: 4,
+            flap_threshold: 3,
+            ..CheckPolicy::default()
+        };
+        run(&mut state, &policy, &["info", "error", "info"]);
+        assert_eq!(state.alerted_status, Some(ConfirmedStatus::Up));
+        // 플래핑 중 down 확정 → 억제 대상
+        let change = apply_result(&mut state, &result("error"), &policy, Utc::now()).unwrap();
+        assert!(change.flapping);
+        assert_eq!(state.status, ConfirmedStatus::Down);
+        // 계속 down: 플래핑이 끝나는 체크에서 up → down을 다시 냄
+        let since = state.since;
+        let changes: Vec<StateChange> = (0..4)
+            .filter_map(|_| apply_result(&mut state, &result("error"), &policy, Utc::now()))
+            .collect();
+        assert!(!state.flapping);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            (changes[0].from, changes[0].to, changes[0].flapping),
+            (ConfirmedStatus::Up, ConfirmedStatus::Down, false)
+        );
+        assert!(changes[0].reason.is_some());
+        assert_eq!(state.since, since);
+        assert_eq!(state.alerted_status, Some(ConfirmedStatus::Down));
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_and_caps() {
+        let policy = CheckPolicy::default();
+        assert_eq!(retry_delay(&policy, 1), Duration::from_millis(500));
+        assert_eq!(retry_delay(&policy, 3), Duration::from_secs(2));
+        assert_eq!(retry_delay(&policy, 20), Duration::from_secs(30));
+
+        let huge = CheckPolicy {
+            retries: u32::MAX,
+            retry_backoff_ms: u32::MAX,
+            ..CheckPolicy::default()
+        }
+        .normalized();
+        assert_eq!((huge.retries, huge.retry_backoff_ms), (5, 30_000));
+        assert_eq!(huge.fail_threshold, policy.fail_threshold);
+    }
+}
@@ -0,0 +1,471 @@
+//! 페이지 내용 변경 감시 (`DomainMonitorLink.content_watch`).
+//! GET 응답 바디에서 비교할 부분만 뽑아(CSS 선택자·JSON 경로) 정규화하고, SHA-256이 바뀌면 스냅샷을 남긴다.
+//! HEAD 체크로는 알 수 없는 외부 상태 페이지·가격표·설정 JSON의 변경을 잡기 위함.
+
+use crate::model::content_watch::{
+    ContentChange, ContentDiff, ContentSnapshot, ContentSnapshotInfo, ContentWatchConfig,
+    ContentWatchState,
+};
+use crate::service::http_probe::json_path_to_pointer;
+use crate::service::http_transport::HttpTransport;
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use scraper::{Html, Selector};
+use similar::{ChangeTag, TextDiff};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 내용 조회 최소 간격. 도메인 체크 주기가 더 짧아도 이보다 자주 받지 않음.
+pub const CONTENT_CHECK_MIN_INTERVAL: Duration = Duration::from_mins(1);
+
+/// url별로 보관하는 스냅샷 수 (오래된 것부터 버림)
+pub const MAX_CONTENT_SNAPSHOTS: usize = 10;
+
+/// 정규화한 내용의 최대 크기. 넘으면 선택자·JSON 경로로 범위를 좁히도록 실패 처리
+pub const MAX_CONTENT_BYTES: usize = 512 * 1024;
+
+/// diff 앞뒤로 보여 줄 줄 수
+const DIFF_CONTEXT_LINES: usize = 3;
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// 저장 전 설정 검증: 선택자·JSON 경로·무시 패턴이 올바른지
+pub fn validate(config: &ContentWatchConfig) -> Result<(), String> {
+    if let Some(path) = non_empty(config.json_path.as_deref()) {
+        json_path_to_pointer(path).ok_or_else(|| format!("invalid JSON path: {path}"))?;
+    }
+    if let Some(selector) = non_empty(config.selector.as_deref()) {
+        Selector::parse(selector).map_err(|e| format!("invalid selector {selector:?}: {e}"))?;
+    }
+    for pattern in &config.ignore_patterns {
+        Regex::new(pattern).map_err(|e| format!("invalid ignore pattern /{pattern}/: {e}"))?;
+    }
+    Ok(())
+}
+
+/// 바디에서 비교할 내용을 뽑아 정규화. JSON 경로 → CSS 선택자 → 바디 전체 순으로 하나만 적용한 뒤
+/// 무시 패턴을 지우고 (설정 시) 공백을 정리.
+pub fn normalize(body: &str, config: &ContentWatchConfig) -> Result<String, String> {
+    let mut content = if let Some(path) = non_empty(config.json_path.as_deref()) {
+        let json: serde_json::Value =
+            serde_json::from_str(body).map_err(|_| "body is not JSON".to_string())?;
+        let pointer =
+            json_path_to_pointer(path).ok_or_else(|| format!("invalid JSON path: {path}"))?;
+        match json.pointer(&pointer) {
+            Some(serde_json::Value::String(s)) => s.clone(),
+            Some(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+            None => return Err(format!("{path} not found")),
+        }
+    } else if let Some(selector) = non_empty(config.selector.as_deref()) {
+        let parsed =
+            Selector::parse(selector).map_err(|e| format!("invalid selector {selector:?}: {e}"))?;
+        let html = Html::parse_document(body);
+        let parts: Vec<String> = html
+            .select(&parsed)
+            .map(|el| el.text().collect::<Vec<_>>().join("\n"))
+            .collect();
+        if parts.is_empty() {
+            return Err(format!("selector {selector:?} matched nothing"));
+        }
+        parts.join("\n")
+    } else {
+        body.to_string()
+    };
+    for pattern in &config.ignore_patterns {
+        let re =
+            Regex::new(pattern).map_err(|e| format!("invalid ignore pattern /{pattern}/: {e}"))?;
+        content = re.replace_all(&content, "").into_owned();
+    }
+    if config.strip_whitespace {
+        content = content
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    if content.len() > MAX_CONTENT_BYTES {
+        return Err(format!(
+            "content is {} KB (limit {} KB); narrow it with a selector or JSON path",
+            content.len() / 1024,
+            MAX_CONTENT_BYTES / 1024
+        ));
+    }
+    Ok(content)
+}
+
+/// SHA-256 (hex)
+pub fn content_hash(content: &str) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, content.as_bytes());
+    digest.as_ref().iter().fold(String::new(), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+/// 줄 단위 unified diff. 끝 줄바꿈 차이는 무시
+pub fn unified_diff(old: &str, new: &str, from: &str, to: &str) -> String {
+    let old = format!("{old}\n");
+    let new = format!("{new}\n");
+    let diff = TextDiff::from_lines(&old, &new);
+    if diff.ratio() >= 1.0 {
+        return String::new();
+    }
+    diff.unified_diff()
+        .context_radius(DIFF_CONTEXT_LINES)
+        .header(from, to)
+        .to_string()
+}
+
+/// (추가된 줄, 지운 줄)
+fn line_counts(old: &str, new: &str) -> (u32, u32) {
+    let (old, new) = (format!("{old}\n"), format!("{new}\n"));
+    let diff = TextDiff::from_lines(&old, &new);
+    diff.iter_all_changes()
+        .fold((0, 0), |(added, removed), change| match change.tag() {
+            ChangeTag::Insert => (added + 1, removed),
+            ChangeTag::Delete => (added, removed + 1),
+            ChangeTag::Equal => (added, removed),
+        })
+}
+
+fn info(snapshot: &ContentSnapshot) -> ContentSnapshotInfo {
+    ContentSnapshotInfo {
+        hash: snapshot.hash.clone(),
+        captured_at: snapshot.captured_at,
+    }
+}
+
+/// GET으로 받아 정규화. 2xx가 아니면 실패 (오류 페이지를 스냅샷으로 남기지 않음)
+pub async fn fetch_content(
+    transport: &HttpTransport,
+    url: &str,
+    config: &ContentWatchConfig,
+) -> Result<String, String> {
+    let response = transport
+        .fetch(reqwest::Method::GET, url, &config.headers, None)
+        .await
+        .map_err(|e| e.message)?;
+    if !response.status.is_success() {
+        return Err(format!("HTTP {}", response.status));
+    }
+    normalize(&String::from_utf8_lossy(&response.body), config)
+}
+
+/// url별 감시 상태와 스냅샷. 상태는 `{dir}/state.json`, 스냅샷은 `{dir}/snapshots/{url 해시}.json`
+pub struct ContentWatchService {
+    states: Mutex<Vec<ContentWatchState>>,
+    dir: PathBuf,
+}
+
+impl ContentWatchService {
+    pub fn new(dir: PathBuf) -> Self {
+        let _ = std::fs::create_dir_all(dir.join("snapshots"));
+        let states = load_versioned(&dir.join("state.json"));
+        Self {
+            states: Mutex::new(states),
+            dir,
+        }
+    }
+
+    fn snapshots_path(&self, url: &str) -> PathBuf {
+        let key = &content_hash(url)[..16];
+        self.dir.join("snapshots").join(format!("{key}.json"))
+    }
+
+    fn save_states(&self, states: &[ContentWatchState]) {
+        save_versioned(&self.dir.join("state.json"), states);
+    }
+
+    pub fn state(&self, url: &str) -> Option<ContentWatchState> {
+        self.states
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.url == url)
+            .cloned()
+    }
+
+    /// 마지막 조회 후 `CONTENT_CHECK_MIN_INTERVAL`이 지났는지
+    pub fn is_due(&self, url: &str, now: DateTime<Utc>) -> bool {
+        self.state(url).is_none_or(|s| {
+            (now - s.last_checked)
+                .to_std()
+                .map_or(true, |age| age >= CONTENT_CHECK_MIN_INTERVAL)
+        })
+    }
+
+    /// url의 스냅샷 (오래된 것부터)
+    pub fn snapshots(&self, url: &str) -> Vec<ContentSnapshot> {
+        load_versioned(&self.snapshots_path(url))
+    }
+
+    /// 조회 결과 반영. 해시가 바뀌면 스냅샷을 추가하고 변경을 반환 (처음 받은 내용은 `previous_hash` 없음)
+    pub fn record(
+        &self,
+        url: &str,
+        result: Result<String, String>,
+        now: DateTime<Utc>,
+    ) -> Option<ContentChange> {
+        let mut states = self.states.lock().unwrap();
+        let index = if let Some(i) = states.iter().position(|s| s.url == url) {
+            i
+        } else {
+            states.push(ContentWatchState {
+                url: url.to_string(),
+                hash: None,
+                last_checked: now,
+                last_changed: None,
+                error: None,
+            });
+            states.len() - 1
+        };
+        let state = &mut states[index];
+        state.last_checked = now;
+        let change = match result {
+            Err(e) => {
+                state.error = Some(e);
+                None
+            }
+            Ok(content) => {
+                state.error = None;
+                let hash = content_hash(&content);
+                if state.hash.as_deref() == Some(hash.as_str()) {
+                    None
+                } else {
+                    let path = self.snapshots_path(url);
+                    let mut snapshots: Vec<ContentSnapshot> = load_versioned(&path);
+                    let (added_lines, removed_lines) = snapshots
+                        .last()
+                        .map_or((0, 0), |prev| line_counts(&prev.content, &content));
+                    let change = ContentChange {
+                        url: url.to_string(),
+                        timestamp: now,
+                        previous_hash: state.hash.take(),
+                        hash: hash.clone(),
+                        added_lines,
+                        removed_lines,
+                    };
+                    snapshots.push(ContentSnapshot {
+                        hash: hash.clone(),
+                        content,
+                        captured_at: now,
+                    });
+                    if snapshots.len() > MAX_CONTENT_SNAPSHOTS {
+                        let excess = snapshots.len() - MAX_CONTENT_SNAPSHOTS;
+                        snapshots.drain(..excess);
+                    }
+                    save_versioned(&path, &snapshots);
+                    state.hash = Some(hash);
+                    if change.previous_hash.is_some() {
+                        state.last_changed = Some(now);
+                    }
+                    Some(change)
+                }
+            }
+        };
+        self.save_states(&states);
+        change
+    }
+
+    /// 두 스냅샷의 diff. `from`·`to`는 해시 (없으면 직전·최신). 스냅샷이 없으면 `Err`
+    pub fn diff(
+        &self,
+        url: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<ContentDiff, String> {
+        let snapshots = self.snapshots(url);
+        let find = |hash: &str| {
+            snapshots
+                .iter()
+                .rposition(|s| s.hash == hash)
+                .ok_or_else(|| format!("snapshot {hash} not found"))
+        };
+        let to_index = match to {
+            Some(hash) => find(hash)?,
+            None => snapshots
+                .len()
+                .checked_sub(1)
+                .ok_or_else(|| "no snapshots yet".to_string())?,
+        };
+        let from_index = match from {
+            Some(hash) => find(hash)?,
+            None => to_index.saturating_sub(1),
+        };
+        let (old, new) = (&snapshots[from_index], &snapshots[to_index]);
+        let label =
+            |s: &ContentSnapshot| format!("{} {}", &s.hash[..12], s.captured_at.to_rfc3339());
+        Ok(ContentDiff {
+            url: url.to_string(),
+            from: info(old),
+            to: info(new),
+            diff: unified_diff(&old.content, &new.content, &label(old), &label(new)),
+        })
+    }
+
+    /// 목록용 스냅샷 정보 (최신순)
+    pub fn snapshot_infos(&self, url: &str) -> Vec<ContentSnapshotInfo> {
+        self.snapshots(url).iter().rev().map(info).collect()
+    }
+
+    /// 감시하지 않는 url의 상태·스냅샷 정리
+    pub fn retain_urls(&self, urls: &[&str]) {
+        let mut states = self.states.lock().unwrap();
+        let (keep, drop): (Vec<_>, Vec<_>) = std::mem::take(&mut *states)
+            .into_iter()
+            .partition(|s| urls.contains(&s.url.as_str()));
+        *states = keep;
+        if drop.is_empty() {
+            return;
+        }
+        for state in &drop {
+            remove_if_exists(&self.snapshots_path(&state.url));
+        }
+        self.save_states(&states);
+    }
+}
+
+fn remove_if_exists(path: &Path) {
+    if path.exists() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ContentWatchConfig {
+        ContentWatchConfig {
+            enabled: true,
+            selector: None,
+            json_path: None,
+            strip_whitespace: true,
+            ignore_patterns: Vec::new(),
+            headers: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_selector_json_and_ignore_patterns() {
+        let html = r#"<html><body><div id="price">
+            <span>Pro</span>   <b>$20</b>
+        </div><p class="ts">Updated 12:00</p></body></html>"#;
+        let selected = normalize(
+            html,
+            &ContentWatchConfig {
+                selector: Some("#price".to_string()),
+                ..config()
+            },
+        )
+        .unwrap();
+        assert_eq!(selected, "Pro\n$20");
+        let ignored = normalize(
+            html,
+            &ContentWatchConfig {
+                selector: Some("p.ts".to_string()),
+                ignore_patterns: vec![r"\d{2}:\d{2}".to_string()],
+                ..config()
+            },
+        )
+        .unwrap();
+        assert_eq!(ignored, "Updated");
+        assert!(normalize(
+            html,
+            &ContentWatchConfig {
+                selector: Some(".missing".to_string()),
+                ..config()
+            }
+        )
+        .is_err());
+
+        let json = r#"{"plans":[{"name":"pro","price":20}],"generatedAt":"now"}"#;
+        let path = ContentWatchConfig {
+            json_path: Some("$.plans[0]".to_string()),
+            ..config()
+        };
+        assert_eq!(
+            normalize(json, &path).unwrap(),
+            "{\n\"name\": \"pro\",\n\"price\": 20\n}"
+        );
+        assert!(validate(&ContentWatchConfig {
+            ignore_patterns: vec!["(".to_string()],
+            ..config()
+        })
+        .is_err());
+        assert!(validate(&ContentWatchConfig {
+            selector: Some("div[".to_string()),
+            ..config()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_records_changes_and_diffs_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = ContentWatchService::new(dir.path().to_path_buf());
+        let url = "https://status.example.com";
+        let t = Utc::now();
+        assert!(service.is_due(url, t));
+
+        let first = service.record(url, Ok("a\nb\nc".to_string()), t).unwrap();
+        assert_eq!(first.previous_hash, None);
+        assert!(!service.is_due(url, t + chrono::Duration::seconds(10)));
+        assert!(service
+            .record(
+                url,
+                Ok("a\nb\nc".to_string()),
+                t + chrono::Duration::minutes(1)
+            )
+            .is_none());
+        assert!(service
+            .record(
+                url,
+                Err("HTTP 503".to_string()),
+                t + chrono::Duration::minutes(2)
+            )
+            .is_none());
+        assert_eq!(
+            service.state(url).unwrap().error.as_deref(),
+            Some("HTTP 503")
+        );
+
+        let second = service
+            .record(
+                url,
+                Ok("a\nB\nc\nd".to_string()),
+                t + chrono::Duration::minutes(3),
+            )
+            .unwrap();
+        assert_eq!(second.previous_hash.as_deref(), Some(first.hash.as_str()));
+        assert_eq!((second.added_lines, second.removed_lines), (2, 1));
+
+        // 재시작 후에도 상태·스냅샷 유지
+        let reloaded = ContentWatchService::new(dir.path().to_path_buf());
+        let state = reloaded.state(url).unwrap();
+        assert_eq!(state.hash.as_deref(), Some(second.hash.as_str()));
+        assert!(state.error.is_none());
+        let diff = reloaded.diff(url, None, None).unwrap();
+        assert_eq!(diff.from.hash, first.hash);
+        assert!(diff.diff.contains("-b\n+B\n"), "{}", diff.diff);
+        assert!(diff.diff.contains("+d\n"), "{}", diff.diff);
+        assert!(diff.diff.starts_with("--- "));
+        assert!(reloaded
+            .diff(url, Some(&second.hash), None)
+            .unwrap()
+            .diff
+            .is_empty());
+        assert_eq!(reloaded.snapshot_infos(url).len(), 2);
+
+        reloaded.retain_urls(&[]);
+        assert!(reloaded.state(url).is_none());
+        assert!(reloaded.snapshots(url).is_empty());
+        assert!(reloaded.diff(url, None, None).is_err());
+    }
+}
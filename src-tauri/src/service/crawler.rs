@@ -0,0 +1,505 @@
+//! 도메인 하위 페이지 크롤러.
+//! robots.txt와 sitemap.xml을 읽고, 루트에서 같은 출처의 `<a href>`를 깊이·페이지 수 제한까지 따라가며
+//! 하위 페이지를 찾는다. 4xx/5xx를 받았거나 요청에 실패한 링크는 깨진 링크로 모은다.
+
+use crate::model::sub_page::{BrokenLink, CrawlOptions, SubPageSource};
+use crate::service::http_transport::HttpTransport;
+use regex::Regex;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, Url};
+use scraper::{Html, Selector};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// 동시에 요청하는 페이지 수
+const CRAWL_CONCURRENCY: usize = 8;
+
+/// 읽는 sitemap 파일 수 상한 (sitemap index가 가리키는 것 포함)
+const MAX_SITEMAPS: usize = 20;
+
+/// robots.txt의 `User-agent: *` 규칙과 `Sitemap:` 목록
+#[derive(Debug, Default)]
+pub struct Robots {
+    /// (allow 여부, 경로 패턴)
+    rules: Vec<(bool, String)>,
+    pub sitemaps: Vec<String>,
+}
+
+impl Robots {
+    /// 일치하는 규칙 중 패턴이 가장 긴 것을 따름 (같으면 Allow). 일치하는 규칙이 없으면 허용
+    pub fn is_allowed(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(_, pattern)| rule_matches(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+            .is_none_or(|(allow, _)| *allow)
+    }
+}
+
+/// robots.txt 경로 패턴 (`*` 와일드카드, 끝의 `$` 고정) 일치 여부
+fn rule_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = path.strip_prefix(parts.next().unwrap_or("")) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if anchored && i + 1 == parts.len() {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+/// robots.txt 파싱. `User-agent: *` 그룹의 Allow·Disallow만 쓰고, `Sitemap:`은 위치와 무관하게 모음
+pub fn parse_robots(text: &str) -> Robots {
+    let mut robots = Robots::default();
+    let mut agents: Vec<String> = Vec::new();
+    let mut in_rules = false;
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "user-agent" => {
+                if in_rules {
+                    agents.clear();
+                    in_rules = false;
+                }
+                agents.push(value.to_ascii_lowercase());
+            }
+            "allow" | "disallow" => {
+                in_rules = true;
+                // 빈 Disallow는 전체 허용
+                if agents.iter().any(|a| a == "*") && !value.is_empty() {
+                    let allow = key.trim().eq_ignore_ascii_case("allow");
+                    robots.rules.push((allow, value.to_string()));
+                }
+            }
+            "sitemap" if !value.is_empty() => robots.sitemaps.push(value.to_string()),
+            _ => {}
+        }
+    }
+    robots
+}
+
+/// sitemap.xml의 `<loc>` 목록. sitemap index면 `sitemaps`, 아니면 `urls`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Sitemap {
+    pub urls: Vec<String>,
+    pub sitemaps: Vec<String>,
+}
+
+fn unescape_xml(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn parse_sitemap(xml: &str) -> Sitemap {
+    let re = Regex::new(r"(?s)<loc>\s*(.*?)\s*</loc>").expect("valid regex");
+    let locs: Vec<String> = re.captures_iter(xml).map(|c| unescape_xml(&c[1])).collect();
+    if xml.contains("<sitemapindex") {
+        Sitemap {
+            urls: Vec::new(),
+            sitemaps: locs,
+        }
+    } else {
+        Sitemap {
+            urls: locs,
+            sitemaps: Vec::new(),
+        }
+    }
+}
+
+/// HTML의 `<title>`과 http(s) 링크 (`base` 기준 절대 url, fragment 제외)
+pub fn parse_page(html: &str, base: &Url) -> (Option<String>, Vec<Url>) {
+    let document = Html::parse_document(html);
+    let title_selector = Selector::parse("title").expect("valid selector");
+    let link_selector = Selector::parse("a[href]").expect("valid selector");
+    let title = document
+        .select(&title_selector)
+        .next()
+        .map(|el| el.text().collect::<String>().trim().to_string())
+        .filter(|t| !t.is_empty());
+    let links = document
+        .select(&link_selector)
+        .filter_map(|el| el.value().attr("href"))
+        .filter_map(|href| base.join(href.trim()).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(|mut url| {
+            url.set_fragment(None);
+            url
+        })
+        .collect();
+    (title, links)
+}
+
+fn same_origin(a: &Url, b: &Url) -> bool {
+    a.scheme() == b.scheme()
+        && a.host_str() == b.host_str()
+        && a.port_or_known_default() == b.port_or_known_default()
+}
+
+/// 하위 페이지로 저장하는 경로 (`/path?query`)
+pub fn page_path(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    }
+}
+
+/// 도메인 url을 요청 가능한 루트 url로 (스킴이 없으면 https)
+pub fn root_url(domain_url: &str) -> String {
+    if domain_url.starts_with("http") {
+        domain_url.to_string()
+    } else {
+        format!("https://{domain_url}")
+    }
+}
+
+/// 하위 페이지의 조회용 url: 도메인 출처 + 경로
+pub fn page_url(domain_url: &str, path: &str) -> Option<String> {
+    let root = Url::parse(&root_url(domain_url)).ok()?;
+    root.join(path).ok().map(String::from)
+}
+
+/// 크롤링에서 찾은 정상 페이지 (루트 제외)
+#[derive(Debug, Clone)]
+pub struct CrawledPage {
+    pub path: String,
+    pub title: Option<String>,
+    pub status: u16,
+    pub source: SubPageSource,
+}
+
+#[derive(Debug, Default)]
+pub struct CrawlOutcome {
+    pub pages: Vec<CrawledPage>,
+    pub pages_crawled: u32,
+    pub sitemap_urls: u32,
+    pub disallowed: u32,
+    pub truncated: bool,
+    pub broken_links: Vec<BrokenLink>,
+}
+
+async fn fetch_text(transport: &HttpTransport, url: &Url) -> Option<String> {
+    let response = transport
+        .fetch(Method::GET, url.as_str(), &HashMap::new(), None)
+        .await
+        .ok()?;
+    response
+        .status
+        .is_success()
+        .then(|| String::from_utf8_lossy(&response.body).into_owned())
+}
+
+/// sitemap(robots.txt의 `Sitemap:`, 없으면 `/sitemap.xml`)에서 같은 출처 url 수집. index는 따라감
+async fn sitemap_urls(transport: &HttpTransport, root: &Url, robots: &Robots) -> Vec<Url> {
+    let mut queue: Vec<Url> = robots
+        .sitemaps
+        .iter()
+        .filter_map(|s| Url::parse(s).ok())
+        .filter(|u| same_origin(u, root))
+        .collect();
+    if queue.is_empty() {
+        queue.extend(root.join("/sitemap.xml").ok());
+    }
+    let mut visited = HashSet::new();
+    let mut urls = Vec::new();
+    while let Some(sitemap) = queue.pop() {
+        if visited.len() >= MAX_SITEMAPS || !visited.insert(sitemap.to_string()) {
+            continue;
+        }
+        let Some(xml) = fetch_text(transport, &sitemap).await else {
+            continue;
+        };
+        let parsed = parse_sitemap(&xml);
+        let same = |s: &String| Url::parse(s).ok().filter(|u| same_origin(u, root));
+        queue.extend(parsed.sitemaps.iter().filter_map(same));
+        urls.extend(parsed.urls.iter().filter_map(same).map(|mut u| {
+            u.set_fragment(None);
+            u
+        }));
+    }
+    urls
+}
+
+/// `root_url`부터 크롤링. 루트 url이 잘못되었을 때만 `Err`
+pub async fn crawl(
+    transport: &HttpTransport,
+    root_url: &str,
+    options: &CrawlOptions,
+) -> Result<CrawlOutcome, String> {
+    let mut root = Url::parse(root_url).map_err(|e| format!("invalid URL {root_url}: {e}"))?;
+    root.set_fragment(None);
+    let robots = match root.join("/robots.txt") {
+        Ok(url) => fetch_text(transport, &url)
+            .await
+            .map(|text| parse_robots(&text))
+            .unwrap_or_default(),
+        Err(_) => Robots::default(),
+    };
+    let allowed = |url: &Url| !options.respect_robots || robots.is_allowed(&page_path(url));
+
+    let mut outcome = CrawlOutcome::default();
+    let mut seen: HashSet<String> = HashSet::from([root.to_string()]);
+    let mut queue: VecDeque<(Url, u32, SubPageSource)> =
+        VecDeque::from([(root.clone(), 0, SubPageSource::Link)]);
+    let from_sitemap = sitemap_urls(transport, &root, &robots).await;
+    outcome.sitemap_urls = from_sitemap.len() as u32;
+    for url in from_sitemap {
+        if !seen.insert(url.to_string()) {
+            continue;
+        }
+        if allowed(&url) {
+            queue.push_back((url, 1, SubPageSource::Sitemap));
+        } else {
+            outcome.disallowed += 1;
+        }
+    }
+
+    // 링크 url → 그 링크가 있던 페이지들
+    let mut found_on: HashMap<String, Vec<String>> = HashMap::new();
+    let max_pages = options.max_pages.max(1) as usize;
+    let mut crawled = 0;
+    while !queue.is_empty() && crawled < max_pages {
+        let n = CRAWL_CONCURRENCY.min(max_pages - crawled).min(queue.len());
+        let batch: Vec<_> = queue.drain(..n).collect();
+        crawled += batch.len();
+        let no_headers = HashMap::new();
+        let fetches = batch
+            .iter()
+            .map(|(url, _, _)| transport.fetch(Method::GET, url.as_str(), &no_headers, None));
+        let results = futures::future::join_all(fetches).await;
+        for ((url, depth, source), result) in batch.into_iter().zip(results) {
+            let response = match result {
+                Ok(response) if response.status.as_u16() < 400 => response,
+                Ok(response) => {
+                    outcome.broken_links.push(BrokenLink {
+                        url: url.to_string(),
+                        status: Some(response.status.as_u16()),
+                        error: None,
+                        found_on: Vec::new(),
+                    });
+                    continue;
+                }
+                Err(e) => {
+                    outcome.broken_links.push(BrokenLink {
+                        url: url.to_string(),
+                        status: None,
+                        error: Some(e.message),
+                        found_on: Vec::new(),
+                    });
+                    continue;
+                }
+            };
+            let is_html = response
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|v| v.contains("html"));
+            // 리다이렉트됐으면 최종 url 기준으로 상대 링크를 풂
+            let base = response
+                .chain
+                .last()
+                .and_then(|hop| Url::parse(&hop.url).ok())
+                .unwrap_or_else(|| url.clone());
+            let (title, links) = if is_html {
+                parse_page(&String::from_utf8_lossy(&response.body), &base)
+            } else {
+                (None, Vec::new())
+            };
+            if url != root {
+                outcome.pages.push(CrawledPage {
+                    path: page_path(&url),
+                    title,
+                    status: response.status.as_u16(),
+                    source,
+                });
+            }
+            if depth >= options.max_depth {
+                continue;
+            }
+            for link in links.into_iter().filter(|l| same_origin(l, &root)) {
+                let key = link.to_string();
+                let pages = found_on.entry(key.clone()).or_default();
+                if !pages.contains(&url.to_string()) {
+                    pages.push(url.to_string());
+                }
+                if !seen.insert(key) {
+                    continue;
+                }
+                if allowed(&link) {
+                    queue.push_back((link, depth + 1, SubPageSource::Link));
+                } else {
+                    outcome.disallowed += 1;
+                }
+            }
+        }
+    }
+    outcome.pages_crawled = crawled as u32;
+    outcome.truncated = !queue.is_empty();
+    for broken in &mut outcome.broken_links {
+        broken.found_on = found_on.remove(&broken.url).unwrap_or_default();
+    }
+    outcome.broken_links.sort_by(|a, b| a.url.cmp(&b.url));
+    outcome.pages.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::cert_check;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_robots_rules_and_sitemap_parsing() {
+        let robots = parse_robots(
+            "User-agent: googlebot\nDisallow: /\n\n\
+             User-agent: *\nDisallow: /private # 비공개\nAllow: /private/open\nDisallow: /*.pdf$\nDisallow:\n\
+             Sitemap: https://a.example/sitemap_index.xml\n",
+        );
+        assert!(robots.is_allowed("/"));
+        assert!(robots.is_allowed("/docs"));
+        assert!(!robots.is_allowed("/private"));
+        assert!(!robots.is_allowed("/private/secret"));
+        assert!(robots.is_allowed("/private/open/page"));
+        assert!(!robots.is_allowed("/files/a.pdf"));
+        assert!(robots.is_allowed("/files/a.pdf?x=1"));
+        assert_eq!(robots.sitemaps, vec!["https://a.example/sitemap_index.xml"]);
+
+        let index = parse_sitemap(
+            "<?xml version=\"1.0\"?><sitemapindex><sitemap><loc>https://a.example/s1.xml</loc></sitemap></sitemapindex>",
+        );
+        assert_eq!(index.sitemaps, vec!["https://a.example/s1.xml"]);
+        assert!(index.urls.is_empty());
+        let urls = parse_sitemap(
+            "<urlset><url><loc>\n  https://a.example/a?x=1&amp;y=2\n</loc></url><url><loc>https://a.example/b</loc></url></urlset>",
+        );
+        assert_eq!(
+            urls.urls,
+            vec!["https://a.example/a?x=1&y=2", "https://a.example/b"]
+        );
+
+        assert_eq!(
+            page_url("a.example", "/docs?q=1").as_deref(),
+            Some("https://a.example/docs?q=1")
+        );
+        assert_eq!(
+            page_url("http://127.0.0.1:8080/app", "/x").as_deref(),
+            Some("http://127.0.0.1:8080/x")
+        );
+    }
+
+    async fn spawn_site() -> String {
+        use axum::{http::header, http::StatusCode, response::IntoResponse, routing::get, Router};
+        fn html(body: &'static str) -> impl IntoResponse {
+            ([(header::CONTENT_TYPE, "text/html; charset=utf-8")], body)
+        }
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let robots =
+            format!("User-agent: *\nDisallow: /private\nSitemap: http://{addr}/sitemap.xml\n");
+        let sitemap = format!(
+            "<urlset><url><loc>http://{addr}/about</loc></url>\
+             <url><loc>http://{addr}/orphan</loc></url>\
+             <url><loc>https://other.example/x</loc></url></urlset>"
+        );
+        let app = Router::new()
+            .route("/robots.txt", get(move || async move { robots }))
+            .route("/sitemap.xml", get(move || async move { sitemap }))
+            .route(
+                "/",
+                get(|| async {
+                    html(
+                        "<html><head><title>Home</title></head><body>\
+                         <a href=\"/docs\">docs</a> <a href=\"docs/intro#top\">intro</a>\
+                         <a href=\"/missing\">missing</a> <a href=\"/private/x\">private</a>\
+                         <a href=\"https://other.example/\">ext</a> <a href=\"mailto:a@b.c\">mail</a>\
+                         </body></html>",
+                    )
+                }),
+            )
+            .route(
+                "/docs",
+                get(|| async {
+                    html("<title>Docs</title><a href=\"/docs/intro\">intro</a><a href=\"/missing\">x</a><a href=\"/docs/deep\">deep</a>")
+                }),
+            )
+            .route("/docs/intro", get(|| async { html("<title>Intro</title>") }))
+            .route("/docs/deep", get(|| async { html("<title>Deep</title><a href=\"/too-deep\">x</a>") }))
+            .route("/about", get(|| async { html("<title>About</title>") }))
+            .route("/orphan", get(|| async { StatusCode::INTERNAL_SERVER_ERROR }))
+            .route("/missing", get(|| async { StatusCode::NOT_FOUND }));
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        format!("http://{addr}/")
+    }
+
+    #[tokio::test]
+    async fn test_crawl_follows_links_and_reports_broken() {
+        let root = spawn_site().await;
+        let transport = HttpTransport::new(Arc::new(cert_check::default_root_store()), None);
+        let options = CrawlOptions {
+            max_depth: 2,
+            ..CrawlOptions::default()
+        };
+        let outcome = crawl(&transport, &root, &options).await.unwrap();
+
+        let paths: Vec<&str> = outcome.pages.iter().map(|p| p.path.as_str()).collect();
+        assert_eq!(paths, vec!["/about", "/docs", "/docs/deep", "/docs/intro"]);
+        let about = outcome.pages.iter().find(|p| p.path == "/about").unwrap();
+        assert_eq!(about.source, SubPageSource::Sitemap);
+        assert_eq!(about.title.as_deref(), Some("About"));
+        assert_eq!(outcome.sitemap_urls, 2);
+        assert_eq!(outcome.disallowed, 1);
+        assert!(!outcome.truncated);
+
+        let broken: Vec<(&str, Option<u16>)> = outcome
+            .broken_links
+            .iter()
+            .map(|b| {
+                (
+                    b.url.trim_start_matches(root.trim_end_matches('/')),
+                    b.status,
+                )
+            })
+            .collect();
+        assert_eq!(
+            broken,
+            vec![("/missing", Some(404)), ("/orphan", Some(500))]
+        );
+        assert_eq!(
+            outcome.broken_links[0].found_on,
+            vec![root.clone(), format!("{root}docs")]
+        );
+        assert!(outcome.broken_links[1].found_on.is_empty());
+
+        let limited = crawl(
+            &transport,
+            &root,
+            &CrawlOptions {
+                max_pages: 3,
+                ..CrawlOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(limited.pages_crawled, 3);
+        assert!(limited.truncated);
+    }
+}
@@ -0,0 +1,524 @@
+use crate::model::dns_check::{
+    DnsAnswer, DnsAnswerStatus, DnsEvent, DnsEventKind, DnsRecordType, DnsSnapshot,
+};
+use chrono::{DateTime, Utc};
+use hickory_resolver::config::{NameServerConfig, NameServerConfigGroup, ResolverConfig};
+use hickory_resolver::name_server::TokioConnectionProvider;
+use hickory_resolver::proto::op::ResponseCode;
+use hickory_resolver::proto::rr::RecordType;
+use hickory_resolver::proto::xfer::Protocol;
+use hickory_resolver::proto::ProtoErrorKind;
+use hickory_resolver::{ResolveError, Resolver, TokioResolver};
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+/// 시스템 리졸버 스냅샷의 `resolver` 라벨
+pub const SYSTEM_RESOLVER: &str = "system";
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 감시용 리졸버. `server`가 없으면 시스템 설정(resolv.conf 등) 사용.
+/// 변경을 바로 보기 위해 캐시는 끔.
+pub fn build_resolver(server: Option<(IpAddr, u16)>) -> Result<TokioResolver, String> {
+    let mut builder = match server {
+        Some((ip, port)) => Resolver::builder_with_config(
+            ResolverConfig::from_parts(
+                None,
+                vec![],
+                NameServerConfigGroup::from_ips_clear(&[ip], port, true),
+            ),
+            TokioConnectionProvider::default(),
+        ),
+        None => Resolver::builder_tokio().map_err(|e| e.to_string())?,
+    };
+    let opts = builder.options_mut();
+    opts.timeout = QUERY_TIMEOUT;
+    opts.attempts = 1;
+    opts.cache_size = 0;
+    Ok(builder.build())
+}
+
+/// UDP만 쓰는 단일 서버 리졸버 (DNS 질의 프로브용. TCP 폴백 없이 UDP 응답 여부를 봄)
+pub fn build_udp_resolver(server: SocketAddr) -> TokioResolver {
+    let config = ResolverConfig::from_parts(
+        None,
+        vec![],
+        vec![NameServerConfig::new(server, Protocol::Udp)],
+    );
+    let mut builder = Resolver::builder_with_config(config, TokioConnectionProvider::default());
+    let opts = builder.options_mut();
+    opts.timeout = QUERY_TIMEOUT;
+    opts.attempts = 1;
+    opts.cache_size = 0;
+    builder.build()
+}
+
+fn to_hickory(record_type: DnsRecordType) -> RecordType {
+    match record_type {
+        DnsRecordType::A => RecordType::A,
+        DnsRecordType::Aaaa => RecordType::AAAA,
+        DnsRecordType::Cname => RecordType::CNAME,
+        DnsRecordType::Mx => RecordType::MX,
+        DnsRecordType::Txt => RecordType::TXT,
+        DnsRecordType::Ns => RecordType::NS,
+    }
+}
+
+fn status_for_error(err: &ResolveError) -> DnsAnswerStatus {
+    match err.proto().map(hickory_resolver::proto::ProtoError::kind) {
+        Some(ProtoErrorKind::NoRecordsFound { response_code, .. }) => match *response_code {
+            ResponseCode::NXDomain => DnsAnswerStatus::Nxdomain,
+            ResponseCode::NoError => DnsAnswerStatus::NoRecords,
+            ResponseCode::ServFail => DnsAnswerStatus::Servfail,
+            _ => DnsAnswerStatus::Error,
+        },
+        _ => DnsAnswerStatus::Error,
+    }
+}
+
+/// 레코드 타입 하나 조회. 실패도 `status`로 표현
+pub async fn lookup_answer(
+    resolver: &TokioResolver,
+    host: &str,
+    record_type: DnsRecordType,
+) -> DnsAnswer {
+    let rtype = to_hickory(record_type);
+    match resolver.lookup(host, rtype).await {
+        Ok(lookup) => {
+            // A/AAAA 질의 응답에 섞인 CNAME 체인은 제외
+            let mut values: Vec<String> = lookup
+                .record_iter()
+                .filter(|r| r.record_type() == rtype)
+                .map(|r| r.data().to_string().trim_end_matches('.').to_string())
+                .collect();
+            values.sort();
+            values.dedup();
+            let status = if values.is_empty() {
+                DnsAnswerStatus::NoRecords
+            } else {
+                DnsAnswerStatus::Ok
+            };
+            DnsAnswer {
+                record_type,
+                status,
+                values,
+                error_message: None,
+            }
+        }
+        Err(e) => {
+            let status = status_for_error(&e);
+            DnsAnswer {
+                record_type,
+                status,
+                values: Vec::new(),
+                error_message: (status == DnsAnswerStatus::Error).then(|| e.to_string()),
+            }
+        }
+    }
+}
+
+/// 한 리졸버로 `record_types`를 모두 조회해 스냅샷 생성
+pub async fn resolve_snapshot(
+    url: &str,
+    host: &str,
+    resolver_label: &str,
+    resolver: &TokioResolver,
+    record_types: &[DnsRecordType],
+) -> DnsSnapshot {
+    let answers = futures::future::join_all(
+        record_types
+            .iter()
+            .map(|rt| lookup_answer(resolver, host, *rt)),
+    )
+    .await;
+    DnsSnapshot {
+        url: url.to_string(),
+        host: host.to_string(),
+        resolver: resolver_label.to_string(),
+        timestamp: Utc::now(),
+        answers,
+    }
+}
+
+/// 조회 자체가 실패한(`Error`) 타입은 이전 답을 유지해 다음 비교 기준으로 삼음.
+pub fn carry_over_errors(previous: Option<&DnsSnapshot>, current: &mut DnsSnapshot) {
+    let Some(previous) = previous else {
+        return;
+    };
+    for answer in &mut current.answers {
+        if answer.status == DnsAnswerStatus::Error {
+            if let Some(prev) = previous.answer(answer.record_type) {
+                answer.clone_from(prev);
+            }
+        }
+    }
+}
+
+fn same_answer(a: &DnsAnswer, b: &DnsAnswer) -> bool {
+    a.status == b.status && a.values == b.values
+}
+
+fn status_label(answer: &DnsAnswer) -> String {
+    match answer.status {
+        DnsAnswerStatus::Ok => answer.values.join(", "),
+        DnsAnswerStatus::NoRecords => "(none)".to_string(),
+        DnsAnswerStatus::Nxdomain => "NXDOMAIN".to_string(),
+        DnsAnswerStatus::Servfail => "SERVFAIL".to_string(),
+        DnsAnswerStatus::Error => "error".to_string(),
+    }
+}
+
+/// 이벤트 기본 필드. `previous`/`current`/`message`는 호출 측에서 채움
+fn event(
+    snapshot: &DnsSnapshot,
+    kind: DnsEventKind,
+    record_type: DnsRecordType,
+    resolver: &str,
+    level: &str,
+    now: DateTime<Utc>,
+) -> DnsEvent {
+    DnsEvent {
+        url: snapshot.url.clone(),
+        host: snapshot.host.clone(),
+        kind,
+        record_type,
+        resolver: resolver.to_string(),
+        level: level.to_string(),
+        previous: Vec::new(),
+        current: Vec::new(),
+        message: String::new(),
+        timestamp: now,
+    }
+}
+
+/// 한 도메인의 이전/현재 스냅샷(리졸버별)을 비교해 이벤트 생성.
+/// - 값 변경 → `Changed` (warning). 첫 스냅샷은 변경으로 보지 않음
+/// - NXDOMAIN·SERVFAIL로 바뀜 → `Nxdomain`/`Servfail` (error). 리졸버당 한 번
+/// - 리졸버끼리 답이 다름 → `Mismatch` (warning). 직전과 같은 불일치면 반복하지 않음
+pub fn diff_snapshots(
+    previous: &[DnsSnapshot],
+    current: &[DnsSnapshot],
+    now: DateTime<Utc>,
+) -> Vec<DnsEvent> {
+    let mut events = Vec::new();
+    let prev_answer = |resolver: &str, rt: DnsRecordType| {
+        previous
+            .iter()
+            .find(|s| s.resolver == resolver)
+            .and_then(|s| s.answer(rt))
+    };
+
+    for snap in current {
+        let mut reported_failure = false;
+        for answer in &snap.answers {
+            let prev = prev_answer(&snap.resolver, answer.record_type);
+            let failure_kind = match answer.status {
+                DnsAnswerStatus::Nxdomain => Some(DnsEventKind::Nxdomain),
+                DnsAnswerStatus::Servfail => Some(DnsEventKind::Servfail),
+                _ => None,
+            };
+            if let Some(kind) = failure_kind {
+                if !reported_failure && prev.is_none_or(|p| p.status != answer.status) {
+                    reported_failure = true;
+                    events.push(DnsEvent {
+                        previous: prev.map(|p| p.values.clone()).unwrap_or_default(),
+                        message: format!(
+                            "{} {} via {}",
+                            snap.host,
+                            status_label(answer),
+                            snap.resolver
+                        ),
+                        ..event(snap, kind, answer.record_type, &snap.resolver, "error", now)
+                    });
+                }
+                continue;
+            }
+            if answer.status == DnsAnswerStatus::Error {
+                continue;
+            }
+            if let Some(prev) = prev.filter(|p| p.status != DnsAnswerStatus::Error) {
+                if !same_answer(prev, answer) {
+                    events.push(DnsEvent {
+                        previous: prev.values.clone(),
+                        current: answer.values.clone(),
+                        message: format!(
+                            "{} {} changed via {}: {} -> {}",
+                            snap.host,
+                            answer.record_type,
+                            snap.resolver,
+                            status_label(prev),
+                            status_label(answer)
+                        ),
+                        ..event(
+                            snap,
+                            DnsEventKind::Changed,
+                            answer.record_type,
+                            &snap.resolver,
+                            "warning",
+                            now,
+                        )
+                    });
+                }
+            }
+        }
+    }
+
+    // 리졸버 간 비교 (조회 실패·SERVFAIL은 제외)
+    let record_types: HashSet<DnsRecordType> = current
+        .iter()
+        .flat_map(|s| s.answers.iter().map(|a| a.record_type))
+        .collect();
+    let mut record_types: Vec<DnsRecordType> = record_types.into_iter().collect();
+    record_types.sort();
+    for rt in record_types {
+        let comparable: Vec<(&DnsSnapshot, &DnsAnswer)> = current
+            .iter()
+            .filter_map(|s| s.answer(rt).map(|a| (s, a)))
+            .filter(|(_, a)| {
+                !matches!(a.status, DnsAnswerStatus::Error | DnsAnswerStatus::Servfail)
+            })
+            .collect();
+        if comparable.len() < 2
+            || comparable
+                .iter()
+                .all(|(_, a)| same_answer(a, comparable[0].1))
+        {
+            continue;
+        }
+        let unchanged = comparable
+            .iter()
+            .all(|(s, a)| prev_answer(&s.resolver, rt).is_some_and(|p| same_answer(p, a)));
+        if unchanged {
+            continue;
+        }
+        let resolvers: Vec<&str> = comparable
+            .iter()
+            .map(|(s, _)| s.resolver.as_str())
+            .collect();
+        let current_values: Vec<String> = comparable
+            .iter()
+            .map(|(s, a)| format!("{}: {}", s.resolver, status_label(a)))
+            .collect();
+        let message = format!(
+            "{} {} differs between resolvers: {}",
+            comparable[0].0.host,
+            rt,
+            current_values.join(" / ")
+        );
+        events.push(DnsEvent {
+            current: current_values,
+            message,
+            ..event(
+                comparable[0].0,
+                DnsEventKind::Mismatch,
+                rt,
+                &resolvers.join(", "),
+                "warning",
+                now,
+            )
+        });
+    }
+    events
+}
+
+/// 테스트용 최소 UDP DNS 서버 (다른 모듈 테스트에서도 사용)
+#[cfg(test)]
+pub(crate) mod test_server {
+    use hickory_resolver::proto::op::{Message, MessageType, ResponseCode};
+    use hickory_resolver::proto::rr::rdata::{A, MX};
+    use hickory_resolver::proto::rr::{Name, RData, Record};
+    use std::net::IpAddr;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::UdpSocket;
+
+    pub struct Zone {
+        pub rcode: ResponseCode,
+        pub records: Vec<RData>,
+    }
+
+    /// 질의 타입에 맞는 레코드만 돌려주는 최소 UDP DNS 서버
+    pub async fn spawn_dns(zone: Arc<Mutex<Zone>>) -> (IpAddr, u16) {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1500];
+            while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+                let Ok(req) = Message::from_vec(&buf[..len]) else {
+                    continue;
+                };
+                let mut resp = Message::new();
+                resp.set_id(req.id())
+                    .set_message_type(MessageType::Response)
+                    .set_op_code(req.op_code())
+                    .set_recursion_desired(true)
+                    .set_recursion_available(true)
+                    .add_queries(req.queries().to_vec());
+                {
+                    let zone = zone.lock().unwrap();
+                    resp.set_response_code(zone.rcode);
+                    if let Some(q) = req.queries().first() {
+                        for rdata in &zone.records {
+                            if rdata.record_type() == q.query_type() {
+                                resp.add_answer(Record::from_rdata(
+                                    q.name().clone(),
+                                    60,
+                                    rdata.clone(),
+                                ));
+                            }
+                        }
+                    }
+                }
+                let _ = socket.send_to(&resp.to_vec().unwrap(), peer).await;
+            }
+        });
+        (addr.ip(), addr.port())
+    }
+
+    pub fn zone_with_a(ip: [u8; 4]) -> Arc<Mutex<Zone>> {
+        Arc::new(Mutex::new(Zone {
+            rcode: ResponseCode::NoError,
+            records: vec![
+                RData::A(A::from(std::net::Ipv4Addr::from(ip))),
+                RData::MX(MX::new(10, Name::from_str("mail.example.test.").unwrap())),
+            ],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::{spawn_dns, zone_with_a};
+    use super::*;
+    use hickory_resolver::proto::rr::rdata::A;
+    use hickory_resolver::proto::rr::RData;
+
+    async fn snapshot(server: (IpAddr, u16), label: &str) -> DnsSnapshot {
+        let resolver = build_resolver(Some(server)).unwrap();
+        resolve_snapshot(
+            "https://example.test",
+            "example.test",
+            label,
+            &resolver,
+            &[DnsRecordType::A, DnsRecordType::Mx, DnsRecordType::Txt],
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_and_change_detection() {
+        let zone = zone_with_a([10, 0, 0, 1]);
+        let server = spawn_dns(zone.clone()).await;
+
+        let first = snapshot(server, "primary").await;
+        let a = first.answer(DnsRecordType::A).unwrap();
+        assert_eq!(a.status, DnsAnswerStatus::Ok);
+        assert_eq!(a.values, vec!["10.0.0.1".to_string()]);
+        assert_eq!(
+            first.answer(DnsRecordType::Mx).unwrap().values,
+            vec!["10 mail.example.test".to_string()]
+        );
+        assert_eq!(
+            first.answer(DnsRecordType::Txt).unwrap().status,
+            DnsAnswerStatus::NoRecords
+        );
+        // 첫 스냅샷은 이벤트 없음, 같은 답도 이벤트 없음
+        assert!(diff_snapshots(&[], std::slice::from_ref(&first), Utc::now()).is_empty());
+        let same = snapshot(server, "primary").await;
+        assert!(diff_snapshots(
+            std::slice::from_ref(&first),
+            std::slice::from_ref(&same),
+            Utc::now()
+        )
+        .is_empty());
+
+        // cutover: A 변경
+        zone.lock().unwrap().records[0] = RData::A(A::new(10, 0, 0, 2));
+        let changed = snapshot(server, "primary").await;
+        let events = diff_snapshots(
+            std::slice::from_ref(&first),
+            std::slice::from_ref(&changed),
+            Utc::now(),
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, DnsEventKind::Changed);
+        assert_eq!(events[0].record_type, DnsRecordType::A);
+        assert_eq!(events[0].previous, vec!["10.0.0.1".to_string()]);
+        assert_eq!(events[0].current, vec!["10.0.0.2".to_string()]);
+
+        // NXDOMAIN은 리졸버당 한 번만, 이어지는 NXDOMAIN은 반복하지 않음
+        {
+            let mut z = zone.lock().unwrap();
+            z.rcode = ResponseCode::NXDomain;
+            z.records.clear();
+        }
+        let gone = snapshot(server, "primary").await;
+        let events = diff_snapshots(
+            std::slice::from_ref(&changed),
+            std::slice::from_ref(&gone),
+            Utc::now(),
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, DnsEventKind::Nxdomain);
+        assert_eq!(events[0].level, "error");
+        assert!(diff_snapshots(
+            std::slice::from_ref(&gone),
+            std::slice::from_ref(&gone),
+            Utc::now()
+        )
+        .is_empty());
+
+        zone.lock().unwrap().rcode = ResponseCode::ServFail;
+        let failing = snapshot(server, "primary").await;
+        assert_eq!(
+            failing.answer(DnsRecordType::A).unwrap().status,
+            DnsAnswerStatus::Servfail
+        );
+        let events = diff_snapshots(
+            std::slice::from_ref(&gone),
+            std::slice::from_ref(&failing),
+            Utc::now(),
+        );
+        assert_eq!(events[0].kind, DnsEventKind::Servfail);
+    }
+
+    #[tokio::test]
+    async fn test_resolver_mismatch() {
+        let internal = spawn_dns(zone_with_a([10, 0, 0, 1])).await;
+        let public = spawn_dns(zone_with_a([203, 0, 113, 7])).await;
+        let current = vec![
+            snapshot(internal, "internal").await,
+            snapshot(public, "public").await,
+        ];
+        let events = diff_snapshots(&[], &current, Utc::now());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, DnsEventKind::Mismatch);
+        assert_eq!(events[0].record_type, DnsRecordType::A);
+        assert_eq!(events[0].resolver, "internal, public");
+
+        // 같은 불일치가 계속되면 다시 알리지 않음
+        assert!(diff_snapshots(&current, &current, Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn test_carry_over_errors_keeps_last_known_answer() {
+        let answer = |status, values: &[&str]| DnsAnswer {
+            record_type: DnsRecordType::A,
+            status,
+            values: values.iter().map(|v| (*v).to_string()).collect(),
+            error_message: None,
+        };
+        let snap = |a| DnsSnapshot {
+            url: "example.test".to_string(),
+            host: "example.test".to_string(),
+            resolver: SYSTEM_RESOLVER.to_string(),
+            timestamp: Utc::now(),
+            answers: vec![a],
+        };
+        let prev = snap(answer(DnsAnswerStatus::Ok, &["10.0.0.1"]));
+        let mut cur = snap(answer(DnsAnswerStatus::Error, &[]));
+        carry_over_errors(Some(&prev), &mut cur);
+        assert_eq!(cur.answers[0].values, vec!["10.0.0.1".to_string()]);
+    }
+}
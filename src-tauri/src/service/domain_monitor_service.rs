@@ -1,28 +1,145 @@
+use crate::model::cert_check::{CertCheckConfig, CertCheckResult};
+use crate::model::check_concurrency::{CheckConcurrency, CheckCycleReport, CheckCycleStats};
+use crate::model::check_policy::{CheckPolicy, ConfirmedState, StateChange};
+use crate::model::content_watch::{
+    ContentChange, ContentDiff, ContentWatchConfig, ContentWatchStatus,
+};
+use crate::model::dns_check::{DnsCheckConfig, DnsEvent, DnsSnapshot};
 use crate::model::domain::Domain;
-use crate::model::domain_monitor_link::{DomainMonitorLink, DomainMonitorWithUrl};
+use crate::model::domain_group::DomainGroup;
+use crate::model::domain_monitor_link::{
+    DomainMonitorLink, DomainMonitorWithUrl, DEFAULT_INTERVAL_SECS,
+};
 use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::heartbeat::{HeartbeatCheck, HeartbeatStatus};
+use crate::model::http_check::HttpCheck;
+use crate::model::incident::Incident;
+use crate::model::latency_baseline::LatencyBaselineReport;
+use crate::model::maintenance::{ActiveMaintenance, MaintenanceMark};
+use crate::model::openapi_check::{
+    OpenApiCheckConfig, OpenApiCheckState, OpenApiCheckStatus, OperationCheckResult,
+};
+use crate::model::probe_options::ProbeOptions;
+use crate::model::security_audit::{SecurityAuditConfig, SecurityAuditResult};
 use crate::model::settings_export::DomainMonitorExport;
-use crate::storage::versioned::{load_versioned, save_versioned};
+use crate::model::status_page::StatusPage;
+use crate::model::sub_page::{CrawlOptions, CrawlReport, SubPageNode};
+use crate::model::tcp_check::TcpCheck;
+use crate::model::uptime::{BucketSize, RollupBucket, UptimeReport};
+use crate::service::alert_service::{AlertService, AlertTarget};
+use crate::service::cert_check;
+use crate::service::check_limiter::{self, CheckLimiter, QueueStats};
+use crate::service::confirmation;
+use crate::service::content_watch::{self, ContentWatchService};
+use crate::service::crawler;
+use crate::service::dns_check;
 use crate::service::domain_group_link_service::DomainGroupLinkService;
-use crate::service::domain_service::DomainService;
 use crate::service::domain_group_service::DomainGroupService;
+use crate::service::domain_service::DomainService;
+use crate::service::email_notify::{self, DigestSection};
+use crate::service::heartbeat_service::{self, HeartbeatService};
+use crate::service::http_probe;
+use crate::service::http_transport::HttpTransport;
+use crate::service::incident_service::{IncidentService, IncidentTarget};
+use crate::service::latency_baseline::{self, LatencyBaselineService, BASELINE_SEED_DAYS};
+use crate::service::maintenance_service::MaintenanceService;
+use crate::service::metrics::MetricsService;
+use crate::service::net_probe::{self, ProbeKind};
+use crate::service::openapi_check::{self, OpenApiCheckService};
 use crate::service::proxy_settings_service::ProxySettingsService;
-use chrono::{Local, Utc};
+use crate::service::security_audit::{self, SecurityAuditService};
+use crate::service::status_page::{self, StatusInputs, StatusPageService};
+use crate::service::sub_page_service::SubPageService;
+use crate::service::uptime_rollup;
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{Local, NaiveDate};
 use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig};
 use hickory_resolver::name_server::TokioConnectionProvider;
 use hickory_resolver::Resolver;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::{create_dir_all, OpenOptions};
 use std::io::Write;
 use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// 허용하는 최소 체크 간격 (초). 너무 짧으면 대상 서버에 부담.
+pub const MIN_INTERVAL_SECS: u32 = 5;
+
+/// 스케줄할 대상이 없을 때 백그라운드 루프가 대기하는 최대 시간.
+const IDLE_WAIT: Duration = Duration::from_mins(1);
+
+/// DNS 스냅샷 최소 간격. 도메인 체크 주기가 더 짧아도 이보다 자주 조회하지 않음.
+const DNS_CHECK_MIN_INTERVAL: Duration = Duration::from_mins(1);
+
+/// 인증서 체크 간격. 만료일은 자주 바뀌지 않으므로 HTTP 체크보다 드물게.
+const CERT_CHECK_INTERVAL: Duration = Duration::from_hours(1);
+
+/// 메모리에 남기는 최근 체크 주기 통계 수
+const MAX_CYCLE_STATS: usize = 100;
+
+/// 체크 주기가 결과를 넘기는 서비스들. 각 서비스는 `app.manage`로 따로 등록되고,
+/// 명령·백그라운드 루프가 등록된 상태를 모아 `check_domain_ids` 등에 넘김.
+pub struct MonitorServices<'a> {
+    /// 확정 상태 변경으로 열리고 닫히는 장애 (logs/incidents.json)
+    pub incidents: &'a IncidentService,
+    /// 알림 규칙·채널과 전송 기록. 전송은 체크 주기를 막지 않도록 백그라운드 태스크에서
+    pub alerts: &'a Arc<AlertService>,
+    /// 점검 시간 (데이터 폴더의 `maintenance_windows.json`)
+    pub maintenance: &'a MaintenanceService,
+    /// `heartbeat://` 대상의 핑 수신 상태 (`logs/heartbeats.json`). 프록시와 공유
+    pub heartbeats: &'a HeartbeatService,
+    /// 공개 상태 페이지 설정과 마지막 페이지. 프록시와 공유
+    pub status_page: &'a StatusPageService,
+    /// `/.watchtower/metrics` 지표 (체크 결과 + 프록시 카운터). 프록시와 공유
+    pub metrics: &'a MetricsService,
+    /// url·시간대별 지연 시간 기준선. 크게 느린 체크를 `degraded`로 표시
+    pub latency_baselines: &'a LatencyBaselineService,
+    /// 크롤링으로 찾은 하위 페이지와 체크 대상
+    pub sub_pages: &'a SubPageService,
+    /// url별 내용 감시 상태·스냅샷 (logs/content/)
+    pub content_watch: &'a ContentWatchService,
+    /// url별 마지막 보안 점검과 회귀 비교 기준 (logs/security/)
+    pub security_audits: &'a SecurityAuditService,
+    /// 도메인별 마지막 `OpenAPI` 엔드포인트 체크 (logs/openapi/)
+    pub openapi_checks: &'a OpenApiCheckService,
+}
 
 pub struct DomainMonitorService {
     pub last_checks: Mutex<Vec<DomainStatusLog>>,
+    /// url별 최신 인증서 체크 결과
+    last_cert_checks: Mutex<Vec<CertCheckResult>>,
+    /// url·리졸버별 최신 DNS 스냅샷 (logs/dns/snapshots.json에 저장, 재시작 후 변경 비교 기준)
+    dns_snapshots: Mutex<Vec<DnsSnapshot>>,
+    /// `download_api_schema`가 스키마를 저장하는 곳 (데이터 폴더의 `schemas/{domain_id}.json`)
+    schemas_dir: PathBuf,
+    /// url별 확정 상태 (logs/state/confirmed.json에 저장). 시도별 결과는 `last_checks`·일별 로그
+    confirmed_states: Mutex<Vec<ConfirmedState>>,
+    /// 상태 페이지용 지난 날짜의 일 롤업 (오늘 날짜, 버킷). 날짜가 바뀌면 다시 읽음
+    status_history: Mutex<Option<(NaiveDate, Vec<RollupBucket>)>>,
+    /// 시간·일 롤업을 만들어 둔 마지막 날짜 (날짜가 바뀐 뒤 첫 체크에서 전날을 롤업)
+    rolled_up_date: Mutex<Option<NaiveDate>>,
     pub base_dir: PathBuf,
     monitor_links_path: PathBuf,
     monitor_links: Mutex<Vec<DomainMonitorLink>>,
+    /// `domain_id` → 다음 체크 예정 시각. 메모리 전용 (앱 시작 시 모든 도메인이 즉시 due).
+    next_due: Mutex<HashMap<u32, Instant>>,
+    /// 체크가 진행 중인 `domain_id`. 느린 체크가 다음 주기와 겹치지 않도록 함.
+    in_flight: Mutex<HashSet<u32>>,
+    /// 간격·활성화 변경 시 백그라운드 루프를 깨움.
+    schedule_changed: Notify,
+    /// 동시 실행·분산 설정 (데이터 폴더의 `check_concurrency.json`)
+    concurrency: Mutex<CheckConcurrency>,
+    concurrency_path: PathBuf,
+    /// 모든 체크 주기가 함께 쓰는 동시 실행 제한. 설정을 바꾸면 새로 만듦 (진행 중인 요청은 이전 제한으로 끝남)
+    limiter: Mutex<Arc<CheckLimiter>>,
+    /// 최근 체크 주기 통계 (최신이 뒤)
+    cycle_stats: Mutex<VecDeque<CheckCycleStats>>,
+    /// 다음 주기 통계에 넣을, 진행 중이라 건너뛴 도메인 수
+    skipped_pending: AtomicU32,
 }
 
 fn parse_dns_server(s: &str) -> Option<(IpAddr, u16)> {
@@ -41,17 +158,15 @@ fn parse_dns_server(s: &str) -> Option<(IpAddr, u16)> {
 }
 
 /// Extract hostname from URL (no port). e.g. "<https://example.com:8080/path>" -> "example.com".
+/// Any scheme is stripped (`tcp://db:5432` -> "db").
 fn host_from_url(url: &str) -> Option<String> {
     let url = url.trim();
-    let authority = if let Some(rest) = url.strip_prefix("https://") {
-        rest.split('/').next().unwrap_or(rest)
-    } else if let Some(rest) = url.strip_prefix("http://") {
-        rest.split('/').next().unwrap_or(rest)
-    } else if !url.is_empty() && !url.starts_with('/') {
-        url.split('/').next().unwrap_or(url)
-    } else {
-        return None;
+    let rest = match url.split_once("://") {
+        Some((_, rest)) => rest,
+        None if !url.is_empty() && !url.starts_with('/') => url,
+        None => return None,
     };
+    let authority = rest.split('/').next().unwrap_or(rest);
     let host = if let Some((h, p)) = authority.split_once(':') {
         if p.chars().all(|c| c.is_ascii_digit()) {
             h
@@ -64,17 +179,281 @@ fn host_from_url(url: &str) -> Option<String> {
     Some(host.to_string())
 }
 
+/// NDJSON 로그 파일에 한 줄씩 추가
+fn append_ndjson<T: serde::Serialize>(path: &std::path::Path, items: &[T]) {
+    if let Some(parent) = path.parent() {
+        let _ = create_dir_all(parent);
+    }
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        for item in items {
+            if let Ok(json) = serde_json::to_string(item) {
+                let _ = writeln!(file, "{json}");
+            }
+        }
+    }
+}
+
+fn read_ndjson<T: serde::de::DeserializeOwned>(path: &std::path::Path) -> Vec<T> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str::<T>(line).ok())
+        .collect()
+}
+
+fn dns_snapshots_path(base_dir: &std::path::Path) -> PathBuf {
+    base_dir.join("dns").join("snapshots.json")
+}
+
+fn load_dns_snapshots(base_dir: &std::path::Path) -> Vec<DnsSnapshot> {
+    std::fs::read_to_string(dns_snapshots_path(base_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn read_json_or_default<T: serde::de::DeserializeOwned + Default>(path: &std::path::Path) -> T {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_json<T: serde::Serialize + ?Sized>(path: &std::path::Path, value: &T) {
+    if let Some(parent) = path.parent() {
+        let _ = create_dir_all(parent);
+    }
+    if let Ok(json) = serde_json::to_string(value) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("잘못된 날짜 형식: {date}"))
+}
+
+fn confirmed_states_path(base_dir: &std::path::Path) -> PathBuf {
+    base_dir.join("state").join("confirmed.json")
+}
+
+fn load_confirmed_states(base_dir: &std::path::Path) -> Vec<ConfirmedState> {
+    std::fs::read_to_string(confirmed_states_path(base_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// 프록시 설정의 DNS 서버로 호스트들을 해석. 서버 주소가 잘못됐거나 실패한 호스트는 빠짐.
+async fn resolve_hosts(dns_server: &str, hosts: &HashSet<String>) -> HashMap<String, IpAddr> {
+    let mut host_to_ip: HashMap<String, IpAddr> = HashMap::new();
+    let Some((ip, port)) = parse_dns_server(dns_server) else {
+        return host_to_ip;
+    };
+    let config = ResolverConfig::from_parts(
+        None,
+        vec![],
+        NameServerConfigGroup::from_ips_clear(&[ip], port, true),
+    );
+    let resolver =
+        Resolver::builder_with_config(config, TokioConnectionProvider::default()).build();
+    for host in hosts {
+        if let Ok(lookup) = resolver.lookup_ip(host.as_str()).await {
+            if let Some(addr) = lookup.iter().next() {
+                host_to_ip.insert(host.clone(), addr);
+            }
+        }
+    }
+    host_to_ip
+}
+
+/// 하트비트 설정에 저장된 토큰 (빈 토큰 제외)
+fn heartbeat_tokens(links: &[DomainMonitorLink]) -> Vec<&str> {
+    links
+        .iter()
+        .filter_map(|l| l.heartbeat.as_ref())
+        .map(|h| h.token.as_str())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// 도메인이 속한 그룹 이름. 그룹이 없으면 `["Default"]`
+fn group_names(
+    domain_id: u32,
+    groups: &[DomainGroup],
+    link_service: &DomainGroupLinkService,
+) -> Vec<String> {
+    let group_ids = link_service.get_group_ids_for_domain(domain_id);
+    if group_ids.is_empty() {
+        return vec!["Default".to_string()];
+    }
+    group_ids
+        .iter()
+        .filter_map(|gid| groups.iter().find(|g| g.id == *gid))
+        .map(|g| g.name.clone())
+        .collect()
+}
+
+fn elapsed_ms(start: Instant) -> u32 {
+    u32::try_from(start.elapsed().as_millis()).unwrap_or(u32::MAX)
+}
+
+/// `interval`을 ±`percent`% 안에서 무작위로 흔든 값 (최소 1초)
+fn jittered(interval: Duration, percent: u32) -> Duration {
+    if percent == 0 {
+        return interval;
+    }
+    let interval_ms = u64::try_from(interval.as_millis()).unwrap_or(u64::MAX);
+    let span = interval_ms / 100 * u64::from(percent);
+    let random = std::hash::BuildHasher::hash_one(&std::hash::RandomState::new(), Instant::now());
+    let ms = (interval_ms - span).saturating_add(random % (span * 2 + 1));
+    Duration::from_millis(ms.max(1000))
+}
+
+/// `spread`일 때 첫 체크 위치: 도메인별로 고정된 간격 안의 오프셋 (재시작해도 같은 자리)
+fn spread_offset(domain_id: u32, interval: Duration) -> Duration {
+    let interval_ms = u64::try_from(interval.as_millis())
+        .unwrap_or(u64::MAX)
+        .max(1);
+    Duration::from_millis(u64::from(domain_id).wrapping_mul(0x9E37_79B9_7F4A_7C15) % interval_ms)
+}
+
+/// HTTP 요청용 전송. 프록시 설정 DNS 서버가 있으면 대상 이름 조회에 사용 (실패하면 시스템 리졸버)
+fn http_transport(
+    proxy_settings_service: &ProxySettingsService,
+    roots: Arc<rustls::RootCertStore>,
+) -> HttpTransport {
+    let resolver = proxy_settings_service
+        .get()
+        .dns_server
+        .as_deref()
+        .and_then(parse_dns_server)
+        .and_then(|server| dns_check::build_resolver(Some(server)).ok());
+    HttpTransport::new(roots, resolver)
+}
+
+/// 주어진 도메인들을 병렬로 체크하여 결과 생성 (로그 저장은 호출 측에서).
+/// url 스킴에 따라 HTTP·TCP·DNS·WebSocket·하트비트 프로브를 고름. `links`: `domain_id` → 체크 옵션
+/// (`http_check`가 없으면 HEAD + 2xx/3xx, `tcp_check`가 없으면 연결만 확인).
+/// `sub_page_urls`에 있는 하위 페이지는 도메인의 재시도 규칙만 따르고 HEAD + 2xx/3xx로 체크.
+/// error면 `policy.retries`만큼 백오프 후 다시 시도. 도메인별 시도 목록을 반환 (마지막이 최종 결과).
+/// 시도마다 `limiter`에서 자리를 받아 전체·호스트별 동시 요청 수를 지킴.
+#[allow(clippy::too_many_arguments)]
+async fn probe_domains(
+    domains: &[Domain],
+    links: &HashMap<u32, DomainMonitorLink>,
+    group_service: &DomainGroupService,
+    link_service: &DomainGroupLinkService,
+    proxy_settings_service: &ProxySettingsService,
+    heartbeats: &HeartbeatService,
+    sub_page_urls: &HashSet<&str>,
+    limiter: &CheckLimiter,
+    queue_stats: &QueueStats,
+) -> Vec<Vec<DomainStatusLog>> {
+    let groups = group_service.get_all();
+    let roots = Arc::new(cert_check::default_root_store());
+    let transport = Arc::new(http_transport(proxy_settings_service, roots.clone()));
+
+    let tasks: Vec<_> = domains
+        .iter()
+        .map(|domain| {
+            let transport = transport.clone();
+            let roots = roots.clone();
+            let link = links.get(&domain.id);
+            let own = link.filter(|_| !sub_page_urls.contains(domain.url.as_str()));
+            let check = own.and_then(|l| l.http_check.clone()).unwrap_or_default();
+            let tcp_check = own.and_then(|l| l.tcp_check.clone()).unwrap_or_default();
+            let heartbeat = own.and_then(|l| l.heartbeat.clone());
+            let probe = link.and_then(|l| l.probe.clone()).unwrap_or_default();
+            let policy = link.and_then(|l| l.policy.clone()).unwrap_or_default();
+            let group_name = group_names(domain.id, &groups, link_service).join(", ");
+            let host = check_limiter::host_key(&domain.url);
+
+            async move {
+                let mut attempts = Vec::new();
+                loop {
+                    let permit = limiter.acquire(host.as_deref(), queue_stats).await;
+                    let mut entry = match ProbeKind::of(&domain.url) {
+                        ProbeKind::Http => {
+                            let url = if domain.url.starts_with("http") {
+                                domain.url.clone()
+                            } else {
+                                format!("https://{}", domain.url)
+                            };
+                            http_probe::probe_http(
+                                &transport,
+                                &url,
+                                &domain.url,
+                                &group_name,
+                                &check,
+                                &probe,
+                            )
+                            .await
+                        }
+                        ProbeKind::Tcp => {
+                            net_probe::probe_tcp(&domain.url, &group_name, &tcp_check).await
+                        }
+                        ProbeKind::Dns => {
+                            net_probe::probe_dns_query(&domain.url, &group_name).await
+                        }
+                        ProbeKind::WebSocket => {
+                            net_probe::probe_websocket(&domain.url, &group_name, roots.clone())
+                                .await
+                        }
+                        ProbeKind::Heartbeat => heartbeat_service::probe_heartbeat(
+                            &domain.url,
+                            &group_name,
+                            heartbeat.as_ref(),
+                            heartbeats,
+                            chrono::Utc::now(),
+                        ),
+                    };
+                    drop(permit);
+                    let attempt = attempts.len() as u32 + 1;
+                    entry.attempt = attempt;
+                    let retry = confirmation::is_failure(&entry) && attempt <= policy.retries;
+                    attempts.push(entry);
+                    if !retry {
+                        break attempts;
+                    }
+                    tokio::time::sleep(confirmation::retry_delay(&policy, attempt)).await;
+                }
+            }
+        })
+        .collect();
+
+    futures::future::join_all(tasks).await
+}
+
 impl DomainMonitorService {
     pub fn new(base_dir: PathBuf, monitor_links_path: PathBuf) -> Self {
         if !base_dir.exists() {
             create_dir_all(&base_dir).expect("failed to create logs directory");
         }
-        let monitor_links = load_versioned(&monitor_links_path);
+        let mut monitor_links: Vec<DomainMonitorLink> = load_versioned(&monitor_links_path);
+        for ds in &mut monitor_links {
+            ds.policy = ds.policy.as_ref().map(CheckPolicy::normalized);
+        }
+        let concurrency_path = monitor_links_path.with_file_name("check_concurrency.json");
+        let concurrency = load_versioned::<CheckConcurrency>(&concurrency_path).normalized();
         Self {
             last_checks: Mutex::new(Vec::new()),
+            last_cert_checks: Mutex::new(Vec::new()),
+            dns_snapshots: Mutex::new(load_dns_snapshots(&base_dir)),
+            schemas_dir: monitor_links_path.with_file_name("schemas"),
+            confirmed_states: Mutex::new(load_confirmed_states(&base_dir)),
+            status_history: Mutex::new(None),
+            rolled_up_date: Mutex::new(None),
             base_dir,
             monitor_links_path,
             monitor_links: Mutex::new(monitor_links),
+            next_due: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashSet::new()),
+            schedule_changed: Notify::new(),
+            limiter: Mutex::new(Arc::new(CheckLimiter::new(&concurrency))),
+            concurrency: Mutex::new(concurrency),
+            concurrency_path,
+            cycle_stats: Mutex::new(VecDeque::new()),
+            skipped_pending: AtomicU32::new(0),
         }
     }
 
@@ -87,6 +466,12 @@ impl DomainMonitorService {
         *self.monitor_links.lock().unwrap() = list.to_vec();
     }
 
+    /// 핑 수신 상태를 설정된 하트비트 토큰에 맞춤 (새 토큰 추가, 지운 토큰 정리)
+    fn sync_heartbeats(&self, heartbeats: &HeartbeatService) {
+        let list = self.load_monitor_links();
+        heartbeats.sync_tokens(&heartbeat_tokens(&list), chrono::Utc::now());
+    }
+
     pub fn get_domain_monitor_list(
         &self,
         domain_service: &DomainService,
@@ -103,6 +488,16 @@ impl DomainMonitorService {
                         url: d.url.clone(),
                         check_enabled: ds.check_enabled,
                         interval_secs: ds.interval_secs,
+                        http_check: ds.http_check.clone(),
+                        probe: ds.probe.clone(),
+                        cert_check: ds.cert_check.clone(),
+                        dns_check: ds.dns_check.clone(),
+                        tcp_check: ds.tcp_check.clone(),
+                        heartbeat: ds.heartbeat.clone(),
+                        content_watch: ds.content_watch.clone(),
+                        security_audit: ds.security_audit.clone(),
+                        openapi_check: ds.openapi_check.clone(),
+                        policy: ds.policy.clone(),
                     })
             })
             .collect()
@@ -117,6 +512,321 @@ impl DomainMonitorService {
             }
         }
         self.save_monitor_links(&list);
+        // 활성화된 도메인은 바로 체크, 비활성화된 도메인은 스케줄에서 제거
+        {
+            let mut next_due = self.next_due.lock().unwrap();
+            for id in &ids {
+                if enabled {
+                    next_due.insert(*id, Instant::now());
+                } else {
+                    next_due.remove(id);
+                }
+            }
+        }
+        self.schedule_changed.notify_one();
+    }
+
+    /// 도메인별 체크 간격 변경. `MIN_INTERVAL_SECS` 미만은 올려서 저장하고, 적용된 간격을 반환.
+    /// 다음 체크는 지금부터 새 간격 뒤로 재설정 (기존 예정 시각이 더 이르면 유지).
+    pub fn set_domain_monitor_interval(&self, domain_ids: &[u32], interval_secs: u32) -> u32 {
+        let interval_secs = interval_secs.max(MIN_INTERVAL_SECS);
+        let mut list = self.load_monitor_links();
+        let ids: HashSet<u32> = domain_ids.iter().copied().collect();
+        for ds in &mut list {
+            if ids.contains(&ds.domain_id) {
+                ds.interval_secs = interval_secs;
+            }
+        }
+        self.save_monitor_links(&list);
+        {
+            let now = Instant::now();
+            let candidate = now + Duration::from_secs(u64::from(interval_secs));
+            let mut next_due = self.next_due.lock().unwrap();
+            for id in &ids {
+                next_due
+                    .entry(*id)
+                    .and_modify(|due| *due = (*due).min(candidate))
+                    .or_insert(now);
+            }
+        }
+        self.schedule_changed.notify_one();
+        interval_secs
+    }
+
+    /// 도메인별 HTTP 체크 정의(메서드·헤더·바디·검증 규칙) 설정. `None`이면 기본 체크로 되돌림.
+    /// 해당 도메인은 바로 다시 체크되도록 스케줄.
+    pub fn set_domain_monitor_http_check(&self, domain_id: u32, check: Option<HttpCheck>) -> bool {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return false;
+        };
+        ds.http_check = check;
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        true
+    }
+
+    /// 도메인별 인증서 체크 설정(활성화·만료 임계값). `None`이면 기본값으로 되돌림.
+    /// 다음 주기에 새 임계값으로 다시 점검하도록 최신 결과를 비움.
+    pub fn set_domain_cert_check(
+        &self,
+        domain_id: u32,
+        config: Option<CertCheckConfig>,
+        domain_service: &DomainService,
+    ) -> bool {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return false;
+        };
+        ds.cert_check = config;
+        self.save_monitor_links(&list);
+        if let Some(domain) = domain_service
+            .get_all()
+            .into_iter()
+            .find(|d| d.id == domain_id)
+        {
+            self.last_cert_checks
+                .lock()
+                .unwrap()
+                .retain(|r| r.url != domain.url);
+        }
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        true
+    }
+
+    /// 도메인별 DNS 감시 설정(레코드 타입·비교 리졸버). `None`이면 감시 중지.
+    pub fn set_domain_dns_check(&self, domain_id: u32, config: Option<DnsCheckConfig>) -> bool {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return false;
+        };
+        ds.dns_check = config;
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        true
+    }
+
+    /// 도메인별 HTTP 요청 방식. `None`이면 기본값. 클라이언트 인증서 경로가 비어 있거나 제한 시간이 0이면 `Err`
+    pub fn set_domain_probe_options(
+        &self,
+        domain_id: u32,
+        options: Option<ProbeOptions>,
+    ) -> Result<(), String> {
+        if let Some(o) = &options {
+            if o.timeout_secs == Some(0) {
+                return Err("제한 시간은 1초 이상이어야 합니다.".to_string());
+            }
+            if o.client_cert
+                .as_ref()
+                .is_some_and(|c| c.cert_path.trim().is_empty() || c.key_path.trim().is_empty())
+            {
+                return Err("클라이언트 인증서와 개인 키 경로를 모두 입력하세요.".to_string());
+            }
+        }
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return Err(format!(
+                "도메인 ID {domain_id} 의 monitor 설정을 찾을 수 없습니다."
+            ));
+        };
+        ds.probe = options;
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        Ok(())
+    }
+
+    /// 도메인별 내용 감시 설정. `None`이면 감시 중지 (상태·스냅샷은 도메인이 삭제될 때 정리).
+    /// 선택자·JSON 경로·무시 패턴이 잘못되었거나 도메인이 없으면 `Err`.
+    pub fn set_domain_content_watch(
+        &self,
+        domain_id: u32,
+        config: Option<ContentWatchConfig>,
+    ) -> Result<(), String> {
+        if let Some(c) = &config {
+            content_watch::validate(c)?;
+        }
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return Err(format!(
+                "도메인 ID {domain_id} 의 monitor 설정을 찾을 수 없습니다."
+            ));
+        };
+        ds.content_watch = config;
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        Ok(())
+    }
+
+    /// 도메인별 보안 점검 설정(활성화·간격). `None`이면 기본값 (하루에 한 번).
+    pub fn set_domain_security_audit(
+        &self,
+        domain_id: u32,
+        config: Option<SecurityAuditConfig>,
+    ) -> bool {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return false;
+        };
+        ds.security_audit = config.map(|c| SecurityAuditConfig {
+            interval_hours: c.interval_hours.clamp(1, 168),
+            ..c
+        });
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        true
+    }
+
+    /// 도메인별 `OpenAPI` 엔드포인트 체크 설정. `None`이면 체크하지 않음.
+    pub fn set_domain_openapi_check(
+        &self,
+        domain_id: u32,
+        config: Option<OpenApiCheckConfig>,
+    ) -> bool {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return false;
+        };
+        ds.openapi_check = config.map(|c| OpenApiCheckConfig {
+            interval_mins: c.interval_mins.clamp(1, 1440),
+            base_url: c.base_url.filter(|u| !u.trim().is_empty()),
+            ..c
+        });
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        true
+    }
+
+    /// `tcp://` 대상의 보낼 데이터·기대 배너 설정. `None`이면 연결만 확인.
+    pub fn set_domain_tcp_check(&self, domain_id: u32, check: Option<TcpCheck>) -> bool {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return false;
+        };
+        ds.tcp_check = check.map(TcpCheck::normalized);
+        self.save_monitor_links(&list);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        true
+    }
+
+    /// `heartbeat://` 대상의 주기·여유 설정. 토큰이 비어 있으면 새로 만들고, 저장된 설정을 반환.
+    /// `None`이면 설정을 지움 (핑 상태도 정리). 도메인이 없으면 `Err`.
+    pub fn set_domain_heartbeat(
+        &self,
+        domain_id: u32,
+        check: Option<HeartbeatCheck>,
+        heartbeats: &HeartbeatService,
+    ) -> Result<Option<HeartbeatCheck>, String> {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return Err(format!(
+                "도메인 ID {domain_id} 의 monitor 설정을 찾을 수 없습니다."
+            ));
+        };
+        let check = check.map(|mut c| {
+            if c.token.trim().is_empty() {
+                c.token = heartbeat_service::new_token();
+            }
+            c
+        });
+        ds.heartbeat.clone_from(&check);
+        self.save_monitor_links(&list);
+        self.sync_heartbeats(heartbeats);
+        self.next_due
+            .lock()
+            .unwrap()
+            .insert(domain_id, Instant::now());
+        self.schedule_changed.notify_one();
+        Ok(check)
+    }
+
+    /// 하트비트 설정이 있는 도메인의 핑 경로와 수신 상태
+    pub fn get_heartbeat_status(
+        &self,
+        domain_service: &DomainService,
+        heartbeats: &HeartbeatService,
+    ) -> Vec<HeartbeatStatus> {
+        let domains = domain_service.get_all();
+        self.load_monitor_links()
+            .into_iter()
+            .filter_map(|ds| {
+                let check = ds.heartbeat?;
+                let domain = domains.iter().find(|d| d.id == ds.domain_id)?;
+                Some(HeartbeatStatus {
+                    domain_id: domain.id,
+                    url: domain.url.clone(),
+                    ping_path: heartbeat_service::ping_path(&check.token),
+                    state: heartbeats.state(&check.token),
+                    check,
+                })
+            })
+            .collect()
+    }
+
+    /// 도메인별 현재 시간대의 지연 시간 기준선과 마지막 체크의 이상 점수
+    pub fn get_latency_baselines(
+        domain_service: &DomainService,
+        latency_baselines: &LatencyBaselineService,
+    ) -> Vec<LatencyBaselineReport> {
+        let now = chrono::Utc::now();
+        domain_service
+            .get_all()
+            .iter()
+            .map(|d| {
+                let baseline = latency_baselines.get(&d.url);
+                latency_baseline::report(d.id, &d.url, baseline.as_ref(), now)
+            })
+            .collect()
+    }
+
+    /// 도메인별 재시도·확정·플랩 규칙 설정. `None`이면 기본값 (재시도 없음, 1회로 확정).
+    /// 진행 중인 연속 횟수는 유지되고 다음 체크부터 새 임계값으로 판정. 허용 범위로 맞춘 정책을 반환.
+    pub fn set_domain_check_policy(
+        &self,
+        domain_id: u32,
+        policy: Option<CheckPolicy>,
+    ) -> Result<Option<CheckPolicy>, String> {
+        let mut list = self.load_monitor_links();
+        let Some(ds) = list.iter_mut().find(|ds| ds.domain_id == domain_id) else {
+            return Err(format!(
+                "도메인 ID {domain_id} 의 monitor 설정을 찾을 수 없습니다."
+            ));
+        };
+        ds.policy = policy.as_ref().map(CheckPolicy::normalized);
+        let saved = ds.policy.clone();
+        self.save_monitor_links(&list);
+        Ok(saved)
     }
 
     /// Export용: monitor_links를 url 키로 변환 (status log는 제외)
@@ -132,13 +842,28 @@ impl DomainMonitorService {
                         url: d.url.clone(),
                         check_enabled: ds.check_enabled,
                         interval_secs: ds.interval_secs,
+                        http_check: ds.http_check.clone(),
+                        probe: ds.probe.clone(),
+                        cert_check: ds.cert_check.clone(),
+                        dns_check: ds.dns_check.clone(),
+                        tcp_check: ds.tcp_check.clone(),
+                        heartbeat: ds.heartbeat.clone(),
+                        content_watch: ds.content_watch.clone(),
+                        security_audit: ds.security_audit.clone(),
+                        openapi_check: ds.openapi_check.clone(),
+                        policy: ds.policy.clone(),
                     })
             })
             .collect()
     }
 
     /// Import: monitor 설정 복원 (URL로 매칭). status log는 제외되어 있음.
-    pub fn import_domain_monitor(&self, list: &[DomainMonitorExport], domain_service: &DomainService) {
+    pub fn import_domain_monitor(
+        &self,
+        list: &[DomainMonitorExport],
+        domain_service: &DomainService,
+        heartbeats: &HeartbeatService,
+    ) {
         let url_to_export: HashMap<&str, &DomainMonitorExport> = list
             .iter()
             .map(|e| (e.url.as_str(), e))
@@ -149,15 +874,29 @@ impl DomainMonitorService {
             if let Some(d) = domains.iter().find(|x| x.id == ds.domain_id) {
                 if let Some(exp) = url_to_export.get(d.url.as_str()) {
                     ds.check_enabled = exp.check_enabled;
-                    ds.interval_secs = exp.interval_secs;
+                    ds.interval_secs = exp.interval_secs.max(MIN_INTERVAL_SECS);
+                    ds.http_check.clone_from(&exp.http_check);
+                    ds.probe.clone_from(&exp.probe);
+                    ds.cert_check.clone_from(&exp.cert_check);
+                    ds.dns_check.clone_from(&exp.dns_check);
+                    ds.tcp_check = exp.tcp_check.clone().map(TcpCheck::normalized);
+                    ds.heartbeat.clone_from(&exp.heartbeat);
+                    ds.content_watch.clone_from(&exp.content_watch);
+                    ds.security_audit.clone_from(&exp.security_audit);
+                    ds.openapi_check.clone_from(&exp.openapi_check);
+                    ds.policy = exp.policy.as_ref().map(CheckPolicy::normalized);
                 }
             }
         }
         self.save_monitor_links(&monitor_list);
+        self.sync_heartbeats(heartbeats);
+        // 가져온 설정으로 전체 재스케줄
+        self.next_due.lock().unwrap().clear();
+        self.schedule_changed.notify_one();
     }
 
-    /// domains 목록과 동기화. 새 도메인 추가, 삭제된 도메인 제거
-    pub fn sync_with_domains(&self, domains: &[Domain]) {
+    /// domains 목록과 동기화. 새 도메인 추가, 삭제된 도메인 제거 (`services`에 남은 기록도 정리)
+    pub fn sync_with_domains(&self, domains: &[Domain], services: &MonitorServices<'_>) {
         let mut list = self.load_monitor_links();
         let domain_ids: HashSet<u32> = domains.iter().map(|d| d.id).collect();
         list.retain(|ds| domain_ids.contains(&ds.domain_id));
@@ -166,189 +905,1634 @@ impl DomainMonitorService {
                 list.push(DomainMonitorLink {
                     domain_id: d.id,
                     check_enabled: true,
-                    interval_secs: 120,
+                    interval_secs: DEFAULT_INTERVAL_SECS,
+                    http_check: None,
+                    probe: None,
+                    cert_check: None,
+                    dns_check: None,
+                    tcp_check: None,
+                    heartbeat: None,
+                    content_watch: None,
+                    security_audit: None,
+                    openapi_check: None,
+                    policy: None,
                 });
             }
         }
         self.save_monitor_links(&list);
+        self.sync_heartbeats(services.heartbeats);
+        self.next_due
+            .lock()
+            .unwrap()
+            .retain(|id, _| domain_ids.contains(id));
+        services.sub_pages.retain_domains(&domain_ids);
+        services.openapi_checks.retain_domains(&domain_ids);
+        let sub_pages = services.sub_pages.monitored(domains);
+        let urls: HashSet<&str> = domains
+            .iter()
+            .chain(&sub_pages)
+            .map(|d| d.url.as_str())
+            .collect();
+        let url_list: Vec<&str> = urls.iter().copied().collect();
+        services.metrics.retain_urls(&url_list);
+        services.latency_baselines.retain_urls(&url_list);
+        services.content_watch.retain_urls(&url_list);
+        services.security_audits.retain_urls(&url_list);
+        self.dns_snapshots
+            .lock()
+            .unwrap()
+            .retain(|s| urls.contains(s.url.as_str()));
+        self.confirmed_states
+            .lock()
+            .unwrap()
+            .retain(|s| urls.contains(s.url.as_str()));
+        self.schedule_changed.notify_one();
+    }
+
+    /// 지금 체크해야 하는 `domain_id` 목록을 반환하고, 해당 도메인의 다음 예정 시각을 `now + interval`로 갱신
+    /// (`jitter_percent`가 있으면 간격을 흔듦).
+    /// 스케줄에 없는(새로 추가·재시작 직후) 도메인은 즉시 due (`spread`면 간격 안의 도메인별 위치에).
+    /// 예정 시각이 됐는데 이전 체크가 아직 진행 중이면 이번 차례는 건너뛰고 `skipped`로 셈.
+    pub fn take_due_domain_ids(&self, domain_service: &DomainService, now: Instant) -> Vec<u32> {
+        let intervals = self.get_check_intervals(domain_service);
+        let settings = self.concurrency.lock().unwrap().clone();
+        let mut in_flight = self.in_flight.lock().unwrap();
+        let mut next_due = self.next_due.lock().unwrap();
+        next_due.retain(|id, _| intervals.contains_key(id));
+        let mut due = Vec::new();
+        let mut skipped = 0;
+        for (id, interval_secs) in intervals {
+            let interval = Duration::from_secs(u64::from(interval_secs));
+            let at = *next_due.entry(id).or_insert_with(|| {
+                if settings.spread {
+                    now + spread_offset(id, interval)
+                } else {
+                    now
+                }
+            });
+            if at > now {
+                continue;
+            }
+            next_due.insert(id, now + jittered(interval, settings.jitter_percent));
+            if in_flight.contains(&id) {
+                skipped += 1;
+                continue;
+            }
+            in_flight.insert(id);
+            due.push(id);
+        }
+        self.skipped_pending.fetch_add(skipped, Ordering::Relaxed);
+        due.sort_unstable();
+        due
+    }
+
+    /// 다음 체크 예정까지 남은 시간. 예정된 도메인이 없으면 `IDLE_WAIT`.
+    /// 진행 중인 체크는 제외 (완료 시 `schedule_changed`로 다시 깨움).
+    pub fn time_until_next_due(&self, now: Instant) -> Duration {
+        let in_flight = self.in_flight.lock().unwrap();
+        self.next_due
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(id, _)| !in_flight.contains(id))
+            .map(|(_, at)| *at)
+            .min()
+            .map_or(IDLE_WAIT, |at| {
+                at.saturating_duration_since(now).min(IDLE_WAIT)
+            })
+    }
+
+    /// `timeout`이 지나거나 스케줄이 변경될 때까지 대기 (백그라운드 루프용).
+    pub async fn wait_for_schedule(&self, timeout: Duration) {
+        let _ = tokio::time::timeout(timeout, self.schedule_changed.notified()).await;
     }
 
-    fn get_domain_ids_to_check(&self, domain_service: &DomainService) -> Vec<u32> {
+    /// 체크 활성화된 `domain_id` → `interval_secs`. 링크가 하나도 없으면 모든 도메인을 기본 간격으로.
+    fn get_check_intervals(&self, domain_service: &DomainService) -> HashMap<u32, u32> {
         let list = self.load_monitor_links();
         if list.is_empty() {
-            return domain_service.get_all().iter().map(|d| d.id).collect();
+            return domain_service
+                .get_all()
+                .iter()
+                .map(|d| (d.id, DEFAULT_INTERVAL_SECS))
+                .collect();
         }
         list.iter()
             .filter(|ds| ds.check_enabled)
-            .map(|ds| ds.domain_id)
+            .map(|ds| (ds.domain_id, ds.interval_secs.max(MIN_INTERVAL_SECS)))
             .collect()
     }
 
+    /// 수동 전체 체크: 활성화된 모든 도메인을 체크하고 스케줄을 현재 기준으로 재설정.
     pub async fn check_domains(
         &self,
         domain_service: &DomainService,
         group_service: &DomainGroupService,
         link_service: &DomainGroupLinkService,
         proxy_settings_service: &ProxySettingsService,
+        services: &MonitorServices<'_>,
+    ) -> Vec<DomainStatusLog> {
+        let intervals = self.get_check_intervals(domain_service);
+        {
+            let now = Instant::now();
+            let jitter_percent = self.concurrency.lock().unwrap().jitter_percent;
+            let mut next_due = self.next_due.lock().unwrap();
+            for (id, interval_secs) in &intervals {
+                let interval = Duration::from_secs(u64::from(*interval_secs));
+                next_due.insert(*id, now + jittered(interval, jitter_percent));
+            }
+        }
+        let domain_ids: Vec<u32> = intervals.into_keys().collect();
+        self.check_domain_ids(
+            &domain_ids,
+            domain_service,
+            group_service,
+            link_service,
+            proxy_settings_service,
+            services,
+        )
+        .await
+    }
+
+    /// 지정한 도메인들을 체크하고 결과를 로그 파일·`last_checks`와 `services`에 반영.
+    /// 백그라운드 루프는 `take_due_domain_ids` 결과를 넘겨 호출.
+    pub async fn check_domain_ids(
+        &self,
+        domain_ids: &[u32],
+        domain_service: &DomainService,
+        group_service: &DomainGroupService,
+        link_service: &DomainGroupLinkService,
+        proxy_settings_service: &ProxySettingsService,
+        services: &MonitorServices<'_>,
     ) -> Vec<DomainStatusLog> {
-        let domain_ids = self.get_domain_ids_to_check(domain_service);
+        let started_at = chrono::Utc::now();
+        let started = Instant::now();
+        let limiter = self.limiter.lock().unwrap().clone();
+        let queue_stats = QueueStats::default();
         let domains: Vec<Domain> = domain_service
             .get_all()
             .into_iter()
             .filter(|d| domain_ids.contains(&d.id))
             .collect();
-        let groups = group_service.get_all();
-        let dns_server = proxy_settings_service.get().dns_server;
-
-        let mut client_builder = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .user_agent("Watchtower/0.1.0");
-
-        if let Some(ref dns) = dns_server {
-            if let Some((ip, port)) = parse_dns_server(dns) {
-                let config = ResolverConfig::from_parts(
-                    None,
-                    vec![],
-                    NameServerConfigGroup::from_ips_clear(&[ip], port, true),
-                );
-                let resolver = Resolver::builder_with_config(
-                    config,
-                    TokioConnectionProvider::default(),
-                )
-                .build();
-                {
-                    let full_urls: Vec<String> = domains
-                        .iter()
-                        .map(|d| {
-                            if d.url.starts_with("http") {
-                                d.url.clone()
-                            } else {
-                                format!("https://{}", d.url)
-                            }
-                        })
-                        .collect();
-                    let unique_hosts: HashSet<String> = full_urls
-                        .iter()
-                        .filter_map(|u| host_from_url(u))
-                        .collect();
-                    let mut host_to_ip: HashMap<String, IpAddr> = HashMap::new();
-                    for host in &unique_hosts {
-                        if let Ok(lookup) = resolver.lookup_ip(host.as_str()).await {
-                            if let Some(addr) = lookup.iter().next(){
-                                host_to_ip.insert(host.clone(), addr);
-                            }
-                        }
-                    }
-                    for (host, addr) in &host_to_ip {
-                        client_builder = client_builder.resolve(
-                            host.as_str(),
-                            SocketAddr::new(*addr, 443),
-                        );
-                    }
-                }
+        self.in_flight
+            .lock()
+            .unwrap()
+            .extend(domains.iter().map(|d| d.id));
+        let links: HashMap<u32, DomainMonitorLink> = self
+            .load_monitor_links()
+            .into_iter()
+            .map(|ds| (ds.domain_id, ds))
+            .collect();
+        // 체크가 켜진 하위 페이지는 소속 도메인과 같은 주기에 함께 체크 (`domain_id`는 도메인 것)
+        let sub_pages = services.sub_pages.monitored(&domains);
+        let sub_page_urls: HashSet<&str> = sub_pages.iter().map(|d| d.url.as_str()).collect();
+        let targets_with_pages: Vec<Domain> = domains
+            .iter()
+            .cloned()
+            .chain(sub_pages.iter().cloned())
+            .collect();
+        let mut attempts = probe_domains(
+            &targets_with_pages,
+            &links,
+            group_service,
+            link_service,
+            proxy_settings_service,
+            services.heartbeats,
+            &sub_page_urls,
+            &limiter,
+            &queue_stats,
+        )
+        .await;
+        let probe_ms = elapsed_ms(started);
+        let now = chrono::Utc::now();
+        let group_ids: HashMap<u32, Vec<u32>> = domains
+            .iter()
+            .map(|d| (d.id, link_service.get_group_ids_for_domain(d.id)))
+            .collect();
+        let marks: HashMap<&str, MaintenanceMark> = targets_with_pages
+            .iter()
+            .filter_map(|d| {
+                let mark = services
+                    .maintenance
+                    .mark_for(d.id, &group_ids[&d.id], now)?;
+                Some((d.url.as_str(), mark))
+            })
+            .collect();
+        for log in attempts.iter_mut().flatten() {
+            log.maintenance = marks.get(log.url.as_str()).cloned();
+        }
+        {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            for d in &domains {
+                in_flight.remove(&d.id);
             }
         }
-
-        let client = client_builder
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
-
-        let tasks: Vec<_> = domains
-            .into_iter()
-            .map(|domain| {
-                let client = client.clone();
-                let group_ids = link_service.get_group_ids_for_domain(domain.id);
-                let group_name = if group_ids.is_empty() {
-                    "Default".to_string()
-                } else {
-                    group_ids
-                        .iter()
-                        .filter_map(|gid| groups.iter().find(|g| g.id == *gid))
-                        .map(|g| g.name.as_str())
-                        .collect::<Vec<_>>()
-                        .join(", ")
+        self.schedule_changed.notify_one();
+        self.observe_latency(&mut attempts, services.latency_baselines);
+        let results: Vec<DomainStatusLog> =
+            attempts.iter().filter_map(|a| a.last().cloned()).collect();
+        self.record_results(&attempts.concat(), &results, domain_service, services);
+        let policies: HashMap<&str, CheckPolicy> = targets_with_pages
+            .iter()
+            .map(|d| {
+                let policy = links.get(&d.id).and_then(|l| l.policy.clone());
+                (d.url.as_str(), policy.unwrap_or_default())
+            })
+            .collect();
+        let changes = self.update_confirmed_states(&results, &policies);
+        let groups = group_service.get_all();
+        // 점검 중인 도메인은 알림 대상에서 뺌 (확정 상태도 갱신하지 않으므로 장애도 열리지 않음)
+        let targets: HashMap<&str, AlertTarget> = targets_with_pages
+            .iter()
+            .filter(|d| !marks.contains_key(d.url.as_str()))
+            .map(|d| {
+                let target = AlertTarget {
+                    domain_id: d.id,
+                    group_ids: group_ids[&d.id].clone(),
+                    groups: group_names(d.id, &groups, link_service),
                 };
-
-                async move {
-                    let start = std::time::Instant::now();
-                    let url = if domain.url.starts_with("http") {
-                        domain.url.clone()
-                    } else {
-                        format!("https://{}", domain.url)
-                    };
-
-                    let response = client.head(&url).send().await;
-                    let latency = start.elapsed().as_millis() as u32;
-
-                    match response {
-                        Ok(resp) => {
-                            let sc = resp.status();
-                            let ok = sc.is_success() || sc.is_redirection();
-                            DomainStatusLog {
-                                url: domain.url.clone(),
-                                status: format!("{sc}"),
-                                level: if ok {
-                                    "info".to_string()
-                                } else if sc.is_client_error() {
-                                    "warning".to_string()
-                                } else {
-                                    "error".to_string()
-                                },
-                                latency,
-                                ok,
-                                group: group_name.clone(),
-                                timestamp: Utc::now(),
-                                error_message: if ok {
-                                    Some("Operation successful".to_string())
-                                } else {
-                                    Some(format!("HTTP Error: {sc}"))
-                                },
-                            }
-                        }
-                        Err(e) => DomainStatusLog {
-                            url: domain.url.clone(),
-                            status: "Request Error".to_string(),
-                            level: "error".to_string(),
-                            latency,
-                            ok: false,
-                            group: group_name,
-                            timestamp: Utc::now(),
-                            error_message: Some(e.to_string()),
-                        },
-                    }
-                }
+                (d.url.as_str(), target)
             })
             .collect();
+        let incidents = if changes.is_empty() {
+            Vec::new()
+        } else {
+            let incident_targets: HashMap<&str, IncidentTarget> = targets
+                .iter()
+                .map(|(url, t)| {
+                    let target = IncidentTarget {
+                        domain_id: t.domain_id,
+                        groups: t.groups.clone(),
+                    };
+                    (*url, target)
+                })
+                .collect();
+            services
+                .incidents
+                .apply_changes(&changes, &incident_targets)
+        };
+        let mut alerts = services
+            .alerts
+            .evaluate_checks(&changes, &results, &incidents, &targets, now);
+        let certs = self
+            .check_certificates_for(
+                &domains,
+                false,
+                proxy_settings_service,
+                services.metrics,
+                &limiter,
+                &queue_stats,
+            )
+            .await;
+        alerts.extend(services.alerts.evaluate_certs(&certs, &targets, now));
+        let audits = self
+            .audit_security_for(
+                &domains,
+                false,
+                proxy_settings_service,
+                services.security_audits,
+                &limiter,
+                &queue_stats,
+            )
+            .await;
+        alerts.extend(services.alerts.evaluate_security(&audits, &targets, now));
+        // 응답 없는 채널의 제한 시간·재시도가 DNS·내용·`OpenAPI` 체크, 롤업, 상태 페이지를 늦추지 않도록 따로 보냄
+        if !alerts.is_empty() {
+            let service = Arc::clone(services.alerts);
+            tokio::spawn(async move {
+                service.dispatch(alerts).await;
+            });
+        }
+        self.check_dns_for(
+            &domains,
+            false,
+            proxy_settings_service,
+            &limiter,
+            &queue_stats,
+        )
+        .await;
+        self.check_content_for(
+            &domains,
+            false,
+            proxy_settings_service,
+            services.content_watch,
+            &limiter,
+            &queue_stats,
+        )
+        .await;
+        self.check_openapi_for(
+            &domains,
+            false,
+            proxy_settings_service,
+            services.openapi_checks,
+            &limiter,
+            &queue_stats,
+        )
+        .await;
+        self.roll_up_previous_day();
+        self.publish_status_page(domain_service, group_service, link_service, services);
+        self.record_cycle(CheckCycleStats {
+            started_at,
+            duration_ms: elapsed_ms(started),
+            probe_ms,
+            domains: u32::try_from(domains.len()).unwrap_or(u32::MAX),
+            targets: u32::try_from(targets_with_pages.len()).unwrap_or(u32::MAX),
+            attempts: queue_stats.attempts.load(Ordering::Relaxed),
+            queued: queue_stats.queued.load(Ordering::Relaxed),
+            max_wait_ms: queue_stats.max_wait_ms.load(Ordering::Relaxed),
+            skipped: self.skipped_pending.swap(0, Ordering::Relaxed),
+        });
+        results
+    }
 
-        let results = futures::future::join_all(tasks).await;
-
-        // Save logs to file
-        let today = Local::now().format("%Y-%m-%d").to_string();
-        let log_file_path = self.base_dir.join(format!("{today}.json"));
-
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&log_file_path)
-        {
-            for result in &results {
-                if let Ok(json) = serde_json::to_string(result) {
-                    let _ = writeln!(file, "{json}");
-                }
-            }
+    fn record_cycle(&self, stats: CheckCycleStats) {
+        let mut cycles = self.cycle_stats.lock().unwrap();
+        cycles.push_back(stats);
+        while cycles.len() > MAX_CYCLE_STATS {
+            cycles.pop_front();
         }
+    }
 
-        let mut last_checks = self.last_checks.lock().unwrap();
-        (*last_checks).clone_from(&results);
+    pub fn get_check_concurrency(&self) -> CheckConcurrency {
+        self.concurrency.lock().unwrap().clone()
+    }
 
-        results
+    /// 동시 실행·분산 설정 저장 (허용 범위로 맞춤). 다음 요청부터 새 제한을 씀
+    pub fn set_check_concurrency(&self, settings: &CheckConcurrency) -> CheckConcurrency {
+        let settings = settings.normalized();
+        save_versioned(&self.concurrency_path, &settings);
+        *self.limiter.lock().unwrap() = Arc::new(CheckLimiter::new(&settings));
+        *self.concurrency.lock().unwrap() = settings.clone();
+        self.schedule_changed.notify_one();
+        settings
     }
 
-    pub fn get_last_status(&self) -> Vec<DomainStatusLog> {
-        self.last_checks.lock().unwrap().clone()
+    /// 최근 체크 주기 통계와 합계 (설정 튜닝용)
+    pub fn get_check_cycle_report(&self) -> CheckCycleReport {
+        let cycles: Vec<CheckCycleStats> = self
+            .cycle_stats
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .cloned()
+            .collect();
+        let total_ms: u64 = cycles.iter().map(|c| u64::from(c.duration_ms)).sum();
+        let avg_duration_ms = if cycles.is_empty() {
+            0
+        } else {
+            u32::try_from(total_ms / cycles.len() as u64).unwrap_or(u32::MAX)
+        };
+        CheckCycleReport {
+            settings: self.get_check_concurrency(),
+            in_flight: u32::try_from(self.in_flight.lock().unwrap().len()).unwrap_or(u32::MAX),
+            avg_duration_ms,
+            max_duration_ms: cycles.iter().map(|c| c.duration_ms).max().unwrap_or(0),
+            total_queued: cycles.iter().map(|c| c.queued).sum(),
+            total_skipped: cycles.iter().map(|c| c.skipped).sum::<u32>()
+                + self.skipped_pending.load(Ordering::Relaxed),
+            cycles,
+        }
     }
 
-    pub fn get_logs_by_date(&self, date: String) -> Vec<DomainStatusLog> {
+    /// 수동 인증서 체크: 체크 활성화된 모든 https 도메인을 간격과 무관하게 점검.
+    pub async fn check_certificates(
+        &self,
+        domain_service: &DomainService,
+        proxy_settings_service: &ProxySettingsService,
+        metrics: &MetricsService,
+    ) -> Vec<CertCheckResult> {
+        let intervals = self.get_check_intervals(domain_service);
+        let domains: Vec<Domain> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| intervals.contains_key(&d.id))
+            .collect();
+        let limiter = self.limiter.lock().unwrap().clone();
+        self.check_certificates_for(
+            &domains,
+            true,
+            proxy_settings_service,
+            metrics,
+            &limiter,
+            &QueueStats::default(),
+        )
+        .await
+    }
+
+    /// https 도메인의 인증서를 점검하고 logs/certs/{date}.json·`last_cert_checks`에 반영.
+    /// `force`가 아니면 마지막 점검 후 `CERT_CHECK_INTERVAL`이 지난 도메인만. TLS 연결마다 `limiter`에서 자리를 받음.
+    async fn check_certificates_for(
+        &self,
+        domains: &[Domain],
+        force: bool,
+        proxy_settings_service: &ProxySettingsService,
+        metrics: &MetricsService,
+        limiter: &CheckLimiter,
+        queue_stats: &QueueStats,
+    ) -> Vec<CertCheckResult> {
+        let configs: HashMap<u32, CertCheckConfig> = self
+            .load_monitor_links()
+            .into_iter()
+            .map(|ds| (ds.domain_id, ds.cert_check.unwrap_or_default()))
+            .collect();
+        let now = chrono::Utc::now();
+        let recent: HashSet<String> = {
+            let last = self.last_cert_checks.lock().unwrap();
+            last.iter()
+                .filter(|r| {
+                    (now - r.timestamp)
+                        .to_std()
+                        .is_ok_and(|age| age < CERT_CHECK_INTERVAL)
+                })
+                .map(|r| r.url.clone())
+                .collect()
+        };
+        let targets: Vec<(&Domain, String, u16, CertCheckConfig)> = domains
+            .iter()
+            .filter(|d| force || !recent.contains(&d.url))
+            .filter_map(|d| {
+                let config = configs.get(&d.id).cloned().unwrap_or_default();
+                if !config.enabled {
+                    return None;
+                }
+                let (host, port) = cert_check::https_target(&d.url)?;
+                Some((d, host, port, config))
+            })
+            .collect();
+        if targets.is_empty() {
+            return Vec::new();
+        }
+
+        let resolved = match proxy_settings_service.get().dns_server {
+            Some(dns) => {
+                let hosts: HashSet<String> =
+                    targets.iter().map(|(_, host, _, _)| host.clone()).collect();
+                resolve_hosts(&dns, &hosts).await
+            }
+            None => HashMap::new(),
+        };
+        let roots = Arc::new(cert_check::default_root_store());
+        let tasks: Vec<_> = targets
+            .iter()
+            .map(|(domain, host, port, config)| {
+                let key = check_limiter::host_key(&domain.url);
+                let inspect = cert_check::inspect_certificate(
+                    &domain.url,
+                    host,
+                    *port,
+                    resolved.get(host).copied(),
+                    roots.clone(),
+                    config,
+                );
+                async move { limiter.run(key.as_deref(), queue_stats, inspect).await }
+            })
+            .collect();
+        let results = futures::future::join_all(tasks).await;
+        self.record_cert_results(&results, metrics);
+        results
+    }
+
+    fn record_cert_results(&self, results: &[CertCheckResult], metrics: &MetricsService) {
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        append_ndjson(
+            &self.base_dir.join("certs").join(format!("{today}.json")),
+            results,
+        );
+        let mut last = self.last_cert_checks.lock().unwrap();
+        last.retain(|r| !results.iter().any(|n| n.url == r.url));
+        last.extend(results.iter().cloned());
+        for r in results {
+            metrics.record_cert(r);
+        }
+    }
+
+    /// 체크 대상인 도메인의 최신 인증서 체크 결과
+    pub fn get_last_cert_checks(
+        &self,
+        domain_service: &DomainService,
+        sub_pages: &SubPageService,
+    ) -> Vec<CertCheckResult> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        self.last_cert_checks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| monitored_urls.contains(&r.url))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_cert_logs_by_date(&self, date: &str) -> Vec<CertCheckResult> {
+        read_ndjson(&self.base_dir.join("certs").join(format!("{date}.json")))
+    }
+
+    /// 수동 보안 점검: `domain_id`가 있으면 그 도메인만, 없으면 점검이 켜진 모든 http(s) 도메인을 간격과 무관하게.
+    pub async fn audit_security(
+        &self,
+        domain_id: Option<u32>,
+        domain_service: &DomainService,
+        proxy_settings_service: &ProxySettingsService,
+        sub_pages: &SubPageService,
+        security_audits: &SecurityAuditService,
+    ) -> Vec<SecurityAuditResult> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        let domains: Vec<Domain> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| monitored_urls.contains(&d.url))
+            .filter(|d| domain_id.is_none_or(|id| d.id == id))
+            .collect();
+        let limiter = self.limiter.lock().unwrap().clone();
+        self.audit_security_for(
+            &domains,
+            true,
+            proxy_settings_service,
+            security_audits,
+            &limiter,
+            &QueueStats::default(),
+        )
+        .await
+    }
+
+    /// http(s) 도메인의 보안 헤더·TLS 설정을 점검해 logs/security/{date}.json에 남김.
+    /// 체크와 같은 요청 방식·추가 헤더로 요청. `force`가 아니면 `interval_hours`가 지난 도메인만.
+    /// 요청·핸드셰이크마다 `limiter`에서 자리를 받고 대기는 `queue_stats`에 집계
+    async fn audit_security_for(
+        &self,
+        domains: &[Domain],
+        force: bool,
+        proxy_settings_service: &ProxySettingsService,
+        security_audits: &SecurityAuditService,
+        limiter: &CheckLimiter,
+        queue_stats: &QueueStats,
+    ) -> Vec<SecurityAuditResult> {
+        let links: HashMap<u32, DomainMonitorLink> = self
+            .load_monitor_links()
+            .into_iter()
+            .map(|ds| (ds.domain_id, ds))
+            .collect();
+        let now = chrono::Utc::now();
+        let targets: Vec<(&Domain, Option<&DomainMonitorLink>)> = domains
+            .iter()
+            .filter(|d| security_audit::audit_urls(&d.url).is_some())
+            .map(|d| (d, links.get(&d.id)))
+            .filter(|(d, link)| {
+                let config = link
+                    .and_then(|l| l.security_audit.clone())
+                    .unwrap_or_default();
+                config.enabled && (force || security_audits.is_due(&d.url, &config, now))
+            })
+            .collect();
+        if targets.is_empty() {
+            return Vec::new();
+        }
+
+        let resolved = match proxy_settings_service.get().dns_server {
+            Some(dns) => {
+                let hosts: HashSet<String> = targets
+                    .iter()
+                    .filter_map(|(d, _)| security_audit::audit_urls(&d.url))
+                    .filter_map(|(https, _)| https.host_str().map(str::to_string))
+                    .collect();
+                resolve_hosts(&dns, &hosts).await
+            }
+            None => HashMap::new(),
+        };
+        let transport = http_transport(
+            proxy_settings_service,
+            Arc::new(cert_check::default_root_store()),
+        );
+        let audits = targets.iter().map(|(domain, link)| {
+            let probe = link.and_then(|l| l.probe.clone()).unwrap_or_default();
+            let check = link.and_then(|l| l.http_check.clone()).unwrap_or_default();
+            let headers = http_probe::request_headers(&check, &probe);
+            let connect_host = security_audit::audit_urls(&domain.url)
+                .and_then(|(https, _)| resolved.get(https.host_str()?).copied());
+            let transport = &transport;
+            async move {
+                match transport.configured(&probe) {
+                    Ok(configured) => {
+                        let transport = configured.as_ref().unwrap_or(transport);
+                        Box::pin(security_audit::audit(
+                            transport,
+                            domain.id,
+                            &domain.url,
+                            &headers,
+                            connect_host,
+                            limiter,
+                            queue_stats,
+                        ))
+                        .await
+                    }
+                    Err(e) => security_audit::build_result(
+                        domain.id,
+                        &domain.url,
+                        Vec::new(),
+                        Vec::new(),
+                        Some(e),
+                    ),
+                }
+            }
+        });
+        let results: Vec<SecurityAuditResult> = futures::future::join_all(audits)
+            .await
+            .into_iter()
+            .map(|r| security_audits.record(r))
+            .collect();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        append_ndjson(
+            &self.base_dir.join("security").join(format!("{today}.json")),
+            &results,
+        );
+        results
+    }
+
+    /// 체크 대상인 도메인의 마지막 보안 점검
+    pub fn get_security_audits(
+        &self,
+        domain_service: &DomainService,
+        sub_pages: &SubPageService,
+        security_audits: &SecurityAuditService,
+    ) -> Vec<SecurityAuditResult> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        domain_service
+            .get_all()
+            .iter()
+            .filter(|d| monitored_urls.contains(&d.url))
+            .filter_map(|d| security_audits.latest(&d.url))
+            .collect()
+    }
+
+    pub fn get_security_audit_logs_by_date(&self, date: &str) -> Vec<SecurityAuditResult> {
+        read_ndjson(&self.base_dir.join("security").join(format!("{date}.json")))
+    }
+
+    /// 수동 `OpenAPI` 체크: `domain_id`가 있으면 그 도메인만, 없으면 체크가 켜진 모든 도메인을 간격과 무관하게.
+    pub async fn check_openapi(
+        &self,
+        domain_id: Option<u32>,
+        domain_service: &DomainService,
+        proxy_settings_service: &ProxySettingsService,
+        sub_pages: &SubPageService,
+        openapi_checks: &OpenApiCheckService,
+    ) -> Vec<OperationCheckResult> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        let domains: Vec<Domain> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| monitored_urls.contains(&d.url))
+            .filter(|d| domain_id.is_none_or(|id| d.id == id))
+            .collect();
+        let limiter = self.limiter.lock().unwrap().clone();
+        self.check_openapi_for(
+            &domains,
+            true,
+            proxy_settings_service,
+            openapi_checks,
+            &limiter,
+            &QueueStats::default(),
+        )
+        .await
+    }
+
+    /// 저장된 스키마의 GET 오퍼레이션을 요청·검증해 오퍼레이션별 결과를 logs/openapi/{date}.json에 남김.
+    /// 체크와 같은 요청 방식·추가 헤더로 요청. `force`가 아니면 `interval_mins`가 지난 도메인만.
+    async fn check_openapi_for(
+        &self,
+        domains: &[Domain],
+        force: bool,
+        proxy_settings_service: &ProxySettingsService,
+        openapi_checks: &OpenApiCheckService,
+        limiter: &CheckLimiter,
+        queue_stats: &QueueStats,
+    ) -> Vec<OperationCheckResult> {
+        let links: HashMap<u32, DomainMonitorLink> = self
+            .load_monitor_links()
+            .into_iter()
+            .map(|ds| (ds.domain_id, ds))
+            .collect();
+        let now = chrono::Utc::now();
+        let targets: Vec<(&Domain, &DomainMonitorLink, OpenApiCheckConfig)> = domains
+            .iter()
+            .filter_map(|d| {
+                let link = links.get(&d.id)?;
+                let config = link.openapi_check.clone().filter(|c| c.enabled)?;
+                let due = force || openapi_checks.is_due(d.id, &config, now);
+                due.then_some((d, link, config))
+            })
+            .collect();
+        if targets.is_empty() {
+            return Vec::new();
+        }
+
+        let transport = http_transport(
+            proxy_settings_service,
+            Arc::new(cert_check::default_root_store()),
+        );
+        let runs = targets.iter().map(|(domain, link, config)| {
+            let probe = link.probe.clone().unwrap_or_default();
+            let check = link.http_check.clone().unwrap_or_default();
+            let headers = http_probe::request_headers(&check, &probe);
+            let schema_path = self.schemas_dir.join(format!("{}.json", domain.id));
+            let transport = &transport;
+            async move {
+                match transport.configured(&probe) {
+                    Ok(configured) => {
+                        let client = openapi_check::OperationClient {
+                            transport,
+                            domain_transport: configured.as_ref().unwrap_or(transport),
+                            domain_headers: &headers,
+                            limiter,
+                            queue_stats,
+                        };
+                        openapi_check::check_domain(
+                            &client,
+                            domain.id,
+                            &domain.url,
+                            &schema_path,
+                            config,
+                        )
+                        .await
+                    }
+                    Err(e) => OpenApiCheckState {
+                        domain_id: domain.id,
+                        last_run: chrono::Utc::now(),
+                        error_message: Some(e),
+                        results: Vec::new(),
+                        skipped: Vec::new(),
+                    },
+                }
+            }
+        });
+        let mut results = Vec::new();
+        for state in futures::future::join_all(runs).await {
+            results.extend(state.results.iter().cloned());
+            openapi_checks.record(state);
+        }
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        append_ndjson(
+            &self.base_dir.join("openapi").join(format!("{today}.json")),
+            &results,
+        );
+        results
+    }
+
+    /// `OpenAPI` 체크가 설정된 도메인의 설정과 마지막 실행 (오퍼레이션별 결과·건너뛴 오퍼레이션)
+    pub fn get_openapi_check_status(
+        &self,
+        domain_service: &DomainService,
+        openapi_checks: &OpenApiCheckService,
+    ) -> Vec<OpenApiCheckStatus> {
+        let links = self.load_monitor_links();
+        domain_service
+            .get_all()
+            .into_iter()
+            .filter_map(|domain| {
+                let config = links
+                    .iter()
+                    .find(|ds| ds.domain_id == domain.id)?
+                    .openapi_check
+                    .clone()?;
+                Some(OpenApiCheckStatus {
+                    domain_id: domain.id,
+                    schema_saved: self
+                        .schemas_dir
+                        .join(format!("{}.json", domain.id))
+                        .exists(),
+                    state: openapi_checks.state(domain.id),
+                    url: domain.url,
+                    config,
+                })
+            })
+            .collect()
+    }
+
+    pub fn get_openapi_check_logs_by_date(&self, date: &str) -> Vec<OperationCheckResult> {
+        read_ndjson(&self.base_dir.join("openapi").join(format!("{date}.json")))
+    }
+
+    /// 수동 DNS 체크: DNS 감시가 켜진 도메인을 간격과 무관하게 조회. 새 스냅샷 반환.
+    pub async fn check_dns(
+        &self,
+        domain_service: &DomainService,
+        proxy_settings_service: &ProxySettingsService,
+        sub_pages: &SubPageService,
+    ) -> Vec<DnsSnapshot> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        let domains: Vec<Domain> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| monitored_urls.contains(&d.url))
+            .collect();
+        let limiter = self.limiter.lock().unwrap().clone();
+        self.check_dns_for(
+            &domains,
+            true,
+            proxy_settings_service,
+            &limiter,
+            &QueueStats::default(),
+        )
+        .await
+    }
+
+    /// DNS 감시가 켜진 도메인을 시스템 리졸버·`ProxySettings.dns_server`·설정된 리졸버로 각각 조회하고,
+    /// 이전 스냅샷과 비교한 이벤트를 logs/dns/{date}.json에 기록.
+    /// `force`가 아니면 마지막 조회 후 `DNS_CHECK_MIN_INTERVAL`이 지난 도메인만.
+    /// 리졸버별 조회도 체크처럼 도메인 호스트로 `limiter`에서 자리를 받음.
+    async fn check_dns_for(
+        &self,
+        domains: &[Domain],
+        force: bool,
+        proxy_settings_service: &ProxySettingsService,
+        limiter: &CheckLimiter,
+        queue_stats: &QueueStats,
+    ) -> Vec<DnsSnapshot> {
+        let configs: HashMap<u32, DnsCheckConfig> = self
+            .load_monitor_links()
+            .into_iter()
+            .filter_map(|ds| ds.dns_check.map(|c| (ds.domain_id, c)))
+            .filter(|(_, c)| c.enabled && !c.record_types.is_empty())
+            .collect();
+        let now = chrono::Utc::now();
+        let recent: HashSet<String> = self
+            .dns_snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| {
+                (now - s.timestamp)
+                    .to_std()
+                    .is_ok_and(|age| age < DNS_CHECK_MIN_INTERVAL)
+            })
+            .map(|s| s.url.clone())
+            .collect();
+        let targets: Vec<(&Domain, String, &DnsCheckConfig)> = domains
+            .iter()
+            .filter(|d| force || !recent.contains(&d.url))
+            .filter_map(|d| {
+                let config = configs.get(&d.id)?;
+                Some((d, host_from_url(&d.url)?, config))
+            })
+            .collect();
+        if targets.is_empty() {
+            return Vec::new();
+        }
+
+        // 리졸버 라벨 → 서버 주소 (None = 시스템)
+        let proxy_dns = proxy_settings_service.get().dns_server;
+        let mut servers: Vec<(String, Option<(IpAddr, u16)>)> =
+            vec![(dns_check::SYSTEM_RESOLVER.to_string(), None)];
+        let configured = proxy_dns
+            .iter()
+            .chain(targets.iter().flat_map(|(_, _, c)| c.resolvers.iter()));
+        for server in configured.filter_map(|s| parse_dns_server(s)) {
+            let label = SocketAddr::new(server.0, server.1).to_string();
+            if !servers.iter().any(|(l, _)| *l == label) {
+                servers.push((label, Some(server)));
+            }
+        }
+        let resolvers: HashMap<String, hickory_resolver::TokioResolver> = servers
+            .into_iter()
+            .filter_map(|(label, server)| {
+                dns_check::build_resolver(server).ok().map(|r| (label, r))
+            })
+            .collect();
+        let proxy_label = proxy_dns
+            .as_deref()
+            .and_then(parse_dns_server)
+            .map(|(ip, port)| SocketAddr::new(ip, port).to_string());
+
+        let tasks: Vec<_> = targets
+            .iter()
+            .map(|(domain, host, config)| {
+                let key = check_limiter::host_key(&domain.url);
+                let extra: HashSet<String> = config
+                    .resolvers
+                    .iter()
+                    .filter_map(|s| parse_dns_server(s))
+                    .map(|(ip, port)| SocketAddr::new(ip, port).to_string())
+                    .collect();
+                let lookups: Vec<_> = resolvers
+                    .iter()
+                    .filter(|(label, _)| {
+                        label.as_str() == dns_check::SYSTEM_RESOLVER
+                            || proxy_label.as_ref() == Some(*label)
+                            || extra.contains(*label)
+                    })
+                    .map(|(label, resolver)| {
+                        dns_check::resolve_snapshot(
+                            &domain.url,
+                            host,
+                            label,
+                            resolver,
+                            &config.record_types,
+                        )
+                    })
+                    .collect();
+                async move {
+                    let key = key.as_deref();
+                    let limited = lookups
+                        .into_iter()
+                        .map(|lookup| limiter.run(key, queue_stats, lookup));
+                    futures::future::join_all(limited).await
+                }
+            })
+            .collect();
+        let per_domain = futures::future::join_all(tasks).await;
+
+        let mut events: Vec<DnsEvent> = Vec::new();
+        let mut fresh: Vec<DnsSnapshot> = Vec::new();
+        {
+            let mut stored = self.dns_snapshots.lock().unwrap();
+            for mut current in per_domain {
+                let Some(url) = current.first().map(|s| s.url.clone()) else {
+                    continue;
+                };
+                current.sort_by(|a, b| a.resolver.cmp(&b.resolver));
+                let previous: Vec<DnsSnapshot> =
+                    stored.iter().filter(|s| s.url == url).cloned().collect();
+                for snap in &mut current {
+                    let prev = previous.iter().find(|p| p.resolver == snap.resolver);
+                    dns_check::carry_over_errors(prev, snap);
+                }
+                events.extend(dns_check::diff_snapshots(&previous, &current, now));
+                stored.retain(|s| s.url != url);
+                stored.extend(current.iter().cloned());
+                fresh.extend(current);
+            }
+            let path = dns_snapshots_path(&self.base_dir);
+            if let Some(parent) = path.parent() {
+                let _ = create_dir_all(parent);
+            }
+            if let Ok(json) = serde_json::to_string_pretty(&*stored) {
+                let _ = std::fs::write(path, json);
+            }
+        }
+        if !events.is_empty() {
+            let today = Local::now().format("%Y-%m-%d").to_string();
+            append_ndjson(
+                &self.base_dir.join("dns").join(format!("{today}.json")),
+                &events,
+            );
+        }
+        fresh
+    }
+
+    /// DNS 감시 중인 도메인의 최신 스냅샷 (리졸버별)
+    pub fn get_dns_snapshots(
+        &self,
+        domain_service: &DomainService,
+        sub_pages: &SubPageService,
+    ) -> Vec<DnsSnapshot> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        let dns_ids: HashSet<u32> = self
+            .load_monitor_links()
+            .into_iter()
+            .filter(|ds| ds.dns_check.as_ref().is_some_and(|c| c.enabled))
+            .map(|ds| ds.domain_id)
+            .collect();
+        let urls: HashSet<String> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| dns_ids.contains(&d.id) && monitored_urls.contains(&d.url))
+            .map(|d| d.url)
+            .collect();
+        self.dns_snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| urls.contains(&s.url))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_dns_events_by_date(&self, date: &str) -> Vec<DnsEvent> {
+        read_ndjson(&self.base_dir.join("dns").join(format!("{date}.json")))
+    }
+
+    /// 수동 내용 감시: 감시가 켜진 모든 도메인을 간격과 무관하게 조회.
+    pub async fn check_content(
+        &self,
+        domain_service: &DomainService,
+        proxy_settings_service: &ProxySettingsService,
+        sub_pages: &SubPageService,
+        content_watch: &ContentWatchService,
+    ) -> Vec<ContentChange> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        let domains: Vec<Domain> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| monitored_urls.contains(&d.url))
+            .collect();
+        let limiter = self.limiter.lock().unwrap().clone();
+        self.check_content_for(
+            &domains,
+            true,
+            proxy_settings_service,
+            content_watch,
+            &limiter,
+            &QueueStats::default(),
+        )
+        .await
+    }
+
+    /// 내용 감시가 켜진 http(s) 도메인을 GET으로 받아 정규화하고, 해시가 바뀌면 스냅샷과
+    /// logs/content/{date}.json 변경 기록을 남김. `force`가 아니면 `CONTENT_CHECK_MIN_INTERVAL`이 지난 도메인만.
+    /// 요청 대기는 주기의 `queue_stats`에 함께 집계.
+    async fn check_content_for(
+        &self,
+        domains: &[Domain],
+        force: bool,
+        proxy_settings_service: &ProxySettingsService,
+        content_watch: &ContentWatchService,
+        limiter: &CheckLimiter,
+        queue_stats: &QueueStats,
+    ) -> Vec<ContentChange> {
+        let configs: HashMap<u32, ContentWatchConfig> = self
+            .load_monitor_links()
+            .into_iter()
+            .filter_map(|ds| ds.content_watch.map(|c| (ds.domain_id, c)))
+            .filter(|(_, c)| c.enabled)
+            .collect();
+        let now = chrono::Utc::now();
+        let targets: Vec<(&Domain, &ContentWatchConfig)> = domains
+            .iter()
+            .filter(|d| ProbeKind::of(&d.url) == ProbeKind::Http)
+            .filter(|d| force || content_watch.is_due(&d.url, now))
+            .filter_map(|d| configs.get(&d.id).map(|c| (d, c)))
+            .collect();
+        if targets.is_empty() {
+            return Vec::new();
+        }
+        let transport = http_transport(
+            proxy_settings_service,
+            Arc::new(cert_check::default_root_store()),
+        );
+        let fetches = targets.iter().map(|(domain, config)| {
+            let url = if domain.url.starts_with("http") {
+                domain.url.clone()
+            } else {
+                format!("https://{}", domain.url)
+            };
+            let host = check_limiter::host_key(&url);
+            let transport = &transport;
+            async move {
+                let fetch = content_watch::fetch_content(transport, &url, config);
+                limiter.run(host.as_deref(), queue_stats, fetch).await
+            }
+        });
+        let results = futures::future::join_all(fetches).await;
+        let changes: Vec<ContentChange> = targets
+            .iter()
+            .zip(results)
+            .filter_map(|((domain, _), result)| content_watch.record(&domain.url, result, now))
+            .filter(|c| c.previous_hash.is_some())
+            .collect();
+        if !changes.is_empty() {
+            let today = Local::now().format("%Y-%m-%d").to_string();
+            append_ndjson(
+                &self.base_dir.join("content").join(format!("{today}.json")),
+                &changes,
+            );
+        }
+        changes
+    }
+
+    /// 내용 감시 설정이 있는 도메인의 상태와 보관 중인 스냅샷
+    pub fn get_content_watch_status(
+        &self,
+        domain_service: &DomainService,
+        content_watch: &ContentWatchService,
+    ) -> Vec<ContentWatchStatus> {
+        let domains = domain_service.get_all();
+        self.load_monitor_links()
+            .into_iter()
+            .filter_map(|ds| {
+                let config = ds.content_watch?;
+                let domain = domains.iter().find(|d| d.id == ds.domain_id)?;
+                Some(ContentWatchStatus {
+                    domain_id: domain.id,
+                    url: domain.url.clone(),
+                    state: content_watch.state(&domain.url),
+                    snapshots: content_watch.snapshot_infos(&domain.url),
+                    config,
+                })
+            })
+            .collect()
+    }
+
+    pub fn get_content_changes_by_date(&self, date: &str) -> Vec<ContentChange> {
+        read_ndjson(&self.base_dir.join("content").join(format!("{date}.json")))
+    }
+
+    /// 도메인의 두 스냅샷 diff. 해시를 생략하면 직전 → 최신
+    pub fn get_content_diff(
+        domain_id: u32,
+        from: Option<&str>,
+        to: Option<&str>,
+        domain_service: &DomainService,
+        content_watch: &ContentWatchService,
+    ) -> Result<ContentDiff, String> {
+        let domain = domain_service
+            .get_all()
+            .into_iter()
+            .find(|d| d.id == domain_id)
+            .ok_or_else(|| format!("도메인 ID {domain_id} 을(를) 찾을 수 없습니다."))?;
+        content_watch.diff(&domain.url, from, to)
+    }
+
+    /// 도메인을 크롤링해 하위 페이지를 갱신하고 결과를 `logs/crawl/{domain_id}.json`에 저장
+    pub async fn crawl_domain(
+        &self,
+        domain_id: u32,
+        options: CrawlOptions,
+        domain_service: &DomainService,
+        proxy_settings_service: &ProxySettingsService,
+        sub_pages: &SubPageService,
+    ) -> Result<CrawlReport, String> {
+        let domain = domain_service
+            .get_all()
+            .into_iter()
+            .find(|d| d.id == domain_id)
+            .ok_or_else(|| format!("도메인 ID {domain_id} 을(를) 찾을 수 없습니다."))?;
+        if ProbeKind::of(&domain.url) != ProbeKind::Http {
+            return Err(format!(
+                "http(s) 도메인만 크롤링할 수 있습니다: {}",
+                domain.url
+            ));
+        }
+        let transport = http_transport(
+            proxy_settings_service,
+            Arc::new(cert_check::default_root_store()),
+        );
+        let root_url = crawler::root_url(&domain.url);
+        let started_at = chrono::Utc::now();
+        let outcome = crawler::crawl(&transport, &root_url, &options).await?;
+        let finished_at = chrono::Utc::now();
+        let pages_added = sub_pages.merge(domain_id, &outcome.pages, finished_at);
+        let report = CrawlReport {
+            domain_id,
+            root_url,
+            options,
+            started_at,
+            finished_at,
+            pages_crawled: outcome.pages_crawled,
+            pages_added,
+            sitemap_urls: outcome.sitemap_urls,
+            disallowed: outcome.disallowed,
+            truncated: outcome.truncated,
+            broken_links: outcome.broken_links,
+        };
+        sub_pages.save_report(&report);
+        Ok(report)
+    }
+
+    /// 도메인의 하위 페이지 트리. 도메인이 없으면 빈 목록
+    pub fn get_sub_page_tree(
+        domain_id: u32,
+        domain_service: &DomainService,
+        sub_pages: &SubPageService,
+    ) -> Vec<SubPageNode> {
+        domain_service
+            .get_all()
+            .into_iter()
+            .find(|d| d.id == domain_id)
+            .map(|domain| sub_pages.tree(&domain))
+            .unwrap_or_default()
+    }
+
+    /// 체크 활성화된 도메인과 그 도메인의 체크가 켜진 하위 페이지 url
+    fn monitored_urls(
+        &self,
+        domain_service: &DomainService,
+        sub_pages: &SubPageService,
+    ) -> HashSet<String> {
+        let intervals = self.get_check_intervals(domain_service);
+        let domains: Vec<Domain> = domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| intervals.contains_key(&d.id))
+            .collect();
+        let pages = sub_pages.monitored(&domains);
+        domains.into_iter().chain(pages).map(|d| d.url).collect()
+    }
+
+    /// 모든 시도(`attempts`)를 당일 로그 파일에 추가하고 최종 결과(`results`)를 `last_checks`에 url 기준으로 병합.
+    /// 체크 대상에서 빠진(비활성·삭제) 도메인의 최신 상태는 제거.
+    fn record_results(
+        &self,
+        attempts: &[DomainStatusLog],
+        results: &[DomainStatusLog],
+        domain_service: &DomainService,
+        services: &MonitorServices<'_>,
+    ) {
+        // Save logs to file
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        append_ndjson(&self.base_dir.join(format!("{today}.json")), attempts);
+
+        let monitored_urls = self.monitored_urls(domain_service, services.sub_pages);
+        let mut last_checks = self.last_checks.lock().unwrap();
+        last_checks.retain(|log| {
+            monitored_urls.contains(&log.url) && !results.iter().any(|r| r.url == log.url)
+        });
+        last_checks.extend(results.iter().cloned());
+        for r in results {
+            services.metrics.record_check(r);
+        }
+    }
+
+    /// 최종 결과로 url별 확정 상태를 갱신. 상태가 바뀌면 logs/state/{date}.json에 기록하고 반환.
+    fn update_confirmed_states(
+        &self,
+        results: &[DomainStatusLog],
+        policies: &HashMap<&str, CheckPolicy>,
+    ) -> Vec<StateChange> {
+        let now = chrono::Utc::now();
+        let mut changes = Vec::new();
+        let default_policy = CheckPolicy::default();
+        let mut states = self.confirmed_states.lock().unwrap();
+        for result in results {
+            // 점검 중 결과로는 상태를 바꾸지 않음. 점검이 끝난 뒤의 체크부터 다시 확정
+            if result.maintenance.is_some() {
+                continue;
+            }
+            let policy = policies.get(result.url.as_str()).unwrap_or(&default_policy);
+            let index = if let Some(i) = states.iter().position(|s| s.url == result.url) {
+                i
+            } else {
+                states.push(ConfirmedState::new(&result.url));
+                states.len() - 1
+            };
+            if let Some(change) =
+                confirmation::apply_result(&mut states[index], result, policy, now)
+            {
+                changes.push(change);
+            }
+        }
+        let path = confirmed_states_path(&self.base_dir);
+        if let Some(parent) = path.parent() {
+            let _ = create_dir_all(parent);
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&*states) {
+            let _ = std::fs::write(path, json);
+        }
+        if !changes.is_empty() {
+            let today = Local::now().format("%Y-%m-%d").to_string();
+            append_ndjson(
+                &self.base_dir.join("state").join(format!("{today}.json")),
+                &changes,
+            );
+        }
+        changes
+    }
+
+    /// 지금 점검 중인 도메인
+    pub fn get_active_maintenance(
+        domain_service: &DomainService,
+        link_service: &DomainGroupLinkService,
+        maintenance: &MaintenanceService,
+    ) -> Vec<ActiveMaintenance> {
+        let domains: Vec<(u32, Vec<u32>)> = domain_service
+            .get_all()
+            .into_iter()
+            .map(|d| (d.id, link_service.get_group_ids_for_domain(d.id)))
+            .collect();
+        maintenance.active(&domains, chrono::Utc::now())
+    }
+
+    /// 체크 활성화된 도메인의 확정 상태 (up/down·연속 횟수·flapping)
+    pub fn get_confirmed_states(
+        &self,
+        domain_service: &DomainService,
+        sub_pages: &SubPageService,
+    ) -> Vec<ConfirmedState> {
+        let monitored_urls = self.monitored_urls(domain_service, sub_pages);
+        self.confirmed_states
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| monitored_urls.contains(&s.url))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_state_changes_by_date(&self, date: &str) -> Vec<StateChange> {
+        read_ndjson(&self.base_dir.join("state").join(format!("{date}.json")))
+    }
+
+    /// 날짜가 바뀐 뒤 첫 체크에서 전날의 시간·일 롤업 파일을 만듦 (더 이전 날짜는 조회 시 필요하면 생성).
+    fn roll_up_previous_day(&self) {
+        let today = Local::now().date_naive();
+        let mut rolled_up = self.rolled_up_date.lock().unwrap();
+        if *rolled_up == Some(today) {
+            return;
+        }
+        if let Some(yesterday) = today.pred_opt() {
+            self.daily_rollups(yesterday, today);
+        }
+        *rolled_up = Some(today);
+    }
+
+    /// 최종 시도를 지연 시간 기준선으로 점수화 (크게 느리면 `degraded`). 기준선이 없는 url은 최근 로그로 먼저 만듦
+    fn observe_latency(
+        &self,
+        attempts: &mut [Vec<DomainStatusLog>],
+        latency_baselines: &LatencyBaselineService,
+    ) {
+        let urls: Vec<&str> = attempts
+            .iter()
+            .filter_map(|a| a.last())
+            .map(|l| l.url.as_str())
+            .collect();
+        let missing = latency_baselines.missing_urls(&urls);
+        if !missing.is_empty() {
+            let today = Local::now().date_naive();
+            let history: Vec<DomainStatusLog> = today
+                .checked_sub_days(chrono::Days::new(BASELINE_SEED_DAYS))
+                .into_iter()
+                .flat_map(|from| from.iter_days().take_while(move |d| *d <= today))
+                .flat_map(|date| self.final_logs(date))
+                .filter(|l| missing.contains(&l.url))
+                .collect();
+            latency_baselines.seed(&missing, &history);
+        }
+        latency_baselines.observe(attempts.iter_mut().filter_map(|a| a.last_mut()));
+    }
+
+    /// 날짜 하루의 최종 시도 로그 (원본 NDJSON)
+    fn final_logs(&self, date: NaiveDate) -> Vec<DomainStatusLog> {
+        uptime_rollup::final_attempts(self.get_logs_by_date(date.format("%Y-%m-%d").to_string()))
+    }
+
+    /// 날짜 하루의 시간 단위 롤업. 지난 날짜는 logs/rollups/hourly/{date}.json을 쓰고 없으면 원본에서 만들어 저장.
+    /// 오늘(아직 기록 중)은 매번 원본에서 계산.
+    fn hourly_rollups(&self, date: NaiveDate, today: NaiveDate) -> Vec<RollupBucket> {
+        if date >= today {
+            return uptime_rollup::roll_up(&self.final_logs(date), uptime_rollup::hour_start);
+        }
+        let path = self
+            .base_dir
+            .join("rollups")
+            .join("hourly")
+            .join(format!("{}.json", date.format("%Y-%m-%d")));
+        if path.exists() {
+            return read_json_or_default(&path);
+        }
+        let logs = self.final_logs(date);
+        let buckets = uptime_rollup::roll_up(&logs, uptime_rollup::hour_start);
+        if !logs.is_empty() {
+            write_json(&path, &buckets);
+        }
+        buckets
+    }
+
+    /// 날짜 하루의 일 단위 롤업 (url별 1개). 지난 날짜는 logs/rollups/daily/{YYYY-MM}.json에 누적 저장.
+    fn daily_rollups(&self, date: NaiveDate, today: NaiveDate) -> Vec<RollupBucket> {
+        let start = uptime_rollup::local_day_start(date);
+        if date >= today {
+            return uptime_rollup::combine(&self.hourly_rollups(date, today), start);
+        }
+        let path = self
+            .base_dir
+            .join("rollups")
+            .join("daily")
+            .join(format!("{}.json", date.format("%Y-%m")));
+        let mut month: Vec<RollupBucket> = read_json_or_default(&path);
+        let stored: Vec<RollupBucket> =
+            month.iter().filter(|b| b.start == start).cloned().collect();
+        if !stored.is_empty() {
+            return stored;
+        }
+        let buckets = uptime_rollup::combine(&self.hourly_rollups(date, today), start);
+        if !buckets.is_empty() {
+            month.extend(buckets.iter().cloned());
+            month.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.url.cmp(&b.url)));
+            write_json(&path, &month);
+        }
+        buckets
+    }
+
+    /// 조회 대상 url. `domain_id`가 있으면 그 도메인, `group_id`가 있으면 그룹 소속 도메인, 둘 다 없으면 전체.
+    pub fn uptime_urls(
+        domain_id: Option<u32>,
+        group_id: Option<u32>,
+        domain_service: &DomainService,
+        link_service: &DomainGroupLinkService,
+    ) -> Vec<String> {
+        let group_domain_ids = group_id.map(|gid| link_service.get_domain_ids_for_group(gid));
+        domain_service
+            .get_all()
+            .into_iter()
+            .filter(|d| domain_id.is_none_or(|id| d.id == id))
+            .filter(|d| {
+                group_domain_ids
+                    .as_ref()
+                    .is_none_or(|ids| ids.contains(&d.id))
+            })
+            .map(|d| d.url)
+            .collect()
+    }
+
+    /// `urls`의 가용성·지연 시간 백분위수와 차트용 시계열. `from`·`to`는 로컬 날짜 (양끝 포함).
+    /// 시간·일 단위는 롤업 파일을 쓰고, 분 단위는 원본 로그에서 계산 (최대 `MAX_MINUTE_RANGE_DAYS`일).
+    pub fn get_uptime_report(
+        &self,
+        urls: Vec<String>,
+        from: &str,
+        to: &str,
+        bucket: BucketSize,
+    ) -> Result<UptimeReport, String> {
+        let (start, end) = (parse_date(from)?, parse_date(to)?);
+        if start > end {
+            return Err(format!("시작 날짜({from})가 종료 날짜({to})보다 늦습니다."));
+        }
+        let days = (end - start).num_days() + 1;
+        if bucket == BucketSize::Minute && days > uptime_rollup::MAX_MINUTE_RANGE_DAYS {
+            return Err(format!(
+                "분 단위 조회는 최대 {}일까지 가능합니다.",
+                uptime_rollup::MAX_MINUTE_RANGE_DAYS
+            ));
+        }
+        let today = Local::now().date_naive();
+        let wanted: HashSet<&str> = urls.iter().map(String::as_str).collect();
+        let mut buckets = Vec::new();
+        for date in start.iter_days().take(usize::try_from(days).unwrap_or(0)) {
+            let day = match bucket {
+                BucketSize::Minute => {
+                    let logs: Vec<DomainStatusLog> = self
+                        .final_logs(date)
+                        .into_iter()
+                        .filter(|l| wanted.contains(l.url.as_str()))
+                        .collect();
+                    uptime_rollup::roll_up(&logs, uptime_rollup::minute_start)
+                }
+                BucketSize::Hour => self.hourly_rollups(date, today),
+                BucketSize::Day => self.daily_rollups(date, today),
+            };
+            buckets.extend(day.into_iter().filter(|b| wanted.contains(b.url.as_str())));
+        }
+        Ok(UptimeReport {
+            from: from.to_string(),
+            to: to.to_string(),
+            bucket,
+            summary: uptime_rollup::stats(&buckets),
+            points: uptime_rollup::points(&buckets),
+            urls,
+        })
+    }
+
+    /// 상태 페이지 내용 (최근 `STATUS_PAGE_DAYS`일). 꺼져 있어도 미리보기용으로 만듦.
+    pub fn build_status_page(
+        &self,
+        domain_service: &DomainService,
+        group_service: &DomainGroupService,
+        link_service: &DomainGroupLinkService,
+        services: &MonitorServices<'_>,
+    ) -> StatusPage {
+        let config = services.status_page.get_config();
+        let today = Local::now().date_naive();
+        let first = today - chrono::Days::new(status_page::STATUS_PAGE_DAYS - 1);
+        let days: Vec<NaiveDate> = first.iter_days().take_while(|d| *d <= today).collect();
+        let mut daily = {
+            let mut history = self.status_history.lock().unwrap();
+            if history.as_ref().is_none_or(|(date, _)| *date != today) {
+                let past = days
+                    .iter()
+                    .filter(|d| **d < today)
+                    .flat_map(|d| self.daily_rollups(*d, today))
+                    .collect();
+                *history = Some((today, past));
+            }
+            history.as_ref().map(|(_, b)| b.clone()).unwrap_or_default()
+        };
+        daily.extend(self.daily_rollups(today, today));
+        let groups: Vec<(DomainGroup, Vec<u32>)> = group_service
+            .get_all()
+            .into_iter()
+            .filter(|g| config.group_ids.contains(&g.id))
+            .map(|g| {
+                let ids = link_service.get_domain_ids_for_group(g.id);
+                (g, ids)
+            })
+            .collect();
+        let in_maintenance: HashSet<u32> =
+            Self::get_active_maintenance(domain_service, link_service, services.maintenance)
+                .into_iter()
+                .map(|m| m.domain_id)
+                .collect();
+        let states = self.confirmed_states.lock().unwrap().clone();
+        let inputs = StatusInputs {
+            domains: &domain_service.get_all(),
+            groups: &groups,
+            states: &states,
+            latest: &self.get_last_status(),
+            in_maintenance: &in_maintenance,
+            days: &days,
+            daily: &daily,
+            incidents: &services.incidents.list(None, false),
+        };
+        status_page::build_page(&config, &inputs, chrono::Utc::now())
+    }
+
+    /// 상태 페이지가 켜져 있으면 다시 만들어 폴더에 쓰고 프록시 제공 내용을 바꿈. 체크 주기마다 호출.
+    pub fn publish_status_page(
+        &self,
+        domain_service: &DomainService,
+        group_service: &DomainGroupService,
+        link_service: &DomainGroupLinkService,
+        services: &MonitorServices<'_>,
+    ) {
+        if !services.status_page.get_config().enabled {
+            return;
+        }
+        let page = self.build_status_page(domain_service, group_service, link_service, services);
+        if let Err(e) = services.status_page.publish(page) {
+            tracing::warn!("[status-page] write failed: {e}");
+        }
+    }
+
+    /// `date`(로컬) 하루의 요약 구역: 전체 + 그룹별 가용성과 그날 열려 있던 장애
+    fn digest_sections(
+        &self,
+        date: NaiveDate,
+        domain_service: &DomainService,
+        group_service: &DomainGroupService,
+        link_service: &DomainGroupLinkService,
+        incidents: &IncidentService,
+    ) -> Vec<DigestSection> {
+        let day = date.format("%Y-%m-%d").to_string();
+        let (start, end) = (
+            uptime_rollup::local_day_start(date),
+            uptime_rollup::local_day_start(date + chrono::Days::new(1)),
+        );
+        let incidents: Vec<Incident> = incidents
+            .list(None, false)
+            .into_iter()
+            .filter(|i| i.started_at < end && i.resolved_at.is_none_or(|r| r >= start))
+            .collect();
+        let section = |group: Option<&DomainGroup>| {
+            let urls = Self::uptime_urls(None, group.map(|g| g.id), domain_service, link_service);
+            let stats = self
+                .get_uptime_report(urls.clone(), &day, &day, BucketSize::Day)
+                .map(|r| r.summary)
+                .unwrap_or_default();
+            let mut list: Vec<Incident> = incidents
+                .iter()
+                .filter(|i| urls.contains(&i.url))
+                .cloned()
+                .collect();
+            list.sort_by_key(|i| i.started_at);
+            DigestSection {
+                group_id: group.map(|g| g.id),
+                title: group.map_or_else(|| "All domains".to_string(), |g| g.name.clone()),
+                stats,
+                incidents: list,
+            }
+        };
+        let groups = group_service.get_all();
+        std::iter::once(section(None))
+            .chain(groups.iter().map(|g| section(Some(g))))
+            .collect()
+    }
+
+    /// 발송 시각이 지난 메일 채널마다 전날 요약을 보냄. 백그라운드 루프가 1분마다 호출.
+    pub async fn send_due_digests(
+        &self,
+        domain_service: &DomainService,
+        group_service: &DomainGroupService,
+        link_service: &DomainGroupLinkService,
+        services: &MonitorServices<'_>,
+    ) {
+        let now = Local::now();
+        let due = services.alerts.due_digests(now);
+        if due.is_empty() {
+            return;
+        }
+        let today = now.date_naive();
+        let Some(date) = today.pred_opt() else {
+            return;
+        };
+        let sections = self.digest_sections(
+            date,
+            domain_service,
+            group_service,
+            link_service,
+            services.incidents,
+        );
+        for (channel_id, config) in due {
+            for (address, e) in email_notify::send_digest(&config, date, &sections).await {
+                tracing::warn!("[alert] digest to {address} failed: {e}");
+            }
+            services.alerts.mark_digest_sent(channel_id, today);
+        }
+    }
+
+    pub fn get_last_status(&self) -> Vec<DomainStatusLog> {
+        self.last_checks.lock().unwrap().clone()
+    }
+
+    pub fn get_logs_by_date(&self, date: String) -> Vec<DomainStatusLog> {
         let log_file_path = self.base_dir.join(format!("{date}.json"));
         if !log_file_path.exists() {
             return Vec::new();
@@ -361,3 +2545,635 @@ impl DomainMonitorService {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::check_policy::ConfirmedStatus;
+
+    /// 앱에서는 `app.manage`로 따로 등록되는 서비스들 (앱과 같은 데이터 폴더 구조)
+    struct Services {
+        incidents: IncidentService,
+        alerts: Arc<AlertService>,
+        maintenance: MaintenanceService,
+        heartbeats: HeartbeatService,
+        status_page: StatusPageService,
+        metrics: MetricsService,
+        latency_baselines: LatencyBaselineService,
+        sub_pages: SubPageService,
+        content_watch: ContentWatchService,
+        security_audits: SecurityAuditService,
+        openapi_checks: OpenApiCheckService,
+    }
+
+    impl Services {
+        fn new(dir: &std::path::Path) -> Self {
+            let logs = dir.join("logs");
+            Self {
+                incidents: IncidentService::new(logs.join("incidents.json")),
+                alerts: Arc::new(AlertService::new(
+                    dir.join("alert_settings.json"),
+                    logs.join("alert_history.json"),
+                )),
+                maintenance: MaintenanceService::new(dir.join("maintenance_windows.json")),
+                heartbeats: HeartbeatService::new(logs.join("heartbeats.json")),
+                status_page: StatusPageService::new(
+                    dir.join("status_page.json"),
+                    dir.join("status_page"),
+                ),
+                metrics: MetricsService::new(),
+                latency_baselines: LatencyBaselineService::new(logs.join("latency_baselines.json")),
+                sub_pages: SubPageService::new(
+                    dir.join("sub_pages.json"),
+                    dir.join("sub_page_monitor_links.json"),
+                    logs.join("crawl"),
+                ),
+                content_watch: ContentWatchService::new(logs.join("content")),
+                security_audits: SecurityAuditService::new(logs.join("security")),
+                openapi_checks: OpenApiCheckService::new(logs.join("openapi")),
+            }
+        }
+
+        fn refs(&self) -> MonitorServices<'_> {
+            MonitorServices {
+                incidents: &self.incidents,
+                alerts: &self.alerts,
+                maintenance: &self.maintenance,
+                heartbeats: &self.heartbeats,
+                status_page: &self.status_page,
+                metrics: &self.metrics,
+                latency_baselines: &self.latency_baselines,
+                sub_pages: &self.sub_pages,
+                content_watch: &self.content_watch,
+                security_audits: &self.security_audits,
+                openapi_checks: &self.openapi_checks,
+            }
+        }
+    }
+
+    fn setup(domain_count: u32) -> (tempfile::TempDir, DomainService, DomainMonitorService) {
+        let dir = tempfile::tempdir().unwrap();
+        let domain_service = DomainService::new(dir.path().join("domains.json"));
+        domain_service.add_domains(
+            (1..=domain_count)
+                .map(|i| format!("https://d{i}.example.com"))
+                .collect(),
+        );
+        let monitor_service = DomainMonitorService::new(
+            dir.path().join("logs"),
+            dir.path().join("domain_monitor_links.json"),
+        );
+        let services = Services::new(dir.path());
+        monitor_service.sync_with_domains(&domain_service.get_all(), &services.refs());
+        (dir, domain_service, monitor_service)
+    }
+
+    #[test]
+    fn test_new_domains_are_due_immediately_then_wait_for_interval() {
+        let (_dir, domain_service, svc) = setup(2);
+        let now = Instant::now();
+        assert_eq!(svc.take_due_domain_ids(&domain_service, now), vec![1, 2]);
+        svc.in_flight.lock().unwrap().clear();
+
+        assert!(svc.take_due_domain_ids(&domain_service, now).is_empty());
+        assert_eq!(svc.time_until_next_due(now), Duration::from_mins(1));
+        let later = now + Duration::from_secs(u64::from(DEFAULT_INTERVAL_SECS));
+        assert_eq!(svc.take_due_domain_ids(&domain_service, later), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_each_domain_honors_its_own_interval() {
+        let (_dir, domain_service, svc) = setup(2);
+        svc.set_domain_monitor_interval(&[1], 15);
+        svc.set_domain_monitor_interval(&[2], 600);
+        let now = Instant::now();
+        svc.take_due_domain_ids(&domain_service, now);
+        svc.in_flight.lock().unwrap().clear();
+
+        assert_eq!(
+            svc.take_due_domain_ids(&domain_service, now + Duration::from_secs(15)),
+            vec![1]
+        );
+        svc.in_flight.lock().unwrap().clear();
+        assert_eq!(
+            svc.take_due_domain_ids(&domain_service, now + Duration::from_mins(10)),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_interval_change_applies_without_restart() {
+        let (_dir, domain_service, svc) = setup(1);
+        let now = Instant::now();
+        svc.take_due_domain_ids(&domain_service, now);
+        svc.in_flight.lock().unwrap().clear();
+
+        // 120s → 15s: next check moves up instead of waiting out the old interval
+        assert_eq!(svc.set_domain_monitor_interval(&[1], 15), 15);
+        let due =
+            svc.take_due_domain_ids(&domain_service, Instant::now() + Duration::from_secs(16));
+        assert_eq!(due, vec![1]);
+        assert_eq!(
+            svc.get_domain_monitor_list(&domain_service)[0].interval_secs,
+            15
+        );
+    }
+
+    #[test]
+    fn test_interval_is_clamped_to_minimum() {
+        let (_dir, _domain_service, svc) = setup(1);
+        assert_eq!(svc.set_domain_monitor_interval(&[1], 1), MIN_INTERVAL_SECS);
+    }
+
+    #[test]
+    fn test_disable_and_enable_reschedule() {
+        let (_dir, domain_service, svc) = setup(2);
+        let now = Instant::now();
+        svc.take_due_domain_ids(&domain_service, now);
+        svc.in_flight.lock().unwrap().clear();
+
+        svc.set_domain_monitor_check_enabled(&[2], false);
+        let later = now + Duration::from_secs(u64::from(DEFAULT_INTERVAL_SECS));
+        assert_eq!(svc.take_due_domain_ids(&domain_service, later), vec![1]);
+
+        svc.set_domain_monitor_check_enabled(&[2], true);
+        assert_eq!(
+            svc.take_due_domain_ids(&domain_service, Instant::now()),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn test_in_flight_domain_is_not_taken_twice() {
+        let (_dir, domain_service, svc) = setup(1);
+        svc.set_domain_monitor_interval(&[1], MIN_INTERVAL_SECS);
+        let now = Instant::now();
+        assert_eq!(svc.take_due_domain_ids(&domain_service, now), vec![1]);
+        let later = now + Duration::from_secs(u64::from(MIN_INTERVAL_SECS) * 2);
+        assert!(svc.take_due_domain_ids(&domain_service, later).is_empty());
+        // 진행 중이라 놓친 차례는 한 번만 셈
+        assert!(svc.take_due_domain_ids(&domain_service, later).is_empty());
+        assert_eq!(svc.get_check_cycle_report().total_skipped, 1);
+    }
+
+    #[test]
+    fn test_spread_and_jitter_keep_checks_within_interval() {
+        let (dir, domain_service, svc) = setup(20);
+        let saved = svc.set_check_concurrency(&CheckConcurrency {
+            max_concurrent: 0,
+            jitter_percent: 90,
+            spread: true,
+            ..CheckConcurrency::default()
+        });
+        assert_eq!((saved.max_concurrent, saved.jitter_percent), (1, 50));
+        let reloaded = DomainMonitorService::new(
+            dir.path().join("logs"),
+            dir.path().join("domain_monitor_links.json"),
+        );
+        assert_eq!(reloaded.get_check_concurrency(), saved);
+        svc.set_check_concurrency(&CheckConcurrency {
+            jitter_percent: 20,
+            spread: true,
+            ..CheckConcurrency::default()
+        });
+
+        let now = Instant::now();
+        let interval = Duration::from_secs(u64::from(DEFAULT_INTERVAL_SECS));
+        let first = svc.take_due_domain_ids(&domain_service, now);
+        assert!(first.len() < 10, "spread: not all domains start at once");
+        let rest = svc.take_due_domain_ids(&domain_service, now + interval);
+        assert_eq!(first.len() + rest.len(), 20);
+        let next_due: Vec<Duration> = svc
+            .next_due
+            .lock()
+            .unwrap()
+            .values()
+            .map(|at| at.duration_since(now))
+            .collect();
+        assert!(next_due
+            .iter()
+            .all(|d| *d >= interval * 4 / 5 && *d <= interval * 2 + interval / 5));
+        assert!(next_due.iter().any(|d| *d != next_due[0]));
+    }
+
+    fn past_log(url: &str, level: &str, latency: u32, ts: &str) -> DomainStatusLog {
+        DomainStatusLog {
+            url: url.to_string(),
+            status: "200 OK".to_string(),
+            level: level.to_string(),
+            latency,
+            ok: level == "info",
+            group: "Default".to_string(),
+            timestamp: chrono::DateTime::parse_from_rfc3339(ts).unwrap().to_utc(),
+            error_message: None,
+            failures: Vec::new(),
+            attempt: 1,
+            timing: None,
+            redirects: Vec::new(),
+            maintenance: None,
+        }
+    }
+
+    #[test]
+    fn test_uptime_report_builds_and_reuses_rollup_files() {
+        let (dir, domain_service, svc) = setup(2);
+        let (a, b) = ("https://d1.example.com", "https://d2.example.com");
+        let raw = svc.base_dir.join("2026-01-10.json");
+        let failed = past_log(a, "error", 0, "2026-01-10T12:10:00Z");
+        let retried = DomainStatusLog {
+            attempt: 2,
+            ..failed.clone()
+        };
+        append_ndjson(
+            &raw,
+            &[
+                past_log(a, "info", 100, "2026-01-10T12:00:00Z"),
+                failed,
+                retried,
+                past_log(a, "info", 300, "2026-01-10T13:00:00Z"),
+                past_log(b, "info", 50, "2026-01-10T12:00:00Z"),
+            ],
+        );
+
+        let urls = DomainMonitorService::uptime_urls(
+            Some(1),
+            None,
+            &domain_service,
+            &DomainGroupLinkService::new(dir.path().join("group_links.json")),
+        );
+        assert_eq!(urls, vec![a.to_string()]);
+
+        let hourly = svc
+            .get_uptime_report(urls.clone(), "2026-01-09", "2026-01-11", BucketSize::Hour)
+            .unwrap();
+        assert_eq!(hourly.summary.total_checks, 3);
+        assert_eq!(hourly.summary.up_checks, 2);
+        assert_eq!(hourly.summary.max_latency, Some(300));
+        assert_eq!(hourly.points.len(), 2);
+        assert!(svc.base_dir.join("rollups/hourly/2026-01-10.json").exists());
+
+        // 롤업 파일이 생긴 뒤에는 원본 없이도 같은 결과
+        std::fs::remove_file(&raw).unwrap();
+        let daily = svc
+            .get_uptime_report(urls, "2026-01-01", "2026-01-31", BucketSize::Day)
+            .unwrap();
+        assert!(svc.base_dir.join("rollups/daily/2026-01.json").exists());
+        assert_eq!(daily.points.len(), 1);
+        assert_eq!(daily.summary, hourly.summary);
+
+        assert!(svc
+            .get_uptime_report(vec![], "2026-01-01", "2026-01-31", BucketSize::Minute)
+            .is_err());
+        assert!(svc
+            .get_uptime_report(vec![], "2026-01-02", "2026-01-01", BucketSize::Hour)
+            .is_err());
+    }
+
+    /// 처음 `failures`번은 503, 이후 200을 돌려주는 서버
+    async fn spawn_flaky_server(failures: usize) -> String {
+        use axum::{http::StatusCode, routing::get, Router};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let hits = Arc::new(AtomicUsize::new(0));
+        let app = Router::new().route(
+            "/",
+            get(move || {
+                let hits = hits.clone();
+                async move {
+                    if hits.fetch_add(1, Ordering::SeqCst) < failures {
+                        StatusCode::SERVICE_UNAVAILABLE
+                    } else {
+                        StatusCode::OK
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        format!("http://{addr}/")
+    }
+
+    async fn check_once(
+        dir: &std::path::Path,
+        domain_service: &DomainService,
+        svc: &DomainMonitorService,
+        services: &Services,
+    ) -> Vec<DomainStatusLog> {
+        svc.check_domain_ids(
+            &[1],
+            domain_service,
+            &DomainGroupService::new(dir.join("groups.json")),
+            &DomainGroupLinkService::new(dir.join("group_links.json")),
+            &ProxySettingsService::new(dir.join("proxy_settings.json")),
+            &services.refs(),
+        )
+        .await
+    }
+
+    fn setup_url(
+        url: String,
+    ) -> (
+        tempfile::TempDir,
+        DomainService,
+        DomainMonitorService,
+        Services,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let domain_service = DomainService::new(dir.path().join("domains.json"));
+        domain_service.add_domains(vec![url]);
+        let monitor_service = DomainMonitorService::new(
+            dir.path().join("logs"),
+            dir.path().join("domain_monitor_links.json"),
+        );
+        let services = Services::new(dir.path());
+        monitor_service.sync_with_domains(&domain_service.get_all(), &services.refs());
+        (dir, domain_service, monitor_service, services)
+    }
+
+    #[tokio::test]
+    async fn test_manual_openapi_check_skips_unmonitored_domains() {
+        let (dir, domain_service, svc, services) = setup_url("http://127.0.0.1:9/".to_string());
+        assert!(svc.set_domain_openapi_check(1, Some(OpenApiCheckConfig::default())));
+        svc.set_domain_monitor_check_enabled(&[1], false);
+        let proxy_settings_service =
+            ProxySettingsService::new(dir.path().join("proxy_settings.json"));
+
+        let results = svc
+            .check_openapi(
+                Some(1),
+                &domain_service,
+                &proxy_settings_service,
+                &services.sub_pages,
+                &services.openapi_checks,
+            )
+            .await;
+        assert!(results.is_empty());
+        assert!(services.openapi_checks.state(1).is_none());
+
+        svc.set_domain_monitor_check_enabled(&[1], true);
+        svc.check_openapi(
+            Some(1),
+            &domain_service,
+            &proxy_settings_service,
+            &services.sub_pages,
+            &services.openapi_checks,
+        )
+        .await;
+        // 스키마를 받지 않았으므로 오류만 남음
+        assert!(services
+            .openapi_checks
+            .state(1)
+            .unwrap()
+            .error_message
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success_and_logs_every_attempt() {
+        let (dir, domain_service, svc, services) = setup_url(spawn_flaky_server(2).await);
+        // 큰 재시도 횟수는 상한으로 맞춰 저장
+        let saved = svc
+            .set_domain_check_policy(
+                1,
+                Some(CheckPolicy {
+                    retries: 10_000,
+                    ..CheckPolicy::default()
+                }),
+            )
+            .unwrap();
+        assert_eq!(saved.unwrap().retries, 5);
+        assert!(svc.set_domain_check_policy(99, None).is_err());
+        svc.set_domain_check_policy(
+            1,
+            Some(CheckPolicy {
+                retries: 2,
+                retry_backoff_ms: 10,
+                ..CheckPolicy::default()
+            }),
+        )
+        .unwrap();
+
+        let results = check_once(dir.path(), &domain_service, &svc, &services).await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].ok);
+        assert_eq!(results[0].attempt, 3);
+        assert_eq!(svc.get_last_status()[0].attempt, 3);
+
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let logged: Vec<u32> = svc
+            .get_logs_by_date(today)
+            .iter()
+            .map(|l| l.attempt)
+            .collect();
+        assert_eq!(logged, vec![1, 2, 3]);
+
+        let states = svc.get_confirmed_states(&domain_service, &services.sub_pages);
+        assert_eq!(states[0].status, ConfirmedStatus::Up);
+
+        let report = svc.get_check_cycle_report();
+        assert_eq!(report.cycles.len(), 1);
+        // 프로브 3번 + 보안 점검 (https·http GET, TLS 핸드셰이크 4번)
+        assert_eq!(
+            (report.cycles[0].targets, report.cycles[0].attempts),
+            (1, 9)
+        );
+        // 보안 점검 6개 요청 중 호스트별 상한(4)을 넘는 2개는 대기
+        assert_eq!(report.cycles[0].queued, 2);
+        assert!(report.cycles[0].duration_ms >= report.cycles[0].probe_ms);
+    }
+
+    #[tokio::test]
+    async fn test_down_is_confirmed_after_consecutive_failures() {
+        let (dir, domain_service, svc, services) = setup_url(spawn_flaky_server(usize::MAX).await);
+        svc.set_domain_check_policy(
+            1,
+            Some(CheckPolicy {
+                fail_threshold: 2,
+                ..CheckPolicy::default()
+            }),
+        )
+        .unwrap();
+
+        check_once(dir.path(), &domain_service, &svc, &services).await;
+        assert_eq!(
+            svc.get_confirmed_states(&domain_service, &services.sub_pages)[0].status,
+            ConfirmedStatus::Unknown
+        );
+        check_once(dir.path(), &domain_service, &svc, &services).await;
+        let state = &svc.get_confirmed_states(&domain_service, &services.sub_pages)[0];
+        assert_eq!(state.status, ConfirmedStatus::Down);
+        assert_eq!(state.consecutive_failures, 2);
+
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let changes = svc.get_state_changes_by_date(&today);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].to, ConfirmedStatus::Down);
+        let incidents = services.incidents.list(None, true);
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].domain_id, 1);
+        assert_eq!(incidents[0].groups, vec!["Default".to_string()]);
+        assert!(incidents[0].first_error.contains("503"));
+
+        // 재시작 후에도 확정 상태 유지
+        let reloaded = DomainMonitorService::new(
+            dir.path().join("logs"),
+            dir.path().join("domain_monitor_links.json"),
+        );
+        assert_eq!(
+            reloaded.get_confirmed_states(&domain_service, &services.sub_pages)[0].status,
+            ConfirmedStatus::Down
+        );
+    }
+
+    #[tokio::test]
+    async fn test_slow_alert_channel_does_not_hold_up_the_cycle() {
+        use crate::model::alert::{
+            AlertChannelConfig, AlertConfig, AlertRule, AlertScope, AlertTrigger, ChannelKind,
+        };
+        let (dir, domain_service, svc, services) = setup_url(spawn_flaky_server(usize::MAX).await);
+        // 요청을 받고 응답하지 않는 웹훅
+        let received = Arc::new(AtomicU32::new(0));
+        let hits = received.clone();
+        let app = axum::Router::new().route(
+            "/hook",
+            axum::routing::post(move || {
+                hits.fetch_add(1, Ordering::SeqCst);
+                async {
+                    tokio::time::sleep(Duration::from_secs(30)).await;
+                    "late"
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let webhook = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        services.alerts.set_config(AlertConfig {
+            rules: vec![AlertRule {
+                id: 0,
+                name: "down".to_string(),
+                enabled: true,
+                scope: AlertScope::All,
+                trigger: AlertTrigger::Down,
+                channel_ids: vec![1],
+                cooldown_secs: 600,
+            }],
+            channels: vec![AlertChannelConfig {
+                id: 0,
+                name: "hook".to_string(),
+                enabled: true,
+                kind: ChannelKind::Webhook {
+                    url: webhook,
+                    headers: HashMap::new(),
+                },
+            }],
+        });
+
+        let started = Instant::now();
+        check_once(dir.path(), &domain_service, &svc, &services).await;
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(svc.get_check_cycle_report().cycles.len(), 1);
+        // 전송은 백그라운드에서 계속
+        for _ in 0..50 {
+            if received.load(Ordering::SeqCst) > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert_eq!(received.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_checks_are_tagged_without_incidents() {
+        use crate::model::maintenance::{MaintenanceSchedule, MaintenanceWindow};
+        let (dir, domain_service, svc, services) = setup_url(spawn_flaky_server(usize::MAX).await);
+        let now = chrono::Utc::now();
+        services
+            .maintenance
+            .set_all(vec![MaintenanceWindow {
+                id: 0,
+                name: "deploy".to_string(),
+                enabled: true,
+                domain_ids: vec![1],
+                group_ids: Vec::new(),
+                schedule: MaintenanceSchedule::Once {
+                    start: now - chrono::Duration::minutes(5),
+                    end: now + chrono::Duration::minutes(5),
+                },
+                exclude_from_uptime: true,
+            }])
+            .unwrap();
+
+        for _ in 0..3 {
+            let results = check_once(dir.path(), &domain_service, &svc, &services).await;
+            assert_eq!(results[0].maintenance.as_ref().unwrap().window_id, 1);
+        }
+        // 실패가 이어져도 확정 상태·장애는 그대로, 가용성 집계에서도 빠짐
+        assert!(svc
+            .get_confirmed_states(&domain_service, &services.sub_pages)
+            .is_empty());
+        assert!(services.incidents.list(None, false).is_empty());
+        let url = domain_service.get_all()[0].url.clone();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let report = svc
+            .get_uptime_report(vec![url], &today, &today, BucketSize::Hour)
+            .unwrap();
+        assert_eq!(report.summary.total_checks, 0);
+        let links = DomainGroupLinkService::new(dir.path().join("group_links.json"));
+        let active = DomainMonitorService::get_active_maintenance(
+            &domain_service,
+            &links,
+            &services.maintenance,
+        );
+        assert_eq!(active[0].window_name, "deploy");
+    }
+
+    #[tokio::test]
+    async fn test_enabled_sub_pages_are_checked_with_their_domain() {
+        use crate::model::http_check::CheckAssertion;
+        use crate::model::sub_page::SubPageSource;
+        use crate::service::crawler::CrawledPage;
+        let (dir, domain_service, svc, services) = setup_url(spawn_flaky_server(0).await);
+        // 도메인의 HTTP 체크 옵션은 하위 페이지에 쓰지 않음 (404도 통과시키는 규칙)
+        svc.set_domain_monitor_http_check(
+            1,
+            Some(HttpCheck {
+                assertions: vec![CheckAssertion::StatusIn {
+                    codes: vec![200, 404],
+                }],
+                ..HttpCheck::default()
+            }),
+        );
+        let found = |path: &str| CrawledPage {
+            path: path.to_string(),
+            title: None,
+            status: 200,
+            source: SubPageSource::Sitemap,
+        };
+        services
+            .sub_pages
+            .merge(1, &[found("/gone"), found("/other")], chrono::Utc::now());
+        let gone = services.sub_pages.get_for_domain(1)[0].id;
+        services.sub_pages.set_check_enabled(&[gone], true);
+
+        let results = check_once(dir.path(), &domain_service, &svc, &services).await;
+        let domain_url = domain_service.get_all()[0].url.clone();
+        let gone_url = format!("{domain_url}gone");
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().any(|r| r.url == domain_url && r.ok));
+        // 기본 체크라 404는 warning (도메인 규칙이었다면 info)
+        let sub = results.iter().find(|r| r.url == gone_url).unwrap();
+        assert_eq!(sub.level, "warning");
+        assert_eq!(svc.get_last_status().len(), 2);
+
+        let tree = DomainMonitorService::get_sub_page_tree(1, &domain_service, &services.sub_pages);
+        assert!(tree.iter().any(|n| n.url == gone_url && n.check_enabled));
+
+        // 도메인을 지우면 하위 페이지도 정리
+        svc.sync_with_domains(&[], &services.refs());
+        assert!(services.sub_pages.get_for_domain(1).is_empty());
+    }
+}
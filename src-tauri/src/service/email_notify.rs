@@ -0,0 +1,435 @@
+//! SMTP 메일 알림 채널과 일일 요약(digest).
+//! 즉시 알림은 `NotificationChannel`로 규칙 알림마다 보내고, 요약은 `DomainMonitorService::send_due_digests`가
+//! 설정한 로컬 시각 이후 하루 한 번 전날의 그룹별 가용성·장애를 모아 보낸다.
+
+use crate::model::alert::{AlertEvent, EmailChannelConfig, SmtpSecurity};
+use crate::model::incident::Incident;
+use crate::model::uptime::UptimeStats;
+use crate::service::alert_service::NotificationChannel;
+use crate::service::chat_notify::{
+    facts, format_duration, headline, render_template, DEFAULT_TEMPLATE,
+};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime};
+use futures::future::BoxFuture;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(15);
+const SUBJECT_PREFIX: &str = "[Watchtower]";
+
+fn transport(config: &EmailChannelConfig) -> Result<AsyncSmtpTransport<Tokio1Executor>, String> {
+    let host = config.smtp_host.as_str();
+    let builder = match config.security {
+        SmtpSecurity::StartTls => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host).map_err(|e| e.to_string())?
+        }
+        SmtpSecurity::Tls => {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(host).map_err(|e| e.to_string())?
+        }
+        SmtpSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+    };
+    let mut builder = builder.port(config.smtp_port).timeout(Some(SMTP_TIMEOUT));
+    if let (Some(username), Some(password)) = (&config.username, &config.password) {
+        builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+    Ok(builder.build())
+}
+
+/// 메일 한 통 전송 (수신자는 모두 To)
+pub async fn send_mail(
+    config: &EmailChannelConfig,
+    to: &[String],
+    subject: &str,
+    body: String,
+) -> Result<(), String> {
+    if to.is_empty() {
+        return Err("no recipients".to_string());
+    }
+    let from: Mailbox = config
+        .from
+        .parse()
+        .map_err(|e| format!("invalid from address {}: {e}", config.from))?;
+    let mut builder = Message::builder().from(from).subject(subject);
+    for address in to {
+        let mailbox: Mailbox = address
+            .parse()
+            .map_err(|e| format!("invalid recipient {address}: {e}"))?;
+        builder = builder.to(mailbox);
+    }
+    let message = builder
+        .header(ContentType::TEXT_PLAIN)
+        .body(body)
+        .map_err(|e| e.to_string())?;
+    transport(config)?
+        .send(message)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("SMTP send failed: {e}"))
+}
+
+/// 기본 수신자 + 알림 대상이 속한 그룹의 수신자 (중복 제거, 순서 유지)
+pub fn recipients_for(config: &EmailChannelConfig, group_ids: &[u32]) -> Vec<String> {
+    let mut list: Vec<String> = Vec::new();
+    let group_lists = config
+        .group_recipients
+        .iter()
+        .filter(|g| group_ids.contains(&g.group_id))
+        .flat_map(|g| g.recipients.iter());
+    for address in config.recipients.iter().chain(group_lists) {
+        if !list.iter().any(|a| a.eq_ignore_ascii_case(address)) {
+            list.push(address.clone());
+        }
+    }
+    list
+}
+
+/// 상태 변경 등 규칙 알림을 바로 메일로 보내는 채널
+pub struct EmailChannel {
+    config: EmailChannelConfig,
+}
+
+impl EmailChannel {
+    pub fn new(config: EmailChannelConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn subject(event: &AlertEvent) -> String {
+        format!("{SUBJECT_PREFIX} {}", headline(event))
+    }
+
+    /// 템플릿 본문 + 요약 항목 (한 줄에 하나)
+    pub fn body(&self, event: &AlertEvent) -> String {
+        let template = self
+            .config
+            .template
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or(DEFAULT_TEMPLATE);
+        let mut body = render_template(template, event);
+        body.push_str("\n\n");
+        for (name, value) in facts(event) {
+            let _ = writeln!(body, "{name}: {value}");
+        }
+        let _ = writeln!(body, "URL: {}", event.url);
+        let _ = writeln!(body, "Rule: {}", event.rule_name);
+        body
+    }
+}
+
+impl NotificationChannel for EmailChannel {
+    fn send<'a>(&'a self, event: &'a AlertEvent) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            let to = recipients_for(&self.config, &event.group_ids);
+            send_mail(&self.config, &to, &Self::subject(event), self.body(event)).await
+        })
+    }
+}
+
+/// `HH:MM` (로컬). 형식이 틀리면 `None`
+pub fn parse_digest_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+/// 오늘 발송 시각이 지났고 오늘 아직 보내지 않았으면 발송 대상
+pub fn digest_due(digest_time: &str, last_sent: Option<NaiveDate>, now: DateTime<Local>) -> bool {
+    let Some(at) = parse_digest_time(digest_time) else {
+        return false;
+    };
+    now.time() >= at && last_sent != Some(now.date_naive())
+}
+
+/// 요약 한 구역 (전체 또는 그룹 하나)
+pub struct DigestSection {
+    /// `None`이면 전체 도메인 구역 (기본 수신자만 받음)
+    pub group_id: Option<u32>,
+    pub title: String,
+    pub stats: UptimeStats,
+    /// 그날 열려 있던 장애 (시작순)
+    pub incidents: Vec<Incident>,
+}
+
+fn ms(value: Option<u32>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| format!("{v}ms"))
+}
+
+/// 요약 메일 본문 (plain text)
+pub fn render_digest(date: NaiveDate, sections: &[&DigestSection]) -> String {
+    let mut body = format!("Watchtower daily digest - {date}\n");
+    for section in sections {
+        let stats = &section.stats;
+        let _ = writeln!(body, "\n== {} ==", section.title);
+        let _ = match stats.uptime_percent {
+            Some(percent) => writeln!(
+                body,
+                "Uptime: {percent:.2}% ({}/{} checks)",
+                stats.up_checks, stats.total_checks
+            ),
+            None => writeln!(body, "Uptime: no checks"),
+        };
+        let _ = writeln!(
+            body,
+            "Latency: avg {}, p95 {}, max {}",
+            ms(stats.avg_latency),
+            ms(stats.p95_latency),
+            ms(stats.max_latency)
+        );
+        let open = section.incidents.iter().filter(|i| i.is_open()).count();
+        let downtime: i64 = section
+            .incidents
+            .iter()
+            .filter_map(|i| i.duration_secs)
+            .sum();
+        let _ = writeln!(
+            body,
+            "Incidents: {} (open {open}, downtime {})",
+            section.incidents.len(),
+            format_duration(downtime)
+        );
+        for incident in &section.incidents {
+            let started = incident
+                .started_at
+                .with_timezone(&Local)
+                .format("%m-%d %H:%M");
+            let duration = incident
+                .duration_secs
+                .map_or_else(|| "ongoing".to_string(), format_duration);
+            let _ = writeln!(
+                body,
+                "  - #{} {} {started} ({duration}) {}",
+                incident.id, incident.url, incident.first_error
+            );
+        }
+    }
+    body
+}
+
+/// 수신자별로 받을 구역: 기본 수신자는 전부, 그룹 수신자는 해당 그룹 구역만
+pub fn digest_recipients<'a>(
+    config: &EmailChannelConfig,
+    sections: &'a [DigestSection],
+) -> BTreeMap<String, Vec<&'a DigestSection>> {
+    let mut by_address: BTreeMap<String, Vec<&DigestSection>> = BTreeMap::new();
+    for address in &config.recipients {
+        by_address.insert(address.to_lowercase(), sections.iter().collect());
+    }
+    for group in &config.group_recipients {
+        for address in &group.recipients {
+            let list = by_address.entry(address.to_lowercase()).or_default();
+            for section in sections
+                .iter()
+                .filter(|s| s.group_id == Some(group.group_id))
+            {
+                if !list.iter().any(|s| std::ptr::eq(*s, section)) {
+                    list.push(section);
+                }
+            }
+        }
+    }
+    by_address.retain(|_, list| !list.is_empty());
+    by_address
+}
+
+/// 수신자별 요약 메일 전송. 실패한 수신자와 사유를 반환.
+pub async fn send_digest(
+    config: &EmailChannelConfig,
+    date: NaiveDate,
+    sections: &[DigestSection],
+) -> Vec<(String, String)> {
+    let subject = format!("{SUBJECT_PREFIX} Daily digest {date}");
+    let mut failures = Vec::new();
+    for (address, list) in digest_recipients(config, sections) {
+        let body = render_digest(date, &list);
+        if let Err(e) = send_mail(config, std::slice::from_ref(&address), &subject, body).await {
+            failures.push((address, e));
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::alert::GroupRecipients;
+    use crate::service::alert_service::sample_event;
+    use chrono::{TimeZone, Utc};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    /// 받은 메일 (수신자 목록, DATA 내용)
+    type Inbox = Arc<Mutex<Vec<(Vec<String>, String)>>>;
+
+    /// 최소한의 SMTP 수신 서버 (인증·TLS 없음)
+    async fn spawn_smtp_sink() -> (u16, Inbox) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let inbox: Inbox = Arc::default();
+        let shared = inbox.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let inbox = shared.clone();
+                tokio::spawn(async move {
+                    let (read, mut write) = stream.into_split();
+                    let mut lines = BufReader::new(read).lines();
+                    let _ = write.write_all(b"220 sink ESMTP\r\n").await;
+                    let mut rcpt = Vec::new();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        let upper = line.to_uppercase();
+                        let reply: &[u8] = if upper.starts_with("EHLO") || upper.starts_with("HELO")
+                        {
+                            b"250 sink\r\n"
+                        } else if upper.starts_with("RCPT TO:") {
+                            rcpt.push(line[8..].trim().trim_matches(['<', '>']).to_string());
+                            b"250 OK\r\n"
+                        } else if upper.starts_with("DATA") {
+                            let _ = write.write_all(b"354 go ahead\r\n").await;
+                            let mut data = String::new();
+                            while let Ok(Some(l)) = lines.next_line().await {
+                                if l == "." {
+                                    break;
+                                }
+                                data.push_str(&l);
+                                data.push('\n');
+                            }
+                            inbox
+                                .lock()
+                                .unwrap()
+                                .push((std::mem::take(&mut rcpt), data));
+                            b"250 queued\r\n"
+                        } else if upper.starts_with("QUIT") {
+                            let _ = write.write_all(b"221 bye\r\n").await;
+                            break;
+                        } else {
+                            b"250 OK\r\n"
+                        };
+                        let _ = write.write_all(reply).await;
+                    }
+                });
+            }
+        });
+        (port, inbox)
+    }
+
+    fn config(port: u16) -> EmailChannelConfig {
+        EmailChannelConfig {
+            smtp_host: "127.0.0.1".to_string(),
+            smtp_port: port,
+            security: SmtpSecurity::None,
+            username: None,
+            password: None,
+            from: "Watchtower <alerts@example.com>".to_string(),
+            recipients: vec!["ops@example.com".to_string()],
+            group_recipients: vec![GroupRecipients {
+                group_id: 7,
+                recipients: vec![
+                    "prod-team@example.com".to_string(),
+                    "OPS@example.com".to_string(),
+                ],
+            }],
+            immediate: true,
+            digest_time: Some("08:30".to_string()),
+            template: Some("{url} is {trigger} ({status})".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_immediate_alert_goes_to_group_recipients() {
+        let (port, inbox) = spawn_smtp_sink().await;
+        let mut event = sample_event(Utc::now());
+        event.group_ids = vec![7];
+        let channel = EmailChannel::new(config(port));
+        channel.send(&event).await.unwrap();
+
+        let inbox = inbox.lock().unwrap();
+        assert_eq!(inbox.len(), 1);
+        let (rcpt, data) = &inbox[0];
+        assert_eq!(
+            rcpt,
+            &vec![
+                "ops@example.com".to_string(),
+                "prod-team@example.com".to_string()
+            ]
+        );
+        assert!(data.contains("Subject: [Watchtower] [DOWN] https://example.com"));
+        assert!(data.contains("https://example.com is DOWN (503 Service Unavailable)"));
+        assert!(data.contains("Latency: 1234ms"));
+    }
+
+    #[tokio::test]
+    async fn test_digest_sections_per_recipient() {
+        let (port, inbox) = spawn_smtp_sink().await;
+        let stats = UptimeStats {
+            total_checks: 100,
+            up_checks: 99,
+            uptime_percent: Some(99.0),
+            avg_latency: Some(120),
+            p95_latency: Some(300),
+            max_latency: Some(900),
+            ..Default::default()
+        };
+        let incident = Incident {
+            id: 3,
+            domain_id: 1,
+            url: "https://a.example.com".to_string(),
+            groups: vec!["Prod".to_string()],
+            started_at: Utc::now(),
+            first_error: "503 Service Unavailable".to_string(),
+            resolved_at: Some(Utc::now()),
+            duration_secs: Some(120),
+            acknowledged_at: None,
+            notes: Vec::new(),
+        };
+        let sections = vec![
+            DigestSection {
+                group_id: None,
+                title: "All domains".to_string(),
+                stats: stats.clone(),
+                incidents: vec![incident.clone()],
+            },
+            DigestSection {
+                group_id: Some(7),
+                title: "Prod".to_string(),
+                stats,
+                incidents: vec![incident],
+            },
+        ];
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let failures = send_digest(&config(port), date, &sections).await;
+        assert!(failures.is_empty(), "{failures:?}");
+
+        let inbox = inbox.lock().unwrap();
+        assert_eq!(inbox.len(), 2);
+        let ops = inbox
+            .iter()
+            .find(|(r, _)| r[0] == "ops@example.com")
+            .unwrap();
+        assert!(ops.1.contains("== All domains =="));
+        assert!(ops.1.contains("== Prod =="));
+        let team = inbox
+            .iter()
+            .find(|(r, _)| r[0] == "prod-team@example.com")
+            .unwrap();
+        assert!(!team.1.contains("== All domains =="));
+        assert!(team.1.contains("Uptime: 99.00% (99/100 checks)"));
+        assert!(team.1.contains("Incidents: 1 (open 0, downtime 2m 0s)"));
+        assert!(team
+            .1
+            .contains("Subject: [Watchtower] Daily digest 2026-10-17"));
+    }
+
+    #[test]
+    fn test_digest_due_once_per_day_after_time() {
+        let at = |h, m| Local.with_ymd_and_hms(2026, 10, 18, h, m, 0).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18);
+        let yesterday = NaiveDate::from_ymd_opt(2026, 10, 17);
+        assert!(!digest_due("08:30", yesterday, at(8, 29)));
+        assert!(digest_due("08:30", yesterday, at(8, 30)));
+        assert!(digest_due("08:30", None, at(23, 0)));
+        assert!(!digest_due("08:30", today, at(9, 0)));
+        assert!(!digest_due("8h", None, at(9, 0)));
+    }
+}
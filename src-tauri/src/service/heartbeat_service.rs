@@ -0,0 +1,278 @@
+//! 푸시 방식 하트비트 감시 (`heartbeat://` 대상).
+//! 크론 작업 등이 로컬 프록시의 `/.watchtower/heartbeat/{token}[/start|/success|/fail]`를 호출하면 수신 상태를 남기고,
+//! 모니터 체크는 네트워크 요청 대신 이 상태로 `DomainStatusLog`를 만든다 (주기 + 여유 안에 핑이 없으면 error).
+
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::heartbeat::{HeartbeatCheck, HeartbeatPing, HeartbeatRun, HeartbeatState};
+use crate::service::chat_notify::format_duration;
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Duration, Utc};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// 프록시 예약 경로 중 핑 경로
+pub const HEARTBEAT_PATH_PREFIX: &str = "/.watchtower/heartbeat/";
+
+/// 토큰별로 보관하는 최근 실행 수
+pub const MAX_HEARTBEAT_RUNS: usize = 50;
+
+/// 새 핑 토큰 (추측하기 어려운 32자 hex)
+pub fn new_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+pub fn ping_path(token: &str) -> String {
+    format!("{HEARTBEAT_PATH_PREFIX}{token}")
+}
+
+/// 예약 경로 뒤의 `{token}[/start|/success|/fail]`를 해석. 모르는 접미사는 `None`
+pub fn parse_ping_path(rest: &str) -> Option<(&str, HeartbeatPing)> {
+    let rest = rest.trim_end_matches('/');
+    let (token, kind) = match rest.split_once('/') {
+        None => (rest, HeartbeatPing::Success),
+        Some((token, "start")) => (token, HeartbeatPing::Start),
+        Some((token, "success")) => (token, HeartbeatPing::Success),
+        Some((token, "fail")) => (token, HeartbeatPing::Fail),
+        Some(_) => return None,
+    };
+    (!token.is_empty()).then_some((token, kind))
+}
+
+/// 핑 수신 상태. 프록시(핑 수신)와 `DomainMonitorService`(판정)가 같은 인스턴스를 공유.
+pub struct HeartbeatService {
+    states: Mutex<Vec<HeartbeatState>>,
+    storage_path: PathBuf,
+}
+
+impl HeartbeatService {
+    pub fn new(storage_path: PathBuf) -> Self {
+        let states = load_versioned(&storage_path);
+        Self {
+            states: Mutex::new(states),
+            storage_path,
+        }
+    }
+
+    /// 설정된 토큰 목록과 동기화. 새 토큰은 `now`부터 감시, 빠진 토큰의 상태는 버림.
+    pub fn sync_tokens(&self, tokens: &[&str], now: DateTime<Utc>) {
+        let mut states = self.states.lock().unwrap();
+        let before = states.len();
+        states.retain(|s| tokens.contains(&s.token.as_str()));
+        let mut changed = states.len() != before;
+        for token in tokens {
+            if !states.iter().any(|s| s.token == *token) {
+                states.push(HeartbeatState {
+                    token: (*token).to_string(),
+                    since: now,
+                    last_ping: None,
+                    last_finished: None,
+                    running_since: None,
+                    runs: Vec::new(),
+                });
+                changed = true;
+            }
+        }
+        if changed {
+            save_versioned(&self.storage_path, &*states);
+        }
+    }
+
+    pub fn state(&self, token: &str) -> Option<HeartbeatState> {
+        self.states
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.token == token)
+            .cloned()
+    }
+
+    /// 핑 기록. 모르는 토큰이면 false
+    pub fn ping(&self, token: &str, kind: HeartbeatPing, now: DateTime<Utc>) -> bool {
+        let mut states = self.states.lock().unwrap();
+        let Some(state) = states.iter_mut().find(|s| s.token == token) else {
+            return false;
+        };
+        state.last_ping = Some(now);
+        match kind {
+            HeartbeatPing::Start => state.running_since = Some(now),
+            HeartbeatPing::Success | HeartbeatPing::Fail => {
+                let started_at = state.running_since.take();
+                state.last_finished = Some(now);
+                state.runs.push(HeartbeatRun {
+                    started_at,
+                    finished_at: now,
+                    success: kind == HeartbeatPing::Success,
+                    duration_ms: started_at
+                        .and_then(|start| u64::try_from((now - start).num_milliseconds()).ok()),
+                });
+                if state.runs.len() > MAX_HEARTBEAT_RUNS {
+                    let excess = state.runs.len() - MAX_HEARTBEAT_RUNS;
+                    state.runs.drain(..excess);
+                }
+            }
+        }
+        save_versioned(&self.storage_path, &*states);
+        true
+    }
+}
+
+/// 하트비트 대상의 체크 결과. 마지막 실행이 fail이면 error, 마지막 success·fail 핑(없으면 감시 시작)부터
+/// 주기 + 여유가 지나면 error. 지연 시간은 마지막 실행의 소요 시간.
+pub fn probe_heartbeat(
+    url: &str,
+    group: &str,
+    check: Option<&HeartbeatCheck>,
+    service: &HeartbeatService,
+    now: DateTime<Utc>,
+) -> DomainStatusLog {
+    let log = |status: &str, level: &str, latency: u32, message: String| DomainStatusLog {
+        url: url.to_string(),
+        status: status.to_string(),
+        level: level.to_string(),
+        latency,
+        ok: level == "info",
+        group: group.to_string(),
+        timestamp: now,
+        error_message: Some(message),
+        failures: Vec::new(),
+        attempt: 1,
+        timing: None,
+        redirects: Vec::new(),
+        maintenance: None,
+    };
+    let Some((check, state)) = check
+        .filter(|c| !c.token.is_empty())
+        .and_then(|c| service.state(&c.token).map(|s| (c, s)))
+    else {
+        return log(
+            "Not Configured",
+            "error",
+            0,
+            "Heartbeat token is not set".to_string(),
+        );
+    };
+    let last_run = state.runs.last();
+    let latency = last_run
+        .and_then(|r| r.duration_ms)
+        .map_or(0, |ms| u32::try_from(ms).unwrap_or(u32::MAX));
+    let reference = state.last_finished.unwrap_or(state.since);
+    let allowed = Duration::seconds(i64::from(check.period_secs) + i64::from(check.grace_secs));
+    if now - reference > allowed {
+        let silent = format_duration((now - reference).num_seconds());
+        let message = match state.last_finished {
+            Some(_) => format!(
+                "No ping for {silent} (expected every {}s + {}s grace)",
+                check.period_secs, check.grace_secs
+            ),
+            None => format!("No ping received since monitoring started {silent} ago"),
+        };
+        return log("Missed", "error", latency, message);
+    }
+    if let Some(run) = last_run.filter(|r| !r.success) {
+        return log(
+            "Failed",
+            "error",
+            latency,
+            format!("Job reported failure at {}", run.finished_at.to_rfc3339()),
+        );
+    }
+    if let Some(started) = state.running_since {
+        let running = format_duration((now - started).num_seconds());
+        return log(
+            "Running",
+            "info",
+            latency,
+            format!("Job running for {running}"),
+        );
+    }
+    match state.last_finished {
+        Some(_) => log("OK", "info", latency, "Operation successful".to_string()),
+        None => log(
+            "Waiting",
+            "info",
+            0,
+            "Waiting for the first ping".to_string(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(token: &str) -> HeartbeatCheck {
+        HeartbeatCheck {
+            token: token.to_string(),
+            period_secs: 60,
+            grace_secs: 30,
+        }
+    }
+
+    #[test]
+    fn test_parse_ping_path() {
+        assert_eq!(
+            parse_ping_path("abc"),
+            Some(("abc", HeartbeatPing::Success))
+        );
+        assert_eq!(
+            parse_ping_path("abc/start"),
+            Some(("abc", HeartbeatPing::Start))
+        );
+        assert_eq!(
+            parse_ping_path("abc/fail/"),
+            Some(("abc", HeartbeatPing::Fail))
+        );
+        assert_eq!(parse_ping_path("abc/other"), None);
+        assert_eq!(parse_ping_path(""), None);
+    }
+
+    #[test]
+    fn test_ping_records_runs_and_goes_down_after_grace() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("heartbeats.json");
+        let service = HeartbeatService::new(path.clone());
+        let t0 = Utc::now();
+        service.sync_tokens(&["t1"], t0);
+        assert!(!service.ping("unknown", HeartbeatPing::Success, t0));
+
+        let probe = |at| {
+            probe_heartbeat(
+                "heartbeat://backup",
+                "Default",
+                Some(&check("t1")),
+                &service,
+                at,
+            )
+        };
+        assert_eq!(probe(t0).status, "Waiting");
+        assert_eq!(probe(t0 + Duration::seconds(91)).level, "error");
+
+        assert!(service.ping("t1", HeartbeatPing::Start, t0 + Duration::seconds(10)));
+        assert_eq!(probe(t0 + Duration::seconds(20)).status, "Running");
+        assert!(service.ping("t1", HeartbeatPing::Success, t0 + Duration::seconds(25)));
+        let ok = probe(t0 + Duration::seconds(30));
+        assert_eq!(
+            (ok.status.as_str(), ok.latency, ok.ok),
+            ("OK", 15_000, true)
+        );
+
+        // 주기(60s) + 여유(30s) 안에 다음 핑이 없으면 down
+        assert!(probe(t0 + Duration::seconds(115)).ok);
+        let missed = probe(t0 + Duration::seconds(116));
+        assert_eq!(
+            (missed.status.as_str(), missed.level.as_str()),
+            ("Missed", "error")
+        );
+
+        assert!(service.ping("t1", HeartbeatPing::Fail, t0 + Duration::seconds(120)));
+        assert_eq!(probe(t0 + Duration::seconds(121)).status, "Failed");
+
+        // 재시작 후에도 유지, 설정에서 빠진 토큰은 정리
+        let reloaded = HeartbeatService::new(path);
+        assert_eq!(reloaded.state("t1").unwrap().runs.len(), 2);
+        reloaded.sync_tokens(&[], t0);
+        assert!(reloaded.state("t1").is_none());
+        let unset = probe_heartbeat("heartbeat://x", "Default", None, &reloaded, t0);
+        assert_eq!(unset.status, "Not Configured");
+    }
+}
@@ -0,0 +1,713 @@
+//! 도메인 HTTP 체크 한 건 실행 + 응답 검증.
+//! `DomainMonitorService`가 도메인별 `HttpCheck`(없으면 HEAD + 2xx/3xx)로 호출한다.
+
+use crate::model::domain_status_log::{DomainStatusLog, ProbeTiming, RedirectHop};
+use crate::model::http_check::{AssertionFailure, CheckAssertion, HttpCheck, HttpCheckMethod};
+use crate::model::probe_options::{ProbeAuth, ProbeOptions};
+use crate::service::http_transport::{FetchError, FetchResponse, HttpTransport};
+use base64::Engine;
+use chrono::Utc;
+use reqwest::header::HeaderMap;
+use reqwest::{StatusCode, Url};
+use std::collections::HashMap;
+
+/// `url`에 체크 요청을 보내고 검증 결과를 `DomainStatusLog`로 반환.
+/// `log_url`은 로그에 남길 원래 도메인 URL (스킴 없는 형태일 수 있음).
+/// `latency`는 바디 수신까지 포함한 전체 시간, 단계별 시간은 `timing`.
+/// 리다이렉트 체인은 `record_redirects`일 때와 리다이렉트 루프일 때 `redirects`에 남김.
+/// `options`: 인증·제한 시간·User-Agent·TLS. HEAD가 405·501이면 (`head_fallback`) GET으로 다시 보내고 그 결과로 판정.
+pub async fn probe_http(
+    transport: &HttpTransport,
+    url: &str,
+    log_url: &str,
+    group: &str,
+    check: &HttpCheck,
+    options: &ProbeOptions,
+) -> DomainStatusLog {
+    let method = match check.method {
+        HttpCheckMethod::Get => reqwest::Method::GET,
+        HttpCheckMethod::Head => reqwest::Method::HEAD,
+        HttpCheckMethod::Post => reqwest::Method::POST,
+    };
+    let headers = request_headers(check, options);
+
+    let mut start = std::time::Instant::now();
+    let response = match transport.configured(options) {
+        Ok(configured) => {
+            let transport = configured.as_ref().unwrap_or(transport);
+            let response = transport
+                .fetch(method.clone(), url, &headers, check.body.as_deref())
+                .await;
+            if options.head_fallback && method == reqwest::Method::HEAD && rejects_head(&response) {
+                start = std::time::Instant::now();
+                transport
+                    .fetch(reqwest::Method::GET, url, &headers, None)
+                    .await
+            } else {
+                response
+            }
+        }
+        Err(message) => Err(FetchError {
+            message,
+            timing: ProbeTiming::default(),
+            chain: Vec::new(),
+            redirect_loop: false,
+        }),
+    };
+    let latency = start.elapsed().as_millis() as u32;
+
+    let resp = match response {
+        Ok(resp) => resp,
+        Err(e) => {
+            let failures = if e.redirect_loop {
+                vec![AssertionFailure {
+                    kind: "redirectLoop".to_string(),
+                    expected: "no redirect loop".to_string(),
+                    actual: e.message.clone(),
+                }]
+            } else {
+                Vec::new()
+            };
+            return DomainStatusLog {
+                url: log_url.to_string(),
+                status: if e.redirect_loop {
+                    "Redirect Loop"
+                } else {
+                    "Request Error"
+                }
+                .to_string(),
+                level: "error".to_string(),
+                latency,
+                ok: false,
+                group: group.to_string(),
+                timestamp: Utc::now(),
+                error_message: Some(e.message),
+                failures,
+                attempt: 1,
+                timing: Some(e.timing),
+                redirects: if check.record_redirects || e.redirect_loop {
+                    e.chain
+                } else {
+                    Vec::new()
+                },
+                maintenance: None,
+            };
+        }
+    };
+
+    let sc = resp.status;
+    let body = check
+        .assertions
+        .iter()
+        .any(CheckAssertion::needs_body)
+        .then(|| String::from_utf8_lossy(&resp.body).into_owned());
+
+    let failures = evaluate_assertions(
+        &check.assertions,
+        sc,
+        &resp.headers,
+        body.as_deref(),
+        latency,
+        &resp.chain,
+    );
+    let ok = failures.is_empty();
+    DomainStatusLog {
+        url: log_url.to_string(),
+        status: format!("{sc}"),
+        level: level_for(sc, &failures).to_string(),
+        latency,
+        ok,
+        group: group.to_string(),
+        timestamp: Utc::now(),
+        error_message: if ok {
+            Some("Operation successful".to_string())
+        } else {
+            Some(
+                failures
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        },
+        failures,
+        attempt: 1,
+        timing: Some(resp.timing),
+        redirects: if check.record_redirects {
+            resp.chain
+        } else {
+            Vec::new()
+        },
+        maintenance: None,
+    }
+}
+
+/// HEAD를 지원하지 않는다는 응답 (405 Method Not Allowed·501 Not Implemented)
+fn rejects_head(response: &Result<FetchResponse, FetchError>) -> bool {
+    response.as_ref().is_ok_and(|r| {
+        matches!(
+            r.status,
+            StatusCode::METHOD_NOT_ALLOWED | StatusCode::NOT_IMPLEMENTED
+        )
+    })
+}
+
+/// `HttpCheck.headers` + `ProbeOptions.auth`의 `Authorization` (헤더에 직접 넣은 값이 우선)
+pub(crate) fn request_headers(
+    check: &HttpCheck,
+    options: &ProbeOptions,
+) -> HashMap<String, String> {
+    let mut headers = check.headers.clone();
+    let has_auth = headers
+        .keys()
+        .any(|name| name.eq_ignore_ascii_case("authorization"));
+    if let (Some(auth), false) = (&options.auth, has_auth) {
+        let value = match auth {
+            ProbeAuth::Basic { username, password } => format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(format!("{username}:{password}"))
+            ),
+            ProbeAuth::Bearer { token } => format!("Bearer {token}"),
+        };
+        headers.insert("Authorization".to_string(), value);
+    }
+    headers
+}
+
+/// 실패 종류로 level 판정. 상태 코드 실패는 기존 규칙(4xx = warning, 그 외 error),
+/// 응답 시간 초과만 있으면 warning, 바디·헤더 검증 실패는 error.
+fn level_for(sc: StatusCode, failures: &[AssertionFailure]) -> &'static str {
+    if failures.is_empty() {
+        return "info";
+    }
+    if failures.iter().any(|f| f.kind == "statusIn") {
+        return if sc.is_client_error() {
+            "warning"
+        } else {
+            "error"
+        };
+    }
+    if failures.iter().all(|f| f.kind == "maxLatency") {
+        return "warning";
+    }
+    "error"
+}
+
+/// 모든 검증 규칙을 평가하여 실패 목록 반환. `StatusIn`이 없으면 2xx/3xx를 기대.
+/// `redirects`: 보낸 요청 순서대로의 체인 (마지막이 최종 응답).
+pub fn evaluate_assertions(
+    assertions: &[CheckAssertion],
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: Option<&str>,
+    latency_ms: u32,
+    redirects: &[RedirectHop],
+) -> Vec<AssertionFailure> {
+    let mut failures = Vec::new();
+    let has_status_rule = assertions
+        .iter()
+        .any(|a| matches!(a, CheckAssertion::StatusIn { .. }));
+    let default_status_ok = status.is_success() || status.is_redirection();
+    if !has_status_rule && !default_status_ok {
+        failures.push(AssertionFailure {
+            kind: "statusIn".to_string(),
+            expected: "2xx or 3xx".to_string(),
+            actual: status.as_u16().to_string(),
+        });
+    }
+    for assertion in assertions {
+        if let Some((expected, actual)) =
+            check_one(assertion, status, headers, body, latency_ms, redirects)
+        {
+            failures.push(AssertionFailure {
+                kind: assertion.kind().to_string(),
+                expected,
+                actual,
+            });
+        }
+    }
+    failures
+}
+
+/// 규칙 하나 평가. 실패 시 `Some((expected, actual))`.
+fn check_one(
+    assertion: &CheckAssertion,
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: Option<&str>,
+    latency_ms: u32,
+    redirects: &[RedirectHop],
+) -> Option<(String, String)> {
+    let body = body.unwrap_or("");
+    let final_url = redirects.last().map(|hop| hop.url.as_str());
+    match assertion {
+        CheckAssertion::StatusIn { codes } => (!codes.contains(&status.as_u16())).then(|| {
+            let expected = codes
+                .iter()
+                .map(u16::to_string)
+                .collect::<Vec<_>>()
+                .join(" | ");
+            (expected, status.as_u16().to_string())
+        }),
+        CheckAssertion::BodyContains { value } => (!body.contains(value.as_str()))
+            .then(|| (format!("body contains {value:?}"), "not found".to_string())),
+        CheckAssertion::BodyMatches { pattern } => match regex::Regex::new(pattern) {
+            Ok(re) => (!re.is_match(body))
+                .then(|| (format!("body matches /{pattern}/"), "no match".to_string())),
+            Err(e) => Some((
+                format!("body matches /{pattern}/"),
+                format!("invalid pattern: {e}"),
+            )),
+        },
+        CheckAssertion::JsonPath { path, equals } => {
+            let expected = format!("{path} == {equals}");
+            let Ok(json) = serde_json::from_str::<serde_json::Value>(body) else {
+                return Some((expected, "body is not JSON".to_string()));
+            };
+            let Some(pointer) = json_path_to_pointer(path) else {
+                return Some((expected, "invalid path".to_string()));
+            };
+            match json.pointer(&pointer) {
+                Some(v) if v == equals => None,
+                Some(v) => Some((expected, v.to_string())),
+                None => Some((expected, "missing".to_string())),
+            }
+        }
+        CheckAssertion::Header {
+            name,
+            equals,
+            contains,
+        } => {
+            let Some(value) = headers.get(name.as_str()) else {
+                return Some((format!("header {name} present"), "missing".to_string()));
+            };
+            let value = value.to_str().unwrap_or("").to_string();
+            if let Some(eq) = equals {
+                if !value.eq_ignore_ascii_case(eq) {
+                    return Some((format!("{name} == {eq:?}"), format!("{value:?}")));
+                }
+            }
+            if let Some(part) = contains {
+                if !value.to_lowercase().contains(&part.to_lowercase()) {
+                    return Some((format!("{name} contains {part:?}"), format!("{value:?}")));
+                }
+            }
+            None
+        }
+        CheckAssertion::MaxLatency { ms } => {
+            (latency_ms > *ms).then(|| (format!("<= {ms}ms"), format!("{latency_ms}ms")))
+        }
+        CheckAssertion::FinalScheme { scheme } => {
+            let actual = final_url
+                .and_then(|url| Url::parse(url).ok())
+                .map_or_else(|| "unknown".to_string(), |url| url.scheme().to_string());
+            (!actual.eq_ignore_ascii_case(scheme))
+                .then(|| (format!("final url on {scheme}"), actual))
+        }
+        CheckAssertion::FinalUrl { url } => {
+            let normalize = |u: &str| Url::parse(u).map_or_else(|_| u.to_string(), String::from);
+            let actual = final_url.map_or_else(|| "unknown".to_string(), normalize);
+            (actual != normalize(url)).then(|| (normalize(url), actual))
+        }
+        CheckAssertion::MaxRedirects { count } => {
+            let followed = redirects.len().saturating_sub(1);
+            (followed > *count as usize).then(|| {
+                (
+                    format!("<= {count} redirects"),
+                    format!("{followed} redirects"),
+                )
+            })
+        }
+    }
+}
+
+/// 간단한 `JSONPath`(`$.a.b[0]`, `$['a-b'].c`, `a.b`)를 JSON Pointer(`/a/b/0`)로 변환.
+/// 와일드카드·필터는 지원하지 않음.
+pub fn json_path_to_pointer(path: &str) -> Option<String> {
+    let path = path.trim();
+    let rest = path.strip_prefix('$').unwrap_or(path);
+    // 선행 `$.` 없이 시작하는 경로 (`a.b`)는 `.a.b`로 취급
+    let rest = if rest.is_empty() || rest.starts_with(['.', '[']) {
+        rest.to_string()
+    } else {
+        format!(".{rest}")
+    };
+    let mut segments: Vec<String> = Vec::new();
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        let seg = match c {
+            '.' => {
+                let mut seg = String::new();
+                while let Some(&n) = chars.peek() {
+                    if n == '.' || n == '[' {
+                        break;
+                    }
+                    seg.push(n);
+                    chars.next();
+                }
+                seg
+            }
+            '[' => {
+                let mut seg = String::new();
+                for n in chars.by_ref() {
+                    if n == ']' {
+                        break;
+                    }
+                    seg.push(n);
+                }
+                let trimmed = seg.trim();
+                trimmed
+                    .strip_prefix('\'')
+                    .and_then(|s| s.strip_suffix('\''))
+                    .or_else(|| trimmed.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+                    .unwrap_or(trimmed)
+                    .to_string()
+            }
+            _ => return None,
+        };
+        if seg.is_empty() || seg == "*" {
+            return None;
+        }
+        segments.push(seg);
+    }
+    let mut pointer = String::new();
+    for seg in segments {
+        pointer.push('/');
+        pointer.push_str(&seg.replace('~', "~0").replace('/', "~1"));
+    }
+    Some(pointer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{response::Redirect, routing::get, Json, Router};
+
+    #[test]
+    fn test_json_path_to_pointer() {
+        assert_eq!(json_path_to_pointer("$.status").as_deref(), Some("/status"));
+        assert_eq!(
+            json_path_to_pointer("$.items[0].state").as_deref(),
+            Some("/items/0/state")
+        );
+        assert_eq!(
+            json_path_to_pointer("$['a/b'].c").as_deref(),
+            Some("/a~1b/c")
+        );
+        assert_eq!(json_path_to_pointer("data.ok").as_deref(), Some("/data/ok"));
+        assert_eq!(json_path_to_pointer("$").as_deref(), Some(""));
+        assert!(json_path_to_pointer("$.items[*]").is_none());
+    }
+
+    #[test]
+    fn test_default_expects_2xx_or_3xx() {
+        let headers = HeaderMap::new();
+        assert!(
+            evaluate_assertions(&[], StatusCode::MOVED_PERMANENTLY, &headers, None, 10, &[])
+                .is_empty()
+        );
+        let failures = evaluate_assertions(&[], StatusCode::BAD_GATEWAY, &headers, None, 10, &[]);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].kind, "statusIn");
+        assert_eq!(failures[0].actual, "502");
+    }
+
+    #[test]
+    fn test_each_assertion_reports_structured_failure() {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", "text/html".parse().unwrap());
+        let assertions = vec![
+            CheckAssertion::StatusIn { codes: vec![200] },
+            CheckAssertion::BodyContains {
+                value: "healthy".to_string(),
+            },
+            CheckAssertion::BodyMatches {
+                pattern: r"version \d+".to_string(),
+            },
+            CheckAssertion::JsonPath {
+                path: "$.status".to_string(),
+                equals: serde_json::json!("ok"),
+            },
+            CheckAssertion::Header {
+                name: "content-type".to_string(),
+                equals: None,
+                contains: Some("json".to_string()),
+            },
+            CheckAssertion::MaxLatency { ms: 100 },
+        ];
+        let failures = evaluate_assertions(
+            &assertions,
+            StatusCode::OK,
+            &headers,
+            Some(r#"{"status":"error"}"#),
+            250,
+            &[],
+        );
+        let kinds: Vec<&str> = failures.iter().map(|f| f.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "bodyContains",
+                "bodyMatches",
+                "jsonPath",
+                "header",
+                "maxLatency"
+            ]
+        );
+        assert_eq!(failures[2].actual, "\"error\"");
+        assert_eq!(failures[4].actual, "250ms");
+    }
+
+    #[test]
+    fn test_redirect_assertions() {
+        let hop = |url: &str, status: u16, location: Option<&str>| RedirectHop {
+            url: url.to_string(),
+            status,
+            location: location.map(str::to_string),
+        };
+        let chain = vec![
+            hop("http://example.com/", 301, Some("https://example.com/")),
+            hop(
+                "https://example.com/",
+                301,
+                Some("https://www.example.com/"),
+            ),
+            hop("https://www.example.com/", 200, None),
+        ];
+        let assertions = vec![
+            CheckAssertion::FinalScheme {
+                scheme: "https".to_string(),
+            },
+            CheckAssertion::FinalUrl {
+                url: "https://www.example.com".to_string(),
+            },
+            CheckAssertion::MaxRedirects { count: 2 },
+        ];
+        let headers = HeaderMap::new();
+        assert!(
+            evaluate_assertions(&assertions, StatusCode::OK, &headers, None, 10, &chain).is_empty()
+        );
+
+        // 정규 도메인 리다이렉트가 빠져 http에 머무는 경우
+        let broken = vec![hop("http://example.com/", 200, None)];
+        let failures =
+            evaluate_assertions(&assertions, StatusCode::OK, &headers, None, 10, &broken);
+        let kinds: Vec<&str> = failures.iter().map(|f| f.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["finalScheme", "finalUrl"]);
+        assert_eq!(failures[0].actual, "http");
+
+        let failures = evaluate_assertions(
+            &[CheckAssertion::MaxRedirects { count: 1 }],
+            StatusCode::OK,
+            &headers,
+            None,
+            10,
+            &chain,
+        );
+        assert_eq!(failures[0].actual, "2 redirects");
+    }
+
+    #[test]
+    fn test_level_for_failures() {
+        let latency_only = vec![AssertionFailure {
+            kind: "maxLatency".to_string(),
+            expected: "<= 1ms".to_string(),
+            actual: "5ms".to_string(),
+        }];
+        assert_eq!(level_for(StatusCode::OK, &latency_only), "warning");
+        let status = vec![AssertionFailure {
+            kind: "statusIn".to_string(),
+            expected: "200".to_string(),
+            actual: "404".to_string(),
+        }];
+        assert_eq!(level_for(StatusCode::NOT_FOUND, &status), "warning");
+        assert_eq!(
+            level_for(StatusCode::INTERNAL_SERVER_ERROR, &status),
+            "error"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_probe_detects_error_payload_behind_200() {
+        let app = Router::new().route(
+            "/health",
+            get(|| async { Json(serde_json::json!({ "status": "degraded" })) }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let check = HttpCheck {
+            method: HttpCheckMethod::Get,
+            assertions: vec![CheckAssertion::JsonPath {
+                path: "$.status".to_string(),
+                equals: serde_json::json!("ok"),
+            }],
+            ..Default::default()
+        };
+        let url = format!("http://{addr}/health");
+        let transport =
+            HttpTransport::new(std::sync::Arc::new(rustls::RootCertStore::empty()), None);
+        let log = probe_http(
+            &transport,
+            &url,
+            &url,
+            "Default",
+            &check,
+            &ProbeOptions::default(),
+        )
+        .await;
+        assert_eq!(log.status, "200 OK");
+        assert!(!log.ok);
+        assert_eq!(log.level, "error");
+        assert_eq!(log.failures.len(), 1);
+        assert_eq!(log.failures[0].actual, "\"degraded\"");
+        assert_eq!(
+            log.timing.as_ref().and_then(|t| t.http_version.as_deref()),
+            Some("HTTP/1.1")
+        );
+
+        // Without assertions the same endpoint looks healthy (previous behavior)
+        let log = probe_http(
+            &transport,
+            &url,
+            &url,
+            "Default",
+            &HttpCheck::default(),
+            &ProbeOptions::default(),
+        )
+        .await;
+        assert!(log.ok);
+        assert!(log.failures.is_empty());
+        assert!(log.redirects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_probe_records_chain_and_reports_loop() {
+        let app = Router::new()
+            .route("/", get(|| async { Redirect::permanent("/home") }))
+            .route("/home", get(|| async { "home" }))
+            .route("/loop", get(|| async { Redirect::temporary("/loop") }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        let transport =
+            HttpTransport::new(std::sync::Arc::new(rustls::RootCertStore::empty()), None);
+
+        let check = HttpCheck {
+            record_redirects: true,
+            assertions: vec![CheckAssertion::FinalScheme {
+                scheme: "https".to_string(),
+            }],
+            ..Default::default()
+        };
+        let url = format!("http://{addr}/");
+        let log = probe_http(
+            &transport,
+            &url,
+            &url,
+            "Default",
+            &check,
+            &ProbeOptions::default(),
+        )
+        .await;
+        assert_eq!(log.redirects.len(), 2);
+        assert_eq!(log.redirects[0].status, 308);
+        assert_eq!(log.failures[0].kind, "finalScheme");
+        assert_eq!(log.level, "error");
+
+        let url = format!("http://{addr}/loop");
+        let log = probe_http(
+            &transport,
+            &url,
+            &url,
+            "Default",
+            &HttpCheck::default(),
+            &ProbeOptions::default(),
+        )
+        .await;
+        assert_eq!(log.status, "Redirect Loop");
+        assert_eq!(log.failures[0].kind, "redirectLoop");
+        assert_eq!(log.redirects.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_head_falls_back_to_get_with_auth() {
+        use axum::http::{header, HeaderMap, Method, StatusCode};
+        use axum::routing::any;
+        // HEAD는 405, 그 외에는 Authorization이 맞아야 200
+        let app = Router::new().route(
+            "/",
+            any(|method: Method, headers: HeaderMap| async move {
+                if method == Method::HEAD {
+                    return StatusCode::METHOD_NOT_ALLOWED;
+                }
+                match headers
+                    .get(header::AUTHORIZATION)
+                    .and_then(|v| v.to_str().ok())
+                {
+                    Some("Basic dXNlcjpwYXNz") => StatusCode::OK,
+                    _ => StatusCode::UNAUTHORIZED,
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        let transport =
+            HttpTransport::new(std::sync::Arc::new(rustls::RootCertStore::empty()), None);
+        let url = format!("http://{addr}/");
+        let auth = ProbeOptions {
+            auth: Some(ProbeAuth::Basic {
+                username: "user".to_string(),
+                password: "pass".to_string(),
+            }),
+            ..ProbeOptions::default()
+        };
+
+        let log = probe_http(
+            &transport,
+            &url,
+            &url,
+            "Default",
+            &HttpCheck::default(),
+            &auth,
+        )
+        .await;
+        assert!(log.ok, "{:?}", log.error_message);
+        assert_eq!(log.status, "200 OK");
+
+        let no_fallback = ProbeOptions {
+            head_fallback: false,
+            ..auth.clone()
+        };
+        let log = probe_http(
+            &transport,
+            &url,
+            &url,
+            "Default",
+            &HttpCheck::default(),
+            &no_fallback,
+        )
+        .await;
+        assert_eq!(log.status, "405 Method Not Allowed");
+
+        // 헤더에 직접 넣은 Authorization이 우선
+        let check = HttpCheck {
+            headers: std::collections::HashMap::from([(
+                "Authorization".to_string(),
+                "Bearer wrong".to_string(),
+            )]),
+            ..HttpCheck::default()
+        };
+        let log = probe_http(&transport, &url, &url, "Default", &check, &auth).await;
+        assert_eq!(log.status, "401 Unauthorized");
+        assert_eq!(log.level, "warning");
+    }
+}
@@ -0,0 +1,764 @@
+//! 모니터 HTTP 체크용 요청 실행기.
+//! 단계별 시간(DNS·TCP 연결·TLS·첫 바이트·다운로드)을 재려고 reqwest 대신
+//! 이름 조회, 연결, TLS 핸드셰이크, hyper 요청을 직접 수행한다. 리다이렉트는 reqwest 기본값처럼 최대 10번 따라가며
+//! 요청마다 체인에 기록하고, 이미 요청한 url로 돌아오면 리다이렉트 루프로 실패.
+
+use crate::model::domain_status_log::{ProbeTiming, RedirectHop};
+use crate::model::probe_options::{ClientCertConfig, ProbeOptions};
+use hickory_resolver::TokioResolver;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Bytes;
+use hyper::client::conn::{http1, http2};
+use hyper::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, COOKIE, HOST, LOCATION};
+use hyper::{Method, Request, StatusCode, Version};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use reqwest::Url;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::CryptoProvider;
+use rustls::{
+    ClientConfig, DigitallySignedStruct, ProtocolVersion, RootCertStore, SignatureScheme,
+};
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+/// 체크 한 건 전체 제한 시간 (리다이렉트 포함). `ProbeOptions.timeout_secs`로 바꿀 수 있음
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// `ProbeOptions.timeout_secs` 상한
+const MAX_TIMEOUT_SECS: u32 = 120;
+const MAX_REDIRECTS: usize = 10;
+/// 이보다 큰 바디는 나머지를 읽지 않음 (다운로드 시간도 여기까지)
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+const USER_AGENT: &str = "Watchtower/0.1.0";
+
+trait Io: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Io for T {}
+
+#[derive(Debug)]
+pub struct FetchResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+    pub timing: ProbeTiming,
+    /// 보낸 요청 순서대로. 마지막 항목이 최종 응답
+    pub chain: Vec<RedirectHop>,
+}
+
+/// 요청 실패. `timing`·`chain`은 실패 전까지 기록된 것만 채워짐
+#[derive(Debug)]
+pub struct FetchError {
+    pub message: String,
+    pub timing: ProbeTiming,
+    pub chain: Vec<RedirectHop>,
+    /// 리다이렉트가 이미 요청한 url로 돌아옴 (일반 요청 실패와 구분)
+    pub redirect_loop: bool,
+}
+
+enum FollowError {
+    Request(String),
+    Loop(String),
+}
+
+impl From<String> for FollowError {
+    fn from(message: String) -> Self {
+        Self::Request(message)
+    }
+}
+
+/// 한 번의 요청 (리다이렉트 한 단계)
+struct Hop {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+fn elapsed_ms(start: Instant) -> u32 {
+    start.elapsed().as_millis() as u32
+}
+
+fn add_ms(total: &mut Option<u32>, ms: Option<u32>) {
+    if let Some(ms) = ms {
+        *total = Some(total.unwrap_or(0) + ms);
+    }
+}
+
+/// 단계별 시간은 더하고 연결 정보는 마지막 요청 값으로
+fn accumulate(total: &mut ProbeTiming, hop: ProbeTiming) {
+    add_ms(&mut total.dns_ms, hop.dns_ms);
+    add_ms(&mut total.connect_ms, hop.connect_ms);
+    add_ms(&mut total.tls_ms, hop.tls_ms);
+    add_ms(&mut total.ttfb_ms, hop.ttfb_ms);
+    add_ms(&mut total.download_ms, hop.download_ms);
+    total.resolved_ip = hop.resolved_ip;
+    total.http_version = hop.http_version;
+    total.tls_version = hop.tls_version;
+}
+
+fn tls_version_name(version: ProtocolVersion) -> String {
+    match version {
+        ProtocolVersion::TLSv1_3 => "TLS 1.3".to_string(),
+        ProtocolVersion::TLSv1_2 => "TLS 1.2".to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+fn http_version_name(version: Version) -> String {
+    match version {
+        Version::HTTP_2 => "HTTP/2".to_string(),
+        Version::HTTP_11 => "HTTP/1.1".to_string(),
+        Version::HTTP_10 => "HTTP/1.0".to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+/// 리다이렉트 후 메서드. 303과 (POST의) 301/302는 GET으로 바꾸고 바디를 버림.
+fn redirect_method(status: StatusCode, method: &Method) -> Method {
+    match status {
+        StatusCode::SEE_OTHER if *method != Method::HEAD => Method::GET,
+        StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND if *method == Method::POST => Method::GET,
+        _ => method.clone(),
+    }
+}
+
+pub fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+/// 서버 인증서를 검증하지 않는 verifier (`ProbeOptions.verify_tls == false`).
+/// 핸드셰이크 서명은 그대로 확인
+#[derive(Debug)]
+pub(crate) struct AcceptAnyServerCert(pub(crate) Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyServerCert {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// PEM 인증서 체인·개인 키 읽기
+fn load_client_cert(
+    config: &ClientCertConfig,
+) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), String> {
+    let certs = CertificateDer::pem_file_iter(&config.cert_path)
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .map_err(|e| {
+            format!(
+                "failed to read client certificate {}: {e}",
+                config.cert_path
+            )
+        })?;
+    if certs.is_empty() {
+        return Err(format!("no certificate in {}", config.cert_path));
+    }
+    let key = PrivateKeyDer::from_pem_file(&config.key_path)
+        .map_err(|e| format!("failed to read client key {}: {e}", config.key_path))?;
+    Ok((certs, key))
+}
+
+fn tls_config(
+    roots: Arc<RootCertStore>,
+    verify: bool,
+    client_cert: Option<&ClientCertConfig>,
+) -> Result<ClientConfig, String> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .expect("ring provider supports the default TLS versions");
+    let builder = if verify {
+        builder.with_root_certificates(roots)
+    } else {
+        builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyServerCert(provider)))
+    };
+    let mut config = match client_cert {
+        Some(cert) => {
+            let (chain, key) = load_client_cert(cert)?;
+            builder
+                .with_client_auth_cert(chain, key)
+                .map_err(|e| format!("invalid client certificate: {e}"))?
+        }
+        None => builder.with_no_client_auth(),
+    };
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}
+
+pub struct HttpTransport {
+    roots: Arc<RootCertStore>,
+    tls: Arc<ClientConfig>,
+    /// 프록시 설정의 DNS 서버. 조회에 실패하면 시스템 리졸버로 다시 조회
+    resolver: Option<TokioResolver>,
+    timeout: Duration,
+    user_agent: String,
+}
+
+impl HttpTransport {
+    pub fn new(roots: Arc<RootCertStore>, resolver: Option<TokioResolver>) -> Self {
+        let config = tls_config(roots.clone(), true, None).expect("no client certificate to load");
+        Self {
+            roots,
+            tls: Arc::new(config),
+            resolver,
+            timeout: REQUEST_TIMEOUT,
+            user_agent: USER_AGENT.to_string(),
+        }
+    }
+
+    /// 도메인별 요청 방식이 기본값과 다르면 그에 맞춘 전송 (리졸버·신뢰 루트는 공유). 같으면 `None`.
+    /// 클라이언트 인증서를 읽지 못하면 `Err`
+    pub fn configured(&self, options: &ProbeOptions) -> Result<Option<Self>, String> {
+        if options.timeout_secs.is_none()
+            && options.user_agent.is_none()
+            && options.verify_tls
+            && options.client_cert.is_none()
+        {
+            return Ok(None);
+        }
+        let tls = if options.verify_tls && options.client_cert.is_none() {
+            self.tls.clone()
+        } else {
+            Arc::new(tls_config(
+                self.roots.clone(),
+                options.verify_tls,
+                options.client_cert.as_ref(),
+            )?)
+        };
+        Ok(Some(Self {
+            roots: self.roots.clone(),
+            tls,
+            resolver: self.resolver.clone(),
+            timeout: options.timeout_secs.map_or(self.timeout, |secs| {
+                Duration::from_secs(u64::from(secs.clamp(1, MAX_TIMEOUT_SECS)))
+            }),
+            user_agent: options
+                .user_agent
+                .clone()
+                .unwrap_or_else(|| self.user_agent.clone()),
+        }))
+    }
+
+    /// 요청을 보내고 (리다이렉트 포함) 응답과 단계별 시간을 반환.
+    pub async fn fetch(
+        &self,
+        method: Method,
+        url: &str,
+        headers: &HashMap<String, String>,
+        body: Option<&str>,
+    ) -> Result<FetchResponse, FetchError> {
+        let mut timing = ProbeTiming::default();
+        let mut chain = Vec::new();
+        let result = tokio::time::timeout(
+            self.timeout,
+            self.fetch_following(method, url, headers, body, &mut timing, &mut chain),
+        )
+        .await;
+        let (message, redirect_loop) = match result {
+            Ok(Ok(hop)) => {
+                return Ok(FetchResponse {
+                    status: hop.status,
+                    headers: hop.headers,
+                    body: hop.body,
+                    timing,
+                    chain,
+                })
+            }
+            Ok(Err(FollowError::Request(message))) => (message, false),
+            Ok(Err(FollowError::Loop(message))) => (message, true),
+            Err(_) => (
+                format!("request timed out after {}s", self.timeout.as_secs()),
+                false,
+            ),
+        };
+        Err(FetchError {
+            message,
+            timing,
+            chain,
+            redirect_loop,
+        })
+    }
+
+    async fn fetch_following(
+        &self,
+        mut method: Method,
+        url: &str,
+        headers: &HashMap<String, String>,
+        body: Option<&str>,
+        timing: &mut ProbeTiming,
+        chain: &mut Vec<RedirectHop>,
+    ) -> Result<Hop, FollowError> {
+        let mut url = Url::parse(url).map_err(|e| format!("invalid URL {url}: {e}"))?;
+        let mut headers = headers.clone();
+        let mut body = body.map(str::to_string);
+        for _ in 0..=MAX_REDIRECTS {
+            let mut hop_timing = ProbeTiming::default();
+            let result = self
+                .send_once(&method, &url, &headers, body.as_deref(), &mut hop_timing)
+                .await;
+            accumulate(timing, hop_timing);
+            let hop = result?;
+            let location = hop
+                .headers
+                .get(LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|loc| url.join(loc).ok());
+            let next = location.filter(|_| is_redirect(hop.status));
+            chain.push(RedirectHop {
+                url: url.to_string(),
+                status: hop.status.as_u16(),
+                location: next.as_ref().map(ToString::to_string),
+            });
+            let Some(next) = next else {
+                return Ok(hop);
+            };
+            if chain.iter().any(|visited| visited.url == next.as_str()) {
+                let path: Vec<&str> = chain.iter().map(|h| h.url.as_str()).collect();
+                return Err(FollowError::Loop(format!(
+                    "redirect loop: {} -> {next}",
+                    path.join(" -> ")
+                )));
+            }
+            let next_method = redirect_method(hop.status, &method);
+            if next_method != method {
+                body = None;
+            }
+            if next.host_str() != url.host_str() {
+                headers.retain(|name, _| {
+                    !name.eq_ignore_ascii_case(AUTHORIZATION.as_str())
+                        && !name.eq_ignore_ascii_case(COOKIE.as_str())
+                });
+            }
+            method = next_method;
+            url = next;
+        }
+        Err(format!("too many redirects (max {MAX_REDIRECTS})").into())
+    }
+
+    async fn resolve(&self, host: &str, port: u16) -> Result<IpAddr, String> {
+        if let Some(resolver) = &self.resolver {
+            if let Some(ip) = resolver
+                .lookup_ip(host)
+                .await
+                .ok()
+                .and_then(|lookup| lookup.iter().next())
+            {
+                return Ok(ip);
+            }
+        }
+        tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| format!("DNS lookup failed for {host}: {e}"))?
+            .next()
+            .map(|addr| addr.ip())
+            .ok_or_else(|| format!("DNS lookup returned no address for {host}"))
+    }
+
+    async fn send_once(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &HashMap<String, String>,
+        body: Option<&str>,
+        timing: &mut ProbeTiming,
+    ) -> Result<Hop, String> {
+        let secure = match url.scheme() {
+            "https" => true,
+            "http" => false,
+            other => return Err(format!("unsupported scheme {other}")),
+        };
+        let host = url
+            .host_str()
+            .map(|h| h.trim_matches(['[', ']']).to_string())
+            .ok_or_else(|| "missing host".to_string())?;
+        let port = url
+            .port_or_known_default()
+            .unwrap_or(if secure { 443 } else { 80 });
+
+        let ip = if let Ok(ip) = host.parse::<IpAddr>() {
+            ip
+        } else {
+            let start = Instant::now();
+            let ip = self.resolve(&host, port).await?;
+            timing.dns_ms = Some(elapsed_ms(start));
+            ip
+        };
+        timing.resolved_ip = Some(ip.to_string());
+
+        let start = Instant::now();
+        let tcp = TcpStream::connect(SocketAddr::new(ip, port))
+            .await
+            .map_err(|e| format!("connect to {ip}:{port} failed: {e}"))?;
+        timing.connect_ms = Some(elapsed_ms(start));
+
+        let (io, h2): (Box<dyn Io>, bool) = if secure {
+            let server_name = ServerName::try_from(host.clone())
+                .map_err(|_| format!("invalid server name {host}"))?;
+            let start = Instant::now();
+            let tls = TlsConnector::from(self.tls.clone())
+                .connect(server_name, tcp)
+                .await
+                .map_err(|e| format!("TLS handshake failed: {e}"))?;
+            timing.tls_ms = Some(elapsed_ms(start));
+            let session = tls.get_ref().1;
+            timing.tls_version = session.protocol_version().map(tls_version_name);
+            let h2 = session.alpn_protocol() == Some(b"h2".as_slice());
+            (Box::new(tls), h2)
+        } else {
+            (Box::new(tcp), false)
+        };
+
+        let path = match url.query() {
+            Some(q) => format!("{}?{q}", url.path()),
+            None => url.path().to_string(),
+        };
+        let authority = match url.port() {
+            Some(p) => format!("{}:{p}", url.host_str().unwrap_or(&host)),
+            None => url.host_str().unwrap_or(&host).to_string(),
+        };
+        let mut builder = Request::builder().method(method.clone());
+        builder = if h2 {
+            builder.uri(format!("{}://{authority}{path}", url.scheme()))
+        } else {
+            builder.uri(path).header(HOST, authority.as_str())
+        };
+        // 헤더에 직접 넣은 User-Agent가 있으면 그쪽을 씀
+        if !headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case(hyper::header::USER_AGENT.as_str()))
+        {
+            builder = builder.header(hyper::header::USER_AGENT, self.user_agent.as_str());
+        }
+        for (name, value) in headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| format!("invalid header name {name}: {e}"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| format!("invalid header value for {name}: {e}"))?;
+            builder = builder.header(name, value);
+        }
+        let request = builder
+            .body(Full::new(Bytes::from(body.unwrap_or_default().to_string())))
+            .map_err(|e| e.to_string())?;
+
+        let start = Instant::now();
+        let response = if h2 {
+            let (mut sender, conn) = http2::handshake(TokioExecutor::new(), TokioIo::new(io))
+                .await
+                .map_err(|e| format!("HTTP/2 handshake failed: {e}"))?;
+            tokio::spawn(conn);
+            sender.send_request(request).await
+        } else {
+            let (mut sender, conn) = http1::handshake(TokioIo::new(io))
+                .await
+                .map_err(|e| format!("HTTP handshake failed: {e}"))?;
+            tokio::spawn(conn);
+            sender.send_request(request).await
+        }
+        .map_err(|e| format!("request failed: {e}"))?;
+        timing.ttfb_ms = Some(elapsed_ms(start));
+        timing.http_version = Some(http_version_name(response.version()));
+
+        let (parts, mut incoming) = response.into_parts();
+        let start = Instant::now();
+        let mut body = Vec::new();
+        while let Some(frame) = incoming.frame().await {
+            let frame = frame.map_err(|e| format!("failed to read body: {e}"))?;
+            if let Ok(data) = frame.into_data() {
+                body.extend_from_slice(&data);
+                if body.len() >= MAX_BODY_BYTES {
+                    body.truncate(MAX_BODY_BYTES);
+                    break;
+                }
+            }
+        }
+        timing.download_ms = Some(elapsed_ms(start));
+
+        Ok(Hop {
+            status: parts.status,
+            headers: parts.headers,
+            body: Bytes::from(body),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{response::Redirect, routing::get, Router};
+
+    #[tokio::test]
+    async fn test_fetch_records_phases_and_follows_redirects() {
+        let app = Router::new()
+            .route("/old", get(|| async { Redirect::temporary("/new") }))
+            .route("/new", get(|| async { "hello" }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let transport = HttpTransport::new(Arc::new(RootCertStore::empty()), None);
+        let url = format!("http://localhost:{}/old", addr.port());
+        let response = transport
+            .fetch(Method::GET, &url, &HashMap::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body.as_ref(), b"hello");
+        let new_url = format!("http://localhost:{}/new", addr.port());
+        assert_eq!(
+            response.chain,
+            vec![
+                RedirectHop {
+                    url: url.clone(),
+                    status: 307,
+                    location: Some(new_url.clone()),
+                },
+                RedirectHop {
+                    url: new_url,
+                    status: 200,
+                    location: None,
+                },
+            ]
+        );
+
+        let timing = response.timing;
+        assert!(timing.dns_ms.is_some());
+        assert!(timing.connect_ms.is_some());
+        assert!(timing.tls_ms.is_none());
+        assert!(timing.ttfb_ms.is_some());
+        assert!(timing.download_ms.is_some());
+        assert!(timing
+            .resolved_ip
+            .as_deref()
+            .unwrap()
+            .parse::<IpAddr>()
+            .unwrap()
+            .is_loopback());
+        assert_eq!(timing.http_version.as_deref(), Some("HTTP/1.1"));
+        assert!(timing.tls_version.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_connect_failure_keeps_partial_timing() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let transport = HttpTransport::new(Arc::new(RootCertStore::empty()), None);
+        let err = transport
+            .fetch(
+                Method::HEAD,
+                &format!("http://{addr}/"),
+                &HashMap::new(),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("connect"));
+        assert_eq!(err.timing.resolved_ip.as_deref(), Some("127.0.0.1"));
+        assert!(err.timing.dns_ms.is_none());
+        assert!(err.timing.ttfb_ms.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_redirect_loop_is_reported_separately() {
+        let app = Router::new()
+            .route("/a", get(|| async { Redirect::permanent("/b") }))
+            .route("/b", get(|| async { Redirect::permanent("/a") }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let transport = HttpTransport::new(Arc::new(RootCertStore::empty()), None);
+        let err = transport
+            .fetch(
+                Method::HEAD,
+                &format!("http://{addr}/a"),
+                &HashMap::new(),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.redirect_loop);
+        assert!(err.message.starts_with("redirect loop"));
+        let statuses: Vec<u16> = err.chain.iter().map(|h| h.status).collect();
+        assert_eq!(statuses, vec![308, 308]);
+        assert_eq!(
+            err.chain[1].location.as_deref(),
+            Some(format!("http://{addr}/a").as_str())
+        );
+    }
+
+    #[test]
+    fn test_redirect_method_rules() {
+        assert_eq!(
+            redirect_method(StatusCode::SEE_OTHER, &Method::POST),
+            Method::GET
+        );
+        assert_eq!(
+            redirect_method(StatusCode::SEE_OTHER, &Method::HEAD),
+            Method::HEAD
+        );
+        assert_eq!(
+            redirect_method(StatusCode::FOUND, &Method::POST),
+            Method::GET
+        );
+        assert_eq!(
+            redirect_method(StatusCode::TEMPORARY_REDIRECT, &Method::POST),
+            Method::POST
+        );
+    }
+
+    /// `CaService`로 서명한 (신뢰 루트에 없는) 인증서로 HTTP/1.1 응답을 주는 로컬 TLS 서버
+    async fn spawn_self_signed_server(dir: &std::path::Path) -> u16 {
+        use crate::service::ca_service::CaService;
+        use hyper::server::conn::http1 as server_http1;
+        use hyper::service::service_fn;
+        let ca = CaService::new(dir).unwrap();
+        let (cert, key) = ca.sign_host_certificate("localhost").unwrap();
+        let key_der = PrivateKeyDer::Pkcs8(key.serialize_der().into());
+        let server_config = rustls::ServerConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert.der().clone()], key_der)
+        .unwrap();
+        let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(server_config));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    let Ok(tls) = acceptor.accept(stream).await else {
+                        return;
+                    };
+                    let service = service_fn(|req: Request<hyper::body::Incoming>| async move {
+                        let ua = req
+                            .headers()
+                            .get(hyper::header::USER_AGENT)
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string();
+                        Ok::<_, std::convert::Infallible>(hyper::Response::new(Full::new(
+                            Bytes::from(ua),
+                        )))
+                    });
+                    let _ = server_http1::Builder::new()
+                        .serve_connection(TokioIo::new(tls), service)
+                        .await;
+                });
+            }
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_configured_transport_skips_tls_verification_and_overrides_agent() {
+        let dir = tempfile::tempdir().unwrap();
+        let port = spawn_self_signed_server(dir.path()).await;
+        let url = format!("https://localhost:{port}/");
+        let transport = HttpTransport::new(Arc::new(RootCertStore::empty()), None);
+        assert!(transport
+            .configured(&ProbeOptions::default())
+            .unwrap()
+            .is_none());
+
+        let err = transport
+            .fetch(Method::GET, &url, &HashMap::new(), None)
+            .await
+            .unwrap_err();
+        assert!(
+            err.message.contains("TLS handshake failed"),
+            "{}",
+            err.message
+        );
+
+        let options = ProbeOptions {
+            verify_tls: false,
+            user_agent: Some("probe/1.0".to_string()),
+            timeout_secs: Some(5),
+            ..ProbeOptions::default()
+        };
+        let insecure = transport.configured(&options).unwrap().unwrap();
+        let response = insecure
+            .fetch(Method::GET, &url, &HashMap::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body.as_ref(), b"probe/1.0");
+        // 헤더에 직접 넣은 User-Agent가 우선
+        let headers = HashMap::from([("user-agent".to_string(), "custom".to_string())]);
+        let response = insecure
+            .fetch(Method::GET, &url, &headers, None)
+            .await
+            .unwrap();
+        assert_eq!(response.body.as_ref(), b"custom");
+
+        let missing = ProbeOptions {
+            client_cert: Some(ClientCertConfig {
+                cert_path: dir.path().join("missing.pem").display().to_string(),
+                key_path: dir.path().join("missing.key").display().to_string(),
+            }),
+            ..ProbeOptions::default()
+        };
+        let err = transport.configured(&missing).err().unwrap();
+        assert!(err.contains("client certificate"), "{err}");
+    }
+}
@@ -0,0 +1,264 @@
+use crate::model::check_policy::{ConfirmedStatus, StateChange};
+use crate::model::incident::{Incident, IncidentNote, IncidentStats, IncidentStatsReport};
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::Utc;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// 확정 상태 변경(`StateChange`)으로 장애를 열고 닫음. `DomainMonitorService`가 체크마다 호출.
+pub struct IncidentService {
+    incidents: Mutex<Vec<Incident>>,
+    storage_path: PathBuf,
+}
+
+/// 장애를 열 때 필요한 도메인 정보
+pub struct IncidentTarget {
+    pub domain_id: u32,
+    pub groups: Vec<String>,
+}
+
+fn stats_for(key: &str, incidents: &[&Incident]) -> IncidentStats {
+    let mut sorted = incidents.to_vec();
+    sorted.sort_by_key(|i| i.started_at);
+    let durations: Vec<i64> = sorted.iter().filter_map(|i| i.duration_secs).collect();
+    let total_downtime_secs: i64 = durations.iter().sum();
+    let gaps: Vec<i64> = sorted
+        .windows(2)
+        .filter_map(|w| {
+            w[0].resolved_at
+                .map(|resolved| (w[1].started_at - resolved).num_seconds().max(0))
+        })
+        .collect();
+    let mean = |values: &[i64]| {
+        (!values.is_empty())
+            .then(|| values.iter().sum::<i64>() / i64::try_from(values.len()).unwrap_or(i64::MAX))
+    };
+    IncidentStats {
+        key: key.to_string(),
+        incident_count: sorted.len() as u32,
+        open_count: sorted.iter().filter(|i| i.is_open()).count() as u32,
+        total_downtime_secs,
+        mttr_secs: mean(&durations),
+        mtbf_secs: mean(&gaps),
+    }
+}
+
+impl IncidentService {
+    pub fn new(storage_path: PathBuf) -> Self {
+        let incidents = load_versioned(&storage_path);
+        Self {
+            incidents: Mutex::new(incidents),
+            storage_path,
+        }
+    }
+
+    fn save(&self, list: &[Incident]) {
+        save_versioned(&self.storage_path, list);
+    }
+
+    /// down 확정이면 장애를 열고 (이미 열려 있으면 무시), down → up이면 해결. 열리거나 해결된 장애를 반환.
+    pub fn apply_changes(
+        &self,
+        changes: &[StateChange],
+        targets: &HashMap<&str, IncidentTarget>,
+    ) -> Vec<Incident> {
+        let mut list = self.incidents.lock().unwrap();
+        let mut touched = Vec::new();
+        for change in changes {
+            let open = list.iter_mut().find(|i| i.url == change.url && i.is_open());
+            match (change.to, open) {
+                (ConfirmedStatus::Down, None) => {
+                    let Some(target) = targets.get(change.url.as_str()) else {
+                        continue;
+                    };
+                    let next_id = list.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+                    let incident = Incident {
+                        id: next_id,
+                        domain_id: target.domain_id,
+                        url: change.url.clone(),
+                        groups: target.groups.clone(),
+                        started_at: change.timestamp,
+                        first_error: change.reason.clone().unwrap_or_default(),
+                        resolved_at: None,
+                        duration_secs: None,
+                        acknowledged_at: None,
+                        notes: Vec::new(),
+                    };
+                    touched.push(incident.clone());
+                    list.push(incident);
+                }
+                (ConfirmedStatus::Up, Some(incident)) => {
+                    incident.resolved_at = Some(change.timestamp);
+                    incident.duration_secs =
+                        Some((change.timestamp - incident.started_at).num_seconds());
+                    touched.push(incident.clone());
+                }
+                _ => {}
+            }
+        }
+        if !touched.is_empty() {
+            self.save(&list);
+        }
+        touched
+    }
+
+    /// 최신 장애부터. `domain_id`가 있으면 해당 도메인만, `open_only`면 해결되지 않은 장애만.
+    pub fn list(&self, domain_id: Option<u32>, open_only: bool) -> Vec<Incident> {
+        let mut list: Vec<Incident> = self
+            .incidents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|i| domain_id.is_none_or(|id| i.domain_id == id))
+            .filter(|i| !open_only || i.is_open())
+            .cloned()
+            .collect();
+        list.sort_by_key(|i| std::cmp::Reverse(i.started_at));
+        list
+    }
+
+    /// 장애에 메모 추가. 없는 id면 `None`
+    pub fn annotate(&self, id: u32, text: String) -> Option<Incident> {
+        let mut list = self.incidents.lock().unwrap();
+        let incident = list.iter_mut().find(|i| i.id == id)?;
+        incident.notes.push(IncidentNote {
+            text,
+            timestamp: Utc::now(),
+        });
+        let updated = incident.clone();
+        self.save(&list);
+        Some(updated)
+    }
+
+    /// 장애 확인 처리. 이미 확인된 장애는 처음 확인 시각을 유지.
+    pub fn acknowledge(&self, id: u32) -> Option<Incident> {
+        let mut list = self.incidents.lock().unwrap();
+        let incident = list.iter_mut().find(|i| i.id == id)?;
+        incident.acknowledged_at.get_or_insert_with(Utc::now);
+        let updated = incident.clone();
+        self.save(&list);
+        Some(updated)
+    }
+
+    /// 도메인(url)별·그룹별 MTTR/MTBF
+    pub fn stats(&self) -> IncidentStatsReport {
+        let list = self.incidents.lock().unwrap();
+        let mut by_domain: BTreeMap<&str, Vec<&Incident>> = BTreeMap::new();
+        let mut by_group: BTreeMap<&str, Vec<&Incident>> = BTreeMap::new();
+        for incident in list.iter() {
+            by_domain.entry(&incident.url).or_default().push(incident);
+            for group in &incident.groups {
+                by_group.entry(group).or_default().push(incident);
+            }
+        }
+        IncidentStatsReport {
+            by_domain: by_domain.iter().map(|(k, v)| stats_for(k, v)).collect(),
+            by_group: by_group.iter().map(|(k, v)| stats_for(k, v)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+
+    fn change(url: &str, to: ConfirmedStatus, at: DateTime<Utc>) -> StateChange {
+        StateChange {
+            url: url.to_string(),
+            from: if to == ConfirmedStatus::Down {
+                ConfirmedStatus::Up
+            } else {
+                ConfirmedStatus::Down
+            },
+            to,
+            flapping: false,
+            reason: (to == ConfirmedStatus::Down).then(|| "503 Service Unavailable".to_string()),
+            timestamp: at,
+        }
+    }
+
+    fn targets() -> HashMap<&'static str, IncidentTarget> {
+        HashMap::from([
+            (
+                "https://a.example.com",
+                IncidentTarget {
+                    domain_id: 1,
+                    groups: vec!["Prod".to_string()],
+                },
+            ),
+            (
+                "https://b.example.com",
+                IncidentTarget {
+                    domain_id: 2,
+                    groups: vec!["Prod".to_string(), "Edge".to_string()],
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_open_resolve_and_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("incidents.json");
+        let svc = IncidentService::new(path.clone());
+        let t0 = Utc::now();
+        let a = "https://a.example.com";
+
+        let opened = svc.apply_changes(&[change(a, ConfirmedStatus::Down, t0)], &targets());
+        assert_eq!(opened.len(), 1);
+        assert_eq!(opened[0].first_error, "503 Service Unavailable");
+        // 이미 열린 장애가 있으면 새로 열지 않음
+        assert!(svc
+            .apply_changes(&[change(a, ConfirmedStatus::Down, t0)], &targets())
+            .is_empty());
+
+        svc.acknowledge(1).unwrap();
+        svc.annotate(1, "upstream deploy".to_string()).unwrap();
+        let resolved = svc.apply_changes(
+            &[change(a, ConfirmedStatus::Up, t0 + Duration::seconds(90))],
+            &targets(),
+        );
+        assert_eq!(resolved[0].duration_secs, Some(90));
+
+        let reloaded = IncidentService::new(path);
+        let list = reloaded.list(Some(1), false);
+        assert_eq!(list.len(), 1);
+        assert!(!list[0].is_open());
+        assert!(list[0].acknowledged_at.is_some());
+        assert_eq!(list[0].notes[0].text, "upstream deploy");
+        assert!(reloaded.list(None, true).is_empty());
+    }
+
+    #[test]
+    fn test_mttr_and_mtbf_per_domain_and_group() {
+        let dir = tempfile::tempdir().unwrap();
+        let svc = IncidentService::new(dir.path().join("incidents.json"));
+        let t0 = Utc::now();
+        let at = |secs: i64| t0 + Duration::seconds(secs);
+        let (a, b) = ("https://a.example.com", "https://b.example.com");
+        let changes = [
+            change(a, ConfirmedStatus::Down, at(0)),
+            change(a, ConfirmedStatus::Up, at(60)),
+            change(a, ConfirmedStatus::Down, at(660)),
+            change(a, ConfirmedStatus::Up, at(840)),
+            change(b, ConfirmedStatus::Down, at(1000)),
+        ];
+        svc.apply_changes(&changes, &targets());
+
+        let report = svc.stats();
+        let domain_a = report.by_domain.iter().find(|s| s.key == a).unwrap();
+        assert_eq!(domain_a.incident_count, 2);
+        assert_eq!(domain_a.total_downtime_secs, 240);
+        assert_eq!(domain_a.mttr_secs, Some(120));
+        assert_eq!(domain_a.mtbf_secs, Some(600));
+
+        let prod = report.by_group.iter().find(|s| s.key == "Prod").unwrap();
+        assert_eq!(prod.incident_count, 3);
+        assert_eq!(prod.open_count, 1);
+        let edge = report.by_group.iter().find(|s| s.key == "Edge").unwrap();
+        assert_eq!(edge.mttr_secs, None);
+        assert_eq!(edge.mtbf_secs, None);
+    }
+}
@@ -0,0 +1,288 @@
+//! 도메인별 지연 시간 기준선과 이상 탐지.
+//! 하루 중 시간대(로컬 0~23시)마다 EWMA 평균·분산을 유지하고, 최종 체크의 지연 시간이 기준선보다
+//! 크게 느리면 level을 `degraded`로 바꾼다. 기준선은 `logs/latency_baselines.json`에 저장되어 재시작 후에도 이어지며,
+//! 처음 보는 url은 저장된 최근 로그(`BASELINE_SEED_DAYS`일)로 초기화한다.
+
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::latency_baseline::{HourBaseline, LatencyBaseline, LatencyBaselineReport};
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Local, Timelike, Utc};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// 체크 결과에 쓰는 level. 응답은 정상이지만 평소보다 느림 (가용성에서는 up)
+pub const DEGRADED_LEVEL: &str = "degraded";
+
+/// 처음 보는 url의 기준선을 만들 때 읽는 과거 로그 일수
+pub const BASELINE_SEED_DAYS: u64 = 14;
+
+/// EWMA 가중치. 시간대마다 하루 한 번씩만 쌓이는 게 아니라 체크 주기마다 쌓이므로 작게
+const ALPHA: f64 = 0.02;
+
+/// 시간대별로 이만큼 쌓여야 점수를 매김
+pub const MIN_BASELINE_SAMPLES: u32 = 20;
+
+/// 이상 점수(표준편차 배수) 기준
+pub const DEGRADED_SCORE: f64 = 3.0;
+
+/// 점수가 높아도 평균과의 차이가 이보다 작으면 무시 (매우 안정적인 대상의 작은 흔들림)
+const MIN_DEVIATION_MS: f64 = 50.0;
+
+/// 표준편차 하한. 분산이 0에 가까울 때 점수가 무한히 커지지 않도록
+const MIN_STDDEV_MS: f64 = 5.0;
+
+fn local_hour(at: DateTime<Utc>) -> usize {
+    at.with_timezone(&Local).hour() as usize
+}
+
+/// 기준선에 반영할 체크: 응답이 정상(`info`·`degraded`)이고 점검 중이 아니며 지연 시간이 있는 것
+fn is_sample(log: &DomainStatusLog) -> bool {
+    matches!(log.level.as_str(), "info" | DEGRADED_LEVEL)
+        && log.latency > 0
+        && log.maintenance.is_none()
+}
+
+fn new_baseline(url: &str) -> LatencyBaseline {
+    LatencyBaseline {
+        url: url.to_string(),
+        hours: vec![HourBaseline::default(); 24],
+        last_latency: None,
+        last_score: None,
+        last_degraded: false,
+        updated_at: None,
+    }
+}
+
+fn stddev(hour: &HourBaseline) -> f64 {
+    hour.variance.sqrt().max(MIN_STDDEV_MS)
+}
+
+/// EWMA 갱신. 처음 몇 개는 단순 평균처럼 빠르게 수렴하도록 가중치를 1/n 이상으로
+fn learn(hour: &mut HourBaseline, latency: u32) {
+    let x = f64::from(latency);
+    hour.samples = hour.samples.saturating_add(1);
+    let alpha = ALPHA.max(1.0 / f64::from(hour.samples));
+    let diff = x - hour.mean_ms;
+    let incr = alpha * diff;
+    hour.mean_ms += incr;
+    hour.variance = (1.0 - alpha) * (hour.variance + diff * incr);
+}
+
+/// 기준선 대비 이상 점수. 체크가 부족하면 `None`
+pub fn score(hour: &HourBaseline, latency: u32) -> Option<f64> {
+    (hour.samples >= MIN_BASELINE_SAMPLES)
+        .then(|| (f64::from(latency) - hour.mean_ms) / stddev(hour))
+}
+
+fn is_degraded(hour: &HourBaseline, latency: u32, score: f64) -> bool {
+    score >= DEGRADED_SCORE && f64::from(latency) - hour.mean_ms >= MIN_DEVIATION_MS
+}
+
+/// 도메인 하나의 현재 시간대 기준선 요약
+pub fn report(
+    domain_id: u32,
+    url: &str,
+    baseline: Option<&LatencyBaseline>,
+    now: DateTime<Utc>,
+) -> LatencyBaselineReport {
+    let hour = local_hour(now);
+    let baseline = baseline.cloned().unwrap_or_else(|| new_baseline(url));
+    let current = baseline.hours.get(hour).cloned().unwrap_or_default();
+    let ready = current.samples >= MIN_BASELINE_SAMPLES;
+    LatencyBaselineReport {
+        domain_id,
+        url: url.to_string(),
+        hour: hour as u32,
+        mean_ms: ready.then_some(current.mean_ms),
+        stddev_ms: ready.then(|| stddev(&current)),
+        samples: current.samples,
+        last_latency: baseline.last_latency,
+        score: baseline.last_score,
+        degraded: baseline.last_degraded,
+        hours: baseline.hours,
+        updated_at: baseline.updated_at,
+    }
+}
+
+pub struct LatencyBaselineService {
+    baselines: Mutex<Vec<LatencyBaseline>>,
+    storage_path: PathBuf,
+}
+
+impl LatencyBaselineService {
+    pub fn new(storage_path: PathBuf) -> Self {
+        let baselines = load_versioned(&storage_path);
+        Self {
+            baselines: Mutex::new(baselines),
+            storage_path,
+        }
+    }
+
+    pub fn get(&self, url: &str) -> Option<LatencyBaseline> {
+        self.baselines
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|b| b.url == url)
+            .cloned()
+    }
+
+    /// 아직 기준선이 없는 url
+    pub fn missing_urls(&self, urls: &[&str]) -> Vec<String> {
+        let baselines = self.baselines.lock().unwrap();
+        urls.iter()
+            .filter(|url| !baselines.iter().any(|b| b.url == **url))
+            .map(|url| (*url).to_string())
+            .collect()
+    }
+
+    /// `urls`의 기준선을 과거 로그(오래된 것부터)로 만듦. 로그가 없어도 빈 기준선을 남겨 다시 읽지 않음
+    pub fn seed(&self, urls: &[String], history: &[DomainStatusLog]) {
+        let mut baselines = self.baselines.lock().unwrap();
+        for url in urls {
+            if baselines.iter().any(|b| &b.url == url) {
+                continue;
+            }
+            let mut baseline = new_baseline(url);
+            for log in history.iter().filter(|l| &l.url == url && is_sample(l)) {
+                learn(&mut baseline.hours[local_hour(log.timestamp)], log.latency);
+            }
+            baselines.push(baseline);
+        }
+        save_versioned(&self.storage_path, &*baselines);
+    }
+
+    /// 최종 체크 결과를 점수화하고 기준선에 반영. 기준선보다 크게 느린 체크는 level을 `degraded`로 바꿈
+    pub fn observe<'a>(&self, logs: impl IntoIterator<Item = &'a mut DomainStatusLog>) {
+        let mut baselines = self.baselines.lock().unwrap();
+        let mut changed = false;
+        for log in logs {
+            if !is_sample(log) {
+                continue;
+            }
+            let index = if let Some(i) = baselines.iter().position(|b| b.url == log.url) {
+                i
+            } else {
+                baselines.push(new_baseline(&log.url));
+                baselines.len() - 1
+            };
+            let baseline = &mut baselines[index];
+            let hour_index = local_hour(log.timestamp);
+            let hour = &mut baseline.hours[hour_index];
+            let score = score(hour, log.latency);
+            let degraded = score.filter(|s| is_degraded(hour, log.latency, *s));
+            if let Some(s) = degraded {
+                log.level = DEGRADED_LEVEL.to_string();
+                log.error_message = Some(format!(
+                    "Latency {}ms is {s:.1}σ above the {hour_index:02}:00 baseline ({:.0}ms ± {:.0}ms)",
+                    log.latency,
+                    hour.mean_ms,
+                    stddev(hour)
+                ));
+            }
+            learn(hour, log.latency);
+            baseline.last_latency = Some(log.latency);
+            baseline.last_score = score;
+            baseline.last_degraded = degraded.is_some();
+            baseline.updated_at = Some(log.timestamp);
+            changed = true;
+        }
+        if changed {
+            save_versioned(&self.storage_path, &*baselines);
+        }
+    }
+
+    /// 감시 대상에서 빠진 url의 기준선 정리
+    pub fn retain_urls(&self, urls: &[&str]) {
+        let mut baselines = self.baselines.lock().unwrap();
+        let before = baselines.len();
+        baselines.retain(|b| urls.contains(&b.url.as_str()));
+        if baselines.len() != before {
+            save_versioned(&self.storage_path, &*baselines);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn log(url: &str, level: &str, latency: u32, timestamp: DateTime<Utc>) -> DomainStatusLog {
+        DomainStatusLog {
+            url: url.to_string(),
+            status: "200 OK".to_string(),
+            level: level.to_string(),
+            latency,
+            ok: level != "error",
+            group: "Default".to_string(),
+            timestamp,
+            error_message: Some("Operation successful".to_string()),
+            failures: Vec::new(),
+            attempt: 1,
+            timing: None,
+            redirects: Vec::new(),
+            maintenance: None,
+        }
+    }
+
+    #[test]
+    fn test_ewma_converges_to_mean_and_variance() {
+        let mut hour = HourBaseline::default();
+        for i in 0..400 {
+            learn(&mut hour, if i % 2 == 0 { 90 } else { 110 });
+        }
+        assert!((hour.mean_ms - 100.0).abs() < 2.0, "{hour:?}");
+        assert!((hour.variance.sqrt() - 10.0).abs() < 2.0, "{hour:?}");
+        assert_eq!(score(&HourBaseline::default(), 100), None);
+    }
+
+    #[test]
+    fn test_slow_check_is_degraded_and_baseline_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("latency_baselines.json");
+        let service = LatencyBaselineService::new(path.clone());
+        let url = "https://a.example";
+        let t = Utc::now();
+        // 같은 시간대의 과거 로그로 초기화. error·warning은 반영하지 않음
+        let mut history: Vec<DomainStatusLog> = (0..30)
+            .map(|i| log(url, "info", 100 + (i % 5) * 5, t - Duration::days(1)))
+            .collect();
+        history.push(log(url, "error", 5000, t - Duration::days(1)));
+        history.push(log(url, "warning", 5000, t - Duration::days(1)));
+        assert_eq!(service.missing_urls(&[url]), vec![url.to_string()]);
+        service.seed(&[url.to_string()], &history);
+        assert!(service.missing_urls(&[url]).is_empty());
+
+        let mut normal = log(url, "info", 115, t);
+        let mut slow = log(url, "info", 400, t);
+        let mut failed = log(url, "error", 0, t);
+        service.observe([&mut normal, &mut slow, &mut failed]);
+        assert_eq!(normal.level, "info");
+        assert_eq!(slow.level, DEGRADED_LEVEL);
+        assert!(slow.error_message.unwrap().contains("baseline"));
+        assert_eq!(failed.level, "error");
+
+        let reloaded = LatencyBaselineService::new(path);
+        let baseline = reloaded.get(url).unwrap();
+        assert_eq!(baseline.last_latency, Some(400));
+        let summary = report(1, url, Some(&baseline), t);
+        assert!(summary.degraded);
+        assert_eq!(summary.samples, 32);
+        assert!(summary.score.unwrap() >= DEGRADED_SCORE);
+
+        // 평균과 차이가 작으면 점수가 높아도 degraded 아님
+        let stable = "https://b.example";
+        let history: Vec<DomainStatusLog> = (0..30)
+            .map(|_| log(stable, "info", 20, t - Duration::days(1)))
+            .collect();
+        reloaded.seed(&[stable.to_string()], &history);
+        let mut jitter = log(stable, "info", 60, t);
+        reloaded.observe([&mut jitter]);
+        assert_eq!(jitter.level, "info");
+        assert!(reloaded.get(stable).unwrap().last_score.unwrap() >= DEGRADED_SCORE);
+
+        reloaded.retain_urls(&[stable]);
+        assert!(reloaded.get(url).is_none());
+    }
+}
@@ -81,6 +81,9 @@ fn parse_dns_server(s: &str) -> Option<(IpAddr, u16)> {
 use crate::model::api_log::ApiLogEntry;
 use crate::service::api_log_service::ApiLogService;
 use crate::service::ca_service::CaService;
+use crate::service::heartbeat_service::{self, HeartbeatService, HEARTBEAT_PATH_PREFIX};
+use crate::service::metrics::{MetricsFormat, MetricsService, ProxyRoute, METRICS_PATH};
+use crate::service::status_page::{self, StatusPageService, STATUS_PAGE_PATH};
 
 pub struct ProxyState {
 
@@ -92,9 +95,21 @@ pub struct ProxyState {
     pub api_logging_map: Arc<RwLock<HashMap<String, (bool, bool)>>>,
     pub api_log_service: Arc<ApiLogService>,
     pub ca_service: Arc<CaService>,
+    reserved: ReservedPathServices,
     pub reqwest_client: reqwest::Client,
 }
 
+/// 예약 경로(`/.watchtower/...`)와 프록시 지표에 쓰는 앱 서비스. 프록시 리스너마다 같은 인스턴스를 공유.
+#[derive(Clone)]
+pub struct ReservedPathServices {
+    /// `/.watchtower/heartbeat/{token}` 핑 수신
+    pub heartbeats: Arc<HeartbeatService>,
+    /// `/.watchtower/status` 공개 상태 페이지
+    pub status_page: Arc<StatusPageService>,
+    /// `/.watchtower/metrics` 지표. 프록시 요청 카운터도 여기에 기록
+    pub metrics: Arc<MetricsService>,
+}
+
 impl ProxyState {
     fn new(
         route_service: Arc<LocalRouteService>,
@@ -103,6 +118,7 @@ impl ProxyState {
         api_logging_map: Arc<RwLock<HashMap<String, (bool, bool)>>>,
         api_log_service: Arc<ApiLogService>,
         ca_service: Arc<CaService>,
+        reserved: ReservedPathServices,
     ) -> Self {
 
         let resolver = dns_server
@@ -127,6 +143,7 @@ impl ProxyState {
             api_logging_map,
             api_log_service,
             ca_service,
+            reserved,
             reqwest_client: reqwest::Client::builder()
                 .no_proxy()
                 .redirect(reqwest::redirect::Policy::none())
@@ -299,6 +316,23 @@ fn resolve_target(
     (target, Some(host.to_string()), None, None)
 }
 
+/// Route health check: where the proxy would send a `/` request for `route`'s domain, decided by
+/// `resolve_target` exactly as in `proxy_handler_inner`. `routes` is the proxy's route list
+/// (enabled routes, or empty when local routing is off). Returns (Host header, local target);
+/// target is `None` when the request would pass through.
+pub(crate) fn route_probe_target(
+    route: &LocalRoute,
+    routes: &[LocalRoute],
+) -> (String, Option<(String, u16)>) {
+    let host = route_domain_to_host(route.domain.as_str()).to_string();
+    let scheme = route_domain_scheme(route.domain.as_str()).unwrap_or("http");
+    let Ok(uri) = Uri::try_from(format!("{scheme}://{host}/")) else {
+        return (host, None);
+    };
+    let (_, _, _, local_origin) = resolve_target(&uri, Some(host.as_str()), routes, scheme);
+    (host, local_origin.map(|(h, p, _)| (h, p)))
+}
+
 /// For CONNECT host:port, if host matches a local route return `Some((target_host`, `target_port`)).
 /// CONNECT is always HTTPS; prefer route whose domain is "https://..." when multiple match.
 fn resolve_connect_target(host: &str, routes: &[LocalRoute]) -> Option<(String, u16)> {
@@ -662,9 +696,12 @@ async fn handle_connect_tunnel(
         return;
     }
     proxy_log!("-> CONNECT pass-through (upstream)");
+    let metrics = Arc::clone(&state.reserved.metrics);
+    metrics.record_request(&key, ProxyRoute::PassThrough, false);
     let mut upstream = match connect_for_connect(&host, port, state.resolver.as_ref()).await {
         Ok(s) => s,
         Err(_e) => {
+            metrics.record_upstream_error(&key);
             let _ = client
                 .write_all(
                     b"HTTP/1.1 502 Bad Gateway\r\nConnection: close\r\nContent-Length: 0\r\n\r\n",
@@ -688,8 +725,9 @@ async fn handle_connect_tunnel(
     let (mut up_r, mut up_w) = upstream.into_split();
     let t1 = tokio::spawn(async move { tokio::io::copy(&mut client_r, &mut up_w).await });
     let t2 = tokio::spawn(async move { tokio::io::copy(&mut up_r, &mut client_w).await });
-    let _ = t1.await;
-    let _ = t2.await;
+    let sent = t1.await.ok().and_then(Result::ok).unwrap_or(0);
+    let received = t2.await.ok().and_then(Result::ok).unwrap_or(0);
+    metrics.add_bytes(&key, sent, received);
 }
 
 /// Reserved path prefix: proxy serves setup page and assets (no forward to local route).
@@ -700,7 +738,11 @@ fn build_pac_js(forward_port: u16) -> String {
     format!("function FindProxyForURL(url, host) {{ if (host === 'localhost' || host === '127.0.0.1') return 'DIRECT'; return \"PROXY 127.0.0.1:{forward_port}; DIRECT\"; }}")
 }
 
-async fn serve_watchtower_reserved_path(state: Arc<ProxyState>, path: &str) -> Response {
+async fn serve_watchtower_reserved_path(
+    state: Arc<ProxyState>,
+    path: &str,
+    headers: &axum::http::HeaderMap,
+) -> Response {
     if path == "/.watchtower/proxy.pac" || path.starts_with("/.watchtower/proxy.pac") {
         let Some(port) = state.forward_proxy_port else {
             return (StatusCode::NOT_FOUND, "Forward proxy port not configured").into_response();
@@ -756,6 +798,34 @@ async fn serve_watchtower_reserved_path(state: Arc<ProxyState>, path: &str) -> R
         }
         return serve_cert_pem(Arc::clone(&state), host).into_response();
     }
+    if let Some(rest) = path.strip_prefix(HEARTBEAT_PATH_PREFIX) {
+        let Some((token, kind)) = heartbeat_service::parse_ping_path(rest) else {
+            return (StatusCode::NOT_FOUND, "Unknown heartbeat path").into_response();
+        };
+        if !state
+            .reserved
+            .heartbeats
+            .ping(token, kind, chrono::Utc::now())
+        {
+            return (StatusCode::NOT_FOUND, "Unknown heartbeat token").into_response();
+        }
+        return (StatusCode::OK, "OK").into_response();
+    }
+    if path == METRICS_PATH {
+        let format =
+            MetricsFormat::from_accept(headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()));
+        return (
+            [(
+                CONTENT_TYPE,
+                HeaderValue::from_static(format.content_type()),
+            )],
+            state.reserved.metrics.render(format),
+        )
+            .into_response();
+    }
+    if let Some(rest) = path.strip_prefix(STATUS_PAGE_PATH) {
+        return serve_status_page(&state, rest);
+    }
     if path == "/.watchtower/ca.crt" || path.starts_with("/.watchtower/ca.crt") {
         let pem = state.ca_service.ca_cert_pem();
         return (
@@ -777,6 +847,23 @@ async fn serve_watchtower_reserved_path(state: Arc<ProxyState>, path: &str) -> R
     (StatusCode::NOT_FOUND, "Not found").into_response()
 }
 
+/// `/.watchtower/status`(HTML)와 `/.watchtower/status.json`. 꺼져 있거나 아직 만들지 않았으면 404.
+fn serve_status_page(state: &ProxyState, rest: &str) -> Response {
+    let json = match rest.trim_end_matches('/') {
+        "" | "/index.html" => false,
+        ".json" | "/status.json" => true,
+        _ => return (StatusCode::NOT_FOUND, "Not found").into_response(),
+    };
+    let Some(page) = state.reserved.status_page.latest() else {
+        return (StatusCode::NOT_FOUND, "Status page is not enabled").into_response();
+    };
+    let no_cache = (header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    if json {
+        return ([no_cache], axum::Json(page)).into_response();
+    }
+    ([no_cache], Html(status_page::render_html(&page))).into_response()
+}
+
 /// Return PEM for download. Uses the same cert as TLS for this host (from shared cache) so installing it trusts the server.
 fn serve_cert_pem(state: Arc<ProxyState>, host: &str) -> Response {
     let Some((_, pem)) = state.cert_cache.get_or_create(host) else {
@@ -850,7 +937,7 @@ async fn proxy_handler_inner(State(state): State<Arc<ProxyState>>, axum::Extensi
 
     if path.starts_with(WATCHTOWER_PATH_PREFIX) {
         proxy_log!("-> watchtower reserved: {}", path);
-        return serve_watchtower_reserved_path(state, path).await;
+        return serve_watchtower_reserved_path(state, path, req.headers()).await;
     }
 
     let host_header = req.headers().get("host").and_then(|v| v.to_str().ok()).map(|s| s.to_string());
@@ -887,6 +974,13 @@ async fn proxy_handler_inner(State(state): State<Arc<ProxyState>>, axum::Extensi
         
         let (logging_enabled, body_enabled) = logging_config.unwrap_or((false, false));
         let _is_local = local_origin.is_some();
+        let metrics = Arc::clone(&state.reserved.metrics);
+        let route = if local_origin.is_some() {
+            ProxyRoute::LocalRoute
+        } else {
+            ProxyRoute::PassThrough
+        };
+        metrics.record_request(&host_key, route, logging_enabled);
 
         // Fix Scheme for Intercepted HTTPS Requests (API Logging)
         // If we intercepted a CONNECT request, `proxy_handler` receives origin-form URI.
@@ -909,10 +1003,15 @@ async fn proxy_handler_inner(State(state): State<Arc<ProxyState>>, axum::Extensi
             );
 
             if has_body {
+                let (sent_metrics, sent_host) = (Arc::clone(&metrics), host_key.clone());
                 let body_stream = TryStreamExt::map_err(
                     TryStreamExt::map_ok(
                         http_body_util::BodyStream::new(body),
-                        |frame| frame.into_data().unwrap_or_default()
+                        move |frame| {
+                            let data = frame.into_data().unwrap_or_default();
+                            sent_metrics.add_bytes(&sent_host, data.len() as u64, 0);
+                            data
+                        }
                     ),
                     |e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>
                 );
@@ -957,13 +1056,18 @@ async fn proxy_handler_inner(State(state): State<Arc<ProxyState>>, axum::Extensi
                             }
                         }
                     }
-                    let stream = res.bytes_stream();
+                    let stream = res.bytes_stream().inspect_ok(move |chunk| {
+                        metrics.add_bytes(&host_key, 0, chunk.len() as u64);
+                    });
                     let body = Body::from_stream(stream.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
                     builder.body(body).unwrap_or_else(|e| {
                         (StatusCode::BAD_GATEWAY, format!("Proxy error: {e}")).into_response()
                     })
                  }
-                 Err(e) => (StatusCode::BAD_GATEWAY, format!("Proxy error: {e}")).into_response(),
+                 Err(e) => {
+                    metrics.record_upstream_error(&host_key);
+                    (StatusCode::BAD_GATEWAY, format!("Proxy error: {e}")).into_response()
+                 }
             }
         } else {
             // Read Request Body
@@ -1026,6 +1130,7 @@ async fn proxy_handler_inner(State(state): State<Arc<ProxyState>>, axum::Extensi
                 Ok(res) => res,
                 Err(e) => {
                     proxy_log!("   reqwest error: {}", e);
+                    metrics.record_upstream_error(&host_key);
                     return (StatusCode::BAD_GATEWAY, format!("Proxy error: {e}")).into_response();
                 }
             };
@@ -1035,8 +1140,12 @@ async fn proxy_handler_inner(State(state): State<Arc<ProxyState>>, axum::Extensi
             let res_headers = response.headers().clone();
             let res_bytes = match response.bytes().await {
                  Ok(b) => b,
-                 Err(e) => return (StatusCode::BAD_GATEWAY, format!("Failed to read response body: {e}")).into_response(),
+                 Err(e) => {
+                    metrics.record_upstream_error(&host_key);
+                    return (StatusCode::BAD_GATEWAY, format!("Failed to read response body: {e}")).into_response();
+                 }
             };
+            metrics.add_bytes(&host_key, req_bytes.len() as u64, res_bytes.len() as u64);
             
             let res_body_str = if body_enabled {
                 String::from_utf8(res_bytes.to_vec()).ok()
@@ -1099,6 +1208,7 @@ pub async fn run_proxy(
     api_logging_map: Arc<RwLock<HashMap<String, (bool, bool)>>>,
     api_log_service: Arc<ApiLogService>,
     ca_service: Arc<CaService>,
+    reserved: ReservedPathServices,
 ) -> std::io::Result<JoinHandle<()>> {
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
     let listener = tokio::net::TcpListener::bind(addr).await?;
@@ -1109,6 +1219,7 @@ pub async fn run_proxy(
         api_logging_map,
         api_log_service,
         ca_service,
+        reserved,
     ));
     let app = proxy_app(Arc::clone(&state), "http"); let handle = tokio::spawn(async move {
         loop {
@@ -1146,6 +1257,7 @@ pub async fn run_proxy(
 /// Reverse HTTP listener: no system proxy. Client connects directly (e.g. hosts 127.0.0.1 dev.modetour.local, then http://dev.modetour.local:port).
 /// Requests are origin-form (GET /path); routing by Host header.
 /// `forward_proxy_port`: port of the main (forward) proxy, for PAC generation.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_reverse_proxy_http(
     port: u16,
     route_service: Arc<LocalRouteService>,
@@ -1154,6 +1266,7 @@ pub async fn run_reverse_proxy_http(
     api_logging_map: Arc<RwLock<HashMap<String, (bool, bool)>>>,
     api_log_service: Arc<ApiLogService>,
     ca_service: Arc<CaService>,
+    reserved: ReservedPathServices,
 ) -> std::io::Result<JoinHandle<()>> {
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
     let listener = tokio::net::TcpListener::bind(addr).await?;
@@ -1164,6 +1277,7 @@ pub async fn run_reverse_proxy_http(
         api_logging_map,
         api_log_service,
         ca_service,
+        reserved,
     ));
     let app = proxy_app(Arc::clone(&state), "http"); let handle = tokio::spawn(async move {
         loop {
@@ -1184,6 +1298,7 @@ pub async fn run_reverse_proxy_http(
 
 /// Reverse HTTPS listener: TLS termination by Host (SNI), then forward by Host. Use https://dev.modetour.local:port with hosts.
 /// `forward_proxy_port`: port of the main (forward) proxy, for PAC generation.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_reverse_proxy_https(
     port: u16,
     route_service: Arc<LocalRouteService>,
@@ -1192,6 +1307,7 @@ pub async fn run_reverse_proxy_https(
     api_logging_map: Arc<RwLock<HashMap<String, (bool, bool)>>>,
     api_log_service: Arc<ApiLogService>,
     ca_service: Arc<CaService>,
+    reserved: ReservedPathServices,
 ) -> std::io::Result<JoinHandle<()>> {
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
     let listener = tokio::net::TcpListener::bind(addr).await?;
@@ -1202,6 +1318,7 @@ pub async fn run_reverse_proxy_https(
         api_logging_map,
         api_log_service,
         ca_service,
+        reserved,
     ));
     let app = proxy_app(Arc::clone(&state), "https"); let config = rustls::ServerConfig::builder()
         .with_no_client_auth()
@@ -1378,7 +1495,10 @@ mod tests {
         use axum::http::{Request, StatusCode};
         use axum::body::Body;
         use crate::service::api_log_service::ApiLogService;
-        use crate::service::local_proxy::{ProxyState, proxy_handler};
+        use crate::service::heartbeat_service::HeartbeatService;
+        use crate::service::local_proxy::{ProxyState, ReservedPathServices, proxy_handler};
+        use crate::service::metrics::MetricsService;
+        use crate::service::status_page::StatusPageService;
         use crate::service::local_route_service::LocalRouteService;
         use tempfile::tempdir;
 
@@ -1413,6 +1533,7 @@ mod tests {
         let api_logging_map = Arc::new(RwLock::new(logging_map));
 
         let ca_service = Arc::new(CaService::new(temp_dir.path()).unwrap());
+        let metrics = Arc::new(MetricsService::new());
 
         let state = Arc::new(ProxyState::new(
             route_service,
@@ -1421,6 +1542,16 @@ mod tests {
             api_logging_map,
             api_log_service.clone(),
             ca_service,
+            ReservedPathServices {
+                heartbeats: Arc::new(HeartbeatService::new(
+                    temp_dir.path().join("heartbeats.json"),
+                )),
+                status_page: Arc::new(StatusPageService::new(
+                    temp_dir.path().join("status_page.json"),
+                    temp_dir.path().join("status_page"),
+                )),
+                metrics: Arc::clone(&metrics),
+            },
         ));
 
         // 3. Perform request
@@ -1437,7 +1568,7 @@ mod tests {
         // 4. Verify log
         let dates = api_log_service.list_dates();
         assert!(!dates.is_empty(), "Log date should be created");
-        let logs = api_log_service.get_logs(&dates[0], None, None, None);
+        let logs = api_log_service.get_logs(&dates[0], None, None, None, false);
         assert!(!logs.is_empty(), "Log entry should be saved");
         let entry = &logs[0];
         assert_eq!(entry.method, "GET");
@@ -1446,5 +1577,150 @@ mod tests {
         assert_eq!(entry.host, "api.test.local");
         assert_eq!(entry.path, "/foo");
         assert_eq!(entry.status_code, Some(200));
+
+        // 5. Verify proxy counters
+        let counters = metrics.host_counters("api.test.local").unwrap();
+        assert_eq!(
+            (counters.requests, counters.local_route, counters.logged),
+            (1, 1, 1)
+        );
+        assert_eq!(counters.bytes_received, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reserved_heartbeat_and_status_paths() {
+        use crate::service::api_log_service::ApiLogService;
+        use crate::service::heartbeat_service::HeartbeatService;
+        use crate::service::local_proxy::{proxy_handler, ProxyState, ReservedPathServices};
+        use crate::service::local_route_service::LocalRouteService;
+        use crate::service::metrics::MetricsService;
+        use crate::service::status_page::StatusPageService;
+        use axum::body::Body;
+        use axum::extract::State;
+        use axum::http::{Request, StatusCode};
+        use std::collections::HashMap;
+        use std::sync::{Arc, RwLock};
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let heartbeats = Arc::new(HeartbeatService::new(
+            temp_dir.path().join("heartbeats.json"),
+        ));
+        heartbeats.sync_tokens(&["tok"], chrono::Utc::now());
+        let status_page = Arc::new(StatusPageService::new(
+            temp_dir.path().join("status_page.json"),
+            temp_dir.path().join("status_page"),
+        ));
+        let state = Arc::new(ProxyState::new(
+            Arc::new(LocalRouteService::new(temp_dir.path().join("routes.json"))),
+            None,
+            None,
+            Arc::new(RwLock::new(HashMap::new())),
+            Arc::new(ApiLogService::new(temp_dir.path().to_path_buf())),
+            Arc::new(CaService::new(temp_dir.path()).unwrap()),
+            ReservedPathServices {
+                heartbeats: Arc::clone(&heartbeats),
+                status_page: Arc::clone(&status_page),
+                metrics: Arc::new(MetricsService::new()),
+            },
+        ));
+        let ping = |path: &str| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("http://127.0.0.1{path}"))
+                .header("host", "127.0.0.1")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let res = proxy_handler(
+            State(Arc::clone(&state)),
+            axum::Extension("http"),
+            ping("/.watchtower/heartbeat/tok/start"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(heartbeats.state("tok").unwrap().running_since.is_some());
+        let res = proxy_handler(
+            State(Arc::clone(&state)),
+            axum::Extension("http"),
+            ping("/.watchtower/heartbeat/tok"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(heartbeats.state("tok").unwrap().runs.len(), 1);
+        let res = proxy_handler(
+            State(Arc::clone(&state)),
+            axum::Extension("http"),
+            ping("/.watchtower/heartbeat/other"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+        // 상태 페이지는 만들기 전(꺼짐)에는 404
+        let res = proxy_handler(
+            State(Arc::clone(&state)),
+            axum::Extension("http"),
+            ping("/.watchtower/status"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        let config = crate::model::status_page::StatusPageConfig {
+            enabled: true,
+            title: "Acme Status".to_string(),
+            ..Default::default()
+        };
+        status_page.set_config(config.clone()).unwrap();
+        status_page
+            .publish(crate::service::status_page::build_page(
+                &config,
+                &crate::service::status_page::StatusInputs {
+                    domains: &[],
+                    groups: &[],
+                    states: &[],
+                    latest: &[],
+                    in_maintenance: &std::collections::HashSet::new(),
+                    days: &[],
+                    daily: &[],
+                    incidents: &[],
+                },
+                chrono::Utc::now(),
+            ))
+            .unwrap();
+        let res = proxy_handler(
+            State(Arc::clone(&state)),
+            axum::Extension("http"),
+            ping("/.watchtower/status/"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("Acme Status"));
+        let res = proxy_handler(
+            State(Arc::clone(&state)),
+            axum::Extension("http"),
+            ping("/.watchtower/status.json"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], "operational");
+
+        let res = proxy_handler(
+            State(state),
+            axum::Extension("http"),
+            ping("/.watchtower/metrics"),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers()[axum::http::header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain"));
     }
 }
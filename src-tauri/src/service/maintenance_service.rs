@@ -0,0 +1,434 @@
+//! 점검 시간(maintenance window) 저장과 활성 여부 판정.
+//! `DomainMonitorService`가 체크마다 도메인별 활성 점검 시간을 찾아 로그에 표시하고, 확정 상태·장애·알림에서 뺀다.
+
+use crate::model::maintenance::{
+    ActiveMaintenance, MaintenanceMark, MaintenanceSchedule, MaintenanceWindow,
+};
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// 반복 점검 시간의 최대 길이 (분). 활성 판정 시 이만큼 거슬러 올라가며 시작 시각을 찾음
+pub const MAX_DURATION_MINS: u32 = 7 * 24 * 60;
+
+/// cron 필드 하나. 허용 값의 비트 집합
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CronField {
+    bits: u64,
+    /// `*`로 시작하는지 (`*/n` 포함). 일·요일 조합 규칙에 필요
+    any: bool,
+}
+
+impl CronField {
+    fn contains(self, value: u32) -> bool {
+        self.bits & (1 << value) != 0
+    }
+}
+
+/// 5필드 cron 식 (분 시 일 월 요일). `*`, 목록(`,`), 범위(`-`), 간격(`/`) 지원. 요일 0·7 = 일요일
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CronExpr {
+    minute: CronField,
+    hour: CronField,
+    day: CronField,
+    month: CronField,
+    weekday: CronField,
+}
+
+fn parse_field(text: &str, min: u32, max: u32) -> Result<CronField, String> {
+    let mut bits = 0u64;
+    for part in text.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| format!("잘못된 간격: {part}"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            let a: u32 = a.parse().map_err(|_| format!("잘못된 값: {part}"))?;
+            let b: u32 = b.parse().map_err(|_| format!("잘못된 값: {part}"))?;
+            (a, b)
+        } else {
+            let value: u32 = range.parse().map_err(|_| format!("잘못된 값: {part}"))?;
+            // `5/15`는 5부터 끝까지 15 간격
+            (value, if step > 1 { max } else { value })
+        };
+        if start < min || end > max || start > end {
+            return Err(format!("범위를 벗어난 값: {part} ({min}-{max})"));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(CronField {
+        bits,
+        any: text.starts_with('*'),
+    })
+}
+
+impl CronExpr {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!(
+                "cron 식은 5개 필드(분 시 일 월 요일)여야 합니다: {expression}"
+            ));
+        };
+        let mut weekday = parse_field(weekday, 0, 7)?;
+        if weekday.contains(7) {
+            weekday.bits |= 1;
+        }
+        Ok(Self {
+            minute: parse_field(minute, 0, 59)?,
+            hour: parse_field(hour, 0, 23)?,
+            day: parse_field(day, 1, 31)?,
+            month: parse_field(month, 1, 12)?,
+            weekday,
+        })
+    }
+
+    /// 분 단위 시각이 식에 맞는지. 일·요일이 둘 다 지정되면 둘 중 하나만 맞아도 됨 (표준 cron 규칙)
+    pub fn matches(&self, at: NaiveDateTime) -> bool {
+        let day = self.day.contains(at.day());
+        let weekday = self.weekday.contains(at.weekday().num_days_from_sunday());
+        let day_ok = match (self.day.any, self.weekday.any) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        };
+        self.minute.contains(at.minute())
+            && self.hour.contains(at.hour())
+            && self.month.contains(at.month())
+            && day_ok
+    }
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M")
+        .map_err(|_| format!("시작 시각은 HH:MM 형식이어야 합니다: {value}"))
+}
+
+fn check_duration(duration_mins: u32) -> Result<(), String> {
+    if duration_mins == 0 || duration_mins > MAX_DURATION_MINS {
+        return Err(format!(
+            "점검 시간 길이는 1~{MAX_DURATION_MINS}분이어야 합니다."
+        ));
+    }
+    Ok(())
+}
+
+/// 저장 전 일정 검사
+pub fn validate(window: &MaintenanceWindow) -> Result<(), String> {
+    match &window.schedule {
+        MaintenanceSchedule::Once { start, end } => {
+            if end <= start {
+                return Err(format!(
+                    "'{}': 종료 시각이 시작 시각보다 빠릅니다.",
+                    window.name
+                ));
+            }
+        }
+        MaintenanceSchedule::Weekly {
+            days,
+            start_time,
+            duration_mins,
+        } => {
+            if days.is_empty() || days.iter().any(|d| *d > 6) {
+                return Err(format!(
+                    "'{}': 요일은 0(일)~6(토)이어야 합니다.",
+                    window.name
+                ));
+            }
+            parse_time(start_time)?;
+            check_duration(*duration_mins)?;
+        }
+        MaintenanceSchedule::Cron {
+            expression,
+            duration_mins,
+        } => {
+            CronExpr::parse(expression)?;
+            check_duration(*duration_mins)?;
+        }
+    }
+    Ok(())
+}
+
+fn local_to_utc(at: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&at)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// `now`가 일정 안이면 이번 회차의 종료 시각
+pub fn active_until(schedule: &MaintenanceSchedule, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let local_now = now.with_timezone(&Local).naive_local();
+    match schedule {
+        MaintenanceSchedule::Once { start, end } => (*start <= now && now < *end).then_some(*end),
+        MaintenanceSchedule::Weekly {
+            days,
+            start_time,
+            duration_mins,
+        } => {
+            let at = parse_time(start_time).ok()?;
+            let duration = Duration::minutes(i64::from(*duration_mins));
+            // 길이가 하루를 넘을 수 있으므로 그만큼 이전 날짜의 회차까지 확인
+            let back_days = i64::from(*duration_mins / (24 * 60)) + 1;
+            (0..=back_days)
+                .filter_map(|n| local_now.date().checked_sub_signed(Duration::days(n)))
+                .filter(|date| {
+                    let weekday = date.weekday().num_days_from_sunday();
+                    days.iter().any(|d| u32::from(*d) == weekday)
+                })
+                .filter_map(|date| local_to_utc(date.and_time(at)))
+                .filter(|start| *start <= now && now < *start + duration)
+                .map(|start| start + duration)
+                .max()
+        }
+        MaintenanceSchedule::Cron {
+            expression,
+            duration_mins,
+        } => {
+            let expr = CronExpr::parse(expression).ok()?;
+            let minute = local_now.with_second(0)?.with_nanosecond(0)?;
+            let duration = Duration::minutes(i64::from(*duration_mins));
+            // 가장 최근 시작 시각이 가장 늦게 끝남
+            (0..i64::from(*duration_mins))
+                .map(|n| minute - Duration::minutes(n))
+                .find(|at| expr.matches(*at))
+                .and_then(local_to_utc)
+                .map(|start| start + duration)
+                .filter(|end| now < *end)
+        }
+    }
+}
+
+fn next_id(list: &[MaintenanceWindow]) -> u32 {
+    list.iter().map(|w| w.id).max().unwrap_or(0) + 1
+}
+
+/// 점검 시간 목록. 데이터 폴더의 `maintenance_windows.json` (versioned)
+pub struct MaintenanceService {
+    windows: Mutex<Vec<MaintenanceWindow>>,
+    storage_path: PathBuf,
+}
+
+impl MaintenanceService {
+    pub fn new(storage_path: PathBuf) -> Self {
+        let windows = load_versioned(&storage_path);
+        Self {
+            windows: Mutex::new(windows),
+            storage_path,
+        }
+    }
+
+    pub fn get_all(&self) -> Vec<MaintenanceWindow> {
+        self.windows.lock().unwrap().clone()
+    }
+
+    /// 전체 교체. id가 0인 항목은 새 id를 받음. 일정이 잘못되면 저장하지 않고 사유를 반환.
+    pub fn set_all(
+        &self,
+        mut windows: Vec<MaintenanceWindow>,
+    ) -> Result<Vec<MaintenanceWindow>, String> {
+        for window in &windows {
+            validate(window)?;
+        }
+        for i in 0..windows.len() {
+            if windows[i].id == 0 {
+                windows[i].id = next_id(&windows);
+            }
+        }
+        save_versioned(&self.storage_path, &windows);
+        self.windows.lock().unwrap().clone_from(&windows);
+        Ok(windows)
+    }
+
+    /// 설정 가져오기용 (검사 없이 그대로 교체)
+    pub fn replace_all(&self, windows: Vec<MaintenanceWindow>) {
+        save_versioned(&self.storage_path, &windows);
+        *self.windows.lock().unwrap() = windows;
+    }
+
+    /// 도메인에 지금 적용되는 점검 시간 (여러 개면 가장 늦게 끝나는 것)과 종료 시각
+    fn active_window(
+        &self,
+        domain_id: u32,
+        group_ids: &[u32],
+        now: DateTime<Utc>,
+    ) -> Option<(MaintenanceWindow, DateTime<Utc>)> {
+        self.windows
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|w| {
+                w.enabled
+                    && (w.domain_ids.contains(&domain_id)
+                        || w.group_ids.iter().any(|g| group_ids.contains(g)))
+            })
+            .filter_map(|w| active_until(&w.schedule, now).map(|end| (w.clone(), end)))
+            .max_by_key(|(_, end)| *end)
+    }
+
+    /// 체크 로그에 붙일 표시
+    pub fn mark_for(
+        &self,
+        domain_id: u32,
+        group_ids: &[u32],
+        now: DateTime<Utc>,
+    ) -> Option<MaintenanceMark> {
+        self.active_window(domain_id, group_ids, now)
+            .map(|(window, _)| MaintenanceMark {
+                window_id: window.id,
+                exclude_from_uptime: window.exclude_from_uptime,
+            })
+    }
+
+    /// `domains`((`domain_id`, 그룹 id 목록)) 중 지금 점검 중인 도메인
+    pub fn active(
+        &self,
+        domains: &[(u32, Vec<u32>)],
+        now: DateTime<Utc>,
+    ) -> Vec<ActiveMaintenance> {
+        domains
+            .iter()
+            .filter_map(|(domain_id, group_ids)| {
+                self.active_window(*domain_id, group_ids, now)
+                    .map(|(window, ends_at)| ActiveMaintenance {
+                        domain_id: *domain_id,
+                        window_id: window.id,
+                        window_name: window.name,
+                        ends_at,
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        let at = NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap();
+        local_to_utc(at).unwrap()
+    }
+
+    #[test]
+    fn test_cron_parse_and_match() {
+        let expr = CronExpr::parse("*/15 2-4 * * 1,7").unwrap();
+        // 2026-10-18은 일요일, 2026-10-19는 월요일
+        let at = |d, h, m| {
+            NaiveDate::from_ymd_opt(2026, 10, d)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+        assert!(expr.matches(at(18, 2, 30)));
+        assert!(expr.matches(at(19, 4, 45)));
+        assert!(!expr.matches(at(19, 4, 40)));
+        assert!(!expr.matches(at(20, 3, 0)));
+        // 일·요일이 둘 다 지정되면 OR
+        let either = CronExpr::parse("0 0 1 * 1").unwrap();
+        assert!(either.matches(at(19, 0, 0)));
+        assert!(CronExpr::parse("0 0 * *").is_err());
+        assert!(CronExpr::parse("60 * * * *").is_err());
+        assert!(CronExpr::parse("*/0 * * * *").is_err());
+    }
+
+    #[test]
+    fn test_active_until() {
+        let weekly = MaintenanceSchedule::Weekly {
+            days: vec![0],
+            start_time: "23:00".to_string(),
+            duration_mins: 120,
+        };
+        // 일요일 23:00 ~ 월요일 01:00
+        assert_eq!(
+            active_until(&weekly, local(2026, 10, 19, 0, 30)),
+            Some(local(2026, 10, 19, 1, 0))
+        );
+        assert_eq!(active_until(&weekly, local(2026, 10, 19, 1, 0)), None);
+        assert_eq!(active_until(&weekly, local(2026, 10, 18, 22, 59)), None);
+
+        let cron = MaintenanceSchedule::Cron {
+            expression: "0 3 * * *".to_string(),
+            duration_mins: 30,
+        };
+        assert_eq!(
+            active_until(&cron, local(2026, 10, 20, 3, 29)),
+            Some(local(2026, 10, 20, 3, 30))
+        );
+        assert_eq!(active_until(&cron, local(2026, 10, 20, 3, 30)), None);
+
+        let once = MaintenanceSchedule::Once {
+            start: local(2026, 10, 18, 9, 0),
+            end: local(2026, 10, 18, 10, 0),
+        };
+        assert!(active_until(&once, local(2026, 10, 18, 9, 0)).is_some());
+        assert!(active_until(&once, local(2026, 10, 18, 10, 0)).is_none());
+    }
+
+    #[test]
+    fn test_mark_for_domain_or_group() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = MaintenanceService::new(dir.path().join("maintenance_windows.json"));
+        let window = |name: &str, domain_ids, group_ids, enabled| MaintenanceWindow {
+            id: 0,
+            name: name.to_string(),
+            enabled,
+            domain_ids,
+            group_ids,
+            schedule: MaintenanceSchedule::Once {
+                start: local(2026, 10, 18, 9, 0),
+                end: local(2026, 10, 18, 10, 0),
+            },
+            exclude_from_uptime: true,
+        };
+        let saved = service
+            .set_all(vec![
+                window("deploy", vec![1], vec![], true),
+                window("db", vec![], vec![5], true),
+                window("off", vec![2], vec![], false),
+            ])
+            .unwrap();
+        assert_eq!(
+            saved.iter().map(|w| w.id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let now = local(2026, 10, 18, 9, 30);
+        assert_eq!(service.mark_for(1, &[], now).unwrap().window_id, 1);
+        assert_eq!(service.mark_for(9, &[5], now).unwrap().window_id, 2);
+        assert!(service.mark_for(2, &[], now).is_none());
+        assert!(service
+            .mark_for(1, &[], local(2026, 10, 18, 10, 30))
+            .is_none());
+
+        let reloaded = MaintenanceService::new(dir.path().join("maintenance_windows.json"));
+        assert_eq!(reloaded.get_all(), saved);
+
+        let mut invalid = window("bad", vec![1], vec![], true);
+        invalid.schedule = MaintenanceSchedule::Cron {
+            expression: "0 3 * *".to_string(),
+            duration_mins: 30,
+        };
+        assert!(service.set_all(vec![invalid]).is_err());
+        assert_eq!(service.get_all().len(), 3);
+    }
+}
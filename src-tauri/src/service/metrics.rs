@@ -0,0 +1,405 @@
+//! Prometheus 텍스트·`OpenMetrics` 형식의 지표.
+//! 모니터 체크 결과(도메인별 up·지연 시간·상태 코드·인증서 만료)와 로컬 프록시 호스트별 카운터를 모아
+//! 프록시의 `/.watchtower/metrics`로 제공. 값은 앱이 켜진 동안만 누적 (재시작 시 0부터).
+
+use crate::model::cert_check::CertCheckResult;
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::uptime::LATENCY_BOUNDS_MS;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 프록시 예약 경로 중 지표 경로
+pub const METRICS_PATH: &str = "/.watchtower/metrics";
+
+/// 호스트 라벨 최대 개수. 넘으면 새 호스트는 `OTHER_HOST`로 합침 (pass-through 호스트가 끝없이 늘어나는 것 방지)
+pub const MAX_METRIC_HOSTS: usize = 500;
+
+pub const OTHER_HOST: &str = "_other";
+
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+pub const OPENMETRICS_CONTENT_TYPE: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricsFormat {
+    Prometheus,
+    OpenMetrics,
+}
+
+impl MetricsFormat {
+    /// 스크레이퍼의 `Accept` 헤더로 고름 (`application/openmetrics-text`를 요청하면 `OpenMetrics`)
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        match accept {
+            Some(a) if a.contains("application/openmetrics-text") => Self::OpenMetrics,
+            _ => Self::Prometheus,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Prometheus => PROMETHEUS_CONTENT_TYPE,
+            Self::OpenMetrics => OPENMETRICS_CONTENT_TYPE,
+        }
+    }
+}
+
+/// 프록시 요청이 어디로 갔는지
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProxyRoute {
+    LocalRoute,
+    PassThrough,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostCounters {
+    pub requests: u64,
+    pub local_route: u64,
+    pub pass_through: u64,
+    /// API 로깅으로 가로챈 요청
+    pub logged: u64,
+    /// 클라이언트 → 대상
+    pub bytes_sent: u64,
+    /// 대상 → 클라이언트
+    pub bytes_received: u64,
+    pub upstream_errors: u64,
+}
+
+/// url 하나의 최신 체크 결과와 지연 시간 히스토그램
+#[derive(Clone, Debug, Default, PartialEq)]
+struct DomainMetrics {
+    group: String,
+    up: bool,
+    latency_ms: u32,
+    status_code: Option<u16>,
+    cert_days: Option<i64>,
+    /// `LATENCY_BOUNDS_MS` 구간별 개수 (누적 아님, 마지막 칸은 초과분)
+    histogram: Vec<u64>,
+    latency_sum_ms: u64,
+    checks: u64,
+}
+
+type Gauge = fn(&DomainMetrics) -> Option<String>;
+type Counter = fn(&HostCounters) -> u64;
+
+pub struct MetricsService {
+    hosts: Mutex<HashMap<String, HostCounters>>,
+    domains: Mutex<HashMap<String, DomainMetrics>>,
+}
+
+impl Default for MetricsService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsService {
+    pub fn new() -> Self {
+        Self {
+            hosts: Mutex::new(HashMap::new()),
+            domains: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn with_host(&self, host: &str, f: impl FnOnce(&mut HostCounters)) {
+        let mut hosts = self.hosts.lock().unwrap();
+        let host = host.trim().to_lowercase();
+        let key = if hosts.contains_key(&host) || hosts.len() < MAX_METRIC_HOSTS {
+            host
+        } else {
+            OTHER_HOST.to_string()
+        };
+        f(hosts.entry(key).or_default());
+    }
+
+    pub fn record_request(&self, host: &str, route: ProxyRoute, logged: bool) {
+        self.with_host(host, |c| {
+            c.requests += 1;
+            match route {
+                ProxyRoute::LocalRoute => c.local_route += 1,
+                ProxyRoute::PassThrough => c.pass_through += 1,
+            }
+            if logged {
+                c.logged += 1;
+            }
+        });
+    }
+
+    pub fn add_bytes(&self, host: &str, sent: u64, received: u64) {
+        self.with_host(host, |c| {
+            c.bytes_sent += sent;
+            c.bytes_received += received;
+        });
+    }
+
+    pub fn record_upstream_error(&self, host: &str) {
+        self.with_host(host, |c| c.upstream_errors += 1);
+    }
+
+    pub fn host_counters(&self, host: &str) -> Option<HostCounters> {
+        self.hosts.lock().unwrap().get(host).cloned()
+    }
+
+    /// 체크의 최종 시도 반영. error면 down
+    pub fn record_check(&self, log: &DomainStatusLog) {
+        let mut domains = self.domains.lock().unwrap();
+        let m = domains.entry(log.url.clone()).or_default();
+        m.group.clone_from(&log.group);
+        m.up = log.level != "error";
+        m.latency_ms = log.latency;
+        m.status_code = log
+            .status
+            .parse::<u16>()
+            .ok()
+            .filter(|c| (100..600).contains(c));
+        if m.histogram.is_empty() {
+            m.histogram = vec![0; LATENCY_BOUNDS_MS.len() + 1];
+        }
+        let slot = LATENCY_BOUNDS_MS
+            .iter()
+            .position(|b| log.latency <= *b)
+            .unwrap_or(LATENCY_BOUNDS_MS.len());
+        m.histogram[slot] += 1;
+        m.latency_sum_ms += u64::from(log.latency);
+        m.checks += 1;
+    }
+
+    pub fn record_cert(&self, result: &CertCheckResult) {
+        if let Some(m) = self.domains.lock().unwrap().get_mut(&result.url) {
+            m.cert_days = result.days_remaining;
+        }
+    }
+
+    /// 삭제된 도메인의 지표를 버림
+    pub fn retain_urls(&self, urls: &[&str]) {
+        self.domains
+            .lock()
+            .unwrap()
+            .retain(|url, _| urls.contains(&url.as_str()));
+    }
+
+    pub fn render(&self, format: MetricsFormat) -> String {
+        let mut out = String::new();
+        let domains: Vec<(String, DomainMetrics)> = {
+            let map = self.domains.lock().unwrap();
+            let mut list: Vec<_> = map.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+            list.sort_by(|a, b| a.0.cmp(&b.0));
+            list
+        };
+        let labels = |url: &str, m: &DomainMetrics| {
+            format!(
+                "url=\"{}\",group=\"{}\"",
+                escape_label(url),
+                escape_label(&m.group)
+            )
+        };
+        let gauges: [(&str, &str, Gauge); 4] = [
+            (
+                "watchtower_up",
+                "Last check succeeded (1) or failed (0).",
+                |m| Some(u8::from(m.up).to_string()),
+            ),
+            (
+                "watchtower_latency_ms",
+                "Latency of the last check in milliseconds.",
+                |m| Some(m.latency_ms.to_string()),
+            ),
+            (
+                "watchtower_status_code",
+                "HTTP status code of the last check.",
+                |m| m.status_code.map(|c| c.to_string()),
+            ),
+            (
+                "watchtower_cert_expiry_days",
+                "Days until the TLS certificate expires.",
+                |m| m.cert_days.map(|d| d.to_string()),
+            ),
+        ];
+        for (name, help, value) in gauges {
+            family_header(&mut out, name, "gauge", help);
+            for (url, m) in &domains {
+                if let Some(v) = value(m) {
+                    let _ = writeln!(out, "{name}{{{}}} {v}", labels(url, m));
+                }
+            }
+        }
+        let name = "watchtower_check_latency_seconds";
+        family_header(
+            &mut out,
+            name,
+            "histogram",
+            "Check latency since the app started.",
+        );
+        for (url, m) in &domains {
+            let l = labels(url, m);
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BOUNDS_MS.iter().zip(&m.histogram) {
+                cumulative += count;
+                let le = f64::from(*bound) / 1000.0;
+                let _ = writeln!(out, "{name}_bucket{{{l},le=\"{le}\"}} {cumulative}");
+            }
+            let _ = writeln!(out, "{name}_bucket{{{l},le=\"+Inf\"}} {}", m.checks);
+            let sum = Duration::from_millis(m.latency_sum_ms).as_secs_f64();
+            let _ = writeln!(out, "{name}_sum{{{l}}} {sum}");
+            let _ = writeln!(out, "{name}_count{{{l}}} {}", m.checks);
+        }
+
+        let hosts: Vec<(String, HostCounters)> = {
+            let map = self.hosts.lock().unwrap();
+            let mut list: Vec<_> = map.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+            list.sort_by(|a, b| a.0.cmp(&b.0));
+            list
+        };
+        let counters: [(&str, &str, Counter); 5] = [
+            (
+                "watchtower_proxy_requests",
+                "Requests handled by the local proxy.",
+                |c| c.requests,
+            ),
+            (
+                "watchtower_proxy_local_route",
+                "Requests sent to a local route.",
+                |c| c.local_route,
+            ),
+            (
+                "watchtower_proxy_pass_through",
+                "Requests passed through to the original host.",
+                |c| c.pass_through,
+            ),
+            (
+                "watchtower_proxy_logged",
+                "Requests intercepted for API logging.",
+                |c| c.logged,
+            ),
+            (
+                "watchtower_proxy_upstream_errors",
+                "Requests that failed to reach the upstream.",
+                |c| c.upstream_errors,
+            ),
+        ];
+        for (name, help, value) in counters {
+            counter_header(&mut out, format, name, help);
+            for (host, c) in &hosts {
+                let host = escape_label(host);
+                let _ = writeln!(out, "{name}_total{{host=\"{host}\"}} {}", value(c));
+            }
+        }
+        let name = "watchtower_proxy_bytes";
+        counter_header(&mut out, format, name, "Bytes relayed by the proxy.");
+        for (host, c) in &hosts {
+            let host = escape_label(host);
+            for (direction, bytes) in [("sent", c.bytes_sent), ("received", c.bytes_received)] {
+                let _ = writeln!(
+                    out,
+                    "{name}_total{{host=\"{host}\",direction=\"{direction}\"}} {bytes}"
+                );
+            }
+        }
+        if format == MetricsFormat::OpenMetrics {
+            out.push_str("# EOF\n");
+        }
+        out
+    }
+}
+
+fn family_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// 카운터 이름: Prometheus 텍스트는 `_total`까지, `OpenMetrics`는 `_total`을 뺀 이름
+fn counter_header(out: &mut String, format: MetricsFormat, name: &str, help: &str) {
+    match format {
+        MetricsFormat::Prometheus => family_header(out, &format!("{name}_total"), "counter", help),
+        MetricsFormat::OpenMetrics => family_header(out, name, "counter", help),
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(url: &str, status: &str, level: &str, latency: u32) -> DomainStatusLog {
+        DomainStatusLog {
+            url: url.to_string(),
+            status: status.to_string(),
+            level: level.to_string(),
+            latency,
+            ok: level == "info",
+            group: "Prod".to_string(),
+            timestamp: chrono::Utc::now(),
+            error_message: None,
+            failures: Vec::new(),
+            attempt: 1,
+            timing: None,
+            redirects: Vec::new(),
+            maintenance: None,
+        }
+    }
+
+    #[test]
+    fn test_render_domain_gauges_and_histogram() {
+        let metrics = MetricsService::new();
+        metrics.record_check(&log("https://a.test", "200", "info", 40));
+        metrics.record_check(&log("https://a.test", "503", "error", 2500));
+        metrics.record_check(&log("tcp://db:5432", "Connected", "info", 5));
+        let text = metrics.render(MetricsFormat::Prometheus);
+
+        assert!(text.contains("watchtower_up{url=\"https://a.test\",group=\"Prod\"} 0"));
+        assert!(text.contains("watchtower_status_code{url=\"https://a.test\",group=\"Prod\"} 503"));
+        assert!(!text.contains("watchtower_status_code{url=\"tcp://db:5432\""));
+        let l = "url=\"https://a.test\",group=\"Prod\"";
+        assert!(text.contains(&format!(
+            "watchtower_check_latency_seconds_bucket{{{l},le=\"0.05\"}} 1"
+        )));
+        assert!(text.contains(&format!(
+            "watchtower_check_latency_seconds_bucket{{{l},le=\"3\"}} 2"
+        )));
+        assert!(text.contains(&format!("watchtower_check_latency_seconds_sum{{{l}}} 2.54")));
+        assert!(text.contains(&format!("watchtower_check_latency_seconds_count{{{l}}} 2")));
+        assert!(!text.contains("# EOF"));
+
+        metrics.retain_urls(&["tcp://db:5432"]);
+        assert!(!metrics
+            .render(MetricsFormat::Prometheus)
+            .contains("https://a.test"));
+    }
+
+    #[test]
+    fn test_proxy_counters_and_host_cap() {
+        let metrics = MetricsService::new();
+        metrics.record_request("API.test", ProxyRoute::LocalRoute, true);
+        metrics.record_request("api.test", ProxyRoute::PassThrough, false);
+        metrics.add_bytes("api.test", 10, 300);
+        metrics.record_upstream_error("api.test");
+        let c = metrics.host_counters("api.test").unwrap();
+        assert_eq!(
+            (c.requests, c.local_route, c.pass_through, c.logged),
+            (2, 1, 1, 1)
+        );
+
+        let prom = metrics.render(MetricsFormat::Prometheus);
+        assert!(prom.contains("# TYPE watchtower_proxy_requests_total counter"));
+        assert!(prom.contains(
+            "watchtower_proxy_bytes_total{host=\"api.test\",direction=\"received\"} 300"
+        ));
+        assert!(prom.contains("watchtower_proxy_upstream_errors_total{host=\"api.test\"} 1"));
+        let om = metrics.render(MetricsFormat::OpenMetrics);
+        assert!(om.contains("# TYPE watchtower_proxy_requests counter"));
+        assert!(om.ends_with("# EOF\n"));
+
+        for i in 0..MAX_METRIC_HOSTS {
+            metrics.record_request(&format!("h{i}.test"), ProxyRoute::PassThrough, false);
+        }
+        assert!(metrics.host_counters(OTHER_HOST).is_some());
+        assert_eq!(metrics.host_counters("api.test").unwrap().requests, 2);
+    }
+}
@@ -0,0 +1,593 @@
+//! HTTP 외 프로브: TCP 연결, UDP DNS 질의, WebSocket 핸드셰이크.
+//! 종류는 `Domain.url` 스킴으로 결정하고 (`tcp://`, `dns://`, `ws://`, `wss://`, `heartbeat://`),
+//! 결과는 HTTP 체크와 같은 `DomainStatusLog`로 만들어 그룹·이력·export를 그대로 씀.
+
+use crate::model::dns_check::{DnsAnswerStatus, DnsRecordType};
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::http_check::AssertionFailure;
+use crate::model::tcp_check::TcpCheck;
+use crate::service::dns_check;
+use base64::Engine;
+use chrono::Utc;
+use rustls::RootCertStore;
+use rustls_pki_types::ServerName;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+/// 배너·핸드셰이크 응답으로 읽는 최대 크기
+const MAX_READ: usize = 16 * 1024;
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// 모니터 대상 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProbeKind {
+    /// `http(s)://` 또는 스킴 없음
+    Http,
+    /// `tcp://host:port`
+    Tcp,
+    /// `dns://server[:port]/name?type=A`
+    Dns,
+    /// `ws://` / `wss://`
+    WebSocket,
+    /// `heartbeat://name`. 요청을 보내지 않고 받은 핑으로 판정 (`heartbeat_service`)
+    Heartbeat,
+}
+
+impl ProbeKind {
+    pub fn of(url: &str) -> Self {
+        let scheme = url
+            .trim()
+            .split_once("://")
+            .map(|(scheme, _)| scheme.to_ascii_lowercase());
+        match scheme.as_deref() {
+            Some("tcp") => Self::Tcp,
+            Some("dns") => Self::Dns,
+            Some("ws" | "wss") => Self::WebSocket,
+            Some("heartbeat") => Self::Heartbeat,
+            _ => Self::Http,
+        }
+    }
+}
+
+fn log(url: &str, group: &str, status: &str, latency: u32) -> DomainStatusLog {
+    DomainStatusLog {
+        url: url.to_string(),
+        status: status.to_string(),
+        level: "info".to_string(),
+        latency,
+        ok: true,
+        group: group.to_string(),
+        timestamp: Utc::now(),
+        error_message: Some("Operation successful".to_string()),
+        failures: Vec::new(),
+        attempt: 1,
+        timing: None,
+        redirects: Vec::new(),
+        maintenance: None,
+    }
+}
+
+fn error_log(
+    url: &str,
+    group: &str,
+    status: &str,
+    latency: u32,
+    message: String,
+) -> DomainStatusLog {
+    DomainStatusLog {
+        level: "error".to_string(),
+        ok: false,
+        error_message: Some(message),
+        ..log(url, group, status, latency)
+    }
+}
+
+/// 검증 실패를 반영. level은 `level` (warning/error)
+fn with_failure(
+    mut entry: DomainStatusLog,
+    level: &str,
+    failure: AssertionFailure,
+) -> DomainStatusLog {
+    entry.level = level.to_string();
+    entry.ok = false;
+    entry.error_message = Some(failure.to_string());
+    entry.failures.push(failure);
+    entry
+}
+
+fn elapsed_ms(start: Instant) -> u32 {
+    start.elapsed().as_millis() as u32
+}
+
+/// `host:port` TCP 연결 (`CONNECT_TIMEOUT`)
+pub(crate) async fn connect(host: &str, port: u16) -> Result<TcpStream, String> {
+    tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((host, port)))
+        .await
+        .map_err(|_| "connect timed out".to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// `until`가 나오거나 EOF·타임아웃·`MAX_READ`까지 읽음. `until`이 비어 있으면 읽지 않음
+async fn read_until<S: AsyncRead + Unpin>(stream: &mut S, until: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    if until.is_empty() {
+        return buf;
+    }
+    let mut chunk = [0u8; 2048];
+    let deadline = tokio::time::Instant::now() + READ_TIMEOUT;
+    while buf.len() < MAX_READ && !buf.windows(until.len()).any(|w| w == until) {
+        match tokio::time::timeout_at(deadline, stream.read(&mut chunk)).await {
+            Ok(Ok(n)) if n > 0 => buf.extend_from_slice(&chunk[..n]),
+            _ => break,
+        }
+    }
+    buf
+}
+
+/// `tcp://host:port`에 연결. `check.send`가 있으면 보내고, `check.expect`가 있으면 응답에 포함되는지 확인.
+pub async fn probe_tcp(url: &str, group: &str, check: &TcpCheck) -> DomainStatusLog {
+    let start = Instant::now();
+    let parsed = reqwest::Url::parse(url.trim()).ok();
+    let Some((host, port)) = parsed.as_ref().and_then(|u| {
+        Some((
+            u.host_str()?.trim_matches(['[', ']']).to_string(),
+            u.port()?,
+        ))
+    }) else {
+        return error_log(
+            url,
+            group,
+            "Invalid Target",
+            0,
+            "expected tcp://host:port".to_string(),
+        );
+    };
+
+    let mut stream = match connect(&host, port).await {
+        Ok(s) => s,
+        Err(e) => return error_log(url, group, "Connect Error", elapsed_ms(start), e),
+    };
+    if let Some(ref send) = check.send {
+        if let Err(e) = stream.write_all(send.as_bytes()).await {
+            return error_log(url, group, "Send Error", elapsed_ms(start), e.to_string());
+        }
+    }
+    let Some(ref expect) = check.expect else {
+        return log(url, group, "Connected", elapsed_ms(start));
+    };
+    let banner = read_until(&mut stream, expect.as_bytes()).await;
+    let latency = elapsed_ms(start);
+    let banner = String::from_utf8_lossy(&banner);
+    let entry = log(url, group, "Connected", latency);
+    if banner.contains(expect.as_str()) {
+        return entry;
+    }
+    let actual = banner.lines().next().unwrap_or("").trim();
+    with_failure(
+        entry,
+        "error",
+        AssertionFailure {
+            kind: "banner".to_string(),
+            expected: format!("contains {expect:?}"),
+            actual: if actual.is_empty() {
+                "(no data)".to_string()
+            } else {
+                format!("{actual:?}")
+            },
+        },
+    )
+}
+
+/// `dns://server[:port]/name?type=A` — 해당 서버에 UDP로 질의. 레코드가 있으면 ok,
+/// 레코드 없음은 warning, NXDOMAIN·SERVFAIL·무응답은 error.
+pub async fn probe_dns_query(url: &str, group: &str) -> DomainStatusLog {
+    let start = Instant::now();
+    let Ok(parsed) = reqwest::Url::parse(url.trim()) else {
+        return error_log(
+            url,
+            group,
+            "Invalid Target",
+            0,
+            "expected dns://server/name".to_string(),
+        );
+    };
+    let name = parsed.path().trim_matches('/').to_string();
+    let Some(server_host) = parsed
+        .host_str()
+        .map(|h| h.trim_matches(['[', ']']).to_string())
+    else {
+        return error_log(
+            url,
+            group,
+            "Invalid Target",
+            0,
+            "missing DNS server".to_string(),
+        );
+    };
+    if name.is_empty() {
+        return error_log(
+            url,
+            group,
+            "Invalid Target",
+            0,
+            "missing query name".to_string(),
+        );
+    }
+    let record_type =
+        parsed
+            .query_pairs()
+            .find(|(k, _)| k == "type")
+            .map_or(Ok(DnsRecordType::A), |(_, v)| {
+                serde_json::from_value::<DnsRecordType>(serde_json::Value::String(
+                    v.to_ascii_uppercase(),
+                ))
+            });
+    let Ok(record_type) = record_type else {
+        return error_log(
+            url,
+            group,
+            "Invalid Target",
+            0,
+            "unsupported record type".to_string(),
+        );
+    };
+    let port = parsed.port().unwrap_or(53);
+    let server = match tokio::net::lookup_host((server_host.as_str(), port)).await {
+        Ok(mut addrs) => addrs.next(),
+        Err(_) => None,
+    };
+    let Some(server) = server else {
+        return error_log(
+            url,
+            group,
+            "Resolve Error",
+            elapsed_ms(start),
+            format!("cannot resolve DNS server {server_host}"),
+        );
+    };
+
+    let resolver = dns_check::build_udp_resolver(server);
+    let answer = dns_check::lookup_answer(&resolver, &name, record_type).await;
+    let latency = elapsed_ms(start);
+    match answer.status {
+        DnsAnswerStatus::Ok => log(url, group, "NOERROR", latency),
+        DnsAnswerStatus::NoRecords => with_failure(
+            log(url, group, "NOERROR", latency),
+            "warning",
+            AssertionFailure {
+                kind: "answer".to_string(),
+                expected: format!("{record_type} records"),
+                actual: "none".to_string(),
+            },
+        ),
+        DnsAnswerStatus::Nxdomain => error_log(
+            url,
+            group,
+            "NXDOMAIN",
+            latency,
+            format!("{name} does not exist"),
+        ),
+        DnsAnswerStatus::Servfail => error_log(
+            url,
+            group,
+            "SERVFAIL",
+            latency,
+            format!("{server} returned SERVFAIL"),
+        ),
+        DnsAnswerStatus::Error => error_log(
+            url,
+            group,
+            "Query Error",
+            latency,
+            answer
+                .error_message
+                .unwrap_or_else(|| "query failed".to_string()),
+        ),
+    }
+}
+
+/// 핸드셰이크 응답의 상태 코드와 `Sec-WebSocket-Accept` 값
+async fn websocket_upgrade<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    host_header: &str,
+    path: &str,
+    key: &str,
+) -> Result<(u16, String, Option<String>), String> {
+    let request = format!(
+        "GET {path} HTTP/1.1\r\nHost: {host_header}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: 13\r\nUser-Agent: Watchtower/0.1.0\r\n\r\n"
+    );
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = read_until(stream, b"\r\n\r\n").await;
+    let response = String::from_utf8_lossy(&response);
+    let mut lines = response.lines();
+    let status_line = lines.next().unwrap_or("").trim().to_string();
+    let code = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|c| c.parse::<u16>().ok())
+        .ok_or_else(|| {
+            if status_line.is_empty() {
+                "no handshake response".to_string()
+            } else {
+                format!("invalid response: {status_line}")
+            }
+        })?;
+    let accept = lines
+        .take_while(|l| !l.is_empty())
+        .filter_map(|l| l.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("sec-websocket-accept"))
+        .map(|(_, value)| value.trim().to_string());
+    let status = status_line
+        .split_once(' ')
+        .map_or("", |(_, rest)| rest)
+        .to_string();
+    Ok((code, status, accept))
+}
+
+/// RFC 6455 `Sec-WebSocket-Accept` = base64(SHA-1(key + GUID))
+fn websocket_accept(key: &str) -> String {
+    let digest = ring::digest::digest(
+        &ring::digest::SHA1_FOR_LEGACY_USE_ONLY,
+        format!("{key}{WEBSOCKET_GUID}").as_bytes(),
+    );
+    base64::engine::general_purpose::STANDARD.encode(digest.as_ref())
+}
+
+/// `ws://` / `wss://` 대상에 업그레이드 요청을 보내 101 응답과 accept 키를 확인.
+pub async fn probe_websocket(url: &str, group: &str, roots: Arc<RootCertStore>) -> DomainStatusLog {
+    let start = Instant::now();
+    let Ok(parsed) = reqwest::Url::parse(url.trim()) else {
+        return error_log(
+            url,
+            group,
+            "Invalid Target",
+            0,
+            "expected ws:// or wss:// URL".to_string(),
+        );
+    };
+    let Some(host) = parsed
+        .host_str()
+        .map(|h| h.trim_matches(['[', ']']).to_string())
+    else {
+        return error_log(url, group, "Invalid Target", 0, "missing host".to_string());
+    };
+    let secure = parsed.scheme() == "wss";
+    let port = parsed
+        .port_or_known_default()
+        .unwrap_or(if secure { 443 } else { 80 });
+    let host_header = match parsed.port() {
+        Some(p) => format!("{}:{p}", parsed.host_str().unwrap_or(&host)),
+        None => parsed.host_str().unwrap_or(&host).to_string(),
+    };
+    let path = match parsed.query() {
+        Some(q) => format!("{}?{q}", parsed.path()),
+        None => parsed.path().to_string(),
+    };
+    let key = base64::engine::general_purpose::STANDARD.encode(uuid::Uuid::new_v4().as_bytes());
+
+    let tcp = match connect(&host, port).await {
+        Ok(s) => s,
+        Err(e) => return error_log(url, group, "Connect Error", elapsed_ms(start), e),
+    };
+    let result = if secure {
+        let builder = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions();
+        let mut client_config = match builder {
+            Ok(b) => b.with_root_certificates(roots).with_no_client_auth(),
+            Err(e) => return error_log(url, group, "TLS Error", elapsed_ms(start), e.to_string()),
+        };
+        client_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        let Ok(server_name) = ServerName::try_from(host.clone()) else {
+            return error_log(
+                url,
+                group,
+                "TLS Error",
+                elapsed_ms(start),
+                format!("invalid server name {host}"),
+            );
+        };
+        let connector = TlsConnector::from(Arc::new(client_config));
+        match tokio::time::timeout(CONNECT_TIMEOUT, connector.connect(server_name, tcp)).await {
+            Ok(Ok(mut tls)) => websocket_upgrade(&mut tls, &host_header, &path, &key).await,
+            Ok(Err(e)) => {
+                return error_log(url, group, "TLS Error", elapsed_ms(start), e.to_string())
+            }
+            Err(_) => {
+                return error_log(
+                    url,
+                    group,
+                    "TLS Error",
+                    elapsed_ms(start),
+                    "TLS handshake timed out".to_string(),
+                )
+            }
+        }
+    } else {
+        let mut tcp = tcp;
+        websocket_upgrade(&mut tcp, &host_header, &path, &key).await
+    };
+    let latency = elapsed_ms(start);
+
+    let (code, status, accept) = match result {
+        Ok(r) => r,
+        Err(e) => return error_log(url, group, "Handshake Error", latency, e),
+    };
+    let entry = log(url, group, &status, latency);
+    if code != 101 {
+        return with_failure(
+            entry,
+            "error",
+            AssertionFailure {
+                kind: "handshake".to_string(),
+                expected: "101".to_string(),
+                actual: code.to_string(),
+            },
+        );
+    }
+    let expected = websocket_accept(&key);
+    if accept.as_deref() != Some(expected.as_str()) {
+        return with_failure(
+            entry,
+            "error",
+            AssertionFailure {
+                kind: "handshake".to_string(),
+                expected: format!("Sec-WebSocket-Accept {expected}"),
+                actual: accept.unwrap_or_else(|| "(missing)".to_string()),
+            },
+        );
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::dns_check::test_server::{spawn_dns, zone_with_a};
+    use hickory_resolver::proto::op::ResponseCode;
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_probe_kind_from_url() {
+        assert_eq!(ProbeKind::of("example.com"), ProbeKind::Http);
+        assert_eq!(ProbeKind::of("https://example.com/ws"), ProbeKind::Http);
+        assert_eq!(ProbeKind::of("tcp://db:5432"), ProbeKind::Tcp);
+        assert_eq!(ProbeKind::of("DNS://8.8.8.8/example.com"), ProbeKind::Dns);
+        assert_eq!(
+            ProbeKind::of("wss://example.com/socket"),
+            ProbeKind::WebSocket
+        );
+    }
+
+    #[test]
+    fn test_websocket_accept_rfc_example() {
+        assert_eq!(
+            websocket_accept("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tcp_connect_and_banner() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let _ = stream.write_all(b"220 mail.example.test ESMTP\r\n").await;
+            }
+        });
+        let url = format!("tcp://127.0.0.1:{port}");
+
+        let result = probe_tcp(&url, "Default", &TcpCheck::default()).await;
+        assert!(result.ok, "{result:?}");
+        assert_eq!(result.status, "Connected");
+
+        let smtp = TcpCheck {
+            send: None,
+            expect: Some("220".to_string()),
+        };
+        assert!(probe_tcp(&url, "Default", &smtp).await.ok);
+
+        let redis = TcpCheck {
+            send: Some("PING\r\n".to_string()),
+            expect: Some("+PONG".to_string()),
+        };
+        let result = probe_tcp(&url, "Default", &redis).await;
+        assert!(!result.ok);
+        assert_eq!(result.failures[0].kind, "banner");
+        assert!(result.failures[0].actual.contains("220"));
+
+        // 빈 기대 배너는 연결만 확인 (저장할 때는 `None`으로 바뀜)
+        let empty = TcpCheck {
+            send: Some(String::new()),
+            expect: Some(String::new()),
+        };
+        assert!(probe_tcp(&url, "Default", &empty).await.ok);
+        assert_eq!(empty.normalized(), TcpCheck::default());
+
+        let closed = probe_tcp("tcp://127.0.0.1:1", "Default", &TcpCheck::default()).await;
+        assert_eq!(closed.level, "error");
+        assert_eq!(closed.status, "Connect Error");
+    }
+
+    #[tokio::test]
+    async fn test_dns_query_probe() {
+        let zone = zone_with_a([10, 0, 0, 1]);
+        let (ip, port) = spawn_dns(zone.clone()).await;
+
+        let ok = probe_dns_query(&format!("dns://{ip}:{port}/example.test"), "Default").await;
+        assert!(ok.ok, "{ok:?}");
+        assert_eq!(ok.status, "NOERROR");
+
+        let none = probe_dns_query(
+            &format!("dns://{ip}:{port}/example.test?type=txt"),
+            "Default",
+        )
+        .await;
+        assert_eq!(none.level, "warning");
+
+        zone.lock().unwrap().rcode = ResponseCode::NXDomain;
+        zone.lock().unwrap().records.clear();
+        let gone = probe_dns_query(&format!("dns://{ip}:{port}/example.test"), "Default").await;
+        assert_eq!(gone.status, "NXDOMAIN");
+        assert_eq!(gone.level, "error");
+    }
+
+    /// 요청의 키로 accept를 계산해 101로 응답 (`accept_ok`가 false면 200 응답)
+    async fn spawn_ws_server(accept_ok: bool) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let request = read_until(&mut stream, b"\r\n\r\n").await;
+                let request = String::from_utf8_lossy(&request).to_string();
+                let key = request
+                    .lines()
+                    .find_map(|l| l.strip_prefix("Sec-WebSocket-Key: "))
+                    .unwrap_or("")
+                    .trim()
+                    .to_string();
+                let response = if accept_ok {
+                    format!(
+                        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                        websocket_accept(&key)
+                    )
+                } else {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_string()
+                };
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_websocket_handshake() {
+        let roots = Arc::new(RootCertStore::empty());
+        let port = spawn_ws_server(true).await;
+        let ok = probe_websocket(
+            &format!("ws://127.0.0.1:{port}/socket?x=1"),
+            "Default",
+            roots.clone(),
+        )
+        .await;
+        assert!(ok.ok, "{ok:?}");
+        assert_eq!(ok.status, "101 Switching Protocols");
+
+        let port = spawn_ws_server(false).await;
+        let bad = probe_websocket(&format!("ws://127.0.0.1:{port}/socket"), "Default", roots).await;
+        assert!(!bad.ok);
+        assert_eq!(bad.failures[0].kind, "handshake");
+        assert_eq!(bad.failures[0].actual, "200");
+    }
+}
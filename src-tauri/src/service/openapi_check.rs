@@ -0,0 +1,979 @@
+//! `OpenAPI` 엔드포인트 체크 (`DomainMonitorLink.openapi_check`).
+//! `download_api_schema`로 받은 스키마(`OpenAPI` 3 / Swagger 2 JSON)에서 필수 파라미터가 없거나 예시 값이 있는
+//! GET 오퍼레이션을 골라 요청하고, 응답 코드를 선언된 `responses`와, JSON 바디를 그 응답의 스키마와 비교한다.
+//! 스키마 검증은 체크에 필요한 만큼만 (`$ref`·`type`·`nullable`·`enum`·`required`·`properties`·
+//! `additionalProperties`·`items`·`allOf`/`anyOf`/`oneOf`). `format`·길이·범위 제약은 보지 않음.
+
+use crate::model::openapi_check::{
+    OpenApiCheckConfig, OpenApiCheckState, OperationCheckResult, SkippedOperation,
+};
+use crate::service::check_limiter::{self, CheckLimiter, QueueStats};
+use crate::service::http_transport::HttpTransport;
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use reqwest::Url;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// 도메인 하나에서 체크하는 최대 오퍼레이션 수 (나머지는 `skipped`)
+const MAX_OPERATIONS: usize = 50;
+/// 도메인 하나에서 동시에 보내는 요청 수 (전체·호스트별 제한은 `OperationClient.limiter`)
+const CONCURRENCY: usize = 4;
+/// 오퍼레이션 하나에 남기는 최대 스키마 위반 수
+const MAX_ERRORS: usize = 20;
+/// `$ref`를 따라가거나 중첩 스키마로 내려가는 최대 깊이 (순환 참조 방지)
+const MAX_DEPTH: usize = 64;
+
+/// 요청할 오퍼레이션 (예시 값을 넣은 url·헤더)
+#[derive(Clone, Debug)]
+pub struct PlannedOperation {
+    pub operation: String,
+    pub operation_id: Option<String>,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    /// 선언된 응답 코드 → JSON 바디 스키마 (JSON 응답이 아니거나 스키마가 없으면 `None`)
+    pub responses: Vec<(String, Option<Value>)>,
+}
+
+/// 스키마 텍스트를 읽음. `openapi`/`swagger` 버전과 `paths`가 있어야 함
+pub fn parse_spec(text: &str) -> Result<Value, String> {
+    let spec: Value = serde_json::from_str(text).map_err(|e| {
+        if text.trim_start().starts_with('{') {
+            format!("invalid JSON schema: {e}")
+        } else {
+            "only JSON schemas are supported (download the .json variant)".to_string()
+        }
+    })?;
+    if spec.get("openapi").is_none() && spec.get("swagger").is_none() {
+        return Err(
+            "not an OpenAPI/Swagger document (no `openapi` or `swagger` field)".to_string(),
+        );
+    }
+    if !spec.get("paths").is_some_and(Value::is_object) {
+        return Err("schema has no `paths`".to_string());
+    }
+    Ok(spec)
+}
+
+/// 같은 문서 안의 `$ref`(`#/...`)를 따라감. 찾지 못하면 `Value::Null` (검증하지 않음)
+fn resolve<'a>(root: &'a Value, mut value: &'a Value) -> &'a Value {
+    for _ in 0..MAX_DEPTH {
+        let Some(reference) = value.get("$ref").and_then(Value::as_str) else {
+            return value;
+        };
+        value = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .unwrap_or(&Value::Null);
+    }
+    &Value::Null
+}
+
+/// 도메인 url (스킴이 없으면 https)
+fn domain_base(domain_url: &str) -> Result<Url, String> {
+    let url = domain_url.trim();
+    let url = if url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    };
+    Url::parse(&url).map_err(|e| format!("invalid domain url: {e}"))
+}
+
+/// 요청 기준 url. 설정 → `servers[0]` (변수는 기본값) → Swagger 2 `schemes`·`host`·`basePath` → 도메인 origin.
+/// 상대 경로는 도메인 url 기준
+pub fn base_url(
+    spec: &Value,
+    domain_url: &str,
+    config: &OpenApiCheckConfig,
+) -> Result<Url, String> {
+    let domain = domain_base(domain_url)?;
+    let join = |raw: &str| {
+        domain
+            .join(raw.trim())
+            .map_err(|e| format!("invalid base url `{raw}`: {e}"))
+    };
+    if let Some(raw) = config.base_url.as_deref().filter(|s| !s.trim().is_empty()) {
+        return join(raw);
+    }
+    if let Some(server) = spec.pointer("/servers/0") {
+        let mut raw = server
+            .get("url")
+            .and_then(Value::as_str)
+            .unwrap_or("/")
+            .to_string();
+        if let Some(variables) = server.get("variables").and_then(Value::as_object) {
+            for (name, variable) in variables {
+                let value = variable
+                    .get("default")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                raw = raw.replace(&format!("{{{name}}}"), value);
+            }
+        }
+        return join(&raw);
+    }
+    if spec.get("swagger").is_some() {
+        let schemes: Vec<&str> = spec
+            .get("schemes")
+            .and_then(Value::as_array)
+            .map(|a| a.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let scheme =
+            if schemes.contains(&"https") || schemes.is_empty() && domain.scheme() == "https" {
+                "https"
+            } else {
+                schemes.first().copied().unwrap_or("http")
+            };
+        let host = spec.get("host").and_then(Value::as_str).map_or_else(
+            || {
+                let host = domain.host_str().unwrap_or_default();
+                domain
+                    .port()
+                    .map_or_else(|| host.to_string(), |port| format!("{host}:{port}"))
+            },
+            str::to_string,
+        );
+        let base_path = spec.get("basePath").and_then(Value::as_str).unwrap_or("/");
+        return Url::parse(&format!("{scheme}://{host}{base_path}"))
+            .map_err(|e| format!("invalid swagger host/basePath: {e}"));
+    }
+    join("/")
+}
+
+/// 경로 파라미터 값 인코딩 (unreserved 문자 외에는 `%XX`)
+fn encode_segment(value: &str) -> String {
+    let mut out = String::new();
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            out.push(char::from(byte));
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+    out
+}
+
+/// 파라미터 값을 요청에 넣을 문자열로 (배열은 쉼표로 이음)
+fn param_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(param_string).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+/// 파라미터의 예시 값: `example` → `examples`의 첫 값 → `x-example` → `default` (스키마 쪽도)
+fn param_example(root: &Value, param: &Value) -> Option<Value> {
+    let schema = param.get("schema").map(|s| resolve(root, s));
+    param
+        .get("example")
+        .or_else(|| {
+            let examples = param.get("examples")?.as_object()?;
+            resolve(root, examples.values().next()?).get("value")
+        })
+        .or_else(|| param.get("x-example"))
+        .or_else(|| schema?.get("example"))
+        .or_else(|| param.get("default"))
+        .or_else(|| schema?.get("default"))
+        .cloned()
+}
+
+/// 응답의 JSON 바디 스키마 (`OpenAPI` 3은 JSON 계열 `content`, Swagger 2는 `schema`)
+fn response_schema(root: &Value, response: &Value) -> Option<Value> {
+    let response = resolve(root, response);
+    if let Some(content) = response.get("content").and_then(Value::as_object) {
+        return content
+            .iter()
+            .find(|(media, _)| media.contains("json") || media.as_str() == "*/*")
+            .and_then(|(_, media)| media.get("schema"))
+            .cloned();
+    }
+    response.get("schema").cloned()
+}
+
+/// GET 오퍼레이션 하나를 요청할 형태로. 필수 파라미터에 예시 값이 없으면 `Err(이유)`
+fn plan_operation(
+    root: &Value,
+    base: &Url,
+    path: &str,
+    path_item: &Value,
+    operation: &Value,
+) -> Result<PlannedOperation, String> {
+    if operation
+        .get("requestBody")
+        .map(|b| resolve(root, b))
+        .and_then(|b| b.get("required"))
+        .and_then(Value::as_bool)
+        == Some(true)
+    {
+        return Err("requires a request body".to_string());
+    }
+    // 오퍼레이션 파라미터가 같은 이름·위치의 경로 공통 파라미터를 덮어씀
+    let mut params: Vec<&Value> = Vec::new();
+    for param in [path_item, operation]
+        .iter()
+        .filter_map(|v| v.get("parameters").and_then(Value::as_array))
+        .flatten()
+        .map(|p| resolve(root, p))
+    {
+        let key = |p: &Value| (p.get("name").cloned(), p.get("in").cloned());
+        params.retain(|p| key(p) != key(param));
+        params.push(param);
+    }
+
+    let mut url_path = path.to_string();
+    let mut query: Vec<(String, String)> = Vec::new();
+    let mut headers = HashMap::new();
+    let mut cookies: Vec<String> = Vec::new();
+    for param in params {
+        let name = param
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let location = param.get("in").and_then(Value::as_str).unwrap_or_default();
+        let required =
+            location == "path" || param.get("required").and_then(Value::as_bool) == Some(true);
+        let Some(example) = param_example(root, param) else {
+            if required {
+                return Err(format!(
+                    "required {location} parameter `{name}` has no example"
+                ));
+            }
+            continue;
+        };
+        let value = param_string(&example);
+        match location {
+            "path" => url_path = url_path.replace(&format!("{{{name}}}"), &encode_segment(&value)),
+            "query" => query.push((name.to_string(), value)),
+            "header" => {
+                headers.insert(name.to_string(), value);
+            }
+            "cookie" => cookies.push(format!("{name}={value}")),
+            "body" | "formData" if required => {
+                return Err(format!("requires a {location} parameter `{name}`"));
+            }
+            _ => {}
+        }
+    }
+    if !cookies.is_empty() {
+        headers.insert("cookie".to_string(), cookies.join("; "));
+    }
+
+    let mut url = Url::parse(&format!(
+        "{}{}",
+        base.as_str().trim_end_matches('/'),
+        url_path
+    ))
+    .map_err(|e| format!("invalid url: {e}"))?;
+    if !query.is_empty() {
+        url.query_pairs_mut().extend_pairs(&query);
+    }
+    let responses = operation
+        .get("responses")
+        .and_then(Value::as_object)
+        .map(|responses| {
+            responses
+                .iter()
+                .map(|(code, response)| (code.clone(), response_schema(root, response)))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(PlannedOperation {
+        operation: format!("GET {path}"),
+        operation_id: operation
+            .get("operationId")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        url: url.to_string(),
+        headers,
+        responses,
+    })
+}
+
+/// 스키마의 GET 오퍼레이션 중 요청할 수 있는 것과 건너뛴 것 (이유 포함)
+pub fn plan(spec: &Value, base: &Url) -> (Vec<PlannedOperation>, Vec<SkippedOperation>) {
+    let mut planned = Vec::new();
+    let mut skipped = Vec::new();
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        return (planned, skipped);
+    };
+    for (path, path_item) in paths {
+        let path_item = resolve(spec, path_item);
+        let Some(operation) = path_item.get("get") else {
+            continue;
+        };
+        let result = if planned.len() >= MAX_OPERATIONS {
+            Err(format!("more than {MAX_OPERATIONS} operations"))
+        } else {
+            plan_operation(spec, base, path, path_item, operation)
+        };
+        match result {
+            Ok(op) => planned.push(op),
+            Err(reason) => skipped.push(SkippedOperation {
+                operation: format!("GET {path}"),
+                reason,
+            }),
+        }
+    }
+    (planned, skipped)
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// `value`가 `schema`를 따르는지 검증해 위반을 `errors`에 추가 (`path`: `$.items[0].id` 형식)
+pub fn validate(root: &Value, schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    validate_at(root, schema, value, path, 0, errors);
+}
+
+fn validate_at(
+    root: &Value,
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    depth: usize,
+    errors: &mut Vec<String>,
+) {
+    if errors.len() >= MAX_ERRORS || depth > MAX_DEPTH {
+        return;
+    }
+    let Some(schema) = resolve(root, schema).as_object() else {
+        return;
+    };
+    let flag = |key: &str| schema.get(key).and_then(Value::as_bool) == Some(true);
+    if value.is_null() && (flag("nullable") || flag("x-nullable")) {
+        return;
+    }
+    for sub in schema
+        .get("allOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        validate_at(root, sub, value, path, depth + 1, errors);
+    }
+    for key in ["anyOf", "oneOf"] {
+        let Some(options) = schema.get(key).and_then(Value::as_array) else {
+            continue;
+        };
+        let matched = options
+            .iter()
+            .filter(|sub| {
+                let mut sub_errors = Vec::new();
+                validate_at(root, sub, value, path, depth + 1, &mut sub_errors);
+                sub_errors.is_empty()
+            })
+            .count();
+        let ok = if key == "anyOf" {
+            matched > 0
+        } else {
+            matched == 1
+        };
+        if !ok {
+            errors.push(format!(
+                "{path}: matches {matched} of {} `{key}` schemas",
+                options.len()
+            ));
+        }
+    }
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| type_matches(t, value)) {
+        errors.push(format!(
+            "{path}: expected {}, got {}",
+            types.join(" | "),
+            type_name(value)
+        ));
+        return;
+    }
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        if !values.contains(value) {
+            errors.push(format!("{path}: {value} is not one of the enum values"));
+        }
+    }
+    match value {
+        Value::Object(map) => {
+            for name in schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                if !map.contains_key(name) {
+                    errors.push(format!("{path}: missing required property `{name}`"));
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (name, child) in map {
+                let child_path = format!("{path}.{name}");
+                let additional = schema.get("additionalProperties");
+                match properties.and_then(|p| p.get(name)).or(additional) {
+                    Some(Value::Bool(false)) => {
+                        errors.push(format!("{child_path}: unexpected property"));
+                    }
+                    Some(sub) => validate_at(root, sub, child, &child_path, depth + 1, errors),
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(sub) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate_at(root, sub, item, &format!("{path}[{i}]"), depth + 1, errors);
+                }
+            }
+        }
+        _ => {}
+    }
+    errors.truncate(MAX_ERRORS);
+}
+
+/// 응답 코드에 맞는 선언: 정확한 코드 → `2XX` 같은 범위 → `default`
+fn matching_response(
+    status: u16,
+    responses: &[(String, Option<Value>)],
+) -> Option<&(String, Option<Value>)> {
+    let exact = status.to_string();
+    let range = format!("{}XX", status / 100);
+    responses
+        .iter()
+        .find(|(code, _)| *code == exact)
+        .or_else(|| {
+            responses
+                .iter()
+                .find(|(code, _)| code.eq_ignore_ascii_case(&range))
+        })
+        .or_else(|| responses.iter().find(|(code, _)| code == "default"))
+}
+
+/// 오퍼레이션 요청에 쓰는 전송·헤더
+pub struct OperationClient<'a> {
+    /// 자격 증명 없이 요청하는 기본 전송
+    pub transport: &'a HttpTransport,
+    /// 도메인 요청 방식(클라이언트 인증서·TLS 검증 등)을 적용한 전송
+    pub domain_transport: &'a HttpTransport,
+    /// 체크와 같은 추가 헤더·인증
+    pub domain_headers: &'a HashMap<String, String>,
+    /// 체크와 같은 전체·호스트별 동시 요청 제한. 요청마다 오퍼레이션 url의 호스트로 자리를 받음
+    pub limiter: &'a CheckLimiter,
+    pub queue_stats: &'a QueueStats,
+}
+
+/// 오퍼레이션 하나 요청·검증. `with_credentials`면 도메인 전송·헤더로 요청 (오퍼레이션 예시 헤더가 우선)
+pub async fn check_operation(
+    client: &OperationClient<'_>,
+    domain_id: u32,
+    spec: &Value,
+    op: &PlannedOperation,
+    with_credentials: bool,
+) -> OperationCheckResult {
+    let (transport, mut request_headers) = if with_credentials {
+        (client.domain_transport, client.domain_headers.clone())
+    } else {
+        (client.transport, HashMap::new())
+    };
+    request_headers.extend(op.headers.clone());
+    if !request_headers
+        .keys()
+        .any(|k| k.eq_ignore_ascii_case("accept"))
+    {
+        request_headers.insert("accept".to_string(), "application/json".to_string());
+    }
+    let mut result = OperationCheckResult {
+        domain_id,
+        operation: op.operation.clone(),
+        operation_id: op.operation_id.clone(),
+        url: op.url.clone(),
+        with_credentials,
+        timestamp: Utc::now(),
+        status: None,
+        expected_statuses: op.responses.iter().map(|(code, _)| code.clone()).collect(),
+        latency_ms: None,
+        schema_checked: false,
+        errors: Vec::new(),
+        level: "error".to_string(),
+    };
+    let host = check_limiter::host_key(&op.url);
+    let permit = client
+        .limiter
+        .acquire(host.as_deref(), client.queue_stats)
+        .await;
+    let start = Instant::now();
+    let response = transport
+        .fetch(reqwest::Method::GET, &op.url, &request_headers, None)
+        .await;
+    drop(permit);
+    result.latency_ms = Some(u32::try_from(start.elapsed().as_millis()).unwrap_or(u32::MAX));
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            result.errors.push(format!("request failed: {}", e.message));
+            return result;
+        }
+    };
+    let status = response.status.as_u16();
+    result.status = Some(status);
+    let Some((code, schema)) = matching_response(status, &op.responses) else {
+        result.errors.push(format!(
+            "status {status} is not declared ({})",
+            result.expected_statuses.join(", ")
+        ));
+        return result;
+    };
+    if !(200..400).contains(&status) {
+        result
+            .errors
+            .push(format!("HTTP {status} (declared as `{code}`)"));
+    }
+    if let Some(schema) = schema {
+        result.schema_checked = true;
+        match serde_json::from_slice::<Value>(&response.body) {
+            Ok(body) => validate(spec, schema, &body, "$", &mut result.errors),
+            Err(e) => result.errors.push(format!("body is not valid JSON: {e}")),
+        }
+    }
+    result.level = if status >= 500 {
+        "error"
+    } else if result.errors.is_empty() {
+        "info"
+    } else {
+        "warning"
+    }
+    .to_string();
+    result
+}
+
+/// 저장된 스키마로 도메인 하나 체크. 스키마가 없거나 읽지 못하면 `error_message`만 채운 상태.
+/// 도메인 자격 증명은 도메인과 출처가 같은 url에만 (`allow_cross_origin_credentials`면 모두)
+pub async fn check_domain(
+    client: &OperationClient<'_>,
+    domain_id: u32,
+    domain_url: &str,
+    schema_path: &Path,
+    config: &OpenApiCheckConfig,
+) -> OpenApiCheckState {
+    let mut state = OpenApiCheckState {
+        domain_id,
+        last_run: Utc::now(),
+        error_message: None,
+        results: Vec::new(),
+        skipped: Vec::new(),
+    };
+    let planned = std::fs::read_to_string(schema_path)
+        .map_err(|e| format!("schema not downloaded ({}): {e}", schema_path.display()))
+        .and_then(|text| parse_spec(&text))
+        .and_then(|spec| {
+            let base = base_url(&spec, domain_url, config)?;
+            let origin = domain_base(domain_url)?.origin();
+            Ok((plan(&spec, &base), spec, origin))
+        });
+    let ((operations, skipped), spec, origin) = match planned {
+        Ok(planned) => planned,
+        Err(e) => {
+            state.error_message = Some(e);
+            return state;
+        }
+    };
+    state.skipped = skipped;
+    state.results = futures::stream::iter(operations.iter().map(|op| {
+        let with_credentials = config.allow_cross_origin_credentials
+            || Url::parse(&op.url).is_ok_and(|url| url.origin() == origin);
+        check_operation(client, domain_id, &spec, op, with_credentials)
+    }))
+    .buffered(CONCURRENCY)
+    .collect()
+    .await;
+    state
+}
+
+/// 도메인별 마지막 실행. `{dir}/state.json` (versioned)
+pub struct OpenApiCheckService {
+    states: Mutex<Vec<OpenApiCheckState>>,
+    dir: PathBuf,
+}
+
+impl OpenApiCheckService {
+    pub fn new(dir: PathBuf) -> Self {
+        let _ = std::fs::create_dir_all(&dir);
+        let states = load_versioned(&dir.join("state.json"));
+        Self {
+            states: Mutex::new(states),
+            dir,
+        }
+    }
+
+    /// 마지막 실행 후 `interval_mins`가 지났는지
+    pub fn is_due(&self, domain_id: u32, config: &OpenApiCheckConfig, now: DateTime<Utc>) -> bool {
+        let interval = chrono::Duration::minutes(i64::from(config.interval_mins.clamp(1, 1440)));
+        self.state(domain_id)
+            .is_none_or(|last| now - last.last_run >= interval)
+    }
+
+    pub fn state(&self, domain_id: u32) -> Option<OpenApiCheckState> {
+        self.states
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.domain_id == domain_id)
+            .cloned()
+    }
+
+    pub fn record(&self, state: OpenApiCheckState) {
+        let mut states = self.states.lock().unwrap();
+        states.retain(|s| s.domain_id != state.domain_id);
+        states.push(state);
+        save_versioned(&self.dir.join("state.json"), &*states);
+    }
+
+    /// 삭제된 도메인의 상태 정리
+    pub fn retain_domains(&self, domain_ids: &HashSet<u32>) {
+        let mut states = self.states.lock().unwrap();
+        let before = states.len();
+        states.retain(|s| domain_ids.contains(&s.domain_id));
+        if states.len() != before {
+            save_versioned(&self.dir.join("state.json"), &*states);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::check_concurrency::CheckConcurrency;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::Json;
+    use serde_json::json;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    /// 필수 파라미터(예시 있음/없음)·body 파라미터·POST·`$ref` 응답을 섞은 스키마
+    const FIXTURE_SPEC: &str = r##"{
+      "openapi": "3.0.3",
+      "info": { "title": "fixture", "version": "1" },
+      "servers": [{ "url": "/{prefix}/v1", "variables": { "prefix": { "default": "api" } } }],
+      "paths": {
+        "/users": {
+          "get": {
+            "operationId": "listUsers",
+            "parameters": [
+              { "name": "limit", "in": "query", "schema": { "type": "integer", "example": 2 } },
+              { "name": "cursor", "in": "query", "schema": { "type": "string" } }
+            ],
+            "responses": {
+              "200": {
+                "description": "ok",
+                "content": { "application/json": { "schema": {
+                  "type": "array", "items": { "$ref": "#/components/schemas/User" }
+                } } }
+              }
+            }
+          },
+          "post": { "responses": { "201": { "description": "created" } } }
+        },
+        "/users/{id}": {
+          "parameters": [{ "name": "id", "in": "path", "required": true, "example": 7 }],
+          "get": {
+            "operationId": "getUser",
+            "responses": {
+              "200": { "$ref": "#/components/responses/UserResponse" },
+              "404": { "description": "not found" }
+            }
+          }
+        },
+        "/orders/{orderId}": {
+          "get": {
+            "parameters": [{ "name": "orderId", "in": "path", "required": true }],
+            "responses": { "200": { "description": "ok" } }
+          }
+        },
+        "/health": {
+          "get": { "responses": { "2XX": { "description": "ok" } } }
+        },
+        "/report": {
+          "get": {
+            "responses": {
+              "200": { "description": "csv", "content": { "text/csv": { "schema": { "type": "string" } } } }
+            }
+          }
+        }
+      },
+      "components": {
+        "responses": {
+          "UserResponse": {
+            "description": "one user",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/User" } } }
+          }
+        },
+        "schemas": {
+          "User": {
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": {
+              "id": { "type": "integer" },
+              "name": { "type": "string" },
+              "role": { "type": "string", "enum": ["admin", "member"] },
+              "manager": { "allOf": [{ "$ref": "#/components/schemas/User" }], "nullable": true }
+            }
+          }
+        }
+      }
+    }"##;
+
+    fn spec() -> Value {
+        parse_spec(FIXTURE_SPEC).unwrap()
+    }
+
+    #[test]
+    fn test_plan_selects_get_operations_with_examples() {
+        let spec = spec();
+        let base = base_url(&spec, "example.com/app", &OpenApiCheckConfig::default()).unwrap();
+        assert_eq!(base.as_str(), "https://example.com/api/v1");
+
+        let (planned, skipped) = plan(&spec, &base);
+        let urls: Vec<&str> = planned.iter().map(|op| op.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/api/v1/health",
+                "https://example.com/api/v1/report",
+                "https://example.com/api/v1/users?limit=2",
+                "https://example.com/api/v1/users/7",
+            ]
+        );
+        let get_user = &planned[3];
+        assert_eq!(get_user.operation, "GET /users/{id}");
+        assert_eq!(get_user.operation_id.as_deref(), Some("getUser"));
+        // `$ref` 응답의 스키마, JSON이 아닌 응답은 스키마 없음
+        assert!(get_user
+            .responses
+            .iter()
+            .any(|(c, s)| c == "200" && s.is_some()));
+        assert!(planned[1].responses[0].1.is_none());
+        assert_eq!(
+            skipped,
+            [SkippedOperation {
+                operation: "GET /orders/{orderId}".to_string(),
+                reason: "required path parameter `orderId` has no example".to_string(),
+            }]
+        );
+
+        let config = OpenApiCheckConfig {
+            base_url: Some("http://10.0.0.5:8080/".to_string()),
+            ..OpenApiCheckConfig::default()
+        };
+        assert_eq!(
+            base_url(&spec, "example.com", &config).unwrap().as_str(),
+            "http://10.0.0.5:8080/"
+        );
+        let swagger = json!({ "swagger": "2.0", "basePath": "/v2", "paths": {} });
+        assert_eq!(
+            base_url(
+                &swagger,
+                "http://petstore.local:81/x",
+                &OpenApiCheckConfig::default()
+            )
+            .unwrap()
+            .as_str(),
+            "http://petstore.local:81/v2"
+        );
+        assert!(parse_spec("openapi: 3.0.0")
+            .unwrap_err()
+            .contains("only JSON"));
+        assert!(parse_spec(r#"{"paths": {}}"#).is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_schema_violations() {
+        let spec = spec();
+        let user = json!({ "$ref": "#/components/schemas/User" });
+        let mut errors = Vec::new();
+        validate(
+            &spec,
+            &user,
+            &json!({ "id": 1, "name": "a", "manager": null }),
+            "$",
+            &mut errors,
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let list = json!({ "type": "array", "items": user });
+        let body = json!([
+            { "id": 1.5, "name": "a" },
+            { "name": "b", "role": "owner", "manager": { "id": "x", "name": "c" } },
+            "oops"
+        ]);
+        validate(&spec, &list, &body, "$", &mut errors);
+        assert_eq!(
+            errors,
+            [
+                "$[0].id: expected integer, got number",
+                "$[1]: missing required property `id`",
+                "$[1].manager.id: expected integer, got string",
+                "$[1].role: \"owner\" is not one of the enum values",
+                "$[2]: expected object, got string",
+            ]
+        );
+
+        let mut errors = Vec::new();
+        let strict = json!({
+            "oneOf": [{ "type": "string" }, { "type": "integer" }],
+            "additionalProperties": false
+        });
+        validate(&spec, &strict, &json!(true), "$", &mut errors);
+        assert_eq!(errors, ["$: matches 0 of 2 `oneOf` schemas"]);
+        let closed = json!({ "type": "object", "additionalProperties": false });
+        let mut errors = Vec::new();
+        validate(&spec, &closed, &json!({ "x": 1 }), "$", &mut errors);
+        assert_eq!(errors, ["$.x: unexpected property"]);
+    }
+
+    #[tokio::test]
+    async fn test_check_domain_against_local_server() {
+        let auth_seen = Arc::new(Mutex::new(Vec::new()));
+        let seen = auth_seen.clone();
+        let app = axum::Router::new()
+            .route(
+                "/api/v1/users",
+                get(move |headers: axum::http::HeaderMap| async move {
+                    let auth = headers.get("authorization").map(|v| v.as_bytes().to_vec());
+                    seen.lock().unwrap().push(auth);
+                    Json(json!([{ "id": 1, "name": "a" }, { "id": 2, "name": "b" }]))
+                }),
+            )
+            // 필수 필드 `name` 누락
+            .route(
+                "/api/v1/users/7",
+                get(|| async { Json(json!({ "id": 7 })) }),
+            )
+            .route(
+                "/api/v1/health",
+                get(|| async { StatusCode::SERVICE_UNAVAILABLE }),
+            )
+            .route(
+                "/api/v1/report",
+                get(|| async { (StatusCode::ACCEPTED, "a,b") }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let schema_path = dir.path().join("1.json");
+        std::fs::write(&schema_path, FIXTURE_SPEC).unwrap();
+        let transport = HttpTransport::new(Arc::new(rustls::RootCertStore::empty()), None);
+        let config = OpenApiCheckConfig::default();
+        let headers = HashMap::from([("authorization".to_string(), "Bearer t".to_string())]);
+        let limiter = CheckLimiter::new(&CheckConcurrency::default());
+        let queue_stats = QueueStats::default();
+        let client = OperationClient {
+            transport: &transport,
+            domain_transport: &transport,
+            domain_headers: &headers,
+            limiter: &limiter,
+            queue_stats: &queue_stats,
+        };
+
+        let state = check_domain(
+            &client,
+            1,
+            &format!("http://127.0.0.1:{port}"),
+            &schema_path,
+            &config,
+        )
+        .await;
+        assert_eq!(state.error_message, None);
+        assert_eq!(state.skipped.len(), 1);
+        assert_eq!(queue_stats.attempts.load(Ordering::Relaxed), 4);
+        let result = |op: &str| state.results.iter().find(|r| r.operation == op).unwrap();
+
+        let list = result("GET /users");
+        assert_eq!((list.status, list.level.as_str()), (Some(200), "info"));
+        assert!(list.schema_checked);
+        assert!(list.latency_ms.is_some());
+        assert!(list.with_credentials);
+        let user = result("GET /users/{id}");
+        assert_eq!(user.level, "warning");
+        assert_eq!(user.errors, ["$: missing required property `name`"]);
+        let health = result("GET /health");
+        assert_eq!((health.status, health.level.as_str()), (Some(503), "error"));
+        assert_eq!(health.errors, ["status 503 is not declared (2XX)"]);
+        let report = result("GET /report");
+        assert_eq!(report.level, "error");
+        assert!(!report.schema_checked);
+
+        // 저장·간격
+        let service = OpenApiCheckService::new(dir.path().join("openapi"));
+        let last_run = state.last_run;
+        service.record(state);
+        assert!(!service.is_due(1, &config, last_run + chrono::Duration::minutes(14)));
+        assert!(service.is_due(1, &config, last_run + chrono::Duration::minutes(15)));
+        let reloaded = OpenApiCheckService::new(dir.path().join("openapi"));
+        assert_eq!(reloaded.state(1).unwrap().results.len(), 4);
+        reloaded.retain_domains(&HashSet::new());
+        assert!(reloaded.state(1).is_none());
+
+        // 스키마가 도메인과 다른 출처를 가리키면 자격 증명 없이, opt-in이면 붙여서
+        let mut cross_origin = OpenApiCheckConfig {
+            base_url: Some(format!("http://127.0.0.1:{port}/api/v1")),
+            ..OpenApiCheckConfig::default()
+        };
+        let state = check_domain(&client, 1, "example.com", &schema_path, &cross_origin).await;
+        assert!(state.results.iter().all(|r| !r.with_credentials));
+        cross_origin.allow_cross_origin_credentials = true;
+        let state = check_domain(&client, 1, "example.com", &schema_path, &cross_origin).await;
+        assert!(state.results.iter().all(|r| r.with_credentials));
+        assert_eq!(
+            *auth_seen.lock().unwrap(),
+            [Some(b"Bearer t".to_vec()), None, Some(b"Bearer t".to_vec())]
+        );
+
+        let missing = check_domain(
+            &client,
+            2,
+            "example.com",
+            &dir.path().join("2.json"),
+            &config,
+        )
+        .await;
+        assert!(missing
+            .error_message
+            .unwrap()
+            .starts_with("schema not downloaded"));
+        assert!(missing.results.is_empty());
+    }
+}
@@ -63,6 +63,17 @@ impl ProxySettingsService {
         out
     }
 
+    /// Set the local route health check interval (clamped to 5–3600 s). None or 0 = off.
+    pub fn set_route_health_interval(&self, interval_secs: Option<u32>) -> ProxySettings {
+        let mut s = self.settings.lock().unwrap();
+        s.route_health_interval_secs = interval_secs
+            .filter(|&secs| secs > 0)
+            .map(|secs| secs.clamp(5, 3600));
+        let out = s.clone();
+        self.save(&out);
+        out
+    }
+
     /// Toggle local routing on/off (persisted).
     pub fn set_local_routing_enabled(&self, enabled: bool) -> ProxySettings {
         let mut s = self.settings.lock().unwrap();
@@ -0,0 +1,248 @@
+//! 로컬 라우트 백엔드 체크. 켜진 `LocalRoute`마다 프록시가 보낼 대상(`local_proxy::route_probe_target`)에
+//! TCP 연결 → 원래 Host 헤더로 `GET /` → 상태·지연 시간.
+//! 최신 결과만 메모리에 두고, 주기는 `ProxySettings.route_health_interval_secs` (없으면 수동 체크만).
+
+use crate::model::local_route::LocalRoute;
+use crate::model::route_health::RouteHealth;
+use crate::service::local_proxy;
+use crate::service::net_probe;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn elapsed_ms(start: Instant) -> u32 {
+    u32::try_from(start.elapsed().as_millis()).unwrap_or(u32::MAX)
+}
+
+/// 라우트 하나 체크. `proxy_routes`는 프록시가 보는 라우트 목록 (로컬 라우팅이 꺼져 있으면 비어 있음)
+pub async fn check_route(
+    client: &reqwest::Client,
+    route: &LocalRoute,
+    proxy_routes: &[LocalRoute],
+) -> RouteHealth {
+    let (host, resolved) = local_proxy::route_probe_target(route, proxy_routes);
+    let pass_through = resolved.is_none();
+    let (target_host, target_port) =
+        resolved.unwrap_or_else(|| (route.target_host.clone(), route.target_port));
+    let shadowed_by =
+        if pass_through || (target_host == route.target_host && target_port == route.target_port) {
+            None
+        } else {
+            proxy_routes
+                .iter()
+                .find(|r| {
+                    r.id != route.id && r.target_host == target_host && r.target_port == target_port
+                })
+                .map(|r| r.id)
+        };
+    let authority = if target_host.contains(':') {
+        format!("[{target_host}]:{target_port}")
+    } else {
+        format!("{target_host}:{target_port}")
+    };
+    let mut health = RouteHealth {
+        route_id: route.id,
+        domain: route.domain.clone(),
+        host: host.clone(),
+        target: authority.clone(),
+        pass_through,
+        shadowed_by,
+        port_open: false,
+        connect_ms: None,
+        status: None,
+        latency_ms: None,
+        level: "error".to_string(),
+        error: None,
+        checked_at: Utc::now(),
+    };
+
+    let start = Instant::now();
+    match net_probe::connect(&target_host, target_port).await {
+        Ok(_) => {
+            health.port_open = true;
+            health.connect_ms = Some(elapsed_ms(start));
+        }
+        Err(e) => {
+            health.error = Some(format!("port closed: {e}"));
+            return health;
+        }
+    }
+
+    let start = Instant::now();
+    let response = client
+        .get(format!("http://{authority}/"))
+        .header(reqwest::header::HOST, host.as_str())
+        .timeout(REQUEST_TIMEOUT)
+        .send()
+        .await;
+    health.latency_ms = Some(elapsed_ms(start));
+    let status = match response {
+        Ok(res) => res.status(),
+        Err(e) => {
+            health.error = Some(format!("request failed: {e}"));
+            return health;
+        }
+    };
+    health.status = Some(status.as_u16());
+    health.level = if status.is_server_error() {
+        "error"
+    } else if status.is_client_error() || pass_through || shadowed_by.is_some() {
+        "warning"
+    } else {
+        "info"
+    }
+    .to_string();
+    health.error = if pass_through {
+        Some("local routing is off; the proxy passes this host through".to_string())
+    } else {
+        shadowed_by.map(|id| format!("route #{id} matches this host first"))
+    };
+    health
+}
+
+pub struct RouteHealthService {
+    client: reqwest::Client,
+    last: Mutex<HashMap<u32, RouteHealth>>,
+    settings_changed: Notify,
+}
+
+impl Default for RouteHealthService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RouteHealthService {
+    pub fn new() -> Self {
+        Self {
+            // 프록시와 같은 설정: 시스템 프록시 무시, 리다이렉트 안 따라감
+            client: reqwest::Client::builder()
+                .no_proxy()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .unwrap_or_default(),
+            last: Mutex::new(HashMap::new()),
+            settings_changed: Notify::new(),
+        }
+    }
+
+    /// 켜진 라우트를 모두 동시에 체크하고 최신 결과를 바꿈 (꺼지거나 삭제된 라우트 결과는 지움)
+    pub async fn check_all(
+        &self,
+        routes: &[LocalRoute],
+        routing_enabled: bool,
+    ) -> Vec<RouteHealth> {
+        let enabled: Vec<LocalRoute> = routes.iter().filter(|r| r.enabled).cloned().collect();
+        let proxy_routes: &[LocalRoute] = if routing_enabled { &enabled } else { &[] };
+        let results = futures::future::join_all(
+            enabled
+                .iter()
+                .map(|route| check_route(&self.client, route, proxy_routes)),
+        )
+        .await;
+        *self.last.lock().unwrap() = results.iter().map(|h| (h.route_id, h.clone())).collect();
+        results
+    }
+
+    /// 라우트 목록 순서대로 최신 결과. 아직 체크하지 않은 라우트는 빠짐
+    pub fn latest(&self, routes: &[LocalRoute]) -> Vec<RouteHealth> {
+        let last = self.last.lock().unwrap();
+        routes
+            .iter()
+            .filter_map(|r| last.get(&r.id).cloned())
+            .collect()
+    }
+
+    /// 체크 주기가 바뀌면 백그라운드 루프를 바로 깨움
+    pub fn notify_settings_changed(&self) {
+        self.settings_changed.notify_one();
+    }
+
+    pub async fn wait(&self, timeout: Duration) {
+        let _ = tokio::time::timeout(timeout, self.settings_changed.notified()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::get;
+
+    fn route(id: u32, domain: &str, port: u16) -> LocalRoute {
+        LocalRoute {
+            id,
+            domain: domain.to_string(),
+            target_host: "127.0.0.1".to_string(),
+            target_port: port,
+            enabled: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checks_follow_proxy_routing() {
+        // Host가 라우트 도메인일 때만 200
+        let app = axum::Router::new().route(
+            "/",
+            get(|headers: HeaderMap| async move {
+                match headers.get("host").and_then(|v| v.to_str().ok()) {
+                    Some("api.example.com") => StatusCode::OK,
+                    _ => StatusCode::MISDIRECTED_REQUEST,
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let live = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let closed = {
+            let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            l.local_addr().unwrap().port()
+        };
+
+        let routes = vec![
+            // http 요청에는 스킴이 맞는 4번이 우선하므로 1번은 4번 대상으로 체크
+            route(1, "api.example.com", closed),
+            route(2, "down.example.com", closed),
+            route(3, "off.example.com", live),
+            route(4, "http://api.example.com", live),
+        ];
+        let mut routes_with_disabled = routes.clone();
+        routes_with_disabled[2].enabled = false;
+        let service = RouteHealthService::new();
+
+        let results = service.check_all(&routes_with_disabled, true).await;
+        assert_eq!(results.len(), 3);
+        let shadowed = &results[0];
+        assert_eq!(shadowed.target, format!("127.0.0.1:{live}"));
+        assert_eq!(shadowed.shadowed_by, Some(4));
+        assert_eq!(shadowed.status, Some(200));
+        assert_eq!(shadowed.level, "warning");
+        let down = &results[1];
+        assert!(!down.port_open);
+        assert_eq!(down.level, "error");
+        assert!(down.status.is_none());
+        let direct = &results[2];
+        assert_eq!(direct.host, "api.example.com");
+        assert_eq!(direct.status, Some(200));
+        assert_eq!(direct.level, "info");
+        assert!(direct.latency_ms.is_some());
+
+        // 꺼진 라우트는 결과에서 빠지고, 로컬 라우팅이 꺼지면 pass-through 경고
+        assert!(service.latest(&routes).iter().all(|h| h.route_id != 3));
+        let results = service.check_all(&routes, false).await;
+        let off = results.iter().find(|h| h.route_id == 3).unwrap();
+        assert!(off.pass_through);
+        assert_eq!(off.status, Some(421));
+        assert_eq!(off.level, "warning");
+        let direct = results.iter().find(|h| h.route_id == 4).unwrap();
+        assert!(direct.pass_through);
+        assert_eq!(direct.level, "warning");
+        assert_eq!(service.latest(&routes).len(), 4);
+    }
+}
@@ -0,0 +1,1054 @@
+//! 보안 헤더·TLS 설정 점검 (`DomainMonitorLink.security_audit`).
+//! HTTPS로 GET한 응답 헤더(HSTS·CSP·X-Content-Type-Options·Referrer-Policy·쿠키 속성), 평문 HTTP가 HTTPS로
+//! 넘어가는지, 서버가 받아 주는 TLS 버전을 규칙별로 채점해 0~100점을 매긴다.
+//! rustls는 TLS 1.2·1.3만 지원하므로 TLS 1.0·1.1은 `ClientHello`를 직접 보내 `ServerHello` 버전으로 판단.
+
+use crate::model::security_audit::{
+    SecurityAuditConfig, SecurityAuditResult, SecurityAuditState, SecurityFinding,
+    SecurityRegression,
+};
+use crate::service::check_limiter::{self, CheckLimiter, QueueStats};
+use crate::service::http_transport::{
+    AcceptAnyServerCert, FetchError, FetchResponse, HttpTransport,
+};
+use crate::service::net_probe::ProbeKind;
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Utc};
+use reqwest::header::{
+    HeaderMap, CONTENT_SECURITY_POLICY, CONTENT_SECURITY_POLICY_REPORT_ONLY, REFERRER_POLICY,
+    SET_COOKIE, STRICT_TRANSPORT_SECURITY, X_CONTENT_TYPE_OPTIONS,
+};
+use reqwest::Url;
+use ring::rand::{SecureRandom, SystemRandom};
+use rustls::SupportedProtocolVersion;
+use rustls_pki_types::ServerName;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+// 규칙별 만점 (합 100)
+const HTTPS_REDIRECT_POINTS: u32 = 15;
+const HSTS_POINTS: u32 = 20;
+const CSP_POINTS: u32 = 15;
+const CONTENT_TYPE_OPTIONS_POINTS: u32 = 10;
+const REFERRER_POLICY_POINTS: u32 = 10;
+const COOKIE_POINTS: u32 = 10;
+const LEGACY_TLS_POINTS: u32 = 15;
+const TLS13_POINTS: u32 = 5;
+
+/// HSTS 권장 최소 max-age (180일)
+const HSTS_MIN_AGE_SECS: u64 = 180 * 24 * 3600;
+/// HSTS preload 목록 요구 max-age (1년)
+const HSTS_PRELOAD_AGE_SECS: u64 = 365 * 24 * 3600;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `Referrer-Policy`에서 교차 출처로 전체 url을 보내지 않는 값
+const STRICT_REFERRER_POLICIES: [&str; 6] = [
+    "no-referrer",
+    "same-origin",
+    "strict-origin",
+    "strict-origin-when-cross-origin",
+    "origin",
+    "origin-when-cross-origin",
+];
+
+fn finding(
+    rule: &str,
+    level: &str,
+    points: u32,
+    max_points: u32,
+    detail: impl Into<String>,
+) -> SecurityFinding {
+    SecurityFinding {
+        rule: rule.to_string(),
+        passed: level == "info",
+        level: level.to_string(),
+        points,
+        max_points,
+        detail: detail.into(),
+    }
+}
+
+fn header_str(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<&str> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+/// 점검할 (https url, 평문 http url). 스킴을 바꾼 쪽은 기본 포트를 씀. http(s)가 아닌 대상은 `None`
+pub fn audit_urls(url: &str) -> Option<(Url, Url)> {
+    let url = url.trim();
+    if ProbeKind::of(url) != ProbeKind::Http {
+        return None;
+    }
+    let full = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    };
+    let original = Url::parse(&full).ok()?;
+    original.host_str()?;
+    let mut switched = original.clone();
+    switched
+        .set_scheme(if original.scheme() == "https" {
+            "http"
+        } else {
+            "https"
+        })
+        .ok()?;
+    switched.set_port(None).ok()?;
+    Some(if original.scheme() == "https" {
+        (original, switched)
+    } else {
+        (switched, original)
+    })
+}
+
+fn hsts_finding(headers: &HeaderMap) -> SecurityFinding {
+    let Some(value) = header_str(headers, STRICT_TRANSPORT_SECURITY) else {
+        return finding("hsts", "error", 0, HSTS_POINTS, "missing");
+    };
+    let directives: Vec<String> = value
+        .split(';')
+        .map(|d| d.trim().to_ascii_lowercase())
+        .collect();
+    let max_age = directives
+        .iter()
+        .find_map(|d| d.strip_prefix("max-age="))
+        .and_then(|v| v.trim_matches('"').parse::<u64>().ok());
+    let subdomains = directives.iter().any(|d| d == "includesubdomains");
+    let preload = directives.iter().any(|d| d == "preload");
+    match max_age {
+        None | Some(0) => finding(
+            "hsts",
+            "error",
+            0,
+            HSTS_POINTS,
+            format!("{value} (no max-age)"),
+        ),
+        Some(age) if age < HSTS_MIN_AGE_SECS => finding(
+            "hsts",
+            "warning",
+            10,
+            HSTS_POINTS,
+            format!("{value} (max-age < 180 days)"),
+        ),
+        Some(age) if preload && subdomains && age >= HSTS_PRELOAD_AGE_SECS => {
+            finding("hsts", "info", HSTS_POINTS, HSTS_POINTS, value)
+        }
+        Some(_) if preload => finding(
+            "hsts",
+            "warning",
+            15,
+            HSTS_POINTS,
+            format!("{value} (preload needs includeSubDomains and max-age of 1 year)"),
+        ),
+        Some(_) => finding(
+            "hsts",
+            "warning",
+            15,
+            HSTS_POINTS,
+            format!("{value} (not preload-ready)"),
+        ),
+    }
+}
+
+fn csp_finding(headers: &HeaderMap) -> SecurityFinding {
+    if let Some(value) = header_str(headers, CONTENT_SECURITY_POLICY) {
+        return finding("csp", "info", CSP_POINTS, CSP_POINTS, value);
+    }
+    match header_str(headers, CONTENT_SECURITY_POLICY_REPORT_ONLY) {
+        Some(value) => finding(
+            "csp",
+            "warning",
+            5,
+            CSP_POINTS,
+            format!("report-only: {value}"),
+        ),
+        None => finding("csp", "warning", 0, CSP_POINTS, "missing"),
+    }
+}
+
+fn content_type_options_finding(headers: &HeaderMap) -> SecurityFinding {
+    let max = CONTENT_TYPE_OPTIONS_POINTS;
+    match header_str(headers, X_CONTENT_TYPE_OPTIONS) {
+        Some(value) if value.eq_ignore_ascii_case("nosniff") => {
+            finding("contentTypeOptions", "info", max, max, value)
+        }
+        Some(value) => finding("contentTypeOptions", "warning", 0, max, value),
+        None => finding("contentTypeOptions", "warning", 0, max, "missing"),
+    }
+}
+
+fn referrer_policy_finding(headers: &HeaderMap) -> SecurityFinding {
+    let max = REFERRER_POLICY_POINTS;
+    let Some(value) = header_str(headers, REFERRER_POLICY) else {
+        return finding("referrerPolicy", "warning", 0, max, "missing");
+    };
+    // 쉼표로 여러 개면 브라우저가 아는 마지막 값이 적용됨
+    let effective = value
+        .split(',')
+        .map(|p| p.trim().to_ascii_lowercase())
+        .rev()
+        .find(|p| {
+            STRICT_REFERRER_POLICIES.contains(&p.as_str())
+                || matches!(p.as_str(), "unsafe-url" | "no-referrer-when-downgrade")
+        });
+    match effective {
+        Some(policy) if STRICT_REFERRER_POLICIES.contains(&policy.as_str()) => {
+            finding("referrerPolicy", "info", max, max, value)
+        }
+        Some(_) => finding(
+            "referrerPolicy",
+            "warning",
+            0,
+            max,
+            format!("{value} (sends full URL cross-origin)"),
+        ),
+        None => finding(
+            "referrerPolicy",
+            "warning",
+            0,
+            max,
+            format!("{value} (unknown policy)"),
+        ),
+    }
+}
+
+/// `Set-Cookie`마다 Secure·HttpOnly·SameSite. 점수는 세 속성을 모두 가진 쿠키 비율
+fn cookie_finding(headers: &HeaderMap) -> SecurityFinding {
+    let cookies: Vec<&str> = headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+    if cookies.is_empty() {
+        return finding(
+            "cookies",
+            "info",
+            COOKIE_POINTS,
+            COOKIE_POINTS,
+            "no cookies set",
+        );
+    }
+    let mut problems = Vec::new();
+    for cookie in &cookies {
+        let mut parts = cookie.split(';');
+        let name = parts
+            .next()
+            .and_then(|p| p.split_once('='))
+            .map_or("?", |(name, _)| name.trim());
+        let attributes: Vec<String> = parts
+            .map(|a| {
+                a.split('=')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_ascii_lowercase()
+            })
+            .collect();
+        let missing: Vec<&str> = [
+            ("secure", "Secure"),
+            ("httponly", "HttpOnly"),
+            ("samesite", "SameSite"),
+        ]
+        .into_iter()
+        .filter(|(attr, _)| !attributes.iter().any(|a| a == attr))
+        .map(|(_, label)| label)
+        .collect();
+        if !missing.is_empty() {
+            problems.push(format!("{name}: missing {}", missing.join(", ")));
+        }
+    }
+    if problems.is_empty() {
+        return finding(
+            "cookies",
+            "info",
+            COOKIE_POINTS,
+            COOKIE_POINTS,
+            format!(
+                "{} cookie(s) with Secure, HttpOnly, SameSite",
+                cookies.len()
+            ),
+        );
+    }
+    let ok = u32::try_from(cookies.len() - problems.len()).unwrap_or(0);
+    let total = u32::try_from(cookies.len()).unwrap_or(u32::MAX);
+    finding(
+        "cookies",
+        "warning",
+        COOKIE_POINTS * ok / total,
+        COOKIE_POINTS,
+        problems.join("; "),
+    )
+}
+
+/// HTTPS 응답 헤더 규칙 (HSTS·CSP·X-Content-Type-Options·Referrer-Policy·쿠키)
+pub fn header_findings(headers: &HeaderMap) -> Vec<SecurityFinding> {
+    vec![
+        hsts_finding(headers),
+        csp_finding(headers),
+        content_type_options_finding(headers),
+        referrer_policy_finding(headers),
+        cookie_finding(headers),
+    ]
+}
+
+/// 평문 http 요청이 (리다이렉트를 따라가서) https로 끝나는지. 평문 포트가 닫혀 있으면 통과
+pub fn https_redirect_finding(plain: &Result<FetchResponse, FetchError>) -> SecurityFinding {
+    let max = HTTPS_REDIRECT_POINTS;
+    let chain = match plain {
+        Ok(response) => &response.chain,
+        Err(e) => &e.chain,
+    };
+    if let Some(location) = chain
+        .iter()
+        .filter_map(|hop| hop.location.as_deref())
+        .find(|location| location.starts_with("https://"))
+    {
+        return finding(
+            "httpsRedirect",
+            "info",
+            max,
+            max,
+            format!("redirects to {location}"),
+        );
+    }
+    match (plain, chain.first()) {
+        (Ok(response), _) => finding(
+            "httpsRedirect",
+            "error",
+            0,
+            max,
+            format!("served over plain HTTP ({})", response.status.as_u16()),
+        ),
+        (Err(_), Some(hop)) => finding(
+            "httpsRedirect",
+            "error",
+            0,
+            max,
+            format!("served over plain HTTP ({})", hop.status),
+        ),
+        (Err(e), None) => finding(
+            "httpsRedirect",
+            "info",
+            max,
+            max,
+            format!("plain HTTP not reachable ({})", e.message),
+        ),
+    }
+}
+
+/// 받아 준 TLS 버전으로 TLS 1.0·1.1 거부, TLS 1.3 지원 규칙
+pub fn tls_findings(versions: &Result<Vec<String>, String>) -> Vec<SecurityFinding> {
+    let versions = match versions {
+        Ok(versions) => versions,
+        Err(e) => {
+            let detail = format!("TLS probe failed: {e}");
+            return vec![
+                finding("legacyTls", "error", 0, LEGACY_TLS_POINTS, detail.clone()),
+                finding("tls13", "error", 0, TLS13_POINTS, detail),
+            ];
+        }
+    };
+    let legacy: Vec<&str> = versions
+        .iter()
+        .map(String::as_str)
+        .filter(|v| matches!(*v, "TLS 1.0" | "TLS 1.1"))
+        .collect();
+    let legacy = if legacy.is_empty() {
+        finding(
+            "legacyTls",
+            "info",
+            LEGACY_TLS_POINTS,
+            LEGACY_TLS_POINTS,
+            "TLS 1.0 and 1.1 rejected",
+        )
+    } else {
+        finding(
+            "legacyTls",
+            "error",
+            0,
+            LEGACY_TLS_POINTS,
+            format!("accepts {}", legacy.join(", ")),
+        )
+    };
+    let tls13 = if versions.iter().any(|v| v == "TLS 1.3") {
+        finding(
+            "tls13",
+            "info",
+            TLS13_POINTS,
+            TLS13_POINTS,
+            "TLS 1.3 supported",
+        )
+    } else {
+        let highest = versions.last().map_or("none", String::as_str);
+        finding(
+            "tls13",
+            "warning",
+            0,
+            TLS13_POINTS,
+            format!("TLS 1.3 not supported (highest: {highest})"),
+        )
+    };
+    vec![legacy, tls13]
+}
+
+pub fn grade(score: u32) -> &'static str {
+    match score {
+        90.. => "A",
+        80..=89 => "B",
+        70..=79 => "C",
+        60..=69 => "D",
+        _ => "F",
+    }
+}
+
+fn worst_level(findings: &[SecurityFinding]) -> &'static str {
+    if findings.iter().any(|f| f.level == "error") {
+        "error"
+    } else if findings.iter().any(|f| f.level == "warning") {
+        "warning"
+    } else {
+        "info"
+    }
+}
+
+/// 규칙 판정으로 점수·등급·level을 채운 결과 (회귀 비교 전)
+pub fn build_result(
+    domain_id: u32,
+    url: &str,
+    findings: Vec<SecurityFinding>,
+    tls_versions: Vec<String>,
+    error_message: Option<String>,
+) -> SecurityAuditResult {
+    let score = findings.iter().map(|f| f.points).sum::<u32>().min(100);
+    let level = if error_message.is_some() {
+        "error"
+    } else {
+        worst_level(&findings)
+    };
+    SecurityAuditResult {
+        domain_id,
+        url: url.to_string(),
+        timestamp: Utc::now(),
+        score,
+        grade: grade(score).to_string(),
+        level: level.to_string(),
+        findings,
+        tls_versions,
+        previous_score: None,
+        regressions: Vec::new(),
+        error_message,
+    }
+}
+
+/// 이전보다 점수가 떨어진 규칙
+pub fn regressions(
+    previous: &SecurityAuditResult,
+    current: &SecurityAuditResult,
+) -> Vec<SecurityRegression> {
+    current
+        .findings
+        .iter()
+        .filter_map(|f| {
+            let prev = previous.findings.iter().find(|p| p.rule == f.rule)?;
+            (f.points < prev.points).then(|| SecurityRegression {
+                rule: f.rule.clone(),
+                previous_points: prev.points,
+                points: f.points,
+                previous_detail: prev.detail.clone(),
+                detail: f.detail.clone(),
+            })
+        })
+        .collect()
+}
+
+async fn connect(host: &str, port: u16, connect_host: Option<IpAddr>) -> Result<TcpStream, String> {
+    match connect_host {
+        Some(ip) => tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((ip, port))).await,
+        None => tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((host, port))).await,
+    }
+    .map_err(|_| "connect timed out".to_string())?
+    .map_err(|e| e.to_string())
+}
+
+/// rustls로 한 버전만 허용해 핸드셰이크. 연결 자체가 실패하면 `Err`
+async fn accepts_version(
+    host: &str,
+    port: u16,
+    connect_host: Option<IpAddr>,
+    version: &'static SupportedProtocolVersion,
+) -> Result<bool, String> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let config = rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_protocol_versions(&[version])
+        .map_err(|e| e.to_string())?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyServerCert(provider)))
+        .with_no_client_auth();
+    let server_name = ServerName::try_from(host.to_string()).map_err(|e| e.to_string())?;
+    let tcp = connect(host, port, connect_host).await?;
+    let handshake = TlsConnector::from(Arc::new(config)).connect(server_name, tcp);
+    Ok(matches!(
+        tokio::time::timeout(CONNECT_TIMEOUT, handshake).await,
+        Ok(Ok(_))
+    ))
+}
+
+/// TLS 1.0(`0x0301`)·1.1(`0x0302`) `ClientHello`. 오래된 서버가 받아 주는 cipher suite와 SNI만 넣음
+fn legacy_client_hello(version: u16, host: &str) -> Vec<u8> {
+    let mut random = [0u8; 32];
+    let _ = SystemRandom::new().fill(&mut random);
+    let suites: [u16; 9] = [
+        0xc013, 0xc014, 0xc009, 0xc00a, 0x0033, 0x0039, 0x002f, 0x0035, 0x000a,
+    ];
+
+    let mut extensions = Vec::new();
+    if host.parse::<IpAddr>().is_err() {
+        let name = host.as_bytes();
+        let name_len = u16::try_from(name.len()).unwrap_or(0);
+        extensions.extend_from_slice(&0x0000u16.to_be_bytes());
+        extensions.extend_from_slice(&(name_len + 5).to_be_bytes());
+        extensions.extend_from_slice(&(name_len + 3).to_be_bytes());
+        extensions.push(0);
+        extensions.extend_from_slice(&name_len.to_be_bytes());
+        extensions.extend_from_slice(name);
+    }
+    // supported_groups (P-256, P-384, x25519), ec_point_formats (uncompressed), renegotiation_info
+    extensions.extend_from_slice(&[
+        0x00, 0x0a, 0x00, 0x08, 0x00, 0x06, 0x00, 0x17, 0x00, 0x18, 0x00, 0x1d,
+    ]);
+    extensions.extend_from_slice(&[0x00, 0x0b, 0x00, 0x02, 0x01, 0x00]);
+    extensions.extend_from_slice(&[0xff, 0x01, 0x00, 0x01, 0x00]);
+
+    let mut hello = Vec::new();
+    hello.extend_from_slice(&version.to_be_bytes());
+    hello.extend_from_slice(&random);
+    hello.push(0); // session id
+    hello.extend_from_slice(&u16::try_from(suites.len() * 2).unwrap_or(0).to_be_bytes());
+    for suite in suites {
+        hello.extend_from_slice(&suite.to_be_bytes());
+    }
+    hello.extend_from_slice(&[1, 0]); // compression: null
+    hello.extend_from_slice(&u16::try_from(extensions.len()).unwrap_or(0).to_be_bytes());
+    hello.extend_from_slice(&extensions);
+
+    let hello_len = u32::try_from(hello.len()).unwrap_or(0).to_be_bytes();
+    let mut handshake = vec![1, hello_len[1], hello_len[2], hello_len[3]];
+    handshake.extend_from_slice(&hello);
+    let mut record = vec![0x16, 0x03, 0x01];
+    record.extend_from_slice(&u16::try_from(handshake.len()).unwrap_or(0).to_be_bytes());
+    record.extend_from_slice(&handshake);
+    record
+}
+
+/// 레거시 버전 `ClientHello`에 같은 버전의 `ServerHello`가 오면 지원. alert·연결 끊김은 거부
+async fn accepts_legacy_version(
+    host: &str,
+    port: u16,
+    connect_host: Option<IpAddr>,
+    version: u16,
+) -> Result<bool, String> {
+    let mut tcp = connect(host, port, connect_host).await?;
+    tcp.write_all(&legacy_client_hello(version, host))
+        .await
+        .map_err(|e| e.to_string())?;
+    // record header (5) + handshake type (1) + length (3) + server_version (2)
+    let mut head = [0u8; 11];
+    let read = tokio::time::timeout(CONNECT_TIMEOUT, tcp.read_exact(&mut head)).await;
+    if !matches!(read, Ok(Ok(_))) {
+        return Ok(false);
+    }
+    Ok(head[0] == 0x16 && head[5] == 2 && u16::from_be_bytes([head[9], head[10]]) == version)
+}
+
+/// 서버가 받아 주는 TLS 버전 (낮은 것부터). 모든 시도에서 연결하지 못하면 `Err`.
+/// 핸드셰이크마다 `limiter`에서 자리를 받음
+pub async fn tls_versions(
+    host: &str,
+    port: u16,
+    connect_host: Option<IpAddr>,
+    limiter: &CheckLimiter,
+    stats: &QueueStats,
+) -> Result<Vec<String>, String> {
+    let key = check_limiter::host_key(host);
+    let key = key.as_deref();
+    let (tls10, tls11, tls12, tls13) = tokio::join!(
+        limiter.run(
+            key,
+            stats,
+            accepts_legacy_version(host, port, connect_host, 0x0301)
+        ),
+        limiter.run(
+            key,
+            stats,
+            accepts_legacy_version(host, port, connect_host, 0x0302)
+        ),
+        limiter.run(
+            key,
+            stats,
+            accepts_version(host, port, connect_host, &rustls::version::TLS12)
+        ),
+        limiter.run(
+            key,
+            stats,
+            accepts_version(host, port, connect_host, &rustls::version::TLS13)
+        ),
+    );
+    let results = [
+        ("TLS 1.0", tls10),
+        ("TLS 1.1", tls11),
+        ("TLS 1.2", tls12),
+        ("TLS 1.3", tls13),
+    ];
+    if results.iter().all(|(_, r)| r.is_err()) {
+        return Err(results
+            .into_iter()
+            .find_map(|(_, r)| r.err())
+            .unwrap_or_default());
+    }
+    Ok(results
+        .iter()
+        .filter(|(_, r)| matches!(r, Ok(true)))
+        .map(|(name, _)| (*name).to_string())
+        .collect())
+}
+
+/// 도메인 하나 점검: https GET(헤더)·평문 http GET(리다이렉트)·TLS 버전을 동시에.
+/// `headers`: 체크와 같은 추가 헤더·인증 (평문 http 요청에는 인증이 새지 않도록 User-Agent만).
+/// `connect_host`: TLS 버전 확인에 쓸 주소 (프록시 DNS로 해석한 IP).
+/// 요청·핸드셰이크마다 체크와 같은 `limiter`에서 자리를 받음
+pub async fn audit(
+    transport: &HttpTransport,
+    domain_id: u32,
+    url: &str,
+    headers: &HashMap<String, String>,
+    connect_host: Option<IpAddr>,
+    limiter: &CheckLimiter,
+    stats: &QueueStats,
+) -> SecurityAuditResult {
+    let Some((https_url, http_url)) = audit_urls(url) else {
+        return build_result(
+            domain_id,
+            url,
+            Vec::new(),
+            Vec::new(),
+            Some("not an http(s) target".to_string()),
+        );
+    };
+    let host = https_url
+        .host_str()
+        .unwrap_or_default()
+        .trim_matches(['[', ']'])
+        .to_string();
+    let port = https_url.port_or_known_default().unwrap_or(443);
+    let plain_headers: HashMap<String, String> = headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("user-agent"))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    let key = check_limiter::host_key(&host);
+    let (secure, plain, versions) = tokio::join!(
+        limiter.run(
+            key.as_deref(),
+            stats,
+            transport.fetch(reqwest::Method::GET, https_url.as_str(), headers, None)
+        ),
+        limiter.run(
+            key.as_deref(),
+            stats,
+            transport.fetch(
+                reqwest::Method::GET,
+                http_url.as_str(),
+                &plain_headers,
+                None
+            )
+        ),
+        tls_versions(&host, port, connect_host, limiter, stats),
+    );
+
+    let mut findings = vec![https_redirect_finding(&plain)];
+    let error_message = match &secure {
+        Ok(response) => {
+            findings.extend(header_findings(&response.headers));
+            None
+        }
+        Err(e) => {
+            let detail = format!("no HTTPS response: {}", e.message);
+            findings.extend(
+                [
+                    ("hsts", HSTS_POINTS),
+                    ("csp", CSP_POINTS),
+                    ("contentTypeOptions", CONTENT_TYPE_OPTIONS_POINTS),
+                    ("referrerPolicy", REFERRER_POLICY_POINTS),
+                    ("cookies", COOKIE_POINTS),
+                ]
+                .into_iter()
+                .map(|(rule, max)| finding(rule, "error", 0, max, detail.clone())),
+            );
+            Some(detail)
+        }
+    };
+    findings.extend(tls_findings(&versions));
+    build_result(
+        domain_id,
+        url,
+        findings,
+        versions.unwrap_or_default(),
+        error_message,
+    )
+}
+
+/// url별 마지막 점검과 회귀 비교 기준. `{dir}/state.json` (versioned)
+pub struct SecurityAuditService {
+    states: Mutex<Vec<SecurityAuditState>>,
+    dir: PathBuf,
+}
+
+impl SecurityAuditService {
+    pub fn new(dir: PathBuf) -> Self {
+        let _ = std::fs::create_dir_all(&dir);
+        let states = load_versioned(&dir.join("state.json"));
+        Self {
+            states: Mutex::new(states),
+            dir,
+        }
+    }
+
+    /// 마지막 점검 후 `interval_hours`가 지났는지
+    pub fn is_due(&self, url: &str, config: &SecurityAuditConfig, now: DateTime<Utc>) -> bool {
+        let interval = chrono::Duration::hours(i64::from(config.interval_hours.clamp(1, 168)));
+        self.latest(url)
+            .is_none_or(|last| now - last.timestamp >= interval)
+    }
+
+    pub fn latest(&self, url: &str) -> Option<SecurityAuditResult> {
+        self.states
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.url == url)
+            .map(|s| s.last.clone())
+    }
+
+    /// 직전 정상 점검과 비교해 `previous_score`·`regressions`를 채우고 상태에 반영.
+    /// 실패한 점검은 비교하지 않고 기준도 바꾸지 않음
+    pub fn record(&self, mut result: SecurityAuditResult) -> SecurityAuditResult {
+        let mut states = self.states.lock().unwrap();
+        let state = states.iter_mut().find(|s| s.url == result.url);
+        let baseline = state.as_ref().and_then(|s| s.last_ok.as_ref());
+        if let (Some(previous), None) = (baseline, &result.error_message) {
+            result.previous_score = Some(previous.score);
+            result.regressions = regressions(previous, &result);
+        }
+        let last_ok = if result.error_message.is_none() {
+            Some(result.clone())
+        } else {
+            baseline.cloned()
+        };
+        match state {
+            Some(state) => {
+                state.last = result.clone();
+                state.last_ok = last_ok;
+            }
+            None => states.push(SecurityAuditState {
+                url: result.url.clone(),
+                last: result.clone(),
+                last_ok,
+            }),
+        }
+        save_versioned(&self.dir.join("state.json"), &*states);
+        result
+    }
+
+    /// 체크 대상이 아닌 url의 상태 정리
+    pub fn retain_urls(&self, urls: &[&str]) {
+        let mut states = self.states.lock().unwrap();
+        let before = states.len();
+        states.retain(|s| urls.contains(&s.url.as_str()));
+        if states.len() != before {
+            save_versioned(&self.dir.join("state.json"), &*states);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::check_concurrency::CheckConcurrency;
+    use crate::service::ca_service::CaService;
+    use reqwest::header::HeaderValue;
+    use rustls_pki_types::pem::PemObject;
+    use rustls_pki_types::CertificateDer;
+    use std::sync::atomic::Ordering;
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+
+    fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.append(
+                reqwest::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        map
+    }
+
+    fn rule<'a>(findings: &'a [SecurityFinding], rule: &str) -> &'a SecurityFinding {
+        findings.iter().find(|f| f.rule == rule).unwrap()
+    }
+
+    #[test]
+    fn test_header_rules() {
+        let strong = headers(&[
+            (
+                "strict-transport-security",
+                "max-age=63072000; includeSubDomains; preload",
+            ),
+            ("content-security-policy", "default-src 'self'"),
+            ("x-content-type-options", "nosniff"),
+            (
+                "referrer-policy",
+                "no-referrer, strict-origin-when-cross-origin",
+            ),
+            (
+                "set-cookie",
+                "sid=1; Path=/; Secure; HttpOnly; SameSite=Lax",
+            ),
+        ]);
+        let findings = header_findings(&strong);
+        assert!(findings.iter().all(|f| f.passed), "{findings:?}");
+        assert_eq!(findings.iter().map(|f| f.points).sum::<u32>(), 65);
+
+        let weak = headers(&[
+            ("strict-transport-security", "max-age=300"),
+            ("content-security-policy-report-only", "default-src 'self'"),
+            ("x-content-type-options", "sniff"),
+            ("referrer-policy", "unsafe-url"),
+            ("set-cookie", "sid=1; Secure; HttpOnly; SameSite=Strict"),
+            ("set-cookie", "theme=dark; Path=/"),
+        ]);
+        let findings = header_findings(&weak);
+        let hsts = rule(&findings, "hsts");
+        assert_eq!((hsts.points, hsts.level.as_str()), (10, "warning"));
+        assert_eq!(rule(&findings, "csp").points, 5);
+        assert_eq!(rule(&findings, "contentTypeOptions").points, 0);
+        assert!(rule(&findings, "referrerPolicy")
+            .detail
+            .contains("cross-origin"));
+        let cookies = rule(&findings, "cookies");
+        assert_eq!(cookies.points, 5);
+        assert_eq!(cookies.detail, "theme: missing Secure, HttpOnly, SameSite");
+
+        let findings = header_findings(&HeaderMap::new());
+        assert_eq!(rule(&findings, "hsts").level, "error");
+        assert!(rule(&findings, "cookies").passed);
+        let preload = headers(&[("strict-transport-security", "max-age=31536000; preload")]);
+        assert_eq!(rule(&header_findings(&preload), "hsts").points, 15);
+    }
+
+    #[test]
+    fn test_audit_urls_and_grades() {
+        let (https, http) = audit_urls("example.com/app").unwrap();
+        assert_eq!(https.as_str(), "https://example.com/app");
+        assert_eq!(http.as_str(), "http://example.com/app");
+        let (https, http) = audit_urls("http://example.com:8080/").unwrap();
+        assert_eq!(https.as_str(), "https://example.com/");
+        assert_eq!(http.as_str(), "http://example.com:8080/");
+        assert!(audit_urls("tcp://db.internal:5432").is_none());
+        assert_eq!(grade(100), "A");
+        assert_eq!(grade(85), "B");
+        assert_eq!(grade(59), "F");
+    }
+
+    #[test]
+    fn test_record_flags_regressions_against_last_successful_audit() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = SecurityAuditService::new(dir.path().join("security"));
+        let strong = headers(&[
+            (
+                "strict-transport-security",
+                "max-age=63072000; includeSubDomains; preload",
+            ),
+            ("x-content-type-options", "nosniff"),
+        ]);
+        let first = service.record(build_result(
+            1,
+            "a.test",
+            header_findings(&strong),
+            Vec::new(),
+            None,
+        ));
+        assert_eq!(first.previous_score, None);
+        assert!(first.regressions.is_empty());
+
+        // 실패한 점검은 비교하지 않고 기준도 그대로
+        let failed = service.record(build_result(
+            1,
+            "a.test",
+            Vec::new(),
+            Vec::new(),
+            Some("no HTTPS response".to_string()),
+        ));
+        assert!(failed.regressions.is_empty());
+        assert_eq!(failed.level, "error");
+
+        let weaker = headers(&[("x-content-type-options", "nosniff")]);
+        let second = service.record(build_result(
+            1,
+            "a.test",
+            header_findings(&weaker),
+            Vec::new(),
+            None,
+        ));
+        assert_eq!(second.previous_score, Some(first.score));
+        assert_eq!(second.regressions.len(), 1);
+        assert_eq!(second.regressions[0].rule, "hsts");
+        assert_eq!(second.regressions[0].previous_points, HSTS_POINTS);
+
+        // 재시작 후에도 기준 유지
+        let reloaded = SecurityAuditService::new(dir.path().join("security"));
+        assert_eq!(reloaded.latest("a.test").unwrap().score, second.score);
+        assert!(!reloaded.is_due("a.test", &SecurityAuditConfig::default(), Utc::now()));
+        reloaded.retain_urls(&[]);
+        assert!(reloaded.latest("a.test").is_none());
+    }
+
+    /// rustls 서버 (TLS 1.2·1.3만). 핸드셰이크만 처리
+    async fn spawn_tls_server() -> u16 {
+        let dir = tempfile::tempdir().unwrap();
+        let ca = CaService::new(dir.path()).unwrap();
+        let (cert, key) = ca.sign_host_certificate("localhost").unwrap();
+        let ca_der = CertificateDer::from_pem_slice(ca.ca_cert_pem().as_bytes()).unwrap();
+        let key_der = rustls_pki_types::PrivateKeyDer::Pkcs8(key.serialize_der().into());
+        let config = rustls::ServerConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert.der().clone(), ca_der], key_der)
+        .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    let _ = acceptor.accept(stream).await;
+                });
+            }
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_audit_sends_no_credentials_over_plain_http() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        let app = axum::Router::new().route(
+            "/",
+            axum::routing::get(move |headers: HeaderMap| async move {
+                recorded.lock().unwrap().push(headers);
+                "ok"
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let transport = HttpTransport::new(Arc::new(rustls::RootCertStore::empty()), None);
+        let headers = HashMap::from([
+            ("Authorization".to_string(), "Bearer secret".to_string()),
+            ("X-Api-Key".to_string(), "key".to_string()),
+            ("User-Agent".to_string(), "probe/1.0".to_string()),
+        ]);
+
+        Box::pin(audit(
+            &transport,
+            1,
+            &format!("http://127.0.0.1:{port}"),
+            &headers,
+            None,
+            &CheckLimiter::new(&CheckConcurrency::default()),
+            &QueueStats::default(),
+        ))
+        .await;
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert!(seen[0].get("authorization").is_none());
+        assert!(seen[0].get("x-api-key").is_none());
+        assert_eq!(seen[0].get("user-agent").unwrap(), "probe/1.0");
+    }
+
+    #[tokio::test]
+    async fn test_tls_versions() {
+        let localhost = Some(IpAddr::from([127, 0, 0, 1]));
+        let limiter = CheckLimiter::new(&CheckConcurrency {
+            max_per_host: 1,
+            ..CheckConcurrency::default()
+        });
+        let stats = QueueStats::default();
+        let port = spawn_tls_server().await;
+        let versions = tls_versions("localhost", port, localhost, &limiter, &stats)
+            .await
+            .unwrap();
+        assert_eq!(stats.attempts.load(Ordering::Relaxed), 4);
+        assert_eq!(stats.queued.load(Ordering::Relaxed), 3);
+        assert_eq!(versions, vec!["TLS 1.2", "TLS 1.3"]);
+        let findings = tls_findings(&Ok(versions));
+        assert!(findings.iter().all(|f| f.passed));
+
+        // TLS 1.0 ServerHello로 답하는 오래된 서버
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let legacy_port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut hello = [0u8; 11];
+                    if stream.read_exact(&mut hello).await.is_err() {
+                        return;
+                    }
+                    let version = [hello[9], hello[10]];
+                    let reply = if version == [0x03, 0x01] {
+                        vec![
+                            0x16, 0x03, 0x01, 0x00, 0x2a, 0x02, 0x00, 0x00, 0x26, 0x03, 0x01,
+                        ]
+                    } else {
+                        vec![0x15, 0x03, 0x01, 0x00, 0x02, 0x02, 0x46]
+                    };
+                    let _ = stream.write_all(&reply).await;
+                });
+            }
+        });
+        let versions = tls_versions("localhost", legacy_port, localhost, &limiter, &stats)
+            .await
+            .unwrap();
+        assert_eq!(versions, vec!["TLS 1.0"]);
+        let findings = tls_findings(&Ok(versions));
+        assert_eq!(rule(&findings, "legacyTls").detail, "accepts TLS 1.0");
+        assert_eq!(rule(&findings, "tls13").level, "warning");
+
+        let closed = {
+            let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            l.local_addr().unwrap().port()
+        };
+        assert!(
+            tls_versions("localhost", closed, localhost, &limiter, &stats)
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,493 @@
+//! 공개 상태 페이지 (HTML + JSON).
+//! 설정한 그룹·도메인만 표시 이름으로 내보내고 url·실패 사유는 넣지 않는다. 체크 주기마다 폴더에 쓰고
+//! 로컬 프록시의 `/.watchtower/status`(`status.json`)로도 제공.
+
+use crate::model::check_policy::{ConfirmedState, ConfirmedStatus};
+use crate::model::domain::Domain;
+use crate::model::domain_group::DomainGroup;
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::incident::Incident;
+use crate::model::status_page::{
+    ComponentStatus, StatusPage, StatusPageComponentState, StatusPageConfig, StatusPageDay,
+    StatusPageGroup, StatusPageIncident,
+};
+use crate::model::uptime::RollupBucket;
+use crate::service::chat_notify::format_duration;
+use crate::service::latency_baseline::DEGRADED_LEVEL;
+use crate::service::uptime_rollup;
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
+
+/// 가용성 막대 일수
+pub const STATUS_PAGE_DAYS: u64 = 90;
+
+/// 페이지에 싣는 최근 장애 수
+pub const MAX_STATUS_PAGE_INCIDENTS: usize = 10;
+
+/// 프록시 예약 경로 중 상태 페이지 (`/.watchtower/status`, `/.watchtower/status.json`)
+pub const STATUS_PAGE_PATH: &str = "/.watchtower/status";
+
+/// 페이지를 만드는 데 필요한 현재 상태
+pub struct StatusInputs<'a> {
+    pub domains: &'a [Domain],
+    /// 그룹과 소속 도메인 id
+    pub groups: &'a [(DomainGroup, Vec<u32>)],
+    pub states: &'a [ConfirmedState],
+    /// url별 최신 체크 결과
+    pub latest: &'a [DomainStatusLog],
+    /// 지금 점검 중인 도메인 id
+    pub in_maintenance: &'a HashSet<u32>,
+    /// 막대에 쓸 로컬 날짜 (오래된 것부터)
+    pub days: &'a [NaiveDate],
+    /// `days`의 일 단위 롤업
+    pub daily: &'a [RollupBucket],
+    pub incidents: &'a [Incident],
+}
+
+fn component_status(domain: &Domain, inputs: &StatusInputs<'_>) -> ComponentStatus {
+    if inputs.in_maintenance.contains(&domain.id) {
+        return ComponentStatus::Maintenance;
+    }
+    let Some(state) = inputs.states.iter().find(|s| s.url == domain.url) else {
+        return ComponentStatus::Unknown;
+    };
+    let warning = inputs
+        .latest
+        .iter()
+        .any(|l| l.url == domain.url && matches!(l.level.as_str(), "warning" | DEGRADED_LEVEL));
+    match state.status {
+        ConfirmedStatus::Down => ComponentStatus::Down,
+        _ if state.flapping => ComponentStatus::Degraded,
+        ConfirmedStatus::Up if warning => ComponentStatus::Degraded,
+        ConfirmedStatus::Up => ComponentStatus::Operational,
+        ConfirmedStatus::Unknown => ComponentStatus::Unknown,
+    }
+}
+
+/// 설정의 그룹 순서대로 구역을 만들고, 각 그룹에서 `components`에 있는 도메인만 표시 이름으로 넣음.
+pub fn build_page(
+    config: &StatusPageConfig,
+    inputs: &StatusInputs<'_>,
+    now: DateTime<Utc>,
+) -> StatusPage {
+    let names: HashMap<u32, &str> = config
+        .components
+        .iter()
+        .filter(|c| !c.display_name.trim().is_empty())
+        .map(|c| (c.domain_id, c.display_name.trim()))
+        .collect();
+    let mut shown: HashMap<u32, &str> = HashMap::new();
+    let groups: Vec<StatusPageGroup> = config
+        .group_ids
+        .iter()
+        .filter_map(|gid| inputs.groups.iter().find(|(g, _)| g.id == *gid))
+        .map(|(group, member_ids)| {
+            let components = inputs
+                .domains
+                .iter()
+                .filter(|d| member_ids.contains(&d.id))
+                .filter_map(|d| {
+                    let name = *names.get(&d.id)?;
+                    shown.insert(d.id, name);
+                    let buckets: Vec<&RollupBucket> =
+                        inputs.daily.iter().filter(|b| b.url == d.url).collect();
+                    let days = inputs
+                        .days
+                        .iter()
+                        .map(|date| {
+                            let start = uptime_rollup::local_day_start(*date);
+                            StatusPageDay {
+                                date: date.format("%Y-%m-%d").to_string(),
+                                uptime_percent: uptime_rollup::stats(
+                                    buckets.iter().copied().filter(|b| b.start == start),
+                                )
+                                .uptime_percent,
+                            }
+                        })
+                        .collect();
+                    Some(StatusPageComponentState {
+                        name: name.to_string(),
+                        status: component_status(d, inputs),
+                        uptime_percent: uptime_rollup::stats(buckets.iter().copied())
+                            .uptime_percent,
+                        days,
+                    })
+                })
+                .collect();
+            StatusPageGroup {
+                name: group.name.clone(),
+                components,
+            }
+        })
+        .filter(|g: &StatusPageGroup| !g.components.is_empty())
+        .collect();
+    let since = inputs
+        .days
+        .first()
+        .map_or(now, |d| uptime_rollup::local_day_start(*d));
+    let mut incidents: Vec<StatusPageIncident> = inputs
+        .incidents
+        .iter()
+        .filter(|i| i.resolved_at.is_none_or(|r| r >= since))
+        .filter_map(|i| {
+            Some(StatusPageIncident {
+                component: (*shown.get(&i.domain_id)?).to_string(),
+                started_at: i.started_at,
+                resolved_at: i.resolved_at,
+                duration_secs: i.duration_secs,
+            })
+        })
+        .collect();
+    incidents.sort_by_key(|i| std::cmp::Reverse(i.started_at));
+    incidents.truncate(MAX_STATUS_PAGE_INCIDENTS);
+    let status = groups
+        .iter()
+        .flat_map(|g| g.components.iter().map(|c| c.status))
+        .max()
+        .unwrap_or(ComponentStatus::Operational);
+    StatusPage {
+        title: config.title.clone(),
+        generated_at: now,
+        status,
+        groups,
+        incidents,
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn status_label(status: ComponentStatus) -> (&'static str, &'static str) {
+    match status {
+        ComponentStatus::Operational => ("ok", "Operational"),
+        ComponentStatus::Unknown => ("none", "Unknown"),
+        ComponentStatus::Maintenance => ("maint", "Maintenance"),
+        ComponentStatus::Degraded => ("warn", "Degraded"),
+        ComponentStatus::Down => ("down", "Down"),
+    }
+}
+
+fn format_percent(p: Option<f64>) -> String {
+    p.map_or_else(|| "—".to_string(), |p| format!("{p:.2}%"))
+}
+
+/// 외부 파일 없이 열리는 단일 HTML. 1분마다 새로고침.
+pub fn render_html(page: &StatusPage) -> String {
+    let title = escape_html(&page.title);
+    let (class, label) = status_label(page.status);
+    let overall = match page.status {
+        ComponentStatus::Operational => "All systems operational".to_string(),
+        _ => format!("Current status: {label}"),
+    };
+    let mut body = String::new();
+    for group in &page.groups {
+        let _ = write!(body, "<section><h2>{}</h2>", escape_html(&group.name));
+        for c in &group.components {
+            let (class, label) = status_label(c.status);
+            let _ = write!(
+                body,
+                "<div class=\"component\"><div class=\"row\"><span class=\"name\">{}</span>\
+                 <span class=\"badge {class}\">{label}</span></div><div class=\"bars\">",
+                escape_html(&c.name)
+            );
+            for day in &c.days {
+                let bar = match day.uptime_percent {
+                    None => "none",
+                    Some(p) if p >= 99.9 => "ok",
+                    Some(p) if p >= 95.0 => "warn",
+                    Some(_) => "down",
+                };
+                let _ = write!(
+                    body,
+                    "<span class=\"bar {bar}\" title=\"{} {}\"></span>",
+                    day.date,
+                    format_percent(day.uptime_percent)
+                );
+            }
+            let _ = write!(
+                body,
+                "</div><div class=\"uptime\">{STATUS_PAGE_DAYS} days · {} uptime</div></div>",
+                format_percent(c.uptime_percent)
+            );
+        }
+        body.push_str("</section>");
+    }
+    body.push_str("<section><h2>Recent incidents</h2>");
+    if page.incidents.is_empty() {
+        body.push_str("<p class=\"muted\">No incidents reported.</p>");
+    }
+    for i in &page.incidents {
+        let state = match i.duration_secs {
+            Some(secs) => format!("Resolved after {}", format_duration(secs)),
+            None => "Ongoing".to_string(),
+        };
+        let _ = write!(
+            body,
+            "<div class=\"incident\"><strong>{}</strong> <span class=\"muted\">{} UTC · {state}</span></div>",
+            escape_html(&i.component),
+            i.started_at.format("%Y-%m-%d %H:%M")
+        );
+    }
+    body.push_str("</section>");
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <meta http-equiv=\"refresh\" content=\"60\"><title>{title}</title><style>\
+         body{{font-family:system-ui,sans-serif;max-width:860px;margin:2rem auto;padding:0 1rem;color:#1f2937}}\
+         h2{{font-size:1.1rem;margin-top:2rem}}.overall{{padding:1rem;border-radius:8px;color:#fff;font-weight:600}}\
+         .component{{padding:.75rem 0;border-bottom:1px solid #e5e7eb}}.row{{display:flex;justify-content:space-between}}\
+         .bars{{display:flex;gap:2px;margin:.5rem 0}}.bar{{flex:1;height:28px;border-radius:2px}}\
+         .badge{{font-size:.85rem;padding:0 .5rem;border-radius:4px;color:#fff}}\
+         .ok{{background:#16a34a}}.warn{{background:#f59e0b}}.down{{background:#dc2626}}\
+         .maint{{background:#2563eb}}.none{{background:#d1d5db}}.muted,.uptime{{color:#6b7280;font-size:.85rem}}\
+         .incident{{padding:.4rem 0}}</style></head><body><h1>{title}</h1>\
+         <div class=\"overall {class}\">{overall}</div>{body}\
+         <p class=\"muted\">Updated {} UTC</p></body></html>\n",
+        page.generated_at.format("%Y-%m-%d %H:%M:%S")
+    )
+}
+
+/// 설정과 마지막으로 만든 페이지. 프록시(제공)와 `DomainMonitorService`(생성)가 같은 인스턴스를 공유.
+pub struct StatusPageService {
+    config: Mutex<StatusPageConfig>,
+    config_path: PathBuf,
+    default_output_dir: PathBuf,
+    latest: RwLock<Option<StatusPage>>,
+}
+
+impl StatusPageService {
+    pub fn new(config_path: PathBuf, default_output_dir: PathBuf) -> Self {
+        let config = load_versioned(&config_path);
+        Self {
+            config: Mutex::new(config),
+            config_path,
+            default_output_dir,
+            latest: RwLock::new(None),
+        }
+    }
+
+    pub fn get_config(&self) -> StatusPageConfig {
+        self.config.lock().unwrap().clone()
+    }
+
+    /// 제목이 비었거나 표시 이름 없는 도메인이 있으면 저장하지 않음. 끄면 프록시 제공도 멈춤.
+    pub fn set_config(&self, mut config: StatusPageConfig) -> Result<StatusPageConfig, String> {
+        config.title = config.title.trim().to_string();
+        if config.title.is_empty() {
+            return Err("상태 페이지 제목이 비어 있습니다.".to_string());
+        }
+        if let Some(c) = config
+            .components
+            .iter()
+            .find(|c| c.display_name.trim().is_empty())
+        {
+            return Err(format!(
+                "도메인 ID {} 의 표시 이름이 비어 있습니다.",
+                c.domain_id
+            ));
+        }
+        config.output_dir = config
+            .output_dir
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty());
+        save_versioned(&self.config_path, &config);
+        if !config.enabled {
+            *self.latest.write().unwrap() = None;
+        }
+        self.config.lock().unwrap().clone_from(&config);
+        Ok(config)
+    }
+
+    pub fn output_dir(&self) -> PathBuf {
+        self.config
+            .lock()
+            .unwrap()
+            .output_dir
+            .as_ref()
+            .map_or_else(|| self.default_output_dir.clone(), PathBuf::from)
+    }
+
+    /// 폴더에 `index.html`·`status.json`을 쓰고 프록시가 제공할 페이지로 보관.
+    pub fn publish(&self, page: StatusPage) -> Result<(), String> {
+        let dir = self.output_dir();
+        let json = serde_json::to_string_pretty(&page).map_err(|e| e.to_string())?;
+        *self.latest.write().unwrap() = Some(page.clone());
+        std::fs::create_dir_all(&dir)
+            .and_then(|()| std::fs::write(dir.join("status.json"), json))
+            .and_then(|()| std::fs::write(dir.join("index.html"), render_html(&page)))
+            .map_err(|e| format!("{}: {e}", dir.display()))
+    }
+
+    /// 마지막으로 만든 페이지. 꺼져 있거나 아직 없으면 `None`
+    pub fn latest(&self) -> Option<StatusPage> {
+        self.latest.read().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::status_page::StatusPageComponent;
+
+    fn domain(id: u32, url: &str) -> Domain {
+        Domain {
+            id,
+            url: url.to_string(),
+        }
+    }
+
+    fn state(url: &str, status: ConfirmedStatus) -> ConfirmedState {
+        ConfirmedState {
+            status,
+            ..ConfirmedState::new(url)
+        }
+    }
+
+    fn bucket(url: &str, date: NaiveDate, total: u32, up: u32) -> RollupBucket {
+        RollupBucket {
+            url: url.to_string(),
+            start: uptime_rollup::local_day_start(date),
+            total,
+            up,
+            latency_sum: 0,
+            latency_max: 0,
+            histogram: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_page_hides_urls_and_unlisted_domains() {
+        let domains = vec![
+            domain(1, "https://api.internal.corp"),
+            domain(2, "https://web.internal.corp"),
+            domain(3, "https://admin.internal.corp"),
+        ];
+        let groups = vec![(
+            DomainGroup {
+                id: 7,
+                name: "Public".to_string(),
+            },
+            vec![1, 2, 3],
+        )];
+        let d1 = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let d2 = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let now = uptime_rollup::local_day_start(d2) + chrono::Duration::hours(12);
+        let incident = |id, domain_id: u32, url: &str, hours| Incident {
+            id,
+            domain_id,
+            url: url.to_string(),
+            groups: vec!["Public".to_string()],
+            started_at: now - chrono::Duration::hours(hours),
+            first_error: "connect 10.0.0.5:443 refused".to_string(),
+            resolved_at: None,
+            duration_secs: None,
+            acknowledged_at: None,
+            notes: Vec::new(),
+        };
+        let config = StatusPageConfig {
+            enabled: true,
+            group_ids: vec![7, 99],
+            components: vec![
+                StatusPageComponent {
+                    domain_id: 1,
+                    display_name: "API".to_string(),
+                },
+                StatusPageComponent {
+                    domain_id: 2,
+                    display_name: " Website ".to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+        let inputs = StatusInputs {
+            domains: &domains,
+            groups: &groups,
+            states: &[
+                state("https://api.internal.corp", ConfirmedStatus::Down),
+                state("https://web.internal.corp", ConfirmedStatus::Up),
+            ],
+            latest: &[],
+            in_maintenance: &HashSet::new(),
+            days: &[d1, d2],
+            daily: &[
+                bucket("https://api.internal.corp", d1, 10, 10),
+                bucket("https://api.internal.corp", d2, 10, 5),
+            ],
+            incidents: &[
+                incident(1, 1, "https://api.internal.corp", 1),
+                incident(2, 3, "https://admin.internal.corp", 2),
+            ],
+        };
+        let page = build_page(&config, &inputs, now);
+
+        assert_eq!(page.status, ComponentStatus::Down);
+        assert_eq!(page.groups.len(), 1);
+        let components = &page.groups[0].components;
+        let names: Vec<&str> = components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["API", "Website"]);
+        assert_eq!(components[0].uptime_percent, Some(75.0));
+        assert_eq!(components[0].days[1].uptime_percent, Some(50.0));
+        assert_eq!(components[1].days[0].uptime_percent, None);
+        assert_eq!(page.incidents.len(), 1);
+        assert_eq!(page.incidents[0].component, "API");
+
+        let json = serde_json::to_string(&page).unwrap();
+        let html = render_html(&page);
+        for text in [&json, &html] {
+            assert!(!text.contains("internal.corp"));
+            assert!(!text.contains("10.0.0.5"));
+        }
+        assert!(html.contains("<h2>Public</h2>"));
+    }
+
+    #[test]
+    fn test_publish_writes_folder_and_disable_clears() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = StatusPageService::new(
+            dir.path().join("status_page.json"),
+            dir.path().join("status_page"),
+        );
+        assert!(service
+            .set_config(StatusPageConfig {
+                title: " ".to_string(),
+                ..Default::default()
+            })
+            .is_err());
+        service
+            .set_config(StatusPageConfig {
+                enabled: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let page = build_page(
+            &service.get_config(),
+            &StatusInputs {
+                domains: &[],
+                groups: &[],
+                states: &[],
+                latest: &[],
+                in_maintenance: &HashSet::new(),
+                days: &[],
+                daily: &[],
+                incidents: &[],
+            },
+            Utc::now(),
+        );
+        service.publish(page).unwrap();
+        let html = std::fs::read_to_string(dir.path().join("status_page/index.html")).unwrap();
+        assert!(html.contains("All systems operational"));
+        assert!(dir.path().join("status_page/status.json").exists());
+        assert!(service.latest().is_some());
+
+        service.set_config(StatusPageConfig::default()).unwrap();
+        assert!(service.latest().is_none());
+    }
+}
@@ -0,0 +1,310 @@
+//! 도메인 하위 페이지 (`sub_pages.json`)와 하위 페이지 모니터 체크 대상 (`sub_page_monitor_links.json`),
+//! 도메인별 마지막 크롤링 결과 (`logs/crawl/{domain_id}.json`).
+//! 하위 페이지는 경로 기준 트리로 묶는다: 부모는 경로상 가장 가까운 상위 페이지 (`/docs/intro` → `/docs`).
+
+use crate::model::domain::Domain;
+use crate::model::sub_page::{CrawlReport, SubPage, SubPageMonitorLink, SubPageNode};
+use crate::service::crawler::{self, CrawledPage};
+use crate::storage::versioned::{load_versioned, save_versioned};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// 부모 후보 경로 (가까운 것부터). 쿼리가 있으면 쿼리를 뺀 경로가 먼저
+fn parent_paths(path: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let base = match path.split_once('?') {
+        Some((base, _)) => {
+            out.push(base.to_string());
+            base
+        }
+        None => path,
+    };
+    let mut rest = base.trim_end_matches('/');
+    while let Some(at) = rest.rfind('/') {
+        rest = &rest[..at];
+        if rest.is_empty() {
+            break;
+        }
+        out.push(format!("{rest}/"));
+        out.push(rest.to_string());
+    }
+    out
+}
+
+/// 도메인 하나의 `parent_id`를 경로 기준으로 다시 계산
+fn relink(pages: &mut [SubPage], domain_id: u32) {
+    let ids: HashMap<String, u32> = pages
+        .iter()
+        .filter(|p| p.domain_id == domain_id)
+        .map(|p| (p.path.clone(), p.id))
+        .collect();
+    for page in pages.iter_mut().filter(|p| p.domain_id == domain_id) {
+        page.parent_id = parent_paths(&page.path)
+            .iter()
+            .find_map(|parent| ids.get(parent).copied())
+            .filter(|id| *id != page.id);
+    }
+}
+
+fn build_tree(
+    parent_id: Option<u32>,
+    pages: &[SubPage],
+    domain_url: &str,
+    enabled: &HashSet<u32>,
+) -> Vec<SubPageNode> {
+    let mut nodes: Vec<SubPageNode> = pages
+        .iter()
+        .filter(|p| p.parent_id == parent_id)
+        .map(|p| SubPageNode {
+            url: crawler::page_url(domain_url, &p.path).unwrap_or_default(),
+            check_enabled: enabled.contains(&p.id),
+            children: build_tree(Some(p.id), pages, domain_url, enabled),
+            page: p.clone(),
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.page.path.cmp(&b.page.path));
+    nodes
+}
+
+pub struct SubPageService {
+    pages: Mutex<Vec<SubPage>>,
+    monitor_links: Mutex<Vec<SubPageMonitorLink>>,
+    pages_path: PathBuf,
+    monitor_links_path: PathBuf,
+    reports_dir: PathBuf,
+}
+
+impl SubPageService {
+    pub fn new(pages_path: PathBuf, monitor_links_path: PathBuf, reports_dir: PathBuf) -> Self {
+        let _ = std::fs::create_dir_all(&reports_dir);
+        Self {
+            pages: Mutex::new(load_versioned(&pages_path)),
+            monitor_links: Mutex::new(load_versioned(&monitor_links_path)),
+            pages_path,
+            monitor_links_path,
+            reports_dir,
+        }
+    }
+
+    pub fn get_for_domain(&self, domain_id: u32) -> Vec<SubPage> {
+        self.pages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.domain_id == domain_id)
+            .cloned()
+            .collect()
+    }
+
+    fn enabled_ids(&self) -> HashSet<u32> {
+        self.monitor_links
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|l| l.check_enabled)
+            .map(|l| l.sub_page_id)
+            .collect()
+    }
+
+    /// 도메인의 하위 페이지 트리 (경로순)
+    pub fn tree(&self, domain: &Domain) -> Vec<SubPageNode> {
+        build_tree(
+            None,
+            &self.get_for_domain(domain.id),
+            &domain.url,
+            &self.enabled_ids(),
+        )
+    }
+
+    /// 크롤링 결과 반영. 같은 경로는 제목·상태·`last_seen`만 갱신하고, 새 경로는 추가한 뒤 트리를 다시 묶음.
+    /// 이번에 찾지 못한 페이지는 그대로 둠. 새로 추가한 수를 반환
+    pub fn merge(&self, domain_id: u32, crawled: &[CrawledPage], now: DateTime<Utc>) -> u32 {
+        let mut pages = self.pages.lock().unwrap();
+        let mut next_id = pages.iter().map(|p| p.id).max().unwrap_or(0) + 1;
+        let mut added = 0;
+        for found in crawled {
+            if let Some(page) = pages
+                .iter_mut()
+                .find(|p| p.domain_id == domain_id && p.path == found.path)
+            {
+                page.title.clone_from(&found.title);
+                page.last_status = Some(found.status);
+                page.last_seen = now;
+                continue;
+            }
+            pages.push(SubPage {
+                id: next_id,
+                domain_id,
+                path: found.path.clone(),
+                parent_id: None,
+                title: found.title.clone(),
+                source: found.source,
+                last_status: Some(found.status),
+                discovered_at: now,
+                last_seen: now,
+            });
+            next_id += 1;
+            added += 1;
+        }
+        relink(&mut pages, domain_id);
+        save_versioned(&self.pages_path, &*pages);
+        added
+    }
+
+    /// 하위 페이지별 모니터 체크 켜기/끄기. 존재하는 페이지 수를 반환
+    pub fn set_check_enabled(&self, sub_page_ids: &[u32], enabled: bool) -> usize {
+        let existing: HashSet<u32> = self.pages.lock().unwrap().iter().map(|p| p.id).collect();
+        let mut links = self.monitor_links.lock().unwrap();
+        let mut count = 0;
+        for id in sub_page_ids.iter().filter(|id| existing.contains(id)) {
+            if let Some(link) = links.iter_mut().find(|l| l.sub_page_id == *id) {
+                link.check_enabled = enabled;
+            } else {
+                links.push(SubPageMonitorLink {
+                    sub_page_id: *id,
+                    check_enabled: enabled,
+                });
+            }
+            count += 1;
+        }
+        save_versioned(&self.monitor_links_path, &*links);
+        count
+    }
+
+    /// 체크가 켜진 하위 페이지를 체크 대상으로: `Domain { id: 소속 도메인, url: 하위 페이지 url }`
+    pub fn monitored(&self, domains: &[Domain]) -> Vec<Domain> {
+        let enabled = self.enabled_ids();
+        let pages = self.pages.lock().unwrap();
+        pages
+            .iter()
+            .filter(|p| enabled.contains(&p.id))
+            .filter_map(|p| {
+                let domain = domains.iter().find(|d| d.id == p.domain_id)?;
+                Some(Domain {
+                    id: domain.id,
+                    url: crawler::page_url(&domain.url, &p.path)?,
+                })
+            })
+            .collect()
+    }
+
+    /// 삭제된 도메인의 하위 페이지·모니터 링크·크롤링 결과 정리 (cascade)
+    pub fn retain_domains(&self, domain_ids: &HashSet<u32>) {
+        let mut pages = self.pages.lock().unwrap();
+        let before = pages.len();
+        pages.retain(|p| domain_ids.contains(&p.domain_id));
+        if pages.len() != before {
+            save_versioned(&self.pages_path, &*pages);
+            let ids: HashSet<u32> = pages.iter().map(|p| p.id).collect();
+            let mut links = self.monitor_links.lock().unwrap();
+            links.retain(|l| ids.contains(&l.sub_page_id));
+            save_versioned(&self.monitor_links_path, &*links);
+        }
+        let Ok(entries) = std::fs::read_dir(&self.reports_dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let stale = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse::<u32>().ok())
+                .is_some_and(|id| !domain_ids.contains(&id));
+            if stale {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    fn report_path(&self, domain_id: u32) -> PathBuf {
+        self.reports_dir.join(format!("{domain_id}.json"))
+    }
+
+    pub fn save_report(&self, report: &CrawlReport) {
+        if let Ok(json) = serde_json::to_string_pretty(report) {
+            let _ = std::fs::write(self.report_path(report.domain_id), json);
+        }
+    }
+
+    pub fn report(&self, domain_id: u32) -> Option<CrawlReport> {
+        let text = std::fs::read_to_string(self.report_path(domain_id)).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::sub_page::SubPageSource;
+
+    fn found(path: &str) -> CrawledPage {
+        CrawledPage {
+            path: path.to_string(),
+            title: None,
+            status: 200,
+            source: SubPageSource::Link,
+        }
+    }
+
+    #[test]
+    fn test_merge_builds_path_tree_and_cascades() {
+        let dir = tempfile::tempdir().unwrap();
+        let new_service = || {
+            SubPageService::new(
+                dir.path().join("sub_pages.json"),
+                dir.path().join("sub_page_monitor_links.json"),
+                dir.path().join("logs/crawl"),
+            )
+        };
+        let service = new_service();
+        let now = Utc::now();
+        let added = service.merge(
+            1,
+            &[
+                found("/docs/a/b"),
+                found("/docs"),
+                found("/search?q=1"),
+                found("/blog/"),
+            ],
+            now,
+        );
+        assert_eq!(added, 4);
+        service.merge(2, &[found("/docs")], now);
+        // 다시 찾은 경로는 추가하지 않고, 새 중간 경로가 생기면 다시 묶음
+        assert_eq!(
+            service.merge(
+                1,
+                &[found("/docs"), found("/docs/a"), found("/search")],
+                now
+            ),
+            2
+        );
+
+        let domain = Domain {
+            id: 1,
+            url: "a.example".to_string(),
+        };
+        let tree = service.tree(&domain);
+        let top: Vec<&str> = tree.iter().map(|n| n.page.path.as_str()).collect();
+        assert_eq!(top, vec!["/blog/", "/docs", "/search"]);
+        assert_eq!(tree[1].children[0].page.path, "/docs/a");
+        assert_eq!(tree[1].children[0].children[0].page.path, "/docs/a/b");
+        assert_eq!(tree[2].children[0].page.path, "/search?q=1");
+        assert_eq!(tree[1].url, "https://a.example/docs");
+
+        let deep = tree[1].children[0].children[0].page.id;
+        assert_eq!(service.set_check_enabled(&[deep, 999], true), 1);
+        let reloaded = new_service();
+        let targets = reloaded.monitored(std::slice::from_ref(&domain));
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].url, "https://a.example/docs/a/b");
+        assert!(reloaded.tree(&domain)[1].children[0].children[0].check_enabled);
+
+        reloaded.retain_domains(&HashSet::from([2]));
+        assert!(reloaded.get_for_domain(1).is_empty());
+        assert_eq!(reloaded.get_for_domain(2).len(), 1);
+        assert!(reloaded.monitored(&[domain]).is_empty());
+    }
+}
@@ -0,0 +1,279 @@
+//! 체크 로그를 시간·일 구간으로 집계하고 가용성·지연 시간 백분위수를 계산.
+
+use crate::model::domain_status_log::DomainStatusLog;
+use crate::model::uptime::{RollupBucket, UptimePoint, UptimeStats, LATENCY_BOUNDS_MS};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Timelike, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+/// 분 단위 조회는 원본 로그를 읽으므로 범위를 제한
+pub const MAX_MINUTE_RANGE_DAYS: i64 = 7;
+
+/// 재시도 중간 시도를 빼고 체크별 최종 시도만 남김.
+/// 같은 url의 다음 줄이 `attempt + 1`이면 중간 시도 (한 체크의 시도는 연속으로 기록됨).
+pub fn final_attempts(logs: Vec<DomainStatusLog>) -> Vec<DomainStatusLog> {
+    let mut next_attempt: HashMap<String, u32> = HashMap::new();
+    let mut finals: Vec<DomainStatusLog> = logs
+        .into_iter()
+        .rev()
+        .filter(|log| {
+            let retried = next_attempt.get(&log.url) == Some(&(log.attempt + 1));
+            next_attempt.insert(log.url.clone(), log.attempt);
+            !retried
+        })
+        .collect();
+    finals.reverse();
+    finals
+}
+
+pub fn minute_start(ts: DateTime<Utc>) -> DateTime<Utc> {
+    ts.with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(ts)
+}
+
+pub fn hour_start(ts: DateTime<Utc>) -> DateTime<Utc> {
+    minute_start(ts).with_minute(0).unwrap_or(ts)
+}
+
+/// 로컬 날짜의 자정 (UTC)
+pub fn local_day_start(date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map_or_else(|| midnight.and_utc(), |t| t.with_timezone(&Utc))
+}
+
+fn empty_bucket(url: &str, start: DateTime<Utc>) -> RollupBucket {
+    RollupBucket {
+        url: url.to_string(),
+        start,
+        total: 0,
+        up: 0,
+        latency_sum: 0,
+        latency_max: 0,
+        histogram: vec![0; LATENCY_BOUNDS_MS.len() + 1],
+    }
+}
+
+fn add_check(bucket: &mut RollupBucket, log: &DomainStatusLog) {
+    bucket.total += 1;
+    if log.level == "error" {
+        return;
+    }
+    bucket.up += 1;
+    bucket.latency_sum += u64::from(log.latency);
+    bucket.latency_max = bucket.latency_max.max(log.latency);
+    let index = LATENCY_BOUNDS_MS
+        .iter()
+        .position(|bound| log.latency <= *bound)
+        .unwrap_or(LATENCY_BOUNDS_MS.len());
+    bucket.histogram[index] += 1;
+}
+
+fn merge(into: &mut RollupBucket, from: &RollupBucket) {
+    into.total += from.total;
+    into.up += from.up;
+    into.latency_sum += from.latency_sum;
+    into.latency_max = into.latency_max.max(from.latency_max);
+    for (a, b) in into.histogram.iter_mut().zip(&from.histogram) {
+        *a += b;
+    }
+}
+
+/// 로그를 url·구간별로 집계. `start_of`: 체크 시각 → 구간 시작. 구간·url 순으로 정렬.
+/// 가용성에서 빼도록 지정한 점검 시간(`MaintenanceMark.exclude_from_uptime`)의 체크는 건너뜀.
+pub fn roll_up(
+    logs: &[DomainStatusLog],
+    start_of: impl Fn(DateTime<Utc>) -> DateTime<Utc>,
+) -> Vec<RollupBucket> {
+    let mut buckets: BTreeMap<(DateTime<Utc>, &str), RollupBucket> = BTreeMap::new();
+    for log in logs {
+        if log
+            .maintenance
+            .as_ref()
+            .is_some_and(|m| m.exclude_from_uptime)
+        {
+            continue;
+        }
+        let start = start_of(log.timestamp);
+        let bucket = buckets
+            .entry((start, log.url.as_str()))
+            .or_insert_with(|| empty_bucket(&log.url, start));
+        add_check(bucket, log);
+    }
+    buckets.into_values().collect()
+}
+
+/// 구간들을 url별로 합쳐 `start` 구간 하나로 (시간 → 일)
+pub fn combine(buckets: &[RollupBucket], start: DateTime<Utc>) -> Vec<RollupBucket> {
+    let mut by_url: BTreeMap<&str, RollupBucket> = BTreeMap::new();
+    for bucket in buckets {
+        let combined = by_url
+            .entry(bucket.url.as_str())
+            .or_insert_with(|| empty_bucket(&bucket.url, start));
+        merge(combined, bucket);
+    }
+    by_url.into_values().collect()
+}
+
+/// 누적 개수가 `p` 비율에 닿는 구간의 상한. 초과 구간이거나 상한이 최대값보다 크면 최대값.
+fn percentile(histogram: &[u32], count: u32, max: u32, p: f64) -> Option<u32> {
+    if count == 0 {
+        return None;
+    }
+    let rank = (f64::from(count) * p).ceil().max(1.0);
+    let mut seen = 0u32;
+    for (index, n) in histogram.iter().enumerate() {
+        seen += n;
+        if f64::from(seen) >= rank {
+            return Some(
+                LATENCY_BOUNDS_MS
+                    .get(index)
+                    .map_or(max, |bound| (*bound).min(max)),
+            );
+        }
+    }
+    Some(max)
+}
+
+pub fn stats<'a>(buckets: impl IntoIterator<Item = &'a RollupBucket>) -> UptimeStats {
+    let mut total = empty_bucket("", DateTime::<Utc>::MIN_UTC);
+    for bucket in buckets {
+        merge(&mut total, bucket);
+    }
+    let has_latency = total.up > 0;
+    UptimeStats {
+        total_checks: total.total,
+        up_checks: total.up,
+        uptime_percent: (total.total > 0)
+            .then(|| f64::from(total.up) * 100.0 / f64::from(total.total)),
+        avg_latency: has_latency
+            .then(|| u32::try_from(total.latency_sum / u64::from(total.up)).unwrap_or(u32::MAX)),
+        p50_latency: percentile(&total.histogram, total.up, total.latency_max, 0.50),
+        p95_latency: percentile(&total.histogram, total.up, total.latency_max, 0.95),
+        p99_latency: percentile(&total.histogram, total.up, total.latency_max, 0.99),
+        max_latency: has_latency.then_some(total.latency_max),
+    }
+}
+
+/// 구간 시작별 시계열 (여러 url은 합산)
+pub fn points(buckets: &[RollupBucket]) -> Vec<UptimePoint> {
+    let mut by_start: BTreeMap<DateTime<Utc>, Vec<&RollupBucket>> = BTreeMap::new();
+    for bucket in buckets {
+        by_start.entry(bucket.start).or_default().push(bucket);
+    }
+    by_start
+        .into_iter()
+        .map(|(start, group)| UptimePoint {
+            start,
+            stats: stats(group),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::maintenance::MaintenanceMark;
+    use chrono::Duration;
+
+    fn log(
+        url: &str,
+        level: &str,
+        latency: u32,
+        attempt: u32,
+        ts: DateTime<Utc>,
+    ) -> DomainStatusLog {
+        DomainStatusLog {
+            url: url.to_string(),
+            status: "200 OK".to_string(),
+            level: level.to_string(),
+            latency,
+            ok: level == "info",
+            group: "Default".to_string(),
+            timestamp: ts,
+            error_message: None,
+            failures: Vec::new(),
+            attempt,
+            timing: None,
+            redirects: Vec::new(),
+            maintenance: None,
+        }
+    }
+
+    #[test]
+    fn test_final_attempts_drops_retried_attempts() {
+        let t = Utc::now();
+        let logs = vec![
+            log("a", "error", 0, 1, t),
+            log("a", "error", 0, 2, t),
+            log("a", "info", 10, 3, t),
+            log("b", "info", 10, 1, t),
+            log("a", "info", 10, 1, t),
+        ];
+        let finals = final_attempts(logs);
+        let kept: Vec<(&str, u32)> = finals.iter().map(|l| (l.url.as_str(), l.attempt)).collect();
+        assert_eq!(kept, vec![("a", 3), ("b", 1), ("a", 1)]);
+    }
+
+    #[test]
+    fn test_stats_uptime_and_percentiles() {
+        let t = hour_start(Utc::now());
+        let mut logs: Vec<DomainStatusLog> = (1..=100)
+            .map(|i| log("a", "info", i * 10, 1, t + Duration::seconds(i64::from(i))))
+            .collect();
+        logs.push(log("a", "error", 0, 1, t));
+        logs.push(log("a", "warning", 40, 1, t));
+
+        let buckets = roll_up(&logs, hour_start);
+        assert_eq!(buckets.len(), 1);
+        let s = stats(&buckets);
+        assert_eq!(s.total_checks, 102);
+        assert_eq!(s.up_checks, 101);
+        assert!((s.uptime_percent.unwrap() - 99.0196).abs() < 0.01);
+        assert_eq!(s.max_latency, Some(1000));
+        // 51번째 값(500ms)이 들어 있는 구간 상한
+        assert_eq!(s.p50_latency, Some(500));
+        assert_eq!(s.p95_latency, Some(1000));
+        assert_eq!(s.p99_latency, Some(1000));
+    }
+
+    #[test]
+    fn test_hourly_buckets_combine_into_day() {
+        let t = hour_start(Utc::now());
+        let logs = vec![
+            log("a", "info", 20, 1, t),
+            log("a", "error", 0, 1, t + Duration::hours(1)),
+            log("b", "info", 30, 1, t + Duration::hours(1)),
+        ];
+        let hourly = roll_up(&logs, hour_start);
+        assert_eq!(hourly.len(), 3);
+        assert_eq!(points(&hourly).len(), 2);
+
+        let daily = combine(&hourly, t);
+        assert_eq!(daily.len(), 2);
+        let a = daily.iter().find(|b| b.url == "a").unwrap();
+        assert_eq!((a.total, a.up, a.latency_max), (2, 1, 20));
+        assert!(stats(&[]).uptime_percent.is_none());
+    }
+
+    #[test]
+    fn test_excluded_maintenance_checks_are_skipped() {
+        let t = hour_start(Utc::now());
+        let in_window = |exclude_from_uptime| DomainStatusLog {
+            maintenance: Some(MaintenanceMark {
+                window_id: 1,
+                exclude_from_uptime,
+            }),
+            ..log("a", "error", 0, 1, t)
+        };
+        let logs = vec![
+            log("a", "info", 20, 1, t),
+            in_window(true),
+            in_window(false),
+        ];
+        let s = stats(&roll_up(&logs, hour_start));
+        assert_eq!((s.total_checks, s.up_checks), (2, 1));
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Tauri 메인 로직 시작 전에 버전 확인 → 필요한 경우 순차 마이그레이션 (1→2→3...)
 
-use serde_json::Value;
+use base64::Engine;
+use serde_json::{Map, Value};
 use std::fs;
 use std::path::Path;
 
@@ -36,10 +37,76 @@ fn migrate_1_to_2(value: Value) -> Value {
     })
 }
 
-/// v2 → v3: (추후 필요 시 구조 변경. migration chain에 추가)
-#[allow(dead_code)]
-fn migrate_2_to_3(value: Value) -> Value {
-    // v3 구조 변경 시 여기에 추가
+/// v2 → v3: 구조 변경 없음 (버전만 올림)
+fn migrate_2_to_3(mut value: Value) -> Value {
+    if let Value::Object(map) = &mut value {
+        map.insert("schema_version".to_string(), Value::from(3));
+    }
+    value
+}
+
+/// `Authorization` 헤더 값 → `probe.auth`. Basic은 `user:password`로 풀리는 경우만
+fn auth_from_header(value: &str) -> Option<Value> {
+    let (scheme, credential) = value.trim().split_once(' ')?;
+    let credential = credential.trim();
+    if scheme.eq_ignore_ascii_case("bearer") && !credential.is_empty() {
+        return Some(serde_json::json!({ "type": "bearer", "token": credential }));
+    }
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(credential)
+        .ok()?;
+    let (username, password) = String::from_utf8(decoded)
+        .ok()?
+        .split_once(':')
+        .map(|(u, p)| (u.to_string(), p.to_string()))?;
+    Some(serde_json::json!({ "type": "basic", "username": username, "password": password }))
+}
+
+/// domain_monitor_links.json v2 → v3: `httpCheck.headers`에 직접 넣어 두던
+/// `User-Agent`·`Authorization`을 `probe.userAgent`·`probe.auth`로 옮김 (`probe`가 없는 링크만)
+fn migrate_monitor_links_2_to_3(value: Value) -> Value {
+    let mut value = migrate_2_to_3(value);
+    let Some(links) = value.get_mut("data").and_then(Value::as_array_mut) else {
+        return value;
+    };
+    for link in links.iter_mut().filter_map(Value::as_object_mut) {
+        if link.contains_key("probe") {
+            continue;
+        }
+        let Some(headers) = link
+            .get_mut("httpCheck")
+            .and_then(|c| c.get_mut("headers"))
+            .and_then(Value::as_object_mut)
+        else {
+            continue;
+        };
+        let mut probe = Map::new();
+        let names: Vec<String> = headers.keys().cloned().collect();
+        for name in names {
+            let Some(text) = headers.get(&name).and_then(Value::as_str) else {
+                continue;
+            };
+            let moved = if name.eq_ignore_ascii_case("user-agent") {
+                probe.insert("userAgent".to_string(), Value::from(text));
+                true
+            } else if name.eq_ignore_ascii_case("authorization") {
+                auth_from_header(text)
+                    .map(|auth| probe.insert("auth".to_string(), auth))
+                    .is_some()
+            } else {
+                false
+            };
+            if moved {
+                headers.remove(&name);
+            }
+        }
+        if !probe.is_empty() {
+            link.insert("probe".to_string(), Value::Object(probe));
+        }
+    }
     value
 }
 
@@ -114,19 +181,18 @@ fn migrate_domain_status_to_monitor_links(app_data_dir: &Path) {
     }
 }
 
-/// 모든 저장소 파일에 대해 마이그레이션 실행. 앱 시작 시 setup() 맨 앞에서 호출.
+/// 모든 저장소 파일에 대해 마이그레이션 실행. 앱 시작 시 `setup()` 맨 앞에서 호출.
 pub fn run_all(app_data_dir: &Path) {
     // domain_status.json → domain_monitor_links.json (파일명 변경)
     migrate_domain_status_to_monitor_links(app_data_dir);
 
     // structure별 migration chain (1→2, 2→3, ...)
-    let migrations: Vec<fn(Value) -> Value> = vec![migrate_1_to_2];
+    let migrations: Vec<fn(Value) -> Value> = vec![migrate_1_to_2, migrate_2_to_3];
 
     let paths = [
         app_data_dir.join("domains.json"),
         app_data_dir.join("groups.json"),
         app_data_dir.join("domain_group_links.json"),
-        app_data_dir.join("domain_monitor_links.json"),
         app_data_dir.join("domain_local_routes.json"),
         app_data_dir.join("proxy_settings.json"),
     ];
@@ -134,4 +200,67 @@ pub fn run_all(app_data_dir: &Path) {
     for path in &paths {
         migrate_file(path, &migrations);
     }
+
+    migrate_file(
+        &app_data_dir.join("domain_monitor_links.json"),
+        &[migrate_1_to_2, migrate_monitor_links_2_to_3],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monitor_links_move_auth_and_agent_headers_to_probe() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("domain_monitor_links.json");
+        let v1 = serde_json::json!([
+            {
+                "domainId": 1,
+                "checkEnabled": true,
+                "interval": 60,
+                "httpCheck": {
+                    "method": "GET",
+                    "headers": {
+                        "authorization": "Basic dXNlcjpwYXNz",
+                        "User-Agent": "probe/1",
+                        "X-Trace": "1"
+                    }
+                }
+            },
+            {
+                "domainId": 2,
+                "checkEnabled": true,
+                "interval": 60,
+                "httpCheck": { "headers": { "Authorization": "Digest abc" } }
+            },
+            { "domainId": 3, "checkEnabled": false, "interval": 60 }
+        ]);
+        fs::write(&path, v1.to_string()).unwrap();
+        run_all(dir.path());
+
+        let migrated: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(migrated["schema_version"], 3);
+        let links = &migrated["data"];
+        assert_eq!(
+            links[0]["probe"],
+            serde_json::json!({
+                "userAgent": "probe/1",
+                "auth": { "type": "basic", "username": "user", "password": "pass" }
+            })
+        );
+        assert_eq!(
+            links[0]["httpCheck"]["headers"],
+            serde_json::json!({ "X-Trace": "1" })
+        );
+        // 옮길 수 없는 인증 방식은 헤더에 그대로
+        assert!(links[1].get("probe").is_none());
+        assert_eq!(
+            links[1]["httpCheck"]["headers"]["Authorization"],
+            "Digest abc"
+        );
+        assert!(links[2].get("probe").is_none());
+        assert!(dir.path().join("domain_monitor_links.json.bak").exists());
+    }
 }
@@ -0,0 +1,109 @@
+/** 규칙이 적용되는 대상 (BE AlertScope) */
+export type AlertScope =
+  | { type: "all" }
+  | { type: "domain"; domainId: number }
+  | { type: "group"; groupId: number };
+
+/** 알림 조건 (BE AlertTrigger) */
+export type AlertTrigger =
+  | { type: "down" }
+  | { type: "recovered" }
+  | { type: "latencyOver"; ms: number }
+  | { type: "certExpiring"; days: number }
+  | { type: "securityRegression" };
+
+/** 알림 규칙 (BE AlertRule). id 0이면 저장 시 새 id 부여 */
+export interface AlertRule {
+  id: number;
+  name: string;
+  enabled: boolean;
+  scope: AlertScope;
+  trigger: AlertTrigger;
+  channelIds: number[];
+  /** 같은 규칙·url로 다시 보내기까지 최소 간격 (초, 기본 900) */
+  cooldownSecs: number;
+}
+
+/**
+ * 채널 종류별 설정 (BE ChannelKind).
+ * slack·discord·teams의 `template` 자리표시자: {url} {status} {latency} {group} {message} {rule} {trigger} {incident} {downtime} {time}.
+ * 없으면 "{message}"
+ */
+export type ChannelKind =
+  | { type: "webhook"; url: string; headers: Record<string, string> }
+  | { type: "slack"; webhookUrl: string; template?: string }
+  | { type: "discord"; webhookUrl: string; template?: string }
+  | { type: "teams"; webhookUrl: string; template?: string }
+  | ({ type: "email" } & EmailChannelConfig);
+
+/** SMTP 연결 보안 (BE SmtpSecurity) */
+export type SmtpSecurity = "startTls" | "tls" | "none";
+
+/** 그룹 소속 도메인의 알림·요약을 받을 추가 수신자 */
+export interface GroupRecipients {
+  groupId: number;
+  recipients: string[];
+}
+
+/** 메일 채널 설정 (BE EmailChannelConfig) */
+export interface EmailChannelConfig {
+  smtpHost: string;
+  /** 기본 587 */
+  smtpPort: number;
+  security: SmtpSecurity;
+  username?: string;
+  password?: string;
+  /** "Watchtower <alerts@example.com>" 형식 가능 */
+  from: string;
+  recipients: string[];
+  groupRecipients: GroupRecipients[];
+  /** 규칙 알림을 바로 메일로 보냄. false면 일일 요약만 */
+  immediate: boolean;
+  /** 일일 요약 발송 시각 (로컬 "HH:MM"). 없으면 보내지 않음 */
+  digestTime?: string;
+  template?: string;
+}
+
+export interface AlertChannelConfig {
+  id: number;
+  name: string;
+  enabled: boolean;
+  kind: ChannelKind;
+}
+
+/** 알림 규칙·채널 (BE AlertConfig). alert_settings.json */
+export interface AlertConfig {
+  rules: AlertRule[];
+  channels: AlertChannelConfig[];
+}
+
+/** 규칙이 발생시킨 알림 (BE AlertEvent). webhook 채널은 이 JSON을 그대로 POST */
+export interface AlertEvent {
+  ruleId: number;
+  ruleName: string;
+  trigger: AlertTrigger["type"];
+  domainId: number;
+  url: string;
+  groups: string[];
+  /** 소속 그룹 id (그룹별 메일 수신자 선택용) */
+  groupIds: number[];
+  status: string;
+  latency?: number;
+  message: string;
+  incidentId?: number;
+  /** recovered 알림의 장애 지속 시간 (초) */
+  downtimeSecs?: number;
+  timestamp: string;
+}
+
+/** 채널 하나로의 전송 기록 (BE AlertRecord). logs/alert_history.json */
+export interface AlertRecord {
+  id: number;
+  channelId: number;
+  event: AlertEvent;
+  delivered: boolean;
+  /** 보낸 횟수 (재시도 포함) */
+  attempts: number;
+  error?: string;
+  sentAt: string;
+}
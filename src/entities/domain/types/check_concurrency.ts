@@ -0,0 +1,41 @@
+/** 체크 동시 실행·분산 설정 (BE CheckConcurrency) */
+export interface CheckConcurrency {
+  /** 동시에 보내는 체크 요청 수 (모든 주기 합산, 1~256, 기본 32) */
+  maxConcurrent: number;
+  /** 같은 호스트로 동시에 보내는 요청 수 (1~64, 기본 4) */
+  maxPerHost: number;
+  /** 다음 체크 예정 시각을 간격의 ±N% 안에서 흔듦 (0~50) */
+  jitterPercent: number;
+  /** 새 도메인·앱 시작 직후 첫 체크를 간격 안에 고르게 나눔 */
+  spread: boolean;
+}
+
+/** 체크 주기 한 번의 시간·대기 통계 (BE CheckCycleStats) */
+export interface CheckCycleStats {
+  startedAt: string;
+  durationMs: number;
+  /** 프로브(재시도 포함) 단계 */
+  probeMs: number;
+  domains: number;
+  /** 도메인 + 체크가 켜진 하위 페이지 */
+  targets: number;
+  /** 재시도를 포함한 요청 수 (인증서·보안 점검·DNS·내용 감시·OpenAPI 요청 포함) */
+  attempts: number;
+  /** 동시 실행 제한 때문에 기다린 요청 수 */
+  queued: number;
+  maxWaitMs: number;
+  /** 이전 체크가 끝나지 않아 건너뛴 도메인 수 */
+  skipped: number;
+}
+
+/** 현재 설정과 최근 체크 주기 통계 (BE CheckCycleReport) */
+export interface CheckCycleReport {
+  settings: CheckConcurrency;
+  inFlight: number;
+  /** 최신이 앞 (최근 100개) */
+  cycles: CheckCycleStats[];
+  avgDurationMs: number;
+  maxDurationMs: number;
+  totalQueued: number;
+  totalSkipped: number;
+}
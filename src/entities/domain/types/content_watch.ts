@@ -0,0 +1,60 @@
+/** 도메인별 내용 변경 감시 설정 (BE ContentWatchConfig). GET 바디를 정규화해 해시가 바뀌면 기록 */
+export interface ContentWatchConfig {
+  enabled: boolean;
+  /** HTML에서 비교할 부분 (CSS 선택자) */
+  selector?: string;
+  /** JSON에서 비교할 값 (`$.a.b[0]`). selector보다 우선 */
+  jsonPath?: string;
+  /** 줄마다 공백 정리·빈 줄 제거 (기본 true) */
+  stripWhitespace: boolean;
+  /** 비교 전에 지울 정규식 (타임스탬프 등) */
+  ignorePatterns: string[];
+  /** 추가 요청 헤더 */
+  headers: Record<string, string>;
+}
+
+/** url별 감시 상태 (BE ContentWatchState) */
+export interface ContentWatchState {
+  url: string;
+  /** 마지막 내용의 SHA-256. 아직 성공한 적 없으면 없음 */
+  hash?: string;
+  lastChecked: string;
+  lastChanged?: string;
+  /** 마지막 조회·추출 실패 사유 */
+  error?: string;
+}
+
+/** 스냅샷 목록용 (내용 제외) */
+export interface ContentSnapshotInfo {
+  hash: string;
+  capturedAt: string;
+}
+
+/** 내용 변경 기록 (BE ContentChange, logs/content/{date}.json) */
+export interface ContentChange {
+  url: string;
+  timestamp: string;
+  previousHash?: string;
+  hash: string;
+  addedLines: number;
+  removedLines: number;
+}
+
+/** 감시 중인 도메인 + 설정 + 상태 (BE ContentWatchStatus) */
+export interface ContentWatchStatus {
+  domainId: number;
+  url: string;
+  config: ContentWatchConfig;
+  state?: ContentWatchState;
+  /** 최신순 */
+  snapshots: ContentSnapshotInfo[];
+}
+
+/** 두 스냅샷의 unified diff (BE ContentDiff) */
+export interface ContentDiff {
+  url: string;
+  from: ContentSnapshotInfo;
+  to: ContentSnapshotInfo;
+  /** 같으면 빈 문자열 */
+  diff: string;
+}
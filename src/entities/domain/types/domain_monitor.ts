@@ -1,13 +1,287 @@
+import type { ContentWatchConfig } from "@/entities/domain/types/content_watch";
+import type { HeartbeatCheck } from "@/entities/domain/types/heartbeat";
+import type { MaintenanceMark } from "@/entities/domain/types/maintenance";
+import type { OpenApiCheckConfig } from "@/entities/domain/types/openapi_check";
+import type { SecurityAuditConfig } from "@/entities/domain/types/security_audit";
+
 /** 체크 결과 구조 (BE DomainStatusLog). 최신은 메모리, 과거는 logs/{date}.json */
 export interface DomainStatusLog {
   url: string;
   status: string;
+  /** info · warning · error, 또는 응답은 정상이지만 지연 시간 기준선보다 크게 느린 degraded */
   level: string;
   latency: number;
   ok: boolean;
   group: string;
   timestamp: string;
   errorMessage?: string;
+  /** 실패한 검증 규칙 (HttpCheck.assertions, TCP banner, WebSocket handshake 등). 성공 시 생략 */
+  failures?: AssertionFailure[];
+  /** 한 체크 안에서 몇 번째 시도인지 (1부터, CheckPolicy.retries) */
+  attempt?: number;
+  /** HTTP 체크 단계별 시간 (리다이렉트는 합산). 실패 시 실패 전까지 잰 단계만 */
+  timing?: ProbeTiming;
+  /** 리다이렉트 체인 (HttpCheck.recordRedirects 또는 리다이렉트 루프). 마지막 항목이 최종 응답 */
+  redirects?: RedirectHop[];
+  /** 점검 시간 중의 체크. 확정 상태·장애·알림에서 제외 */
+  maintenance?: MaintenanceMark;
+}
+
+/** 리다이렉트를 따라가며 보낸 요청 한 건 (BE RedirectHop) */
+export interface RedirectHop {
+  url: string;
+  status: number;
+  /** 다음 요청 url (절대 url). 리다이렉트가 아니면 생략 */
+  location?: string;
+}
+
+/** HTTP 체크 단계별 시간 (ms)과 연결 정보. 해당 단계가 없으면 생략 (IP 주소면 dnsMs, http면 tlsMs 없음) */
+export interface ProbeTiming {
+  dnsMs?: number;
+  connectMs?: number;
+  tlsMs?: number;
+  ttfbMs?: number;
+  downloadMs?: number;
+  resolvedIp?: string;
+  /** `HTTP/1.1` / `HTTP/2` */
+  httpVersion?: string;
+  /** `TLS 1.2` / `TLS 1.3` */
+  tlsVersion?: string;
+}
+
+/** 실패한 검증 한 건 (BE AssertionFailure) */
+export interface AssertionFailure {
+  kind: CheckAssertion["type"] | "redirectLoop" | "banner" | "answer" | "handshake";
+  expected: string;
+  actual: string;
+}
+
+/** 응답 검증 규칙 (BE CheckAssertion) */
+export type CheckAssertion =
+  | { type: "statusIn"; codes: number[] }
+  | { type: "bodyContains"; value: string }
+  | { type: "bodyMatches"; pattern: string }
+  | { type: "jsonPath"; path: string; equals: unknown }
+  | { type: "header"; name: string; equals?: string; contains?: string }
+  | { type: "maxLatency"; ms: number }
+  | { type: "finalScheme"; scheme: string }
+  | { type: "finalUrl"; url: string }
+  | { type: "maxRedirects"; count: number };
+
+/** 도메인별 HTTP 체크 정의 (BE HttpCheck). 없으면 HEAD + 2xx/3xx */
+export interface HttpCheck {
+  method: "GET" | "HEAD" | "POST";
+  headers: Record<string, string>;
+  body?: string;
+  assertions: CheckAssertion[];
+  /** 리다이렉트 체인을 DomainStatusLog.redirects에 기록 */
+  recordRedirects?: boolean;
+}
+
+/** 요청 인증 (BE ProbeAuth). HttpCheck.headers에 Authorization이 있으면 그쪽이 우선 */
+export type ProbeAuth =
+  | { type: "basic"; username: string; password: string }
+  | { type: "bearer"; token: string };
+
+/** mTLS 클라이언트 인증서·개인 키 PEM 경로 (BE ClientCertConfig) */
+export interface ClientCertConfig {
+  certPath: string;
+  keyPath: string;
+}
+
+/** 도메인별 HTTP 요청 방식 (BE ProbeOptions). 없으면 HEAD→GET 재시도, 10초, TLS 검증 */
+export interface ProbeOptions {
+  /** HEAD가 405·501이면 GET으로 다시 요청 */
+  headFallback: boolean;
+  auth?: ProbeAuth;
+  /** 1~120초 */
+  timeoutSecs?: number;
+  userAgent?: string;
+  /** false면 서버 인증서 검증 안 함 (자체 서명 내부 서버) */
+  verifyTls: boolean;
+  clientCert?: ClientCertConfig;
+}
+
+/** `tcp://host:port` 대상의 추가 검사 (BE TcpCheck). 없으면 연결만 확인 */
+export interface TcpCheck {
+  /** 연결 후 보낼 데이터 (예: "PING\r\n") */
+  send?: string;
+  /** 응답(배너)에 포함돼야 하는 문자열 (예: "220", "+PONG") */
+  expect?: string;
+}
+
+/** 재시도·확정·플랩 판정 규칙 (BE CheckPolicy). 없으면 재시도 없이 1회 결과로 확정 */
+export interface CheckPolicy {
+  retries: number;
+  /** 첫 재시도 전 대기 (ms), 이후 2배씩 */
+  retryBackoffMs: number;
+  /** 연속 N회 실패 시 down */
+  failThreshold: number;
+  /** 연속 M회 성공 시 up */
+  successThreshold: number;
+  flapWindow: number;
+  /** flapWindow 안에서 성공/실패가 이 횟수 이상 바뀌면 flapping */
+  flapThreshold: number;
+}
+
+export type ConfirmedStatus = "unknown" | "up" | "down";
+
+/** url별 확정 상태 (BE ConfirmedState). logs/state/confirmed.json */
+export interface ConfirmedState {
+  url: string;
+  status: ConfirmedStatus;
+  since?: string | null;
+  consecutiveFailures: number;
+  consecutiveSuccesses: number;
+  recent: boolean[];
+  flapping: boolean;
+  lastChecked?: string | null;
+  /** 플래핑으로 억제되지 않은 마지막 변경의 상태 */
+  alertedStatus?: ConfirmedStatus;
+}
+
+/** 확정 상태 변경 (BE StateChange). logs/state/{date}.json */
+export interface StateChange {
+  url: string;
+  from: ConfirmedStatus;
+  to: ConfirmedStatus;
+  flapping: boolean;
+  reason?: string;
+  timestamp: string;
+}
+
+/** 장애 메모 (BE IncidentNote) */
+export interface IncidentNote {
+  text: string;
+  timestamp: string;
+}
+
+/** down 확정 시 열리고 up 확정 시 해결되는 장애 (BE Incident). logs/incidents.json */
+export interface Incident {
+  id: number;
+  domainId: number;
+  url: string;
+  groups: string[];
+  startedAt: string;
+  firstError: string;
+  resolvedAt?: string | null;
+  durationSecs?: number | null;
+  acknowledgedAt?: string | null;
+  notes: IncidentNote[];
+}
+
+/** 도메인(url) 또는 그룹 하나의 장애 통계 (BE IncidentStats) */
+export interface IncidentStats {
+  key: string;
+  incidentCount: number;
+  openCount: number;
+  totalDowntimeSecs: number;
+  /** 평균 복구 시간 (초) */
+  mttrSecs?: number | null;
+  /** 평균 장애 간격 (초) */
+  mtbfSecs?: number | null;
+}
+
+export interface IncidentStatsReport {
+  byDomain: IncidentStats[];
+  byGroup: IncidentStats[];
+}
+
+export type BucketSize = "minute" | "hour" | "day";
+
+/** 가용성·지연 시간 요약 (BE UptimeStats). 백분위수는 히스토그램 구간 상한 근사값 */
+export interface UptimeStats {
+  totalChecks: number;
+  upChecks: number;
+  uptimePercent?: number | null;
+  avgLatency?: number | null;
+  p50Latency?: number | null;
+  p95Latency?: number | null;
+  p99Latency?: number | null;
+  maxLatency?: number | null;
+}
+
+/** 차트용 시계열 한 점 (BE UptimePoint) */
+export interface UptimePoint extends UptimeStats {
+  start: string;
+}
+
+/** 기간별 가용성 리포트 (BE UptimeReport) */
+export interface UptimeReport {
+  from: string;
+  to: string;
+  bucket: BucketSize;
+  urls: string[];
+  summary: UptimeStats;
+  points: UptimePoint[];
+}
+
+/** 도메인별 인증서 체크 설정 (BE CertCheckConfig). 없으면 warning 30일, error 7일 */
+export interface CertCheckConfig {
+  enabled: boolean;
+  warningDays: number;
+  errorDays: number;
+}
+
+/** https 도메인의 리프 인증서 체크 결과 (BE CertCheckResult). 과거는 logs/certs/{date}.json */
+export interface CertCheckResult {
+  url: string;
+  host: string;
+  port: number;
+  timestamp: string;
+  level: string;
+  ok: boolean;
+  notAfter?: string | null;
+  /** 만료까지 남은 일수. 이미 만료면 음수 */
+  daysRemaining?: number | null;
+  issuer?: string | null;
+  subject?: string | null;
+  sans: string[];
+  chainLength: number;
+  chainValid: boolean;
+  hostnameMatch: boolean;
+  errorMessage?: string | null;
+}
+
+export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS";
+
+/** 도메인별 DNS 감시 설정 (BE DnsCheckConfig). 없으면 감시하지 않음 */
+export interface DnsCheckConfig {
+  enabled: boolean;
+  recordTypes: DnsRecordType[];
+  /** 시스템 리졸버·프록시 DNS 서버 외에 비교할 리졸버 ("8.8.8.8", "10.0.0.2:53") */
+  resolvers: string[];
+}
+
+export interface DnsAnswer {
+  recordType: DnsRecordType;
+  status: "ok" | "noRecords" | "nxdomain" | "servfail" | "error";
+  values: string[];
+  errorMessage?: string;
+}
+
+/** 리졸버 하나로 조회한 레코드 스냅샷 (BE DnsSnapshot) */
+export interface DnsSnapshot {
+  url: string;
+  host: string;
+  /** "system" 또는 "ip:port" */
+  resolver: string;
+  timestamp: string;
+  answers: DnsAnswer[];
+}
+
+/** DNS 변경·이상 이벤트 (BE DnsEvent). logs/dns/{date}.json */
+export interface DnsEvent {
+  url: string;
+  host: string;
+  kind: "changed" | "nxdomain" | "servfail" | "mismatch";
+  recordType: DnsRecordType;
+  resolver: string;
+  level: string;
+  previous: string[];
+  current: string[];
+  message: string;
+  timestamp: string;
 }
 
 /** monitor 체크 대상 + url (BE DomainMonitorWithUrl) */
@@ -16,4 +290,14 @@ export interface DomainMonitorWithUrl {
   url: string;
   checkEnabled: boolean;
   intervalSecs: number;
+  httpCheck?: HttpCheck | null;
+  probe?: ProbeOptions | null;
+  certCheck?: CertCheckConfig | null;
+  dnsCheck?: DnsCheckConfig | null;
+  tcpCheck?: TcpCheck | null;
+  heartbeat?: HeartbeatCheck | null;
+  contentWatch?: ContentWatchConfig | null;
+  securityAudit?: SecurityAuditConfig | null;
+  openapiCheck?: OpenApiCheckConfig | null;
+  policy?: CheckPolicy | null;
 }
@@ -0,0 +1,40 @@
+/** `heartbeat://` 대상의 설정 (BE HeartbeatCheck). 작업이 프록시의 `/.watchtower/heartbeat/{token}`을 호출 */
+export interface HeartbeatCheck {
+  /** 비워서 저장하면 BE가 새로 발급 */
+  token: string;
+  /** 핑 예상 주기 (초) */
+  periodSecs: number;
+  /** 주기를 넘겨도 down으로 보지 않는 여유 (초) */
+  graceSecs: number;
+}
+
+/** 작업 실행 한 번 (success·fail 핑 기준) */
+export interface HeartbeatRun {
+  startedAt?: string;
+  finishedAt: string;
+  success: boolean;
+  durationMs?: number;
+}
+
+/** 토큰별 핑 수신 상태 (BE HeartbeatState) */
+export interface HeartbeatState {
+  token: string;
+  /** 감시 시작 시각. 첫 핑 전에는 여기서부터 주기를 셈 */
+  since: string;
+  lastPing?: string;
+  lastFinished?: string;
+  /** start 핑 후 끝나지 않은 실행 */
+  runningSince?: string;
+  /** 최근 실행 (오래된 것부터) */
+  runs: HeartbeatRun[];
+}
+
+/** 하트비트 도메인 + 핑 경로 + 수신 상태 (BE HeartbeatStatus) */
+export interface HeartbeatStatus {
+  domainId: number;
+  url: string;
+  /** 프록시 기준 경로. 뒤에 `/start`, `/success`, `/fail`을 붙일 수 있음 */
+  pingPath: string;
+  check: HeartbeatCheck;
+  state?: HeartbeatState;
+}
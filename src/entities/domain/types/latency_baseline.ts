@@ -0,0 +1,25 @@
+/** 하루 중 한 시간대의 지연 시간 기준선 (BE HourBaseline, EWMA) */
+export interface HourBaseline {
+  meanMs: number;
+  variance: number;
+  samples: number;
+}
+
+/** 도메인의 현재 시간대 기준선과 마지막 체크의 이상 점수 (BE LatencyBaselineReport) */
+export interface LatencyBaselineReport {
+  domainId: number;
+  url: string;
+  /** 현재 로컬 시각의 시간 (0~23) */
+  hour: number;
+  /** 체크가 부족하면 없음 */
+  meanMs?: number;
+  stddevMs?: number;
+  samples: number;
+  lastLatency?: number;
+  /** 평균에서 표준편차 몇 배만큼 느린지 */
+  score?: number;
+  degraded: boolean;
+  /** 0~23시 기준선 */
+  hours: HourBaseline[];
+  updatedAt?: string;
+}
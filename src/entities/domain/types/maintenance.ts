@@ -0,0 +1,35 @@
+/**
+ * 점검 시간 일정 (BE MaintenanceSchedule). weekly·cron은 로컬 시각 기준.
+ * weekly `days`: 0=일요일 … 6=토요일. cron: "분 시 일 월 요일" 5필드
+ */
+export type MaintenanceSchedule =
+  | { type: "once"; start: string; end: string }
+  | { type: "weekly"; days: number[]; startTime: string; durationMins: number }
+  | { type: "cron"; expression: string; durationMins: number };
+
+/** 점검 시간 (BE MaintenanceWindow). id 0이면 저장 시 새 id 부여 */
+export interface MaintenanceWindow {
+  id: number;
+  name: string;
+  enabled: boolean;
+  /** 대상 도메인 (groupIds와 합집합) */
+  domainIds: number[];
+  groupIds: number[];
+  schedule: MaintenanceSchedule;
+  /** 가용성 롤업에서 이 시간의 체크를 뺌 */
+  excludeFromUptime: boolean;
+}
+
+/** 점검 시간 중 체크에 붙는 표시 (DomainStatusLog.maintenance) */
+export interface MaintenanceMark {
+  windowId: number;
+  excludeFromUptime?: boolean;
+}
+
+/** 지금 점검 중인 도메인 (BE ActiveMaintenance) */
+export interface ActiveMaintenance {
+  domainId: number;
+  windowId: number;
+  windowName: string;
+  endsAt: string;
+}
@@ -0,0 +1,58 @@
+/** 도메인별 OpenAPI 엔드포인트 체크 설정 (BE OpenApiCheckConfig). 없으면 체크하지 않음 */
+export interface OpenApiCheckConfig {
+  enabled: boolean;
+  /** 1~1440분 */
+  intervalMins: number;
+  /** 요청 기준 url. 없으면 스키마의 servers (Swagger 2는 host·basePath), 그것도 없으면 도메인 url */
+  baseUrl?: string | null;
+  /** 도메인과 출처가 다른 url에도 도메인 자격 증명(추가 헤더·인증·클라이언트 인증서)을 보낼지. 기본은 같은 출처에만 */
+  allowCrossOriginCredentials?: boolean;
+}
+
+/** 오퍼레이션 하나의 체크 결과 (BE OperationCheckResult). 과거 결과는 logs/openapi/{date}.json */
+export interface OperationCheckResult {
+  domainId: number;
+  /** "GET /users/{id}" */
+  operation: string;
+  operationId?: string;
+  /** 예시 값을 넣어 실제로 요청한 url */
+  url: string;
+  /** 도메인 자격 증명을 붙여 요청했는지 */
+  withCredentials: boolean;
+  timestamp: string;
+  status?: number;
+  /** 스키마에 선언된 응답 코드 ("200", "2XX", "default" …) */
+  expectedStatuses: string[];
+  latencyMs?: number;
+  /** 응답 바디를 선언된 스키마로 검증했는지 */
+  schemaChecked: boolean;
+  /** 요청 실패·선언되지 않은 응답 코드·바디 스키마 위반 ("$.items[0].id: expected integer, got string") */
+  errors: string[];
+  level: "info" | "warning" | "error";
+}
+
+/** 체크하지 않은 오퍼레이션과 이유 (BE SkippedOperation) */
+export interface SkippedOperation {
+  operation: string;
+  reason: string;
+}
+
+/** 도메인별 마지막 실행 (BE OpenApiCheckState) */
+export interface OpenApiCheckState {
+  domainId: number;
+  lastRun: string;
+  /** 스키마가 없거나 읽지 못한 경우 */
+  errorMessage?: string;
+  results: OperationCheckResult[];
+  skipped: SkippedOperation[];
+}
+
+/** get_openapi_checks 항목 (BE OpenApiCheckStatus) */
+export interface OpenApiCheckStatus {
+  domainId: number;
+  url: string;
+  config: OpenApiCheckConfig;
+  /** schemas/{domainId}.json이 있는지 (download_api_schema로 저장) */
+  schemaSaved: boolean;
+  state?: OpenApiCheckState;
+}
@@ -0,0 +1,54 @@
+/** 도메인별 보안 헤더·TLS 점검 설정 (BE SecurityAuditConfig). 없으면 하루에 한 번 점검 */
+export interface SecurityAuditConfig {
+  enabled: boolean;
+  /** 1~168시간 */
+  intervalHours: number;
+}
+
+/** 규칙 id (BE SecurityFinding.rule) */
+export type SecurityRule =
+  | "httpsRedirect"
+  | "hsts"
+  | "csp"
+  | "contentTypeOptions"
+  | "referrerPolicy"
+  | "cookies"
+  | "legacyTls"
+  | "tls13";
+
+/** 규칙 하나의 판정 (BE SecurityFinding) */
+export interface SecurityFinding {
+  rule: SecurityRule;
+  passed: boolean;
+  level: "info" | "warning" | "error";
+  points: number;
+  maxPoints: number;
+  /** 본 값과 판정 이유 */
+  detail: string;
+}
+
+/** 직전 정상 점검보다 점수가 떨어진 규칙 (BE SecurityRegression) */
+export interface SecurityRegression {
+  rule: SecurityRule;
+  previousPoints: number;
+  points: number;
+  previousDetail: string;
+  detail: string;
+}
+
+/** 보안 점검 한 번의 결과 (BE SecurityAuditResult). 과거 결과는 logs/security/{date}.json */
+export interface SecurityAuditResult {
+  domainId: number;
+  url: string;
+  timestamp: string;
+  /** 0~100 */
+  score: number;
+  grade: "A" | "B" | "C" | "D" | "F";
+  level: "info" | "warning" | "error";
+  findings: SecurityFinding[];
+  /** 핸드셰이크에 성공한 버전 ("TLS 1.0" ~ "TLS 1.3") */
+  tlsVersions: string[];
+  previousScore?: number;
+  regressions: SecurityRegression[];
+  errorMessage?: string;
+}
@@ -0,0 +1,49 @@
+/** 상태 페이지에 보일 도메인과 표시 이름 (url은 페이지에 나가지 않음) */
+export interface StatusPageComponent {
+  domainId: number;
+  displayName: string;
+}
+
+/** 공개 상태 페이지 설정 (BE StatusPageConfig) */
+export interface StatusPageConfig {
+  enabled: boolean;
+  title: string;
+  /** 페이지 구역 (순서대로). 각 그룹에서 components에 있는 도메인만 보임 */
+  groupIds: number[];
+  components: StatusPageComponent[];
+  /** index.html·status.json을 쓸 폴더. 없으면 데이터 폴더의 status_page/ */
+  outputDir?: string;
+}
+
+export type ComponentStatus = "operational" | "unknown" | "maintenance" | "degraded" | "down";
+
+export interface StatusPageDay {
+  /** 로컬 날짜 YYYY-MM-DD */
+  date: string;
+  uptimePercent: number | null;
+}
+
+export interface StatusPageComponentState {
+  name: string;
+  status: ComponentStatus;
+  /** 최근 90일 가용성 */
+  uptimePercent: number | null;
+  days: StatusPageDay[];
+}
+
+export interface StatusPageIncident {
+  component: string;
+  startedAt: string;
+  resolvedAt?: string;
+  durationSecs?: number;
+}
+
+/** 상태 페이지 내용 (BE StatusPage, `status.json`과 같은 구조) */
+export interface StatusPage {
+  title: string;
+  generatedAt: string;
+  status: ComponentStatus;
+  groups: { name: string; components: StatusPageComponentState[] }[];
+  /** 최근 장애 (최신순) */
+  incidents: StatusPageIncident[];
+}
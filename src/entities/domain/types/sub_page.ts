@@ -0,0 +1,62 @@
+/** 하위 페이지를 처음 찾은 경로 */
+export type SubPageSource = "sitemap" | "link";
+
+/** 도메인 하위 페이지 (BE SubPage, sub_pages.json). url은 도메인 출처 + path */
+export interface SubPage {
+  id: number;
+  domainId: number;
+  /** `/`로 시작하는 경로 (쿼리 포함 가능) */
+  path: string;
+  /** 경로상 가장 가까운 상위 페이지. 없으면 도메인 바로 아래 */
+  parentId?: number;
+  title?: string;
+  source: SubPageSource;
+  /** 마지막 크롤링에서 받은 HTTP 상태 */
+  lastStatus?: number;
+  discoveredAt: string;
+  /** 마지막으로 크롤링에서 발견한 시각 */
+  lastSeen: string;
+}
+
+/** 트리 표시용 (BE SubPageNode) */
+export interface SubPageNode extends SubPage {
+  url: string;
+  /** 소속 도메인의 체크 주기에 함께 체크하는지 */
+  checkEnabled: boolean;
+  children: SubPageNode[];
+}
+
+/** 크롤링 범위 (BE CrawlOptions) */
+export interface CrawlOptions {
+  /** 기본 3 */
+  maxDepth: number;
+  /** 루트 포함, 기본 200 */
+  maxPages: number;
+  /** robots.txt `User-agent: *` 규칙 준수 (기본 true) */
+  respectRobots: boolean;
+}
+
+/** 4xx/5xx 또는 요청 실패한 같은 출처 링크 */
+export interface BrokenLink {
+  url: string;
+  status?: number;
+  error?: string;
+  /** 링크가 있던 페이지 (sitemap에만 있으면 비어 있음) */
+  foundOn: string[];
+}
+
+/** 도메인의 마지막 크롤링 결과 (BE CrawlReport, logs/crawl/{domainId}.json) */
+export interface CrawlReport {
+  domainId: number;
+  rootUrl: string;
+  options: CrawlOptions;
+  startedAt: string;
+  finishedAt: string;
+  pagesCrawled: number;
+  pagesAdded: number;
+  sitemapUrls: number;
+  disallowed: number;
+  /** maxPages에 걸려 요청하지 못한 url이 있는지 */
+  truncated: boolean;
+  brokenLinks: BrokenLink[];
+}
@@ -27,6 +27,29 @@ export interface ProxySettings {
   reverse_https_port?: number | null;
   /** When true, local routes are applied; when false, all traffic passes through. */
   local_routing_enabled: boolean;
+  /** Seconds between route backend health checks (5–3600). null = only on demand. */
+  route_health_interval_secs?: number | null;
+}
+
+/** 로컬 라우트 백엔드 체크 결과 (BE RouteHealth). 대상은 프록시가 실제로 보낼 곳 */
+export interface RouteHealth {
+  routeId: number;
+  domain: string;
+  /** 요청에 보낸 Host 헤더 */
+  host: string;
+  /** 체크한 host:port */
+  target: string;
+  /** 로컬 라우팅이 꺼져 있어 프록시가 이 라우트를 쓰지 않음 */
+  passThrough: boolean;
+  /** 같은 호스트의 다른 라우트가 대신 매칭됨 */
+  shadowedBy?: number;
+  portOpen: boolean;
+  connectMs?: number;
+  status?: number;
+  latencyMs?: number;
+  level: "info" | "warning" | "error";
+  error?: string;
+  checkedAt: string;
 }
 
 /** 도메인별 API 로깅 설정 링크. */
@@ -1,8 +1,33 @@
-/** Monitor settings per domain (check_enabled, interval). Status logs are excluded. */
+import type { ContentWatchConfig } from "@/entities/domain/types/content_watch";
+import type {
+  CertCheckConfig,
+  CheckPolicy,
+  DnsCheckConfig,
+  HttpCheck,
+  ProbeOptions,
+  TcpCheck,
+} from "@/entities/domain/types/domain_monitor";
+import type { HeartbeatCheck } from "@/entities/domain/types/heartbeat";
+import type { MaintenanceWindow } from "@/entities/domain/types/maintenance";
+import type { OpenApiCheckConfig } from "@/entities/domain/types/openapi_check";
+import type { SecurityAuditConfig } from "@/entities/domain/types/security_audit";
+import type { StatusPageConfig } from "@/entities/domain/types/status_page";
+
+/** Monitor settings per domain (check_enabled, interval, http check, cert thresholds, dns watch). Status logs are excluded. */
 export interface DomainMonitorExport {
   url: string;
   checkEnabled: boolean;
   intervalSecs: number;
+  httpCheck?: HttpCheck;
+  probe?: ProbeOptions;
+  certCheck?: CertCheckConfig;
+  dnsCheck?: DnsCheckConfig;
+  tcpCheck?: TcpCheck;
+  heartbeat?: HeartbeatCheck;
+  contentWatch?: ContentWatchConfig;
+  securityAudit?: SecurityAuditConfig;
+  openapiCheck?: OpenApiCheckConfig;
+  policy?: CheckPolicy;
 }
 
 /** Full app settings export payload (matches Rust SettingsExport). */
@@ -24,9 +49,13 @@ export interface SettingsExport {
     proxy_port: number;
     reverse_http_port?: number | null;
     reverse_https_port?: number | null;
+    route_health_interval_secs?: number | null;
   };
   /** Monitor settings per domain. Status logs are excluded from export. */
   domainMonitor?: DomainMonitorExport[];
   /** @deprecated Use domainMonitor. Backward compat for old exports. */
   domainStatus?: DomainMonitorExport[];
+  /** Maintenance windows (domain/group ids refer to domains/groups above). */
+  maintenanceWindows?: MaintenanceWindow[];
+  statusPage?: StatusPageConfig;
 }
@@ -142,7 +142,7 @@ export function VirtualizedGroupSection({ group, apps }: VirtualizedGroupSection
                                     "px-3 py-1 rounded-full text-[10px] font-black uppercase tracking-widest border transition-all",
                                     app.level === "error"
                                       ? "bg-error/10 border-error/20 text-error shadow-lg shadow-error/5"
-                                      : app.level === "warning"
+                                      : app.level === "warning" || app.level === "degraded"
                                         ? "bg-warning/10 border-warning/20 text-warning shadow-lg shadow-warning/5"
                                         : "bg-success/10 border-success/20 text-success shadow-lg shadow-success/5",
                                   )}
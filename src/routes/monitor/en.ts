@@ -6,6 +6,7 @@ export const en = {
   copyReport: "Copy Report",
   healthy: "Healthy",
   warnings: "Warnings",
+  degraded: "Degraded",
   critical: "Critical",
   latencyAvg: "Latency avg",
   filterPlaceholder: "Filter by domain name...",
@@ -77,7 +77,7 @@ function MonitorIndex() {
 
   const stats = useMemo(() => {
     const healthy = siteCheck.filter((s) => s.level === "info").length;
-    const warnings = siteCheck.filter((s) => s.level === "warning").length;
+    const warnings = siteCheck.filter((s) => s.level === "warning" || s.level === "degraded").length;
     const errors = siteCheck.filter((s) => s.level === "error").length;
     const avgLatency =
       siteCheck.length > 0
@@ -242,6 +242,7 @@ function MonitorIndex() {
             {[
               { id: "info", label: t.healthy, color: "success" },
               { id: "warning", label: t.warnings, color: "warning" },
+              { id: "degraded", label: t.degraded, color: "warning" },
               { id: "error", label: t.critical, color: "error" },
             ].map((l) => (
               <button
@@ -255,7 +256,7 @@ function MonitorIndex() {
                   filterLevel.includes(l.id)
                     ? {
                         "bg-success/10 text-success border-success/20": l.id === "info",
-                        "bg-warning/10 text-warning border-warning/20": l.id === "warning",
+                        "bg-warning/10 text-warning border-warning/20": l.id === "warning" || l.id === "degraded",
                         "bg-error/10 text-error border-error/20": l.id === "error",
                       }
                     : "bg-base-200 text-base-content/40 border-transparent hover:bg-base-300",
@@ -12,6 +12,7 @@ export const ko: TranslationSchema = {
   copyReport: "보고서 복사",
   healthy: "정상",
   warnings: "경고",
+  degraded: "지연",
   critical: "치명적",
   latencyAvg: "평균 지연 시간",
   filterPlaceholder: "도메인 이름으로 필터링...",
@@ -21,5 +21,6 @@ export const en = {
   openUrl: "Open URL",
   levelInfo: "Info",
   levelWarning: "Warning",
+  levelDegraded: "Degraded",
   levelError: "Error",
 } as const;
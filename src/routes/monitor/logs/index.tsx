@@ -46,6 +46,7 @@ function MonitorLogs() {
   const LEVELS = [
     { id: "info", label: t.levelInfo },
     { id: "warning", label: t.levelWarning },
+    { id: "degraded", label: t.levelDegraded },
     { id: "error", label: t.levelError },
   ] as const;
 
@@ -174,7 +175,7 @@ function MonitorLogs() {
                 levelFilter.includes(id)
                   ? id === "error"
                     ? "bg-red-600 hover:bg-red-700"
-                    : id === "warning"
+                    : id === "warning" || id === "degraded"
                       ? "bg-amber-600 hover:bg-amber-700"
                       : id === "info"
                         ? "bg-green-600 hover:bg-green-700"
@@ -273,7 +274,7 @@ function MonitorLogs() {
                     <div className="flex justify-center w-[100px] text-center">
                       <Badge
                         variant={{
-                          color: log.level === "error" ? "red" : log.level === "warning" ? "amber" : "green",
+                          color: log.level === "error" ? "red" : log.level === "info" ? "green" : "amber",
                         }}
                       >
                         {log.level.toUpperCase()}
@@ -329,7 +330,7 @@ function MonitorLogs() {
               <span className="text-[10px] font-bold uppercase tracking-wider text-slate-400">{t.tableLevel}</span>
               <Badge
                 variant={{
-                  color: selectedLog?.level === "error" ? "red" : selectedLog?.level === "warning" ? "amber" : "green",
+                  color: selectedLog?.level === "error" ? "red" : selectedLog?.level === "info" ? "green" : "amber",
                 }}
               >
                 {selectedLog?.level?.toUpperCase() || ""}
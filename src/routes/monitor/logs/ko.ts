@@ -27,5 +27,6 @@ export const ko: TranslationSchema = {
   openUrl: "URL 열기",
   levelInfo: "정보",
   levelWarning: "경고",
+  levelDegraded: "지연",
   levelError: "오류",
 };
@@ -31,4 +31,12 @@ export const en = {
   add: "Add",
   routes: (count: number) => `Routes (${count})`,
   noRoutesYet: "No routes yet. Add a domain and target above.",
+  checkNow: "Check now",
+  autoCheck: "Auto check",
+  autoCheckOff: "Off",
+  everySecs: (secs: number) => (secs < 60 ? `Every ${secs}s` : `Every ${secs / 60}m`),
+  healthUp: (ms?: number) => (ms != null ? `Up · ${ms}ms` : "Up"),
+  healthDown: "Down",
+  healthUnchecked: "Not checked",
+  healthPortClosed: "Port closed",
 } as const;
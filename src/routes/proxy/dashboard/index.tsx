@@ -1,11 +1,11 @@
 import { createFileRoute, useNavigate } from "@tanstack/react-router";
 import { listen } from "@tauri-apps/api/event";
 import { useAtom, useAtomValue } from "jotai";
-import { AlertCircle, Globe, Loader2Icon, Play, Plus, Server, Trash2, XCircle } from "lucide-react";
+import { AlertCircle, Globe, Loader2Icon, Play, Plus, RefreshCw, Server, Trash2, XCircle } from "lucide-react";
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { globalDomainsAtom, globalLocalRoutesAtom } from "@/domain/global-data/store";
 import { languageAtom } from "@/domain/i18n/store";
-import type { ProxySettings, ProxyStatusPayload } from "@/entities/proxy/types/local_route";
+import type { ProxySettings, ProxyStatusPayload, RouteHealth } from "@/entities/proxy/types/local_route";
 import { invokeApi } from "@/shared/api";
 import { Badge } from "@/shared/ui/badge/badge";
 import { Button } from "@/shared/ui/button/Button";
@@ -29,6 +29,9 @@ export const Route = createFileRoute("/proxy/dashboard/")({
   component: ProxyPage,
 });
 
+const ROUTE_HEALTH_INTERVALS = [30, 60, 300, 900];
+const HEALTH_COLOR = { info: "green", warning: "amber", error: "red" } as const;
+
 function ProxyPage() {
   const lang = useAtomValue(languageAtom);
   const t = lang === "ko" ? ko : en;
@@ -53,6 +56,23 @@ function ProxyPage() {
   const [proxyPortSaving, setProxyPortSaving] = useState(false);
   const [reverseHttpInput, setReverseHttpInput] = useAtom(proxyReverseHttpPortInputAtom);
   const [reverseHttpsInput, setReverseHttpsInput] = useAtom(proxyReverseHttpsPortInputAtom);
+  const [routeHealth, setRouteHealth] = useState<Record<number, RouteHealth>>({});
+  const [healthChecking, setHealthChecking] = useState(false);
+
+  const applyRouteHealth = useCallback((list: RouteHealth[]) => {
+    setRouteHealth(Object.fromEntries(list.map((h) => [h.routeId, h])));
+  }, []);
+
+  const fetchRouteHealth = useCallback(async () => {
+    try {
+      const res = await invokeApi("get_route_health");
+      if (res.success) {
+        applyRouteHealth(res.data ?? []);
+      }
+    } catch (e) {
+      console.error("get_route_health:", e);
+    }
+  }, [applyRouteHealth]);
 
   const fetchRoutes = useCallback(async () => {
     try {
@@ -115,6 +135,13 @@ function ProxyPage() {
     fetchRoutes();
   }, [fetchRoutes]);
 
+  // 백그라운드 체크 결과를 주기적으로 반영
+  useEffect(() => {
+    fetchRouteHealth();
+    const id = setInterval(fetchRouteHealth, 15_000);
+    return () => clearInterval(id);
+  }, [fetchRouteHealth]);
+
   useEffect(() => {
     fetchDomains();
   }, [fetchDomains]);
@@ -286,6 +313,52 @@ function ProxyPage() {
     }
   };
 
+  const handleCheckRoutes = async () => {
+    setHealthChecking(true);
+    try {
+      const res = await invokeApi("check_local_routes");
+      if (res.success) {
+        applyRouteHealth(res.data ?? []);
+      }
+    } catch (e) {
+      console.error("check_local_routes:", e);
+    } finally {
+      setHealthChecking(false);
+    }
+  };
+
+  const handleHealthIntervalChange = async (value: string) => {
+    try {
+      const res = await invokeApi("set_route_health_interval", {
+        payload: { intervalSecs: value === "" ? null : Number(value) },
+      });
+      if (res.success && res.data) {
+        setProxySettings(res.data);
+      }
+    } catch (e) {
+      console.error("set_route_health_interval:", e);
+    }
+  };
+
+  const healthBadge = (h: RouteHealth | undefined) => {
+    if (!h) {
+      return <Badge variant={{ color: "gray" }}>{t.healthUnchecked}</Badge>;
+    }
+    const label = !h.portOpen
+      ? t.healthPortClosed
+      : h.status == null
+        ? t.healthDown
+        : h.level === "info"
+          ? t.healthUp(h.latencyMs)
+          : `${h.status} · ${h.latencyMs ?? 0}ms`;
+    const detail = [`Host: ${h.host} → ${h.target}`, h.error].filter(Boolean).join("\n");
+    return (
+      <Badge variant={{ color: HEALTH_COLOR[h.level] }} className="cursor-help">
+        <span title={detail}>{label}</span>
+      </Badge>
+    );
+  };
+
   const handleRemove = async (id: number) => {
     try {
       await invokeApi("remove_local_route", { payload: { id } });
@@ -519,10 +592,39 @@ function ProxyPage() {
       </Card>
 
       <Card className="p-4 md:p-6 flex flex-col">
-        <h2 className="font-bold text-base-content mb-4 flex items-center gap-2">
-          <Globe className="w-4 h-4" />
-          {t.routes(routes.length)}
-        </h2>
+        <div className="mb-4 flex flex-wrap items-center gap-2">
+          <h2 className="font-bold text-base-content flex items-center gap-2">
+            <Globe className="w-4 h-4" />
+            {t.routes(routes.length)}
+          </h2>
+          <div className="ml-auto flex items-center gap-2">
+            <label htmlFor="proxy-route-health-interval" className="text-xs font-medium text-base-content/50">
+              {t.autoCheck}
+            </label>
+            <select
+              id="proxy-route-health-interval"
+              className="bg-base-100 border border-base-300 rounded-lg px-2 py-1 text-xs focus:ring-2 focus:ring-primary focus:border-transparent outline-none cursor-pointer text-base-content"
+              value={proxySettings?.route_health_interval_secs ?? ""}
+              onChange={(e) => handleHealthIntervalChange(e.target.value)}
+            >
+              <option value="">{t.autoCheckOff}</option>
+              {ROUTE_HEALTH_INTERVALS.map((secs) => (
+                <option key={secs} value={secs}>
+                  {t.everySecs(secs)}
+                </option>
+              ))}
+            </select>
+            <Button
+              variant="secondary"
+              size="sm"
+              className="gap-2 flex items-center"
+              onClick={handleCheckRoutes}
+              disabled={healthChecking || routes.length === 0}
+            >
+              <RefreshCw className={`w-4 h-4 ${healthChecking ? "animate-spin" : ""}`} /> {t.checkNow}
+            </Button>
+          </div>
+        </div>
         {loading ? (
           <div className="flex justify-center py-8">
             <Loader2Icon className="w-8 h-8 text-primary animate-spin" />
@@ -546,6 +648,7 @@ function ProxyPage() {
                 <span className="text-sm text-base-content/60">
                   {r.target_host}:{r.target_port}
                 </span>
+                {r.enabled && healthBadge(routeHealth[r.id])}
                 <button type="button" onClick={() => handleToggleEnabled(r.id, !r.enabled)} className="ml-auto">
                   <Badge variant={{ color: r.enabled ? "green" : "gray" }} className="cursor-pointer hover:opacity-80">
                     {r.enabled ? t.on : t.off}
@@ -36,4 +36,12 @@ export const ko: TranslationSchema = {
   add: "추가",
   routes: (count: number) => `경로 (${count})`,
   noRoutesYet: "아직 경로가 없습니다. 위에 도메인과 대상을 추가하세요.",
+  checkNow: "지금 확인",
+  autoCheck: "자동 확인",
+  autoCheckOff: "끔",
+  everySecs: (secs: number) => (secs < 60 ? `${secs}초마다` : `${secs / 60}분마다`),
+  healthUp: (ms?: number) => (ms != null ? `정상 · ${ms}ms` : "정상"),
+  healthDown: "응답 없음",
+  healthUnchecked: "확인 전",
+  healthPortClosed: "포트 닫힘",
 };
@@ -1,6 +1,46 @@
+import type { AlertChannelConfig, AlertConfig, AlertRecord } from "@/entities/domain/types/alert";
+import type {
+  ContentChange,
+  ContentDiff,
+  ContentWatchConfig,
+  ContentWatchStatus,
+} from "@/entities/domain/types/content_watch";
+import type { CheckConcurrency, CheckCycleReport } from "@/entities/domain/types/check_concurrency";
 import type { Domain, DomainGroupLink } from "@/entities/domain/types/domain";
 import type { DomainGroup } from "@/entities/domain/types/domain_group";
-import type { DomainMonitorWithUrl, DomainStatusLog } from "@/entities/domain/types/domain_monitor";
+import type {
+  BucketSize,
+  CertCheckConfig,
+  CertCheckResult,
+  CheckPolicy,
+  ConfirmedState,
+  DnsCheckConfig,
+  DnsEvent,
+  DnsSnapshot,
+  DomainMonitorWithUrl,
+  DomainStatusLog,
+  HttpCheck,
+  Incident,
+  IncidentStatsReport,
+  ProbeOptions,
+  StateChange,
+  TcpCheck,
+  UptimeReport,
+} from "@/entities/domain/types/domain_monitor";
+import type { HeartbeatCheck, HeartbeatStatus } from "@/entities/domain/types/heartbeat";
+import type { LatencyBaselineReport } from "@/entities/domain/types/latency_baseline";
+import type { ActiveMaintenance, MaintenanceWindow } from "@/entities/domain/types/maintenance";
+import type {
+  OpenApiCheckConfig,
+  OpenApiCheckStatus,
+  OperationCheckResult,
+} from "@/entities/domain/types/openapi_check";
+import type {
+  SecurityAuditConfig,
+  SecurityAuditResult,
+} from "@/entities/domain/types/security_audit";
+import type { StatusPage, StatusPageConfig } from "@/entities/domain/types/status_page";
+import type { CrawlOptions, CrawlReport, SubPageNode } from "@/entities/domain/types/sub_page";
 import type {
   ApiLogEntry,
   ApiRequestResult,
@@ -8,6 +48,7 @@ import type {
   LocalRoute,
   ProxySettings,
   ProxyStatusPayload,
+  RouteHealth,
   SchemaDownloadResult,
 } from "@/entities/proxy/types/local_route";
 import type { SettingsExport } from "@/entities/settings/types/settings_export";
@@ -53,6 +94,168 @@ export interface ApiCommandMap {
     request: { payload: { domainIds: number[]; enabled: boolean } };
     response: boolean;
   };
+  set_domain_monitor_interval: {
+    request: { payload: { domainIds: number[]; intervalSecs: number } };
+    response: number;
+  };
+  set_domain_monitor_http_check: {
+    request: { payload: { domainId: number; httpCheck: HttpCheck | null } };
+    response: boolean;
+  };
+  set_domain_probe_options: {
+    request: { payload: { domainId: number; probe: ProbeOptions | null } };
+    response: boolean;
+  };
+  set_domain_tcp_check: {
+    request: { payload: { domainId: number; tcpCheck: TcpCheck | null } };
+    response: boolean;
+  };
+  set_domain_heartbeat: {
+    request: { payload: { domainId: number; heartbeat: HeartbeatCheck | null } };
+    response: HeartbeatCheck | null;
+  };
+  get_heartbeat_status: { request?: undefined; response: HeartbeatStatus[] };
+  get_latency_baselines: { request?: undefined; response: LatencyBaselineReport[] };
+  get_check_cycle_stats: { request?: undefined; response: CheckCycleReport };
+  set_check_concurrency: {
+    request: { payload: CheckConcurrency };
+    response: CheckConcurrency;
+  };
+  set_domain_check_policy: {
+    request: { payload: { domainId: number; policy: CheckPolicy | null } };
+    response: CheckPolicy | null;
+  };
+  get_confirmed_states: { request?: undefined; response: ConfirmedState[] };
+  get_state_changes: {
+    request: { payload: { date: string } };
+    response: StateChange[];
+  };
+  get_incidents: {
+    request: { payload: { domainId?: number; openOnly?: boolean } };
+    response: Incident[];
+  };
+  annotate_incident: {
+    request: { payload: { id: number; text: string } };
+    response: Incident | null;
+  };
+  acknowledge_incident: {
+    request: { payload: { id: number } };
+    response: Incident | null;
+  };
+  get_incident_stats: { request?: undefined; response: IncidentStatsReport };
+  get_uptime_report: {
+    request: {
+      payload: {
+        domainId?: number;
+        groupId?: number;
+        from: string;
+        to: string;
+        bucket: BucketSize;
+      };
+    };
+    response: UptimeReport | null;
+  };
+  get_alert_config: { request?: undefined; response: AlertConfig };
+  set_alert_config: {
+    request: { payload: { config: AlertConfig } };
+    response: AlertConfig;
+  };
+  get_alert_history: {
+    request: { payload: { limit?: number } };
+    response: AlertRecord[];
+  };
+  send_test_alert: {
+    request: { payload: { channel: AlertChannelConfig } };
+    response: boolean;
+  };
+  get_maintenance_windows: { request?: undefined; response: MaintenanceWindow[] };
+  set_maintenance_windows: {
+    request: { payload: { windows: MaintenanceWindow[] } };
+    response: MaintenanceWindow[];
+  };
+  get_active_maintenance: { request?: undefined; response: ActiveMaintenance[] };
+  get_status_page_config: { request?: undefined; response: StatusPageConfig };
+  set_status_page_config: {
+    request: { payload: { config: StatusPageConfig } };
+    response: StatusPageConfig;
+  };
+  preview_status_page: { request?: undefined; response: StatusPage };
+  set_domain_cert_check: {
+    request: { payload: { domainId: number; certCheck: CertCheckConfig | null } };
+    response: boolean;
+  };
+  get_latest_cert_checks: { request?: undefined; response: CertCheckResult[] };
+  check_domain_certificates: { request?: undefined; response: CertCheckResult[] };
+  get_cert_check_logs: {
+    request: { payload: { date: string } };
+    response: CertCheckResult[];
+  };
+  set_domain_security_audit: {
+    request: { payload: { domainId: number; securityAudit: SecurityAuditConfig | null } };
+    response: boolean;
+  };
+  get_security_audits: { request?: undefined; response: SecurityAuditResult[] };
+  audit_domain_security: {
+    request: { payload: { domainId?: number } };
+    response: SecurityAuditResult[];
+  };
+  get_security_audit_logs: {
+    request: { payload: { date: string } };
+    response: SecurityAuditResult[];
+  };
+  set_domain_openapi_check: {
+    request: { payload: { domainId: number; openapiCheck: OpenApiCheckConfig | null } };
+    response: boolean;
+  };
+  get_openapi_checks: { request?: undefined; response: OpenApiCheckStatus[] };
+  run_openapi_checks: {
+    request: { payload: { domainId?: number } };
+    response: OperationCheckResult[];
+  };
+  get_openapi_check_logs: {
+    request: { payload: { date: string } };
+    response: OperationCheckResult[];
+  };
+  set_domain_dns_check: {
+    request: { payload: { domainId: number; dnsCheck: DnsCheckConfig | null } };
+    response: boolean;
+  };
+  get_dns_snapshots: { request?: undefined; response: DnsSnapshot[] };
+  check_domain_dns: { request?: undefined; response: DnsSnapshot[] };
+  get_dns_events: {
+    request: { payload: { date: string } };
+    response: DnsEvent[];
+  };
+  set_domain_content_watch: {
+    request: { payload: { domainId: number; contentWatch: ContentWatchConfig | null } };
+    response: boolean;
+  };
+  get_content_watch_status: { request?: undefined; response: ContentWatchStatus[] };
+  check_domain_content: { request?: undefined; response: ContentChange[] };
+  get_content_changes: {
+    request: { payload: { date: string } };
+    response: ContentChange[];
+  };
+  get_content_diff: {
+    request: { payload: { domainId: number; from?: string; to?: string } };
+    response: ContentDiff | null;
+  };
+  crawl_domain: {
+    request: { payload: { domainId: number; options?: CrawlOptions } };
+    response: CrawlReport | null;
+  };
+  get_sub_pages: {
+    request: { payload: { domainId: number } };
+    response: SubPageNode[];
+  };
+  set_sub_page_monitor_check_enabled: {
+    request: { payload: { subPageIds: number[]; enabled: boolean } };
+    response: number;
+  };
+  get_crawl_report: {
+    request: { payload: { domainId: number } };
+    response: CrawlReport | null;
+  };
 
   get_domain_group_links: { request?: undefined; response: DomainGroupLink[] };
   set_domain_groups: {
@@ -86,6 +289,12 @@ export interface ApiCommandMap {
   };
 
   get_local_routes: { request?: undefined; response: LocalRoute[] };
+  get_route_health: { request?: undefined; response: RouteHealth[] };
+  check_local_routes: { request?: undefined; response: RouteHealth[] };
+  set_route_health_interval: {
+    request: { payload: { intervalSecs: number | null } };
+    response: ProxySettings;
+  };
   add_local_route: {
     request: {
       payload: {